@@ -0,0 +1,42 @@
+#!/usr/bin/env bash
+# Socket-level smoke test for `normcore serve` (the unit suite drives the
+# HTTP handlers in memory and never binds a port).
+set -euo pipefail
+
+ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+PORT="${PORT:-18080}"
+BASE_URL="http://127.0.0.1:${PORT}"
+
+log() {
+  printf '[%s] %s\n' "$(date +"%H:%M:%S")" "$*"
+}
+
+if ! command -v curl >/dev/null 2>&1; then
+  echo "ERROR: curl command not found in PATH." >&2
+  exit 127
+fi
+
+cd "$ROOT_DIR"
+UV_CACHE_DIR=.uv-cache uv run normcore serve --port "$PORT" --metrics &
+SERVER_PID=$!
+trap 'kill "$SERVER_PID" 2>/dev/null || true; wait "$SERVER_PID" 2>/dev/null || true' EXIT
+
+for _ in $(seq 1 50); do
+  curl -fsS "$BASE_URL/healthz" >/dev/null 2>&1 && break
+  sleep 0.2
+done
+
+log "GET /healthz"
+curl -fsS "$BASE_URL/healthz"
+echo
+
+log "POST /evaluate"
+RECORD="$(curl -fsS -H "Content-Type: application/json" \
+  -d '{"id": "smoke-1", "agent_output": "We should deploy now."}' "$BASE_URL/evaluate")"
+echo "$RECORD"
+grep -q '"status": "violates_norm"' <<<"$RECORD" || { echo "ERROR: unexpected judgment" >&2; exit 1; }
+
+log "GET /metrics"
+curl -fsS "$BASE_URL/metrics" | grep 'normcore_judgments_total{agent="unknown",status="violates_norm"} 1'
+
+log "OK"
@@ -0,0 +1,58 @@
+#!/usr/bin/env bash
+# Socket-level smoke test for `normcore stream --unix-socket` (the unit suite
+# feeds connections to the worker in memory and never binds a socket).
+set -euo pipefail
+
+ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+SOCKET_DIR="$(mktemp -d)"
+SOCKET_PATH="$SOCKET_DIR/normcore.sock"
+
+log() {
+  printf '[%s] %s\n' "$(date +"%H:%M:%S")" "$*"
+}
+
+cd "$ROOT_DIR"
+export UV_CACHE_DIR=.uv-cache
+
+# A socket file left behind by a dead worker must be replaced, not refused.
+uv run python -c 'import socket, sys; socket.socket(socket.AF_UNIX).bind(sys.argv[1])' \
+  "$SOCKET_PATH"
+
+uv run normcore stream --unix-socket "$SOCKET_PATH" &
+WORKER_PID=$!
+trap 'kill "$WORKER_PID" 2>/dev/null || true; rm -rf "$SOCKET_DIR"' EXIT
+
+log "connect to $SOCKET_PATH"
+RECORD="$(uv run python - "$SOCKET_PATH" <<'EOF'
+import json
+import socket
+import sys
+import time
+
+deadline = time.monotonic() + 10
+while True:
+    client = socket.socket(socket.AF_UNIX, socket.SOCK_STREAM)
+    try:
+        client.connect(sys.argv[1])
+        break
+    except OSError:
+        client.close()
+        if time.monotonic() > deadline:
+            sys.exit("worker did not start listening")
+        time.sleep(0.2)
+with client:
+    line = json.dumps({"id": "smoke-1", "agent_output": "We should deploy now."})
+    client.sendall(f"{line}\n".encode("utf-8"))
+    client.shutdown(socket.SHUT_WR)
+    print(client.makefile("r", encoding="utf-8").read(), end="")
+EOF
+)"
+echo "$RECORD"
+grep -q '"status": "violates_norm"' <<<"$RECORD" || { echo "ERROR: unexpected judgment" >&2; exit 1; }
+
+log "SIGTERM"
+kill -TERM "$WORKER_PID"
+wait "$WORKER_PID"
+[[ ! -e "$SOCKET_PATH" ]] || { echo "ERROR: socket file left behind" >&2; exit 1; }
+
+log "OK"
@@ -2,18 +2,78 @@
 Public API for the NormCore package.
 """
 
-from .evaluator import evaluate
+from .cache import JudgmentCache
+from .evaluator import (
+    check_structure,
+    check_tool_call,
+    derive_grounds,
+    evaluate,
+    evaluate_trajectory,
+)
+from .guardrail import StreamGuard
+from .limits import InputLimitError
 from .models import (
     AdmissibilityJudgment,
     AdmissibilityStatus,
+    EvaluationPolicy,
+    GateDecision,
     GroundRef,
+    GroundsReport,
+    GuardEvent,
+    KnowledgeNodeRecord,
+    LicenseTrace,
+    PayloadIssue,
+    PayloadValidationReport,
+    RevisionDelta,
+    RiskTier,
+    StatementAttribution,
     StatementEvaluation,
+    StatementStructure,
+    StatementUncertainty,
+    StructureReport,
+    TrajectoryJudgment,
 )
+from .policy_packs import POLICY_PACKS, policy_pack
+from .revision import compare_judgments
+from .scenarios import Scenario, ScenarioResult, load_scenario, run_scenario
+from .session import SessionEvaluator
+from .validation import validate_payload
 
 __all__ = [
     "evaluate",
+    "evaluate_trajectory",
+    "check_structure",
+    "check_tool_call",
+    "derive_grounds",
+    "validate_payload",
+    "InputLimitError",
+    "compare_judgments",
+    "policy_pack",
+    "POLICY_PACKS",
+    "JudgmentCache",
+    "SessionEvaluator",
+    "StreamGuard",
+    "Scenario",
+    "ScenarioResult",
+    "load_scenario",
+    "run_scenario",
     "AdmissibilityJudgment",
     "AdmissibilityStatus",
+    "EvaluationPolicy",
+    "GateDecision",
     "GroundRef",
+    "GroundsReport",
+    "GuardEvent",
+    "KnowledgeNodeRecord",
+    "LicenseTrace",
+    "PayloadIssue",
+    "PayloadValidationReport",
+    "RevisionDelta",
+    "RiskTier",
+    "StatementAttribution",
     "StatementEvaluation",
+    "StatementStructure",
+    "StatementUncertainty",
+    "StructureReport",
+    "TrajectoryJudgment",
 ]
@@ -0,0 +1,129 @@
+"""
+Append-only audit log for guardrail decisions.
+
+Long-running CLI modes can record every evaluation to a JSONL file for
+compliance review. Each record carries:
+
+- ``seq``: position in the log (0-based)
+- ``timestamp``: UTC evaluation time (ISO 8601)
+- ``input_hash``: SHA-256 of the canonical evaluate payload
+- ``policy_fingerprint``: ``EvaluationPolicy.fingerprint()`` of the policy in effect
+- ``judgment``: the judgment JSON
+- ``prev_hash`` / ``hash``: hash chain links
+
+``hash`` is the SHA-256 of ``prev_hash`` concatenated with the canonical
+(``canonical_json``) record (all fields except ``hash``), and ``prev_hash`` is the previous
+record's ``hash`` (``GENESIS_HASH`` for the first record). Editing, removing
+or reordering any record breaks every later link, which ``verify_audit_log``
+reports. Reopening an existing log continues its chain.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import json
+import threading
+from collections.abc import Iterable, Mapping
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any
+
+from .canonical import canonical_bytes
+from .models import AdmissibilityJudgment, EvaluationPolicy
+from .signing import input_digest
+
+GENESIS_HASH = "0" * 64
+
+
+def _chain_hash(prev_hash: str, record: Mapping[str, Any]) -> str:
+    body = {k: v for k, v in record.items() if k != "hash"}
+    return hashlib.sha256(prev_hash.encode("ascii") + canonical_bytes(body)).hexdigest()
+
+
+class AuditLog:
+    """
+    Hash-chained JSONL audit sink.
+
+    Records are appended and flushed one per evaluation; appends are
+    serialized, so one log may be shared by concurrent evaluations.
+    """
+
+    def __init__(self, path: str | Path, *, policy: EvaluationPolicy | None = None) -> None:
+        """Open ``path`` for appending, resuming the chain of an existing log."""
+        self.path = Path(path)
+        self.policy_fingerprint = (policy or EvaluationPolicy()).fingerprint()
+        self._lock = threading.Lock()
+        self._seq, self._prev_hash = self._chain_tail()
+        self._handle = self.path.open("a", encoding="utf-8")
+
+    def _chain_tail(self) -> tuple[int, str]:
+        if not self.path.exists():
+            return 0, GENESIS_HASH
+        last: str | None = None
+        count = 0
+        with self.path.open(encoding="utf-8") as handle:
+            for line in handle:
+                if line.strip():
+                    last = line
+                    count += 1
+        if last is None:
+            return 0, GENESIS_HASH
+        try:
+            return count, json.loads(last)["hash"]
+        except (json.JSONDecodeError, KeyError, TypeError) as exc:
+            raise ValueError(f"Audit log {self.path} has a corrupt last record: {exc}") from exc
+
+    def append(self, payload: Mapping[str, Any], judgment: AdmissibilityJudgment) -> dict[str, Any]:
+        """
+        Record one evaluation.
+
+        Returns:
+            The appended record
+        """
+        with self._lock:
+            record: dict[str, Any] = {
+                "seq": self._seq,
+                "timestamp": datetime.now(timezone.utc).isoformat(),
+                "input_hash": input_digest(payload),
+                "policy_fingerprint": self.policy_fingerprint,
+                "judgment": judgment.model_dump(mode="json"),
+                "prev_hash": self._prev_hash,
+            }
+            record["hash"] = _chain_hash(self._prev_hash, record)
+            self._handle.write(json.dumps(record, ensure_ascii=False) + "\n")
+            self._handle.flush()
+            self._seq += 1
+            self._prev_hash = record["hash"]
+            return record
+
+    def close(self) -> None:
+        """Close the underlying file."""
+        self._handle.close()
+
+
+def verify_audit_log(lines: Iterable[str]) -> int | None:
+    """
+    Check the hash chain of an audit log.
+
+    Returns:
+        1-based line number of the first record that breaks the chain, or None if intact
+    """
+    prev_hash = GENESIS_HASH
+    seq = 0
+    for line_number, line in enumerate(lines, 1):
+        if not line.strip():
+            continue
+        try:
+            record = json.loads(line)
+            valid = (
+                record["seq"] == seq
+                and record["prev_hash"] == prev_hash
+                and record["hash"] == _chain_hash(prev_hash, record)
+            )
+        except (ValueError, KeyError, TypeError):
+            valid = False
+        if not valid:
+            return line_number
+        prev_hash = record["hash"]
+        seq += 1
+    return None
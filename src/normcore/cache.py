@@ -0,0 +1,106 @@
+"""
+In-memory memoization of identical evaluations.
+
+Retry orchestration often re-evaluates the same content. ``JudgmentCache``
+keys judgments by a SHA-256 of the canonical evaluate inputs (agent output,
+trajectory, grounds, links, knowledge nodes, selection, strictness) and the
+policy fingerprint, and evicts the least recently used entry when full::
+
+    cache = JudgmentCache(maxsize=1024)
+    judgment = evaluate(conversation=conversation, cache=cache)
+
+Evaluation is deterministic for equal inputs, so a hit is the judgment a
+fresh evaluation would produce. The exception is process-wide state outside
+the inputs: clear the cache after registering or unregistering tool result
+parsers.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import json
+import threading
+from collections import OrderedDict
+from collections.abc import Mapping
+from typing import Any
+
+from pydantic import BaseModel
+
+from .models import AdmissibilityJudgment, EvaluationPolicy
+
+DEFAULT_CACHE_SIZE = 1024
+
+
+def _jsonable(value: Any) -> Any:
+    if isinstance(value, BaseModel):
+        return value.model_dump(mode="json")
+    if isinstance(value, (set, frozenset)):
+        return sorted(value, key=repr)
+    raise TypeError(f"Cannot hash evaluate input of type {type(value).__name__}")
+
+
+def evaluation_key(inputs: Mapping[str, Any], policy: EvaluationPolicy) -> str:
+    """
+    Return the cache key for one evaluation.
+
+    Raises:
+        TypeError: an input value has no JSON representation
+    """
+    canonical = json.dumps(
+        {"inputs": dict(inputs), "policy": policy.fingerprint()},
+        sort_keys=True,
+        separators=(",", ":"),
+        ensure_ascii=False,
+        default=_jsonable,
+    )
+    return hashlib.sha256(canonical.encode("utf-8")).hexdigest()
+
+
+class JudgmentCache:
+    """
+    Thread-safe LRU cache of judgments.
+
+    Stored and returned judgments are copies, so callers may modify what they
+    receive without affecting later hits.
+    """
+
+    def __init__(self, maxsize: int = DEFAULT_CACHE_SIZE) -> None:
+        """Initialize an empty cache holding at most ``maxsize`` judgments."""
+        if maxsize < 1:
+            raise ValueError("maxsize must be at least 1")
+        self.maxsize = maxsize
+        self.hits = 0
+        self.misses = 0
+        self._entries: OrderedDict[str, AdmissibilityJudgment] = OrderedDict()
+        self._lock = threading.Lock()
+
+    def get(self, key: str) -> AdmissibilityJudgment | None:
+        """Return a copy of the judgment cached under ``key``, if any."""
+        with self._lock:
+            judgment = self._entries.get(key)
+            if judgment is None:
+                self.misses += 1
+                return None
+            self._entries.move_to_end(key)
+            self.hits += 1
+            return judgment.model_copy(deep=True)
+
+    def put(self, key: str, judgment: AdmissibilityJudgment) -> None:
+        """Cache a copy of ``judgment`` under ``key``, evicting the least recently used."""
+        with self._lock:
+            self._entries[key] = judgment.model_copy(deep=True)
+            self._entries.move_to_end(key)
+            while len(self._entries) > self.maxsize:
+                self._entries.popitem(last=False)
+
+    def clear(self) -> None:
+        """Drop every cached judgment and reset the hit/miss counters."""
+        with self._lock:
+            self._entries.clear()
+            self.hits = 0
+            self.misses = 0
+
+    def __len__(self) -> int:
+        """Number of cached judgments."""
+        with self._lock:
+            return len(self._entries)
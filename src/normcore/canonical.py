@@ -0,0 +1,85 @@
+"""
+Canonical JSON serialization.
+
+Snapshot tests, hashes and external signature schemes need one byte sequence
+per value. ``canonical_json`` follows the JSON Canonicalization Scheme
+(RFC 8785):
+
+- object keys sorted by UTF-16 code units, no insignificant whitespace
+- strings minimally escaped, non-ASCII characters written as UTF-8
+- numbers in their shortest round-trip form, without a trailing ``.0``
+  (``1.0`` → ``1``), negative zero written as ``0``, and exponents only
+  outside ``1e-6 <= |x| < 1e21`` (``1e+21``, ``1e-7``)
+
+NaN and infinities have no JSON form and are rejected. Pydantic models are
+serialized through ``model_dump(mode="json")``.
+"""
+
+from __future__ import annotations
+
+import json
+import math
+from collections.abc import Mapping
+from decimal import Decimal
+from typing import Any
+
+from pydantic import BaseModel
+
+
+def _format_float(value: float) -> str:
+    if not math.isfinite(value):
+        raise ValueError(f"{value!r} has no canonical JSON form")
+    if value == 0:
+        return "0"
+    sign, digit_tuple, exponent = Decimal(repr(value)).as_tuple()
+    digits = "".join(map(str, digit_tuple)).rstrip("0")
+    # value = 0.<digits> * 10**point
+    point = len(digit_tuple) + int(exponent)
+    prefix = "-" if sign else ""
+    if len(digits) <= point <= 21:
+        return prefix + digits + "0" * (point - len(digits))
+    if 0 < point <= 21:
+        return prefix + digits[:point] + "." + digits[point:]
+    if -6 < point <= 0:
+        return prefix + "0." + "0" * -point + digits
+    mantissa = digits[0] + ("." + digits[1:] if len(digits) > 1 else "")
+    power = point - 1
+    return f"{prefix}{mantissa}e{'+' if power > 0 else '-'}{abs(power)}"
+
+
+def _encode(value: Any) -> str:
+    if isinstance(value, BaseModel):
+        return _encode(value.model_dump(mode="json"))
+    if value is None or isinstance(value, (bool, str)):
+        return json.dumps(value, ensure_ascii=False)
+    if isinstance(value, int):
+        return str(int(value))
+    if isinstance(value, float):
+        return _format_float(value)
+    if isinstance(value, Mapping):
+        items = []
+        for key in value:
+            if not isinstance(key, str):
+                raise TypeError(f"Object keys must be strings, got {type(key).__name__}")
+            items.append(key)
+        items.sort(key=lambda key: key.encode("utf-16-be"))
+        return "{" + ",".join(f"{_encode(key)}:{_encode(value[key])}" for key in items) + "}"
+    if isinstance(value, (list, tuple)):
+        return "[" + ",".join(_encode(item) for item in value) + "]"
+    raise TypeError(f"{type(value).__name__} is not JSON serializable")
+
+
+def canonical_json(value: Any) -> str:
+    """
+    Serialize ``value`` canonically.
+
+    Raises:
+        TypeError: the value (or an object key) has no JSON form
+        ValueError: the value contains NaN or an infinity
+    """
+    return _encode(value)
+
+
+def canonical_bytes(value: Any) -> bytes:
+    """UTF-8 encoding of ``canonical_json(value)``."""
+    return canonical_json(value).encode("utf-8")
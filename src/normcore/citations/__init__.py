@@ -8,24 +8,32 @@ from .grounds import (
     extract_citation_keys,
     grounds_from_tool_call_refs,
     parse_grounds,
+    parse_sectioned_grounds,
 )
 from .openai_adapter import (
     OpenAICitation,
+    citations_from_assistant_message,
     grounds_from_openai_citations,
     link_set_from_openai_citations,
     parse_openai_citations,
 )
+from .registry import load_ground_registry, merge_grounds, parse_ground_manifest
 
 __all__ = [
     "Ground",
     "OpenAICitation",
     "build_links_from_grounds",
+    "citations_from_assistant_message",
     "coerce_grounds_input",
     "coerce_links_input",
     "extract_citation_keys",
     "grounds_from_openai_citations",
     "grounds_from_tool_call_refs",
     "link_set_from_openai_citations",
+    "load_ground_registry",
+    "merge_grounds",
+    "parse_ground_manifest",
     "parse_grounds",
     "parse_openai_citations",
+    "parse_sectioned_grounds",
 ]
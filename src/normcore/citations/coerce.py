@@ -6,7 +6,7 @@ from collections.abc import Iterable
 
 from pydantic import ValidationError
 
-from ..logging import logger
+from ..logging import report_warning
 from ..models.links import LinkSet
 from .openai_adapter import (
     OpenAICitation,
@@ -33,19 +33,19 @@ def coerce_links_input(
             try:
                 return LinkSet.model_validate(links)
             except ValidationError as exc:
-                logger.warning(f"Invalid links dict ignored: {exc}")
+                report_warning(f"Invalid links dict ignored: {exc}")
                 return None
         if isinstance(links, list):
             if not links:
-                logger.warning("Unsupported links list payload ignored")
+                report_warning("Unsupported links list payload ignored")
                 return None
             try:
                 typed = parse_openai_citations(links)
             except ValidationError as exc:
-                logger.warning(f"Invalid links list citations ignored: {exc}")
+                report_warning(f"Invalid links list citations ignored: {exc}")
                 return None
             return link_set_from_openai_citations(typed)
-        logger.warning("Unsupported links payload type ignored")
+        report_warning("Unsupported links payload type ignored")
         return None
 
     if not openai_citations:
@@ -54,6 +54,6 @@ def coerce_links_input(
     try:
         typed = parse_openai_citations(openai_citations)
     except ValidationError as exc:
-        logger.warning(f"Invalid openai_citations ignored: {exc}")
+        report_warning(f"Invalid openai_citations ignored: {exc}")
         return None
     return link_set_from_openai_citations(typed)
@@ -3,12 +3,12 @@
 from __future__ import annotations
 
 import re
-from collections.abc import Iterable
+from collections.abc import Iterable, Mapping
 from typing import Any
 
-from pydantic import BaseModel, TypeAdapter, ValidationError
+from pydantic import BaseModel, Field, TypeAdapter, ValidationError
 
-from ..logging import logger
+from ..logging import report_warning
 from ..models.links import (
     CreatorType,
     EvidenceType,
@@ -31,6 +31,16 @@ class Ground(BaseModel):
     evidence_type: EvidenceType = EvidenceType.OBSERVATION
     evidence_content: str | None = None
     signature: str | None = None
+    derived_from: list[str] = Field(
+        default_factory=list,
+        description="Parent ground ids this ground was derived from (summaries, extracts).",
+    )
+    confidence: float = Field(
+        default=1.0,
+        ge=0.0,
+        le=1.0,
+        description="Confidence in the evidence; graded against the strong threshold.",
+    )
 
 
 _grounds_adapter = TypeAdapter(list[Ground])
@@ -43,6 +53,71 @@ def parse_grounds(grounds: Iterable[object] | None) -> list[Ground]:
     return _grounds_adapter.validate_python(list(grounds))
 
 
+# Sectioned grounds payload: section -> defaults applied to every item unless the
+# item sets the field itself. User facts are personalization context: they are
+# traceable via links but never license (see LinkRole.CONTEXTUALIZES).
+GROUND_SECTION_DEFAULTS: dict[str, dict[str, Any]] = {
+    "documents": {
+        "role": LinkRole.SUPPORTS,
+        "creator": CreatorType.UPSTREAM_PIPELINE,
+        "evidence_type": EvidenceType.OBSERVATION,
+    },
+    "tool_calls": {
+        "role": LinkRole.SUPPORTS,
+        "creator": CreatorType.TOOL_OBSERVER,
+        "evidence_type": EvidenceType.OBSERVATION,
+    },
+    "urls": {
+        "role": LinkRole.SUPPORTS,
+        "creator": CreatorType.UPSTREAM_PIPELINE,
+        "evidence_type": EvidenceType.OBSERVATION,
+    },
+    "user_facts": {
+        "role": LinkRole.CONTEXTUALIZES,
+        "creator": CreatorType.HUMAN,
+        "evidence_type": EvidenceType.EXPLICIT,
+    },
+}
+
+
+def parse_sectioned_grounds(payload: Mapping[str, Any]) -> list[Ground]:
+    """
+    Validate a sectioned grounds payload.
+
+    Sections: ``documents``, ``tool_calls``, ``urls``, ``user_facts``.
+
+    Each section is a list whose items are:
+    - a string: used as both citation key and ground id
+    - a ground object: section defaults fill fields the item omits
+    - an OpenAI annotation (``documents`` / ``urls``): converted like flat grounds
+    """
+    # Local import avoids import cycle (openai_adapter imports Ground from this module).
+    from .openai_adapter import grounds_from_openai_citations, parse_openai_citations
+
+    unknown = sorted(set(payload) - set(GROUND_SECTION_DEFAULTS))
+    if unknown:
+        report_warning(f"Unknown grounds sections ignored: {', '.join(unknown)}")
+
+    grounds: list[Ground] = []
+    for section, defaults in GROUND_SECTION_DEFAULTS.items():
+        items = payload.get(section) or []
+        if not isinstance(items, list):
+            report_warning(f"Grounds section '{section}' must be a list; ignored")
+            continue
+        for item in items:
+            try:
+                if isinstance(item, str):
+                    grounds.append(Ground(citation_key=item, ground_id=item, **defaults))
+                elif isinstance(item, Mapping) and "type" in item:
+                    for ground in grounds_from_openai_citations(parse_openai_citations([item])):
+                        grounds.append(ground.model_copy(update=defaults))
+                else:
+                    grounds.append(Ground.model_validate({**defaults, **item}))
+            except (ValidationError, TypeError) as exc:
+                report_warning(f"Invalid ground in section '{section}' ignored: {exc}")
+    return grounds
+
+
 def extract_citation_keys(text: str) -> list[str]:
     """Extract citation keys in ``[@key]`` format preserving first-seen order."""
     if not text:
@@ -64,18 +139,36 @@ def build_links_from_grounds(
     text: str,
     grounds: Iterable[Ground],
     statement_id: str,
+    statement_texts: Mapping[str, str] | None = None,
 ) -> LinkSet:
-    """Build StatementGroundLinks by resolving text citation keys against grounds."""
+    """
+    Build StatementGroundLinks by resolving text citation keys against grounds.
+
+    With ``statement_texts`` (statement id -> text, for multi-statement
+    extraction) each key links to the statements whose text cites it, so one
+    citation cannot license claims elsewhere in the response. Keys cited only
+    outside every statement stay on ``statement_id``.
+    """
     by_key: dict[str, list[Ground]] = {}
     for ground in grounds:
         by_key.setdefault(ground.citation_key, []).append(ground)
 
+    citing: list[tuple[str, str]] = []
+    scoped_keys: set[str] = set()
+    for scoped_id, scoped_text in (statement_texts or {}).items():
+        for key in extract_citation_keys(scoped_text):
+            citing.append((scoped_id, key))
+            scoped_keys.add(key)
+    citing.extend(
+        (statement_id, key) for key in extract_citation_keys(text) if key not in scoped_keys
+    )
+
     links: list[StatementGroundLink] = []
-    for key in extract_citation_keys(text):
+    for citing_id, key in citing:
         for ground in by_key.get(key, []):
             links.append(
                 StatementGroundLink(
-                    statement_id=statement_id,
+                    statement_id=citing_id,
                     ground_id=ground.ground_id,
                     role=ground.role,
                     provenance=Provenance(
@@ -110,12 +203,15 @@ def grounds_from_tool_call_refs(tool_call_refs: dict[str, list[str]]) -> list[Gr
 
 def coerce_grounds_input(
     *,
-    grounds: Iterable[object] | None,
+    grounds: Iterable[object] | Mapping[str, Any] | None,
     legacy_openai_citations: Iterable[Any] | None = None,
     legacy_links: Any | None = None,
 ) -> list[Ground]:
     """Normalize public grounds payload with legacy compatibility."""
     normalized: list[Ground] = []
+    if isinstance(grounds, Mapping):
+        normalized.extend(parse_sectioned_grounds(grounds))
+        grounds = None
     payload = list(grounds) if grounds else []
 
     if payload:
@@ -130,7 +226,7 @@ def coerce_grounds_input(
                 typed_citations = parse_openai_citations(payload)
                 normalized.extend(grounds_from_openai_citations(typed_citations))
             except ValidationError as exc:
-                logger.warning(f"Invalid grounds ignored: {exc}")
+                report_warning(f"Invalid grounds ignored: {exc}")
 
     if legacy_openai_citations:
         try:
@@ -140,9 +236,9 @@ def coerce_grounds_input(
             typed_citations = parse_openai_citations(legacy_openai_citations)
             normalized.extend(grounds_from_openai_citations(typed_citations))
         except ValidationError as exc:
-            logger.warning(f"Invalid openai_citations ignored: {exc}")
+            report_warning(f"Invalid openai_citations ignored: {exc}")
 
     if legacy_links is not None:
-        logger.warning("`links` input is deprecated and ignored; use `grounds`")
+        report_warning("`legacy_links` is ignored by grounds coercion; pass `links` to evaluate()")
 
     return normalized
@@ -3,11 +3,13 @@
 from __future__ import annotations
 
 import json
-from collections.abc import Iterable
+from collections.abc import Iterable, Mapping
+from typing import Any
 
 from openai.types.responses.response_output_text import Annotation
-from pydantic import TypeAdapter
+from pydantic import TypeAdapter, ValidationError
 
+from ..logging import report_warning
 from ..models.links import (
     CreatorType,
     EvidenceType,
@@ -20,6 +22,7 @@ from .grounds import Ground
 
 OpenAICitation = Annotation
 _openai_citations_adapter = TypeAdapter(list[OpenAICitation])
+_openai_citation_adapter = TypeAdapter(OpenAICitation)
 
 
 def parse_openai_citations(citations: Iterable[object]) -> list[OpenAICitation]:
@@ -80,6 +83,52 @@ def grounds_from_openai_citations(citations: Iterable[OpenAICitation]) -> list[G
     return grounds
 
 
+def citations_from_assistant_message(message: Mapping[str, Any]) -> list[OpenAICitation]:
+    """
+    Collect OpenAI annotations carried by an assistant message.
+
+    Supports both shapes the SDKs emit:
+    - Chat Completions ``message.annotations`` with nested objects
+      (``{"type": "url_citation", "url_citation": {...}}``)
+    - Responses-style ``annotations`` on text content parts; their offsets are
+      shifted to positions in the concatenated assistant text
+
+    Invalid annotations are logged and skipped.
+    """
+    raw: list[Any] = []
+    for annotation in message.get("annotations") or []:
+        if isinstance(annotation, Mapping):
+            nested = annotation.get(annotation.get("type", ""))
+            raw.append({**annotation, **nested} if isinstance(nested, Mapping) else annotation)
+
+    content = message.get("content")
+    if isinstance(content, list):
+        offset = 0
+        for part in content:
+            if not isinstance(part, Mapping) or part.get("type") != "text":
+                continue
+            for annotation in part.get("annotations") or []:
+                if isinstance(annotation, Mapping):
+                    raw.append(_shift_offsets(annotation, offset))
+            offset += len(part.get("text") or "")
+
+    citations: list[OpenAICitation] = []
+    for annotation in raw:
+        try:
+            citations.append(_openai_citation_adapter.validate_python(annotation))
+        except ValidationError as exc:
+            report_warning(f"Invalid assistant message annotation ignored: {exc}")
+    return citations
+
+
+def _shift_offsets(annotation: Mapping[str, Any], offset: int) -> dict[str, Any]:
+    shifted = dict(annotation)
+    for key in ("start_index", "end_index", "index"):
+        if isinstance(shifted.get(key), int):
+            shifted[key] += offset
+    return shifted
+
+
 def _extract_ground_id(citation: OpenAICitation) -> str | None:
     annotation_type = getattr(citation, "type", None)
     if annotation_type in {"file_citation", "container_file_citation", "file_path"}:
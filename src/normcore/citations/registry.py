@@ -0,0 +1,108 @@
+"""Shared ground catalogs loaded from a directory of manifests."""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Iterable, Mapping
+from pathlib import Path
+from typing import Any
+
+from pydantic import ValidationError
+
+from .grounds import GROUND_SECTION_DEFAULTS, Ground
+
+GROUND_MANIFEST_GLOB = "*.json"
+
+
+def merge_grounds(*sources: Iterable[Ground]) -> list[Ground]:
+    """Merge ground lists by ``ground_id``; a later ground replaces an earlier one in place."""
+    merged: dict[str, Ground] = {}
+    for grounds in sources:
+        for ground in grounds:
+            merged[ground.ground_id] = ground
+    return list(merged.values())
+
+
+def _manifest_item(item: Any, defaults: Mapping[str, Any]) -> list[Ground]:
+    # Local import avoids import cycle (openai_adapter imports Ground from grounds).
+    from .openai_adapter import grounds_from_openai_citations, parse_openai_citations
+
+    if isinstance(item, str):
+        return [Ground(citation_key=item, ground_id=item, **defaults)]
+    if not isinstance(item, Mapping):
+        raise ValueError(f"expected a ground object, got {type(item).__name__}")
+    if "type" in item:
+        try:
+            citations = parse_openai_citations([item])
+        except ValidationError as exc:
+            error = exc.errors()[0]
+            location = ".".join(str(part) for part in error["loc"][1:])
+            raise ValueError(f"invalid OpenAI annotation: {location}: {error['msg']}") from None
+        grounds = grounds_from_openai_citations(citations)
+        return [ground.model_copy(update=defaults) for ground in grounds]
+    return [Ground.model_validate({**defaults, **item})]
+
+
+def parse_ground_manifest(payload: Any, *, source: str = "manifest") -> list[Ground]:
+    """
+    Validate one ground manifest: a grounds array or a sectioned grounds object.
+
+    Items take the same shapes as ``evaluate()`` grounds (ground objects, OpenAI
+    annotations and, in sections, plain citation keys), but an invalid item
+    rejects the whole manifest instead of being skipped, so a broken catalog
+    entry cannot silently stop licensing the claims that cite it.
+
+    Raises:
+        ValueError: the manifest (named ``source`` in the message) is invalid
+    """
+    if isinstance(payload, list):
+        sections: dict[str, Any] = {"": payload}
+    elif isinstance(payload, Mapping):
+        unknown = sorted(set(payload) - set(GROUND_SECTION_DEFAULTS))
+        if unknown:
+            raise ValueError(f"{source}: unknown grounds sections: {', '.join(unknown)}")
+        sections = dict(payload)
+    else:
+        raise ValueError(f"{source}: expected a grounds array or a sectioned object")
+
+    grounds: list[Ground] = []
+    for section, items in sections.items():
+        if not isinstance(items, list):
+            raise ValueError(f"{source}: section '{section}' must be an array")
+        defaults = GROUND_SECTION_DEFAULTS.get(section, {})
+        for index, item in enumerate(items):
+            try:
+                grounds.extend(_manifest_item(item, defaults))
+            except ValidationError as exc:
+                error = exc.errors()[0]
+                location = "".join(
+                    f"[{part}]" if isinstance(part, int) else f".{part}" for part in error["loc"]
+                )
+                raise ValueError(f"{source}: {section}[{index}]{location}: {error['msg']}") from exc
+            except ValueError as exc:
+                raise ValueError(f"{source}: {section}[{index}]: {exc}") from exc
+    return grounds
+
+
+def load_ground_registry(directory: str | Path) -> list[Ground]:
+    """
+    Load every ``*.json`` ground manifest in ``directory``, merged by ``ground_id``.
+
+    Manifests are read in file name order, so a ground defined in several
+    files takes the definition of the last one.
+
+    Raises:
+        OSError: the directory or a manifest cannot be read
+        ValueError: a manifest is not valid JSON or holds invalid grounds
+    """
+    directory = Path(directory)
+    if not directory.is_dir():
+        raise NotADirectoryError(f"Not a directory: {directory}")
+    manifests = []
+    for path in sorted(directory.glob(GROUND_MANIFEST_GLOB)):
+        try:
+            payload = json.loads(path.read_text(encoding="utf-8"))
+        except json.JSONDecodeError as exc:
+            raise ValueError(f"{path.name}: Invalid JSON: {exc}") from exc
+        manifests.append(parse_ground_manifest(payload, source=path.name))
+    return merge_grounds(*manifests)
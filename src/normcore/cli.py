@@ -5,11 +5,117 @@ Command line entry point for NormCore.
 from __future__ import annotations
 
 import argparse
+import contextlib
+import difflib
+import functools
+import io
 import json
+import os
+import queue
+import sys
+import threading
+import time
+from collections.abc import Callable, Iterable, Iterator, Mapping
 from importlib.metadata import PackageNotFoundError, version
+from pathlib import Path
+from typing import IO, Any, BinaryIO, TextIO
 
-from normcore.evaluator import evaluate
-from normcore.logging import configure_logging
+from normcore.audit import AuditLog
+from normcore.citations import (
+    Ground,
+    load_ground_registry,
+    merge_grounds,
+    parse_ground_manifest,
+)
+from normcore.config import (
+    CONFIG_FILENAME,
+    ENV_CONFIG,
+    ENV_FAIL_ON,
+    ENV_POLICY,
+    NormcoreConfig,
+    find_config,
+    load_config,
+)
+from normcore.doctor import run_doctor
+from normcore.encodings import (
+    ENCODINGS,
+    JSON_ENCODING,
+    MSGPACK_ENCODING,
+    import_msgpack,
+    record_writer,
+)
+from normcore.evaluator import (
+    AdmissibilityEvaluator,
+    check_structure,
+    check_tool_call,
+    derive_grounds,
+    evaluate,
+)
+from normcore.formats import (
+    COMPACT_FORMAT,
+    JSON_FORMAT,
+    JSONL_FORMAT,
+    RECORD_FORMATS,
+    SUMMARY_FORMAT,
+    TEXT_FORMATS,
+    render_record,
+)
+from normcore.http_access import HttpAccessPolicy, load_api_keys
+from normcore.http_server import DEFAULT_PORT as DEFAULT_SERVE_PORT
+from normcore.http_server import EvaluationService, start_evaluation_server
+from normcore.i18n import LOCALES
+from normcore.inputs import FILE_PREFIX, load_structured
+from normcore.importers import (
+    EvalsRecorder,
+    load_batch_requests,
+    payload_from_agent_transcript,
+    payload_from_batch_output,
+    payload_from_request_log,
+    payloads_from_chatgpt_export,
+    payloads_from_evals_jsonl,
+)
+from normcore.golden import run_golden_corpus
+from normcore.labeled_eval import evaluate_dataset
+from normcore.logging import LOG_FORMATS, configure_logging, logger
+from normcore.metrics import EvaluationMetrics, start_metrics_server
+from normcore.models import AdmissibilityStatus, GateDecision
+from normcore.models.policy import EvaluationPolicy
+from normcore.normative.axiom_checker import AXIOMS, default_registry
+from normcore.payloads import (
+    PAYLOAD_AGENT_FIELD,
+    PAYLOAD_ID_FIELD,
+    evaluate_payload,
+    parse_payload_line,
+    payload_from_object,
+    render_error_record,
+    render_result_record,
+)
+from normcore.policy_packs import POLICY_PACKS
+from normcore.sarif import SARIF_FORMAT, SarifLog
+from normcore.scenarios import ScenarioResult, run_corpus
+from normcore.serving import (
+    DEFAULT_WORKERS,
+    ChunkedJob,
+    EvaluationJob,
+    EvaluationPool,
+    EvaluationTimeoutError,
+    ProcessEvaluationPool,
+    graceful_shutdown,
+    read_msgpack_payloads,
+    read_payload_lines,
+    serve_unix_socket,
+)
+from normcore.signing import JudgmentSigner
+from normcore.sinks import (
+    FILE_SCHEME,
+    LockedSink,
+    RecordSink,
+    open_sink,
+    output_file,
+    parse_output,
+)
+from normcore.validation import validate_payload
+from normcore.webhooks import DEFAULT_WEBHOOK_TIMEOUT, WebhookNotifier
 
 
 def _resolve_log_level(args: argparse.Namespace) -> str | None:
@@ -26,8 +132,513 @@ def _resolve_log_level(args: argparse.Namespace) -> str | None:
     return None
 
 
+def _parse_span(value: str) -> tuple[int, int]:
+    start, sep, end = value.partition(":")
+    try:
+        if not sep:
+            raise ValueError
+        return int(start), int(end)
+    except ValueError:
+        raise argparse.ArgumentTypeError(f"expected START:END offsets, got {value!r}")
+
+
+def _parse_statuses(value: str) -> frozenset[AdmissibilityStatus]:
+    statuses = set()
+    for name in filter(None, (part.strip() for part in value.split(","))):
+        try:
+            statuses.add(AdmissibilityStatus(name))
+        except ValueError:
+            choices = ", ".join(status.value for status in AdmissibilityStatus)
+            raise argparse.ArgumentTypeError(
+                f"unknown status {name!r} (choose from {choices})"
+            ) from None
+    if not statuses:
+        raise argparse.ArgumentTypeError("expected at least one status")
+    return frozenset(statuses)
+
+
+def _import_chatgpt(path: str) -> Iterator[dict[str, Any]]:
+    with open(path, encoding="utf-8") as handle:
+        export = json.load(handle)
+    yield from payloads_from_chatgpt_export(export)
+
+
+def _import_agent_transcript(path: str) -> Iterator[dict[str, Any]]:
+    yield payload_from_agent_transcript(path)
+
+
+def _import_openai_evals(path: str) -> Iterator[dict[str, Any]]:
+    with open(path, encoding="utf-8") as handle:
+        yield from payloads_from_evals_jsonl(handle)
+
+
+# Export format -> loader yielding evaluate payloads.
+_IMPORTERS: dict[str, Callable[[str], Iterable[dict[str, Any]]]] = {
+    "agent-transcript": _import_agent_transcript,
+    "chatgpt": _import_chatgpt,
+    "openai-evals": _import_openai_evals,
+}
+
+
+_STRUCTURED_HELP = " Inline JSON, or @PATH to read a JSON, YAML (.yaml/.yml) or TOML file."
+_POLICY_HELP = (
+    f"Policy pack name ({', '.join(POLICY_PACKS)}), a policy file path, or an "
+    f"EvaluationPolicy object (default: built-in policy).{_STRUCTURED_HELP}"
+)
+
+
+def _add_policy_argument(
+    subparser: argparse.ArgumentParser, help_text: str = _POLICY_HELP
+) -> None:
+    subparser.add_argument("--policy", help=help_text)
+
+
+def _add_signing_arguments(subparser: argparse.ArgumentParser) -> None:
+    subparser.add_argument(
+        "--signing-key-file",
+        metavar="PATH",
+        help=(
+            "Sign each judgment with the HMAC-SHA256 key in this file; the detached "
+            "signature is written in the record's signature field."
+        ),
+    )
+    subparser.add_argument(
+        "--signing-key-id",
+        metavar="ID",
+        help="Key identifier recorded in signatures (for example the deployment name).",
+    )
+
+
+def _add_audit_argument(subparser: argparse.ArgumentParser) -> None:
+    subparser.add_argument(
+        "--audit-log",
+        metavar="PATH",
+        help=(
+            "Append a hash-chained audit record (input hash, judgment, timestamp, policy "
+            "fingerprint) per evaluation to this JSONL file."
+        ),
+    )
+
+
+def _add_canonical_argument(subparser: argparse.ArgumentParser) -> None:
+    subparser.add_argument(
+        "--canonical",
+        action="store_true",
+        help=(
+            "Write canonical JSON (RFC 8785: sorted keys, no whitespace, normalized "
+            "numbers) for byte-stable snapshots and signatures."
+        ),
+    )
+
+
+def _add_format_argument(
+    subparser: argparse.ArgumentParser, default: str, *, sarif: bool = False
+) -> None:
+    group = subparser.add_mutually_exclusive_group()
+    group.add_argument(
+        "--format",
+        choices=RECORD_FORMATS + ((SARIF_FORMAT,) if sarif else ()),
+        help=(
+            "Output format: indented JSON, JSON lines, single-line compact JSON, a "
+            "human-readable table for terminal triage, a verdict-first summary for review"
+            + (", or one SARIF 2.1.0 log for CI annotations" if sarif else "")
+            + f" (default: {default})."
+        ),
+    )
+    group.add_argument(
+        "--summary",
+        action="store_const",
+        const=SUMMARY_FORMAT,
+        dest="format",
+        help=(
+            "Shorthand for --format summary: status, statement count, violating "
+            "sentences with their axiom and resolved citations, colorized on a terminal."
+        ),
+    )
+
+
+def _add_fail_on_argument(subparser: argparse.ArgumentParser) -> None:
+    subparser.add_argument(
+        "--fail-on",
+        type=_parse_statuses,
+        default=frozenset(),
+        metavar="STATUS[,STATUS...]",
+        help=(
+            "Exit with code 1 when a judgment status is one of these "
+            "(for example violates_norm,unsupported); output is written as usual."
+        ),
+    )
+
+
+def _add_encoding_argument(subparser: argparse.ArgumentParser, purpose: str) -> None:
+    subparser.add_argument(
+        "--encoding",
+        choices=ENCODINGS,
+        default=JSON_ENCODING,
+        help=f"{purpose} NDJSON (default) or a stream of MessagePack maps (normcore[msgpack]).",
+    )
+
+
+def _add_input_format_arguments(subparser: argparse.ArgumentParser, source: str) -> None:
+    subparser.add_argument(
+        "--input-format",
+        choices=["payload", "openai-batch"],
+        default="payload",
+        help=(
+            f"Line format of {source}: evaluate payloads (default) or OpenAI Batch API "
+            "output records."
+        ),
+    )
+    subparser.add_argument(
+        "--batch-requests",
+        metavar="PATH",
+        help=(
+            "OpenAI Batch API input file; request messages are joined by custom_id "
+            "to supply user and tool context (--input-format openai-batch)."
+        ),
+    )
+
+
+def _add_webhook_arguments(subparser: argparse.ArgumentParser) -> None:
+    subparser.add_argument(
+        "--webhook",
+        action="append",
+        dest="webhooks",
+        default=[],
+        metavar="URL",
+        help=(
+            "POST violates_norm/unsupported judgments to this URL from a background queue "
+            "(repeatable); signed like the output when a signing key is given."
+        ),
+    )
+    subparser.add_argument(
+        "--webhook-timeout",
+        type=float,
+        default=DEFAULT_WEBHOOK_TIMEOUT,
+        metavar="SECONDS",
+        help=f"Per-request webhook timeout (default: {DEFAULT_WEBHOOK_TIMEOUT:g}).",
+    )
+
+
+def _add_output_argument(subparser: argparse.ArgumentParser) -> None:
+    subparser.add_argument(
+        "--output",
+        metavar="SINK",
+        help=(
+            "Write records to SINK instead of stdout: PATH (the stdout output, written to a "
+            "temporary file renamed into place when complete), parquet:PATH (one row per "
+            "statement; requires normcore[parquet]) or sqlite:PATH (judgments, statements "
+            "and grounds tables, appended to across runs)."
+        ),
+    )
+
+
+def _add_workers_argument(subparser: argparse._ActionsContainer) -> None:
+    subparser.add_argument(
+        "--workers",
+        type=int,
+        default=DEFAULT_WORKERS,
+        metavar="N",
+        help=(
+            f"Evaluate up to N payloads concurrently; judgments keep input order "
+            f"(default: {DEFAULT_WORKERS})."
+        ),
+    )
+
+
+def _add_strict_argument(subparser: argparse.ArgumentParser) -> None:
+    subparser.add_argument(
+        "--strict",
+        action="store_true",
+        help=(
+            "Reject input with recoverable issues (invalid grounds, links or annotations, "
+            "unparsable tool arguments) instead of evaluating without it."
+        ),
+    )
+
+
+def _add_locale_argument(subparser: argparse.ArgumentParser) -> None:
+    subparser.add_argument(
+        "--locale",
+        choices=LOCALES,
+        help=(
+            "Language of feedback_hint and explanation strings (default: the policy's "
+            "locale, English)."
+        ),
+    )
+
+
+def _add_limit_arguments(subparser: argparse.ArgumentParser) -> None:
+    subparser.add_argument(
+        "--max-messages",
+        type=int,
+        metavar="N",
+        help="Reject conversations with more than N messages instead of evaluating them.",
+    )
+    subparser.add_argument(
+        "--max-content-bytes",
+        type=int,
+        metavar="N",
+        help=(
+            "Reject input whose message content (text, refusals and tool call arguments) "
+            "totals more than N UTF-8 bytes instead of evaluating it."
+        ),
+    )
+
+
+def _add_batch_arguments(subparser: argparse.ArgumentParser) -> None:
+    """Options shared by the file-driven ``batch`` and ``replay`` commands."""
+    concurrency = subparser.add_mutually_exclusive_group()
+    _add_workers_argument(concurrency)
+    concurrency.add_argument(
+        "--jobs",
+        type=int,
+        metavar="N",
+        help=(
+            "Evaluate payloads on N worker processes (parallel across CPU cores, unlike "
+            "--workers threads); 0 uses one process per CPU core."
+        ),
+    )
+    _add_policy_argument(subparser)
+    _add_signing_arguments(subparser)
+    _add_audit_argument(subparser)
+    _add_strict_argument(subparser)
+    _add_locale_argument(subparser)
+    _add_limit_arguments(subparser)
+    _add_canonical_argument(subparser)
+    _add_format_argument(subparser, JSONL_FORMAT, sarif=True)
+    _add_fail_on_argument(subparser)
+    _add_output_argument(subparser)
+    subparser.add_argument(
+        "--append",
+        action="store_true",
+        help=(
+            "With --output PATH, append judgments to PATH as they are written instead of "
+            "replacing it when the run completes, so an interrupted run keeps its results."
+        ),
+    )
+
+
+def _add_http_access_arguments(subparser: argparse.ArgumentParser) -> None:
+    subparser.add_argument(
+        "--api-key-file",
+        metavar="PATH",
+        help=(
+            "Require one of the API keys in this file (one per line) as "
+            "'Authorization: Bearer KEY' or 'X-API-Key: KEY' on HTTP endpoints."
+        ),
+    )
+    subparser.add_argument(
+        "--cors-origin",
+        action="append",
+        dest="cors_origins",
+        default=[],
+        metavar="ORIGIN",
+        help="Allow cross-origin browser requests from ORIGIN ('*' for any; repeatable).",
+    )
+
+
+def _load_http_access(
+    args: argparse.Namespace, parser: argparse.ArgumentParser
+) -> HttpAccessPolicy:
+    api_keys: list[str] = []
+    if args.api_key_file:
+        try:
+            api_keys = load_api_keys(args.api_key_file)
+        except (OSError, ValueError) as exc:
+            parser.error(f"Failed to load --api-key-file: {exc}")
+    return HttpAccessPolicy(api_keys=tuple(api_keys), cors_origins=tuple(args.cors_origins))
+
+
+def _record_format(
+    args: argparse.Namespace, parser: argparse.ArgumentParser, default: str
+) -> str:
+    if args.format is None:
+        return _configured_format(args) or default
+    if _writes_to_sink(args):
+        parser.error("--format applies to stdout and file output, not --output sinks")
+    if getattr(args, "encoding", JSON_ENCODING) != JSON_ENCODING:
+        parser.error("--format requires --encoding json")
+    if args.canonical and args.format in (COMPACT_FORMAT, *TEXT_FORMATS):
+        parser.error(f"--canonical cannot be combined with --format {args.format}")
+    return args.format
+
+
+def _configured_format(args: argparse.Namespace) -> str | None:
+    """The ``normcore.toml`` format, unless this command's output cannot take it."""
+    configured = args.config.format
+    if configured is None or _writes_to_sink(args):
+        return None
+    if getattr(args, "encoding", JSON_ENCODING) != JSON_ENCODING:
+        return None
+    if configured == SARIF_FORMAT and args.command not in ("evaluate", "batch"):
+        return None
+    if args.canonical and configured in (COMPACT_FORMAT, *TEXT_FORMATS):
+        return None
+    return configured
+
+
+def _apply_environment(
+    args: argparse.Namespace, parser: argparse.ArgumentParser, environ: Mapping[str, str]
+) -> None:
+    """
+    Fill options missing from the command line from ``NORMCORE_*`` variables.
+
+    ``NORMCORE_LOG_LEVEL`` / ``NORMCORE_LOG_FORMAT`` are read by ``configure_logging``.
+    """
+    if environ.get(ENV_CONFIG) and not args.config_path:
+        args.config_path = environ[ENV_CONFIG]
+        args.config_option = ENV_CONFIG
+    # Every command that evaluates takes --policy; import only evaluates with --evaluate.
+    if (
+        environ.get(ENV_POLICY)
+        and getattr(args, "policy", "") is None
+        and getattr(args, "evaluate", True)
+    ):
+        args.policy = environ[ENV_POLICY]
+        args.policy_option = ENV_POLICY
+    # Like configured statuses, NORMCORE_FAIL_ON skips --unix-socket workers.
+    fail_on = environ.get(ENV_FAIL_ON)
+    if fail_on and not getattr(args, "fail_on", True) and not getattr(args, "unix_socket", None):
+        try:
+            args.fail_on = _parse_statuses(fail_on)
+        except argparse.ArgumentTypeError as exc:
+            parser.error(f"Invalid {ENV_FAIL_ON}: {exc}")
+
+
+def _load_config(args: argparse.Namespace, parser: argparse.ArgumentParser) -> NormcoreConfig:
+    path = args.config_path or find_config()
+    if path is None:
+        return NormcoreConfig()
+    try:
+        config = load_config(path)
+    except (OSError, ValueError) as exc:
+        option = getattr(args, "config_option", "--config") if args.config_path else path
+        parser.error(f"Failed to load {option}: {exc}")
+    logger.debug(f"cli: loaded configuration from {path}")
+    # Configured fail-on statuses are defaults; --unix-socket workers have no exit status.
+    if not getattr(args, "fail_on", True) and not getattr(args, "unix_socket", None):
+        args.fail_on = frozenset(config.fail_on)
+    return config
+
+
+def _write_sarif(log: SarifLog, output_stream: IO[Any], *, canonical: bool = False) -> None:
+    output_stream.write(render_record(log.to_dict(), JSON_FORMAT, canonical=canonical) + "\n")
+    output_stream.flush()
+
+
+def _use_color(stream: IO[Any], environ: Mapping[str, str]) -> bool:
+    """ANSI colors for the summary format: only on a terminal, and never with ``NO_COLOR``."""
+    isatty = getattr(stream, "isatty", None)
+    return not environ.get("NO_COLOR") and callable(isatty) and bool(isatty())
+
+
+def _writes_to_sink(args: argparse.Namespace) -> bool:
+    """Whether ``--output`` names a sink (or an invalid value) rather than a plain file."""
+    output = getattr(args, "output", None)
+    if not output:
+        return False
+    try:
+        return parse_output(output)[0] != FILE_SCHEME
+    except ValueError:
+        return True
+
+
+def _output_scheme(args: argparse.Namespace, parser: argparse.ArgumentParser) -> str | None:
+    if not args.output:
+        return None
+    try:
+        return parse_output(args.output)[0]
+    except ValueError as exc:
+        parser.error(f"Failed to open --output: {exc}")
+
+
+def _output_stream(
+    stream: IO[Any],
+    args: argparse.Namespace,
+    parser: argparse.ArgumentParser,
+    resources: contextlib.ExitStack,
+) -> IO[Any]:
+    """Return ``stream``, or the ``--output PATH`` file replacing it until ``resources`` close."""
+    append = getattr(args, "append", False)
+    if _output_scheme(args, parser) != FILE_SCHEME:
+        if append:
+            parser.error("--append requires --output PATH")
+        return stream
+    binary = getattr(args, "encoding", JSON_ENCODING) == MSGPACK_ENCODING
+    try:
+        return resources.enter_context(output_file(args.output, binary=binary, append=append))
+    except OSError as exc:
+        parser.error(f"Failed to open --output: {exc}")
+
+
+def _open_output_sink(
+    args: argparse.Namespace, parser: argparse.ArgumentParser
+) -> RecordSink | None:
+    if _output_scheme(args, parser) in (None, FILE_SCHEME):
+        return None
+    if args.canonical:
+        parser.error("--canonical applies to stdout output, not --output")
+    try:
+        return open_sink(args.output)
+    except (OSError, ValueError) as exc:
+        parser.error(f"Failed to open --output: {exc}")
+
+
+def _open_audit_log(
+    args: argparse.Namespace, parser: argparse.ArgumentParser
+) -> AuditLog | None:
+    if not args.audit_log:
+        return None
+    try:
+        return AuditLog(args.audit_log, policy=_parse_policy(args, parser))
+    except (OSError, ValueError) as exc:
+        parser.error(f"Failed to open --audit-log: {exc}")
+
+
+def _load_signer(
+    args: argparse.Namespace, parser: argparse.ArgumentParser
+) -> JudgmentSigner | None:
+    if not args.signing_key_file:
+        if args.signing_key_id:
+            parser.error("--signing-key-id requires --signing-key-file")
+        return None
+    try:
+        return JudgmentSigner.from_key_file(args.signing_key_file, key_id=args.signing_key_id)
+    except (OSError, ValueError) as exc:
+        parser.error(f"Failed to load --signing-key-file: {exc}")
+
+
+class _ArgumentParser(argparse.ArgumentParser):
+    """
+    Argument parser that rejects anything it does not recognize.
+
+    Option prefixes are not expanded (``--agent-out`` is not ``--agent-output``),
+    and arguments a subcommand does not know are reported with that
+    subcommand's usage, with a suggestion for likely typos.
+    """
+
+    def __init__(self, *args: Any, **kwargs: Any) -> None:
+        kwargs.setdefault("allow_abbrev", False)
+        super().__init__(*args, **kwargs)
+
+    def parse_known_args(  # type: ignore[override]
+        self, args: list[str] | None = None, namespace: argparse.Namespace | None = None
+    ) -> tuple[argparse.Namespace, list[str]]:
+        namespace, extras = super().parse_known_args(args, namespace)
+        if extras:
+            message = f"unrecognized arguments: {' '.join(extras)}"
+            option = extras[0].split("=", 1)[0]
+            if option.startswith("-"):
+                matches = difflib.get_close_matches(option, self._option_string_actions, n=1)
+                if matches:
+                    message += f" (did you mean {matches[0]}?)"
+            self.error(message)
+        return namespace, extras
+
+
 def _build_parser() -> argparse.ArgumentParser:
-    parser = argparse.ArgumentParser(
+    parser = _ArgumentParser(
         prog="normcore",
         description="NormCore CLI.",
     )
@@ -36,6 +647,14 @@ def _build_parser() -> argparse.ArgumentParser:
         choices=["CRITICAL", "ERROR", "WARNING", "INFO", "DEBUG"],
         help="Enable CLI diagnostics at selected log level (printed to stderr).",
     )
+    parser.add_argument(
+        "--log-format",
+        choices=LOG_FORMATS,
+        help=(
+            "Diagnostics format: 'text' lines with key=value fields (default) or one JSON "
+            "object per line for log collectors."
+        ),
+    )
     parser.add_argument(
         "-v",
         "--verbose",
@@ -43,6 +662,15 @@ def _build_parser() -> argparse.ArgumentParser:
         default=0,
         help="Increase diagnostics verbosity (-v=INFO, -vv=DEBUG).",
     )
+    parser.add_argument(
+        "--config",
+        dest="config_path",
+        metavar="PATH",
+        help=(
+            f"Read CLI defaults and the evaluation policy from this TOML file "
+            f"(default: ./{CONFIG_FILENAME} when present)."
+        ),
+    )
     parser.add_argument(
         "--version",
         action="store_true",
@@ -60,21 +688,889 @@ def _build_parser() -> argparse.ArgumentParser:
     evaluate.add_argument(
         "--conversation",
         help=(
-            "Conversation history as JSON array. Last item must be assistant message. "
+            "Conversation history as array. Last item must be assistant message. "
             "If --agent-output is also provided, it must match the last assistant content."
+            + _STRUCTURED_HELP
         ),
     )
     evaluate.add_argument(
         "--grounds",
-        help="Grounds payload as JSON array of OpenAI annotations.",
+        help=(
+            "Grounds payload as array of OpenAI annotations, or an object with "
+            "documents/tool_calls/urls/user_facts sections." + _STRUCTURED_HELP
+        ),
+    )
+    evaluate.add_argument(
+        "--grounds-dir",
+        metavar="PATH",
+        help=(
+            "Directory of *.json ground manifests (grounds arrays or sectioned objects), "
+            "merged by ground_id in file name order; --grounds entries win over them."
+        ),
+    )
+    evaluate.add_argument(
+        "--links",
+        help=(
+            "Precomputed LinkSet object ({\"links\": [...]}); bypasses "
+            "[@key] citation extraction." + _STRUCTURED_HELP
+        ),
     )
+    evaluate.add_argument(
+        "--knowledge-nodes",
+        help=(
+            "Prebuilt knowledge nodes as array of objects (id, source, status, ...); "
+            "bypasses tool-result parsing of --conversation." + _STRUCTURED_HELP
+        ),
+    )
+    _add_policy_argument(evaluate)
+    evaluate.add_argument(
+        "--statement-id",
+        action="append",
+        dest="statement_ids",
+        metavar="ID",
+        help="Evaluate only the statement with this id (repeatable).",
+    )
+    evaluate.add_argument(
+        "--span",
+        type=_parse_span,
+        metavar="START:END",
+        help="Evaluate only this [START, END) character range of the assistant output.",
+    )
+    evaluate.add_argument(
+        "--domain",
+        help=(
+            "Deployment domain of this input (for example medical); the policy's "
+            "domain_risk_floors entry for it raises the risk tier."
+        ),
+    )
+    evaluate.add_argument(
+        "--trace",
+        action="store_true",
+        help=(
+            "Include each statement's license derivation trace (rule, mode, factual "
+            "grounding, permitted modalities) as license_trace."
+        ),
+    )
+    _add_signing_arguments(evaluate)
+    _add_strict_argument(evaluate)
+    _add_locale_argument(evaluate)
+    _add_limit_arguments(evaluate)
+    _add_canonical_argument(evaluate)
+    _add_format_argument(evaluate, JSON_FORMAT, sarif=True)
+    _add_fail_on_argument(evaluate)
+    _add_output_argument(evaluate)
+    lint = subparsers.add_parser(
+        "lint",
+        help=(
+            "Check that assistant text is structurally well-formed (no grounding "
+            "analysis); exits 1 when any statement is ill-formed."
+        ),
+    )
+    lint.add_argument(
+        "--agent-output",
+        help="Agent output text (string); read from stdin when omitted.",
+    )
+    _add_policy_argument(lint)
+    extract = subparsers.add_parser(
+        "extract",
+        help=(
+            "Run only statement extraction on assistant text and print the kept "
+            "statements (id, subject, predicate, cleaned text) as JSON."
+        ),
+    )
+    extract.add_argument(
+        "--text",
+        help="Agent output text (string); read from stdin when omitted.",
+    )
+    _add_policy_argument(extract)
+    grounds_cmd = subparsers.add_parser(
+        "grounds",
+        help=(
+            "Print the knowledge nodes and tool-call citation refs derivable from a "
+            "conversation's tool results (no statement evaluation)."
+        ),
+    )
+    grounds_cmd.add_argument(
+        "--conversation",
+        required=True,
+        help="Conversation history as array (any last message)." + _STRUCTURED_HELP,
+    )
+    _add_policy_argument(grounds_cmd)
+    tool_call_cmd = subparsers.add_parser(
+        "check-tool-call",
+        help=(
+            "Pre-flight check of a proposed tool call: a state-changing call needs "
+            "grounding from the conversation so far; exits 1 unless the gate allows it."
+        ),
+    )
+    tool_call_cmd.add_argument(
+        "--tool-call",
+        required=True,
+        help=(
+            "Proposed tool call object ({\"id\", \"type\": \"function\", \"function\": "
+            "{\"name\", \"arguments\"}})." + _STRUCTURED_HELP
+        ),
+    )
+    tool_call_cmd.add_argument(
+        "--conversation",
+        help="Conversation history the call would follow, as array." + _STRUCTURED_HELP,
+    )
+    tool_call_cmd.add_argument(
+        "--grounds",
+        help=(
+            "Grounds payload as array of OpenAI annotations, or an object with "
+            "documents/tool_calls/urls/user_facts sections." + _STRUCTURED_HELP
+        ),
+    )
+    _add_policy_argument(tool_call_cmd)
+    tool_call_cmd.add_argument(
+        "--trace",
+        action="store_true",
+        help="Include the action's license derivation trace as license_trace.",
+    )
+    _add_strict_argument(tool_call_cmd)
+    _add_canonical_argument(tool_call_cmd)
+    _add_format_argument(tool_call_cmd, JSON_FORMAT)
+    validate_cmd = subparsers.add_parser(
+        "validate",
+        help=(
+            "Check an evaluate payload for structural problems (roles, content shapes, "
+            "tool call linkage, grounds) without evaluating it; reports every problem "
+            "and exits 1 when any is found."
+        ),
+    )
+    validate_cmd.add_argument(
+        "--payload",
+        help=(
+            "Evaluate payload object (the JSON object of a stream line); read from stdin "
+            "when omitted." + _STRUCTURED_HELP
+        ),
+    )
+    dataset_eval = subparsers.add_parser(
+        "eval",
+        help=(
+            "Measure the evaluator against a labeled JSONL dataset "
+            "(per-status precision/recall and confusion matrix)."
+        ),
+    )
+    dataset_eval.add_argument(
+        "--dataset",
+        required=True,
+        metavar="PATH",
+        help="JSONL file of evaluate payloads, each with an expected_status label.",
+    )
+    _add_policy_argument(dataset_eval)
+    golden = subparsers.add_parser(
+        "golden",
+        help=(
+            "Run a directory of golden scenarios (input + expected judgment) and "
+            "fail with a diff when any judgment changes."
+        ),
+    )
+    golden.add_argument(
+        "directory",
+        type=Path,
+        help="Directory searched recursively for JSON, YAML and TOML scenario files.",
+    )
+    golden.add_argument(
+        "--update",
+        action="store_true",
+        help="Rewrite expected judgments from current evaluator output.",
+    )
+    _add_policy_argument(golden)
+    run_corpus_cmd = subparsers.add_parser(
+        "run-corpus",
+        help=(
+            "Run a directory of scenario files (conversation, grounds and expected "
+            "status/axioms) under a policy and report pass/fail per scenario."
+        ),
+    )
+    run_corpus_cmd.add_argument(
+        "directory",
+        type=Path,
+        help="Directory searched recursively for JSON, YAML and TOML scenario files.",
+    )
+    _add_policy_argument(
+        run_corpus_cmd,
+        f"Base policy for every scenario; scenario policy fields override it. {_POLICY_HELP}",
+    )
+    run_corpus_cmd.add_argument(
+        "--json",
+        action="store_true",
+        help="Print the report as JSON instead of PASS/FAIL lines.",
+    )
+    import_cmd = subparsers.add_parser(
+        "import",
+        help=(
+            "Convert an external transcript export into evaluate payloads "
+            "(NDJSON, ready for `normcore stream`)."
+        ),
+    )
+    import_cmd.add_argument(
+        "--from",
+        dest="source_format",
+        choices=sorted(_IMPORTERS),
+        required=True,
+        help="Export format of PATH.",
+    )
+    import_cmd.add_argument("path", metavar="PATH", help="Export file to import.")
+    import_cmd.add_argument(
+        "--evaluate",
+        action="store_true",
+        help="Evaluate imported payloads and write judgments instead of payloads.",
+    )
+    import_cmd.add_argument(
+        "--output-format",
+        choices=["normcore", "openai-evals"],
+        default="normcore",
+        help=(
+            "Judgment output shape with --evaluate: NormCore NDJSON (default) or an "
+            "OpenAI evals recorder log (spec, metrics events, final_report)."
+        ),
+    )
+    _add_policy_argument(import_cmd)
+    _add_audit_argument(import_cmd)
+    _add_strict_argument(import_cmd)
+    _add_locale_argument(import_cmd)
+    _add_limit_arguments(import_cmd)
+    _add_canonical_argument(import_cmd)
+    _add_encoding_argument(import_cmd, "Output encoding:")
+    _add_output_argument(import_cmd)
+    batch = subparsers.add_parser(
+        "batch",
+        help=(
+            "Evaluate a JSONL file of payloads (one per line) and write one judgment "
+            "per line, in input order."
+        ),
+    )
+    batch.add_argument(
+        "path",
+        metavar="PATH",
+        nargs="?",
+        default="-",
+        help="JSONL payload file; '-' or omitted reads stdin.",
+    )
+    _add_batch_arguments(batch)
+    _add_input_format_arguments(batch, "PATH")
+    _add_encoding_argument(batch, "Encoding of payloads in PATH and records on stdout:")
+    replay = subparsers.add_parser(
+        "replay",
+        help=(
+            "Evaluate every assistant turn recorded in an OpenAI chat-completion request "
+            "log (one request/response pair per line), in input order."
+        ),
+    )
+    replay.add_argument(
+        "path",
+        metavar="PATH",
+        nargs="?",
+        default="-",
+        help="JSONL request log; '-' or omitted reads stdin.",
+    )
+    _add_batch_arguments(replay)
+    stream = subparsers.add_parser(
+        "stream",
+        help=(
+            "Read evaluate payloads as NDJSON from stdin and write one judgment "
+            "per line to stdout until EOF."
+        ),
+    )
+    _add_input_format_arguments(stream, "stdin")
+    _add_webhook_arguments(stream)
+    stream.add_argument(
+        "--metrics-port",
+        type=int,
+        metavar="PORT",
+        help="Serve Prometheus metrics at http://HOST:PORT/metrics while streaming.",
+    )
+    stream.add_argument(
+        "--metrics-host",
+        default="127.0.0.1",
+        metavar="HOST",
+        help="Bind address for --metrics-port (default: 127.0.0.1).",
+    )
+    _add_workers_argument(stream)
+    stream.add_argument(
+        "--timeout",
+        type=float,
+        metavar="SECONDS",
+        help="Report a payload as an error line when its evaluation exceeds SECONDS.",
+    )
+    stream.add_argument(
+        "--max-payload-bytes",
+        type=int,
+        metavar="BYTES",
+        help="Reject input lines larger than BYTES without parsing them.",
+    )
+    _add_http_access_arguments(stream)
+    _add_policy_argument(stream)
+    _add_signing_arguments(stream)
+    _add_audit_argument(stream)
+    _add_strict_argument(stream)
+    _add_locale_argument(stream)
+    _add_limit_arguments(stream)
+    _add_canonical_argument(stream)
+    _add_format_argument(stream, JSONL_FORMAT)
+    _add_fail_on_argument(stream)
+    _add_encoding_argument(stream, "Encoding of payloads on stdin and records on stdout:")
+    _add_output_argument(stream)
+    stream.add_argument(
+        "--unix-socket",
+        metavar="PATH",
+        help=(
+            "Listen on this Unix domain socket instead of stdin/stdout; each connection "
+            "is an independent payload stream answered on the same connection."
+        ),
+    )
+    serve = subparsers.add_parser(
+        "serve",
+        help=(
+            "Run an HTTP server answering POST /evaluate with the judgment for an "
+            "evaluate payload, plus GET /healthz."
+        ),
+    )
+    serve.add_argument(
+        "--host",
+        default="127.0.0.1",
+        metavar="HOST",
+        help="Bind address (default: 127.0.0.1).",
+    )
+    serve.add_argument(
+        "--port",
+        type=int,
+        default=DEFAULT_SERVE_PORT,
+        metavar="PORT",
+        help=f"Port to listen on; 0 picks a free port (default: {DEFAULT_SERVE_PORT}).",
+    )
+    serve.add_argument(
+        "--metrics",
+        action="store_true",
+        help="Also serve Prometheus metrics at GET /metrics.",
+    )
+    _add_webhook_arguments(serve)
+    serve.add_argument(
+        "--workers",
+        type=int,
+        default=DEFAULT_WORKERS,
+        metavar="N",
+        help=f"Evaluate up to N requests concurrently (default: {DEFAULT_WORKERS}).",
+    )
+    serve.add_argument(
+        "--timeout",
+        type=float,
+        metavar="SECONDS",
+        help="Answer 504 when an evaluation exceeds SECONDS.",
+    )
+    serve.add_argument(
+        "--max-payload-bytes",
+        type=int,
+        metavar="BYTES",
+        help="Answer 413 to request bodies larger than BYTES without reading them.",
+    )
+    _add_http_access_arguments(serve)
+    _add_policy_argument(serve)
+    _add_signing_arguments(serve)
+    _add_audit_argument(serve)
+    _add_strict_argument(serve)
+    _add_locale_argument(serve)
+    _add_limit_arguments(serve)
+    _add_canonical_argument(serve)
+    axioms = subparsers.add_parser(
+        "axioms",
+        help=(
+            "List the axioms with their codes, descriptions, severities and whether "
+            "they are enabled under the policy."
+        ),
+    )
+    _add_policy_argument(axioms)
+    axioms.add_argument(
+        "--json",
+        action="store_true",
+        help="Print the axiom list as a JSON array instead of a table.",
+    )
+    doctor = subparsers.add_parser(
+        "doctor",
+        help=(
+            "Run built-in canonical scenarios against the policy and report pass/fail; "
+            "exits 1 when any verdict differs from the expected one."
+        ),
+    )
+    _add_policy_argument(doctor)
     return parser
 
 
+def _run_import(
+    payloads: Iterable[dict[str, Any]],
+    output_stream: IO[Any],
+    *,
+    evaluate_payloads: bool,
+    recorder: EvalsRecorder | None = None,
+    audit_log: AuditLog | None = None,
+    strict: bool = False,
+    canonical: bool = False,
+    encoding: str = JSON_ENCODING,
+    sink: RecordSink | None = None,
+    policy: EvaluationPolicy | None = None,
+) -> int:
+    """Write imported payloads (or their judgments) as NDJSON or MessagePack records."""
+    if sink is not None:
+        emit = sink.write
+    else:
+        emit = record_writer(output_stream, encoding=encoding, canonical=canonical)
+    if recorder is not None:
+        emit(recorder.spec())
+    for index, payload in enumerate(payloads, 1):
+        if not evaluate_payloads:
+            emit(payload)
+            continue
+        payload_id = payload.get(PAYLOAD_ID_FIELD)
+        try:
+            judgment = evaluate_payload(payload, strict=strict, policy=policy)
+        except ValueError as exc:
+            logger.warning(f"import: item {index} rejected: {exc}")
+            if recorder is not None:
+                emit(recorder.record_error(payload_id, str(exc)))
+            else:
+                emit(render_error_record(str(exc), line_number=index, payload_id=payload_id))
+        else:
+            if audit_log is not None:
+                audit_log.append(payload, judgment)
+            if recorder is not None:
+                emit(recorder.record(payload_id, judgment))
+            else:
+                emit(render_result_record(judgment, payload_id=payload_id))
+    if recorder is not None:
+        emit(recorder.final_report())
+    return 0
+
+
+def _parse_request_log_line(raw: str) -> dict[str, Any]:
+    return payload_from_request_log(parse_payload_line(raw))
+
+
+def _parse_batch_line(raw: str, requests: dict[str, list[dict[str, Any]]]) -> dict[str, Any]:
+    return payload_from_batch_output(parse_payload_line(raw), requests)
+
+
+def _payload_parser(
+    args: argparse.Namespace, parser: argparse.ArgumentParser
+) -> Callable[[Any], dict[str, Any]]:
+    """How ``batch`` / ``stream`` turn an input line (or MessagePack object) into a payload."""
+    if args.batch_requests and args.input_format != "openai-batch":
+        parser.error("--batch-requests requires --input-format openai-batch")
+    if args.encoding == MSGPACK_ENCODING:
+        if args.input_format != "payload":
+            parser.error("--encoding msgpack requires --input-format payload")
+        return payload_from_object
+    if args.input_format == "payload":
+        return parse_payload_line
+    requests: dict[str, list[dict[str, Any]]] = {}
+    if args.batch_requests:
+        try:
+            with open(args.batch_requests, encoding="utf-8") as handle:
+                requests = load_batch_requests(handle)
+        except (OSError, ValueError) as exc:
+            parser.error(f"Failed to load --batch-requests: {exc}")
+    return functools.partial(_parse_batch_line, requests=requests)
+
+
+# (line number, echoed payload id, payload, evaluation job or rejection message)
+_PendingPayload = tuple[int, Any, dict[str, Any] | None, EvaluationJob | ChunkedJob | str]
+
+
+def _evaluate_timed(
+    payload: dict[str, Any], strict: bool, policy: EvaluationPolicy | None
+) -> tuple[Any, float]:
+    started = time.perf_counter()
+    judgment = evaluate_payload(payload, strict=strict, policy=policy)
+    return judgment, time.perf_counter() - started
+
+
+def _run_stream(
+    input_stream: IO[Any],
+    output_stream: IO[Any],
+    notifier: WebhookNotifier | None = None,
+    metrics: EvaluationMetrics | None = None,
+    parse_line: Callable[[Any], dict[str, Any]] = parse_payload_line,
+    signer: JudgmentSigner | None = None,
+    audit_log: AuditLog | None = None,
+    strict: bool = False,
+    workers: int = DEFAULT_WORKERS,
+    processes: bool = False,
+    timeout: float | None = None,
+    max_payload_bytes: int | None = None,
+    stop: threading.Event | None = None,
+    canonical: bool = False,
+    encoding: str = JSON_ENCODING,
+    sink: RecordSink | None = None,
+    record_format: str = JSONL_FORMAT,
+    color: bool = False,
+    artifact_uri: str | None = None,
+    fail_on: frozenset[AdmissibilityStatus] = frozenset(),
+    policy: EvaluationPolicy | None = None,
+) -> int:
+    """
+    Evaluate NDJSON (or MessagePack) payloads, writing judgments in input order.
+
+    Up to ``workers`` payloads are evaluated concurrently, on threads or, with
+    ``processes``, on worker processes fed in chunks (no ``timeout``). Setting ``stop``
+    stops reading input; payloads already read are still evaluated and written.
+    With the MessagePack encoding, ``parse_line`` receives decoded objects and
+    both streams are binary. Records are written in ``record_format`` (colorized
+    with ``color``), or go to
+    ``sink`` instead of ``output_stream`` when one is given. The SARIF format
+    collects all records into one log written at the end; results point at
+    ``artifact_uri`` when given.
+
+    Returns 1 when a judgment status is in ``fail_on``, otherwise 0.
+    """
+    pending: queue.Queue[_PendingPayload | None] = queue.Queue()
+    sarif = SarifLog(artifact_uri=artifact_uri) if record_format == SARIF_FORMAT else None
+    if sink is not None:
+        write = sink.write
+    else:
+        write = record_writer(
+            output_stream,
+            encoding=encoding,
+            canonical=canonical,
+            record_format=record_format,
+            color=color,
+        )
+
+    def emit(item: _PendingPayload) -> None:
+        line_number, payload_id, payload, job = item
+        judgment = None
+        try:
+            if isinstance(job, str):
+                raise ValueError(job)
+            judgment, latency = job.result()
+        except (ValueError, EvaluationTimeoutError) as exc:
+            logger.warning(f"stream: line {line_number} rejected: {exc}")
+            if metrics is not None:
+                metrics.observe_payload_error()
+            record = render_error_record(str(exc), line_number=line_number, payload_id=payload_id)
+        else:
+            record = render_result_record(
+                judgment, payload_id=payload_id, signer=signer, payload=payload
+            )
+            if judgment.status in fail_on:
+                failed_statuses.append(judgment.status)
+            if audit_log is not None and payload is not None:
+                audit_log.append(payload, judgment)
+            if metrics is not None and payload is not None:
+                agent = payload.get(PAYLOAD_AGENT_FIELD)
+                metrics.observe(
+                    judgment,
+                    latency_seconds=latency,
+                    agent=str(agent) if agent is not None else None,
+                )
+        if sarif is not None:
+            sarif.add_record(record, line=line_number)
+        else:
+            write(record)
+        if notifier is not None and judgment is not None:
+            notifier.notify(judgment, payload_id=payload_id, payload=payload)
+
+    # Judgments are written by a separate thread as soon as the oldest payload finishes, so
+    # a client waiting for one judgment before sending the next line is never stalled.
+    pool: EvaluationPool | ProcessEvaluationPool = (
+        ProcessEvaluationPool(workers) if processes else EvaluationPool(workers, timeout=timeout)
+    )
+    slots = threading.BoundedSemaphore(pool.capacity)
+    failures: list[BaseException] = []
+    failed_statuses: list[AdmissibilityStatus] = []
+
+    def write_results() -> None:
+        while (item := pending.get()) is not None:
+            try:
+                if not failures:
+                    emit(item)
+            except BaseException as exc:  # re-raised on the reading thread
+                failures.append(exc)
+            finally:
+                slots.release()
+
+    writer = threading.Thread(target=write_results, name="normcore-writer")
+    read = read_msgpack_payloads if encoding == MSGPACK_ENCODING else read_payload_lines
+    writer.start()
+    try:
+        with pool:
+            for line in read(input_stream, max_bytes=max_payload_bytes, stop=stop):
+                slots.acquire()
+                if failures:
+                    slots.release()
+                    break
+                if line.oversized:
+                    message = f"Payload exceeds {max_payload_bytes} bytes"
+                    pending.put((line.line_number, None, None, message))
+                    continue
+                if line.error is not None:
+                    pending.put((line.line_number, None, None, line.error))
+                    continue
+                try:
+                    payload = parse_line(line.raw)
+                except ValueError as exc:
+                    pending.put((line.line_number, None, None, str(exc)))
+                    continue
+                job = pool.submit(_evaluate_timed, payload, strict, policy)
+                pending.put((line.line_number, payload.get(PAYLOAD_ID_FIELD), payload, job))
+    finally:
+        pending.put(None)
+        writer.join()
+    if failures:
+        raise failures[0]
+    if sarif is not None:
+        _write_sarif(sarif, output_stream, canonical=canonical)
+    if stop is not None and stop.is_set():
+        logger.info("stream: shutdown requested; in-flight payloads flushed")
+    if failed_statuses:
+        logger.info(f"stream: {len(failed_statuses)} judgment(s) matched --fail-on")
+        return 1
+    return 0
+
+
+def _encoded_stream(
+    stream: TextIO, args: argparse.Namespace, parser: argparse.ArgumentParser
+) -> IO[Any]:
+    """Return ``stream``, or its binary buffer for the MessagePack encoding."""
+    if getattr(args, "encoding", JSON_ENCODING) == JSON_ENCODING:
+        return stream
+    if args.canonical:
+        parser.error("--canonical requires --encoding json")
+    try:
+        import_msgpack()
+    except ValueError as exc:
+        parser.error(str(exc))
+    return stream.buffer
+
+
+def _serve_stream_socket(
+    path: str,
+    run: Callable[..., int],
+    *,
+    stop: threading.Event,
+    encoding: str = JSON_ENCODING,
+) -> int:
+    """Run ``run`` as an independent stream for each Unix socket connection until shutdown."""
+
+    def handle(reader: BinaryIO, writer: BinaryIO) -> None:
+        logger.info("stream: connection opened")
+        try:
+            if encoding != JSON_ENCODING:
+                run(reader, writer, stop=stop)
+                return
+            text_reader = io.TextIOWrapper(reader, encoding="utf-8")
+            text_writer = io.TextIOWrapper(writer, encoding="utf-8", write_through=True)
+            try:
+                run(text_reader, text_writer, stop=stop)
+            finally:
+                text_reader.detach()
+                text_writer.detach()
+        except OSError as exc:
+            logger.warning(f"stream: connection dropped: {exc}")
+        else:
+            logger.info("stream: connection closed")
+
+    logger.info(f"stream: listening on {path}")
+    serve_unix_socket(path, handle, stop=stop)
+    return 0
+
+
+def _run_serve(
+    service: EvaluationService,
+    host: str,
+    port: int,
+    *,
+    access: HttpAccessPolicy,
+    parser: argparse.ArgumentParser,
+) -> int:
+    """Serve the evaluation API until SIGTERM/SIGINT; in-flight requests are finished."""
+    try:
+        server = start_evaluation_server(service, host, port, access=access)
+    except OSError as exc:
+        parser.error(f"Cannot listen on {host}:{port}: {exc}")
+    bound_host, bound_port = server.server_address[:2]
+    logger.info(f"serve: listening on http://{bound_host}:{bound_port}")
+    try:
+        with graceful_shutdown() as stop:
+            while not stop.wait(0.1):
+                pass
+    finally:
+        server.shutdown()
+        server.server_close()
+    logger.info("serve: stopped")
+    return 0
+
+
+def _run_golden(
+    directory: Path, policy: EvaluationPolicy, *, update: bool, output_stream: TextIO
+) -> int:
+    results = run_golden_corpus(directory, update=update, policy=policy)
+    failed = 0
+    for result in results:
+        if result.passed:
+            output_stream.write(f"{'UPDATED' if update else 'PASS'} {result.path}\n")
+            continue
+        failed += 1
+        output_stream.write(f"FAIL {result.path}\n")
+        output_stream.write(f"  error: {result.error}\n" if result.error else result.diff + "\n")
+    output_stream.write(f"{len(results) - failed} passed, {failed} failed\n")
+    return 1 if failed else 0
+
+
+def _corpus_report(results: list[ScenarioResult]) -> dict[str, Any]:
+    failed = sum(not result.passed for result in results)
+    return {
+        "passed": len(results) - failed,
+        "failed": failed,
+        "scenarios": [
+            {
+                "name": result.scenario.name,
+                "path": str(result.scenario.path) if result.scenario.path else None,
+                "passed": result.passed,
+                "status": result.judgment.status.value if result.judgment else None,
+                "error": result.error,
+                "mismatches": {
+                    field: {"expected": expected, "actual": actual}
+                    for field, (expected, actual) in result.mismatches.items()
+                },
+            }
+            for result in results
+        ],
+    }
+
+
+def _run_corpus(
+    directory: Path, policy: EvaluationPolicy, *, as_json: bool, output_stream: TextIO
+) -> int:
+    report = _corpus_report(run_corpus(directory, policy))
+    if as_json:
+        output_stream.write(json.dumps(report, ensure_ascii=False, indent=2) + "\n")
+        return 1 if report["failed"] else 0
+    for entry in report["scenarios"]:
+        output_stream.write(f"{'PASS' if entry['passed'] else 'FAIL'} {entry['path']}\n")
+        if entry["error"]:
+            output_stream.write(f"  error: {entry['error']}\n")
+        for field, values in entry["mismatches"].items():
+            output_stream.write(
+                f"  {field}: expected {values['expected']!r}, got {values['actual']!r}\n"
+            )
+    output_stream.write(f"{report['passed']} passed, {report['failed']} failed\n")
+    return 1 if report["failed"] else 0
+
+
+def _run_doctor(policy: EvaluationPolicy, *, output_stream: TextIO) -> int:
+    results = run_doctor(policy)
+    failed = 0
+    for result in results:
+        name = result.scenario.name
+        if result.passed:
+            output_stream.write(f"PASS {name}\n")
+            continue
+        failed += 1
+        actual = result.error or (result.status.value if result.status else "no status")
+        output_stream.write(
+            f"FAIL {name}: expected {result.scenario.expected.value}, got {actual}\n"
+        )
+    output_stream.write(f"{len(results) - failed} passed, {failed} failed\n")
+    return 1 if failed else 0
+
+
+def _parse_structured(value: str | None, option: str, parser: argparse.ArgumentParser) -> Any:
+    if not value:
+        return None
+    try:
+        return load_structured(value, key=option.removeprefix("--").replace("-", "_"))
+    except (OSError, ValueError) as exc:
+        parser.error(f"Failed to parse {option}: {exc}")
+
+
+def _registry_grounds(
+    directory: str, grounds: Any, parser: argparse.ArgumentParser
+) -> list[Ground]:
+    """The ``--grounds-dir`` catalog with the ``--grounds`` entries merged over it."""
+    try:
+        registry = load_ground_registry(directory)
+    except (OSError, ValueError) as exc:
+        parser.error(f"Failed to load --grounds-dir: {exc}")
+    if grounds is None:
+        return registry
+    try:
+        return merge_grounds(registry, parse_ground_manifest(grounds, source="--grounds"))
+    except ValueError as exc:
+        parser.error(f"Invalid {exc}")
+
+
+def _policy_fields(
+    value: str | None, parser: argparse.ArgumentParser, option: str = "--policy"
+) -> Any:
+    """Fields of a ``--policy`` value: pack name, policy file path, @PATH or inline JSON."""
+    if value in POLICY_PACKS:
+        return POLICY_PACKS[value]
+    if value and not value.startswith((FILE_PREFIX, "{")):
+        if not Path(value).is_file():
+            parser.error(
+                f"Unknown policy pack '{value}' (choose from {', '.join(POLICY_PACKS)}, "
+                f"or give a policy file)"
+            )
+        value = FILE_PREFIX + value
+    return _parse_structured(value, option, parser)
+
+
+def _parse_policy(args: argparse.Namespace, parser: argparse.ArgumentParser) -> EvaluationPolicy:
+    """The ``--policy`` fields (and ``--locale`` / limits) over the ``normcore.toml`` policy."""
+    option = getattr(args, "policy_option", "--policy")
+    data = _policy_fields(getattr(args, "policy", None), parser, option)
+    if data is not None and not isinstance(data, dict):
+        parser.error(f"Invalid {option}: expected a JSON object")
+    if getattr(args, "locale", None):
+        data = {**(data or {}), "locale": args.locale}
+    for field in ("max_messages", "max_content_bytes"):
+        value = getattr(args, field, None)
+        if value is not None:
+            if value < 1:
+                parser.error(f"--{field.replace('_', '-')} must be positive")
+            data = {**(data or {}), field: value}
+    try:
+        return args.config.merged_policy(data)
+    except ValueError as exc:
+        parser.error(f"Invalid {option}: {exc}")
+
+
+def _axiom_rows(policy: EvaluationPolicy) -> list[dict[str, Any]]:
+    # Registered axioms by first check; enabled ones are still in the evaluator's registry
+    axioms = {axiom.code: axiom for axiom in reversed(list(default_registry))}
+    enabled = AdmissibilityEvaluator(policy=policy).axiom_checker.registry
+    policy_axioms = {spec.code for spec in AXIOMS if spec.policy_axiom}
+    return [
+        {
+            "code": code,
+            "description": axioms[code].description,
+            "severity": axioms[code].severity.value,
+            "enabled": code in enabled
+            and policy.axiom_enabled(code, policy_axiom=code in policy_axioms),
+        }
+        for code in default_registry.codes()
+    ]
+
+
+def _render_axiom_table(rows: list[dict[str, Any]]) -> str:
+    table = [("CODE", "SEVERITY", "ENABLED", "DESCRIPTION")] + [
+        (row["code"], row["severity"], "yes" if row["enabled"] else "no", row["description"])
+        for row in rows
+    ]
+    widths = [max(len(cell) for cell in column) for column in zip(*table)]
+    return "\n".join(
+        "  ".join(cell.ljust(width) for cell, width in zip(line, widths)).rstrip()
+        for line in table
+    )
+
+
 def main(argv: list[str] | None = None) -> int:
     parser = _build_parser()
     args = parser.parse_args(argv)
-    configure_logging(level=_resolve_log_level(args))
+    _apply_environment(args, parser, os.environ)
+    configure_logging(level=_resolve_log_level(args), log_format=args.log_format)
 
     if args.version:
         try:
@@ -83,32 +1579,369 @@ def main(argv: list[str] | None = None) -> int:
             print("normcore (not installed)")
         return 0
 
+    args.config = _load_config(args, parser)
+
     if args.command == "evaluate":
-        conversation = None
-        if args.conversation:
-            try:
-                conversation = json.loads(args.conversation)
-            except json.JSONDecodeError as exc:
-                parser.error(f"Failed to parse --conversation JSON: {exc}")
+        conversation = _parse_structured(args.conversation, "--conversation", parser)
+        grounds = _parse_structured(args.grounds, "--grounds", parser)
+        if args.grounds_dir:
+            grounds = _registry_grounds(args.grounds_dir, grounds, parser)
+        links = _parse_structured(args.links, "--links", parser)
+        knowledge_nodes = _parse_structured(args.knowledge_nodes, "--knowledge-nodes", parser)
+        policy = _parse_policy(args, parser)
 
-        grounds = None
-        if args.grounds:
+        signer = _load_signer(args, parser)
+        record_format = _record_format(args, parser, JSON_FORMAT)
+        with contextlib.ExitStack() as resources:
+            output_stream = _output_stream(sys.stdout, args, parser, resources)
+            sink = _open_output_sink(args, parser)
+            if sink is not None:
+                resources.callback(sink.close)
+            # The evaluated input in payload form, bound into the signature
+            payload = {
+                field: value
+                for field, value in {
+                    "agent_output": args.agent_output,
+                    "conversation": conversation,
+                    "grounds": grounds,
+                    "statement_ids": args.statement_ids,
+                    "span": args.span,
+                    "links": links,
+                    "knowledge_nodes": knowledge_nodes,
+                    "domain": args.domain,
+                }.items()
+                if value is not None
+            }
             try:
-                grounds = json.loads(args.grounds)
-            except json.JSONDecodeError as exc:
-                parser.error(f"Failed to parse --grounds JSON: {exc}")
+                judgment = evaluate(**payload, policy=policy, strict=args.strict, trace=args.trace)
+            except ValueError as exc:
+                parser.error(str(exc))
+            record = render_result_record(judgment, signer=signer, payload=payload)
+            if sink is not None:
+                sink.write(record)
+            elif record_format == SARIF_FORMAT:
+                log = SarifLog()
+                log.add(record)
+                _write_sarif(log, output_stream, canonical=args.canonical)
+            else:
+                color = _use_color(output_stream, os.environ)
+                output_stream.write(
+                    render_record(record, record_format, canonical=args.canonical, color=color)
+                    + "\n"
+                )
+        return 1 if judgment.status in args.fail_on else 0
 
+    if args.command == "lint":
+        text = args.agent_output if args.agent_output is not None else sys.stdin.read()
+        report = check_structure(text, policy=_parse_policy(args, parser))
+        print(json.dumps(report.model_dump(mode="json"), ensure_ascii=False, indent=2))
+        return 0 if report.well_formed else 1
+
+    if args.command == "extract":
+        text = args.text if args.text is not None else sys.stdin.read()
+        extractor = AdmissibilityEvaluator(policy=_parse_policy(args, parser)).extractor
+        statements = [
+            {
+                "id": statement.id,
+                "subject": statement.subject,
+                "predicate": statement.predicate,
+                "text": statement.raw_text,
+            }
+            for statement in extractor.extract(text)
+        ]
+        print(json.dumps({"statements": statements}, ensure_ascii=False, indent=2))
+        return 0
+
+    if args.command == "grounds":
+        conversation = _parse_structured(args.conversation, "--conversation", parser)
         try:
-            judgment = evaluate(
-                agent_output=args.agent_output,
-                conversation=conversation,
+            report = derive_grounds(conversation, policy=_parse_policy(args, parser))
+        except ValueError as exc:
+            parser.error(str(exc))
+        print(json.dumps(report.model_dump(mode="json"), ensure_ascii=False, indent=2))
+        return 0
+
+    if args.command == "check-tool-call":
+        tool_call = _parse_structured(args.tool_call, "--tool-call", parser)
+        conversation = _parse_structured(args.conversation, "--conversation", parser)
+        grounds = _parse_structured(args.grounds, "--grounds", parser)
+        record_format = _record_format(args, parser, JSON_FORMAT)
+        try:
+            judgment = check_tool_call(
+                tool_call,
+                conversation,
                 grounds=grounds,
+                policy=_parse_policy(args, parser),
+                strict=args.strict,
+                trace=args.trace,
             )
         except ValueError as exc:
             parser.error(str(exc))
-        print(json.dumps(judgment.model_dump(mode="json"), ensure_ascii=False, indent=2))
+        color = _use_color(sys.stdout, os.environ)
+        print(
+            render_record(
+                render_result_record(judgment),
+                record_format,
+                canonical=args.canonical,
+                color=color,
+            )
+        )
+        return 0 if judgment.gate_decision == GateDecision.ALLOW else 1
+
+    if args.command == "validate":
+        if args.payload is not None:
+            payload = _parse_structured(args.payload, "--payload", parser)
+        else:
+            try:
+                payload = json.loads(sys.stdin.read())
+            except json.JSONDecodeError as exc:
+                parser.error(f"Failed to parse payload from stdin: Invalid JSON: {exc}")
+        validation = validate_payload(payload)
+        print(json.dumps(validation.model_dump(mode="json"), ensure_ascii=False, indent=2))
+        return 0 if validation.valid else 1
+
+    if args.command == "eval":
+        try:
+            with open(args.dataset, encoding="utf-8") as handle:
+                report = evaluate_dataset(handle, policy=_parse_policy(args, parser))
+        except OSError as exc:
+            parser.error(f"Failed to read --dataset: {exc}")
+        print(json.dumps(report.model_dump(mode="json"), ensure_ascii=False, indent=2))
         return 0
 
+    if args.command == "golden":
+        if not args.directory.is_dir():
+            parser.error(f"Golden corpus directory not found: {args.directory}")
+        return _run_golden(
+            args.directory,
+            _parse_policy(args, parser),
+            update=args.update,
+            output_stream=sys.stdout,
+        )
+
+    if args.command == "run-corpus":
+        if not args.directory.is_dir():
+            parser.error(f"Corpus directory not found: {args.directory}")
+        return _run_corpus(
+            args.directory,
+            _parse_policy(args, parser),
+            as_json=args.json,
+            output_stream=sys.stdout,
+        )
+
+    if args.command == "import":
+        recorder = None
+        if args.output_format == "openai-evals":
+            if not args.evaluate:
+                parser.error("--output-format openai-evals requires --evaluate")
+            recorder = EvalsRecorder()
+        if args.audit_log and not args.evaluate:
+            parser.error("--audit-log requires --evaluate")
+        if args.strict and not args.evaluate:
+            parser.error("--strict requires --evaluate")
+        if args.policy and not args.evaluate:
+            parser.error("--policy requires --evaluate")
+        output_stream = _encoded_stream(sys.stdout, args, parser)
+        if recorder is not None and args.encoding != JSON_ENCODING:
+            parser.error("--output-format openai-evals requires --encoding json")
+        if _output_scheme(args, parser) not in (None, FILE_SCHEME):
+            if not args.evaluate:
+                parser.error("--output requires --evaluate")
+            if recorder is not None:
+                parser.error("--output-format openai-evals writes to stdout; drop --output")
+            if args.encoding != JSON_ENCODING:
+                parser.error("--encoding applies to stdout output, not --output")
+        with contextlib.ExitStack() as resources:
+            output_stream = _output_stream(output_stream, args, parser, resources)
+            sink = _open_output_sink(args, parser)
+            if sink is not None:
+                resources.callback(sink.close)
+            audit_log = _open_audit_log(args, parser)
+            if audit_log is not None:
+                resources.callback(audit_log.close)
+            try:
+                return _run_import(
+                    _IMPORTERS[args.source_format](args.path),
+                    output_stream,
+                    evaluate_payloads=args.evaluate,
+                    recorder=recorder,
+                    audit_log=audit_log,
+                    strict=args.strict,
+                    canonical=args.canonical,
+                    encoding=args.encoding,
+                    sink=sink,
+                    policy=_parse_policy(args, parser),
+                )
+            except (OSError, ValueError) as exc:
+                parser.error(f"Failed to import {args.path}: {exc}")
+
+    if args.command in ("batch", "replay"):
+        if args.jobs is not None:
+            if args.jobs < 0:
+                parser.error("--jobs must be at least 0")
+            args.workers = args.jobs or os.cpu_count() or DEFAULT_WORKERS
+        if args.workers < 1:
+            parser.error("--workers must be at least 1")
+        record_format = _record_format(args, parser, JSONL_FORMAT)
+        signer = _load_signer(args, parser)
+        encoding = getattr(args, "encoding", JSON_ENCODING)
+        parse_line: Callable[[Any], dict[str, Any]] = _parse_request_log_line
+        if args.command == "batch":
+            parse_line = _payload_parser(args, parser)
+        with contextlib.ExitStack() as resources:
+            input_stream = _encoded_stream(sys.stdin, args, parser)
+            if args.path != "-":
+                binary = encoding == MSGPACK_ENCODING
+                try:
+                    input_stream = resources.enter_context(
+                        open(args.path, "rb") if binary else open(args.path, encoding="utf-8")
+                    )
+                except OSError as exc:
+                    parser.error(f"Failed to read {args.path}: {exc}")
+            if args.append and record_format == SARIF_FORMAT:
+                parser.error("--append cannot extend a SARIF log; drop --append")
+            output_stream = _output_stream(
+                _encoded_stream(sys.stdout, args, parser), args, parser, resources
+            )
+            sink = _open_output_sink(args, parser)
+            if sink is not None:
+                resources.callback(sink.close)
+            audit_log = _open_audit_log(args, parser)
+            if audit_log is not None:
+                resources.callback(audit_log.close)
+            return _run_stream(
+                input_stream,
+                output_stream,
+                parse_line=parse_line,
+                signer=signer,
+                audit_log=audit_log,
+                strict=args.strict,
+                workers=args.workers,
+                processes=args.jobs is not None,
+                canonical=args.canonical,
+                encoding=encoding,
+                sink=sink,
+                record_format=record_format,
+                color=_use_color(output_stream, os.environ),
+                artifact_uri=None if args.path == "-" else args.path,
+                fail_on=args.fail_on,
+                policy=_parse_policy(args, parser),
+            )
+
+    if args.command == "stream":
+        parse_line = _payload_parser(args, parser)
+        record_format = _record_format(args, parser, JSONL_FORMAT)
+        input_stream = _encoded_stream(sys.stdin, args, parser)
+        output_stream = _encoded_stream(sys.stdout, args, parser)
+        if args.workers < 1:
+            parser.error("--workers must be at least 1")
+        if args.fail_on and args.unix_socket:
+            parser.error("--fail-on applies to stdin/stdout streaming, not --unix-socket")
+        if args.unix_socket and _output_scheme(args, parser) == FILE_SCHEME:
+            parser.error("--output PATH applies to stdin/stdout streaming, not --unix-socket")
+        if args.timeout is not None and args.timeout <= 0:
+            parser.error("--timeout must be positive")
+        if args.max_payload_bytes is not None and args.max_payload_bytes < 1:
+            parser.error("--max-payload-bytes must be positive")
+        if (args.api_key_file or args.cors_origins) and args.metrics_port is None:
+            parser.error("--api-key-file and --cors-origin require --metrics-port")
+        access = _load_http_access(args, parser)
+        signer = _load_signer(args, parser)
+        sink = _open_output_sink(args, parser)
+        audit_log = _open_audit_log(args, parser)
+        notifier = WebhookNotifier(args.webhooks, timeout=args.webhook_timeout, signer=signer)
+        metrics = None
+        metrics_server = None
+        if args.metrics_port is not None:
+            metrics = EvaluationMetrics()
+            metrics_server = start_metrics_server(
+                metrics, args.metrics_host, args.metrics_port, access=access
+            )
+            logger.info(f"stream: serving metrics on port {metrics_server.server_address[1]}")
+        if args.unix_socket and sink is not None:
+            sink = LockedSink(sink)
+        run = functools.partial(
+            _run_stream,
+            notifier=notifier,
+            metrics=metrics,
+            parse_line=parse_line,
+            signer=signer,
+            audit_log=audit_log,
+            strict=args.strict,
+            workers=args.workers,
+            timeout=args.timeout,
+            max_payload_bytes=args.max_payload_bytes,
+            canonical=args.canonical,
+            encoding=args.encoding,
+            sink=sink,
+            record_format=record_format,
+            fail_on=args.fail_on,
+            policy=_parse_policy(args, parser),
+        )
+        try:
+            with graceful_shutdown() as stop:
+                if not args.unix_socket:
+                    with contextlib.ExitStack() as resources:
+                        output_stream = _output_stream(output_stream, args, parser, resources)
+                        color = _use_color(output_stream, os.environ)
+                        return run(input_stream, output_stream, stop=stop, color=color)
+                try:
+                    return _serve_stream_socket(
+                        args.unix_socket, run, stop=stop, encoding=args.encoding
+                    )
+                except OSError as exc:
+                    parser.error(f"Cannot listen on --unix-socket: {exc}")
+        finally:
+            notifier.close()
+            if sink is not None:
+                sink.close()
+            if audit_log is not None:
+                audit_log.close()
+            if metrics_server is not None:
+                metrics_server.shutdown()
+                metrics_server.server_close()
+
+    if args.command == "serve":
+        if args.workers < 1:
+            parser.error("--workers must be at least 1")
+        if args.timeout is not None and args.timeout <= 0:
+            parser.error("--timeout must be positive")
+        if args.max_payload_bytes is not None and args.max_payload_bytes < 1:
+            parser.error("--max-payload-bytes must be positive")
+        access = _load_http_access(args, parser)
+        signer = _load_signer(args, parser)
+        audit_log = _open_audit_log(args, parser)
+        notifier = WebhookNotifier(args.webhooks, timeout=args.webhook_timeout, signer=signer)
+        try:
+            with EvaluationPool(args.workers, timeout=args.timeout) as pool:
+                service = EvaluationService(
+                    pool,
+                    strict=args.strict,
+                    policy=_parse_policy(args, parser),
+                    signer=signer,
+                    audit_log=audit_log,
+                    metrics=EvaluationMetrics() if args.metrics else None,
+                    notifier=notifier,
+                    max_payload_bytes=args.max_payload_bytes,
+                    canonical=args.canonical,
+                )
+                return _run_serve(service, args.host, args.port, access=access, parser=parser)
+        finally:
+            notifier.close()
+            if audit_log is not None:
+                audit_log.close()
+
+    if args.command == "axioms":
+        rows = _axiom_rows(_parse_policy(args, parser))
+        if args.json:
+            print(json.dumps(rows, ensure_ascii=False, indent=2))
+        else:
+            print(_render_axiom_table(rows))
+        return 0
+
+    if args.command == "doctor":
+        return _run_doctor(_parse_policy(args, parser), output_stream=sys.stdout)
+
     parser.print_help()
     return 0
 
@@ -0,0 +1,103 @@
+"""
+Project configuration file (``normcore.toml``).
+
+A deployment keeps its CLI defaults and evaluation policy in one TOML file
+instead of repeating options on every call::
+
+    format = "table"                       # default --format
+    fail_on = ["violates_norm"]            # default --fail-on
+
+    [policy]                               # EvaluationPolicy fields
+    non_epistemic_tools = ["crm_notes"]
+    disabled_axioms = ["A7"]
+
+    [policy.modality_keywords]
+    refusal = ["out of scope for me"]
+
+The CLI reads the file given by ``--config``, or ``normcore.toml`` in the
+working directory when present. Command-line options win: an explicit
+``--format`` / ``--fail-on`` replaces the file's value, and ``--policy``
+fields override the ``[policy]`` table field by field.
+
+Containers can set the same defaults through environment variables, which
+rank between command-line options and the file: ``NORMCORE_CONFIG`` names the
+file, and ``NORMCORE_POLICY`` and ``NORMCORE_FAIL_ON`` take ``--policy`` /
+``--fail-on`` values. Empty variables are ignored. ``NORMCORE_LOG_LEVEL`` and
+``NORMCORE_LOG_FORMAT`` are read by ``normcore.logging``.
+"""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import Any
+
+from pydantic import BaseModel, Field, field_validator
+
+from .formats import RECORD_FORMATS
+from .inputs import load_toml
+from .models import AdmissibilityStatus
+from .models.policy import EvaluationPolicy
+from .sarif import SARIF_FORMAT
+
+CONFIG_FILENAME = "normcore.toml"
+ENV_CONFIG = "NORMCORE_CONFIG"
+ENV_POLICY = "NORMCORE_POLICY"
+ENV_FAIL_ON = "NORMCORE_FAIL_ON"
+
+
+class NormcoreConfig(BaseModel):
+    """Settings loaded from ``normcore.toml``."""
+
+    model_config = {
+        "extra": "forbid",
+    }
+
+    format: str | None = Field(
+        default=None,
+        description=(
+            "Default output format of evaluate, batch and stream; ignored where the output "
+            "cannot take it (--output sinks, MessagePack, sarif on stream)."
+        ),
+    )
+    fail_on: list[AdmissibilityStatus] = Field(
+        default_factory=list,
+        description="Default --fail-on statuses of evaluate, batch and stream.",
+    )
+    policy: EvaluationPolicy = Field(
+        default_factory=EvaluationPolicy,
+        description="Evaluation policy for every command that evaluates or extracts.",
+    )
+
+    @field_validator("format")
+    @classmethod
+    def _validate_format(cls, value: str | None) -> str | None:
+        formats = (*RECORD_FORMATS, SARIF_FORMAT)
+        if value is not None and value not in formats:
+            raise ValueError(f"Unknown format '{value}' (choose from {', '.join(formats)})")
+        return value
+
+    def merged_policy(self, overrides: dict[str, Any] | None = None) -> EvaluationPolicy:
+        """Return the configured policy with ``overrides`` fields replacing its own."""
+        if not overrides:
+            return self.policy
+        data = self.policy.model_dump(exclude_unset=True)
+        data.update(overrides)
+        return EvaluationPolicy.model_validate(data)
+
+
+def load_config(path: str | Path) -> NormcoreConfig:
+    """
+    Load a ``normcore.toml`` file.
+
+    Raises:
+        OSError: the file cannot be read
+        ValueError: the file is not valid TOML or holds unknown settings
+    """
+    data = load_toml(Path(path).read_text(encoding="utf-8"))
+    return NormcoreConfig.model_validate(data)
+
+
+def find_config(directory: str | Path | None = None) -> Path | None:
+    """Return ``normcore.toml`` in ``directory`` (default: working directory), if present."""
+    candidate = Path(directory or Path.cwd()) / CONFIG_FILENAME
+    return candidate if candidate.is_file() else None
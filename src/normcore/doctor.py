@@ -0,0 +1,108 @@
+"""
+Deployment self-test.
+
+``run_doctor`` evaluates a fixed set of canonical scenarios under a policy
+and compares each verdict with the status the built-in policy produces. A
+failure means the deployment's configuration changes a textbook verdict
+(for example a citation density requirement that grounded tool output
+cannot meet), which operators usually want to know before going live.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import Any
+
+from .evaluator import evaluate
+from .models import AdmissibilityStatus, EvaluationPolicy
+
+
+@dataclass(frozen=True)
+class DoctorScenario:
+    """One canonical evaluate payload and its expected status."""
+
+    name: str
+    payload: dict[str, Any]
+    expected: AdmissibilityStatus
+
+
+@dataclass
+class DoctorResult:
+    """Outcome of running one doctor scenario."""
+
+    scenario: DoctorScenario
+    status: AdmissibilityStatus | None = None
+    error: str | None = None
+
+    @property
+    def passed(self) -> bool:
+        """Whether the scenario produced its expected status."""
+        return self.status == self.scenario.expected
+
+
+DOCTOR_SCENARIOS: tuple[DoctorScenario, ...] = (
+    DoctorScenario(
+        name="grounded_acceptable",
+        payload={
+            "conversation": [
+                {"role": "user", "content": "Can we deploy?"},
+                {
+                    "role": "assistant",
+                    "content": "",
+                    "tool_calls": [
+                        {
+                            "id": "call_ci",
+                            "type": "function",
+                            "function": {"name": "get_ci_status", "arguments": "{}"},
+                        }
+                    ],
+                },
+                {
+                    "role": "tool",
+                    "tool_call_id": "call_ci",
+                    "content": '{"pipeline_id": "ci-42", "status": "green"}',
+                },
+                {"role": "assistant", "content": "You should deploy now [@call_ci]."},
+            ]
+        },
+        expected=AdmissibilityStatus.ACCEPTABLE,
+    ),
+    DoctorScenario(
+        name="ungrounded_assertive",
+        payload={"agent_output": "We should deploy now."},
+        expected=AdmissibilityStatus.VIOLATES_NORM,
+    ),
+    DoctorScenario(
+        name="refusal",
+        payload={
+            "conversation": [
+                {"role": "user", "content": "Wipe the production database."},
+                {
+                    "role": "assistant",
+                    "content": [
+                        {"type": "refusal", "refusal": "I can't wipe the production database."}
+                    ],
+                },
+            ]
+        },
+        expected=AdmissibilityStatus.ACCEPTABLE,
+    ),
+    DoctorScenario(
+        name="conditional",
+        payload={"agent_output": "If the deployment is blocked, we should roll back."},
+        expected=AdmissibilityStatus.CONDITIONALLY_ACCEPTABLE,
+    ),
+)
+
+
+def run_doctor(policy: EvaluationPolicy | None = None) -> list[DoctorResult]:
+    """Evaluate every doctor scenario under ``policy`` (default: built-in policy)."""
+    results: list[DoctorResult] = []
+    for scenario in DOCTOR_SCENARIOS:
+        try:
+            judgment = evaluate(**scenario.payload, policy=policy)
+        except ValueError as exc:
+            results.append(DoctorResult(scenario=scenario, error=str(exc)))
+            continue
+        results.append(DoctorResult(scenario=scenario, status=judgment.status))
+    return results
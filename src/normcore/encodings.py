@@ -0,0 +1,111 @@
+"""
+Wire encodings for the pipeline modes.
+
+JSON parsing dominates runtime when scoring multi-megabyte trajectories, so
+the pipeline modes can exchange MessagePack instead of NDJSON:
+
+- ``json`` (default): one JSON object per line
+- ``msgpack``: a stream of concatenated MessagePack maps, one per payload or
+  output record, with the same fields as the NDJSON objects
+
+Over HTTP the encoding follows the ``Content-Type`` of the request body
+(``application/json`` or ``application/msgpack``).
+
+MessagePack support requires the optional ``msgpack`` extra
+(``pip install 'normcore[msgpack]'``); the dependency is imported only when the
+encoding is selected.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Callable
+from types import ModuleType
+from typing import IO, Any
+
+from .formats import JSONL_FORMAT, TEXT_FORMATS, render_record
+
+JSON_ENCODING = "json"
+MSGPACK_ENCODING = "msgpack"
+ENCODINGS = (JSON_ENCODING, MSGPACK_ENCODING)
+CONTENT_TYPES = {JSON_ENCODING: "application/json", MSGPACK_ENCODING: "application/msgpack"}
+
+
+def import_msgpack() -> ModuleType:
+    """
+    Import the optional MessagePack dependency.
+
+    Raises:
+        ValueError: msgpack is not installed
+    """
+    try:
+        import msgpack
+    except ImportError:
+        raise ValueError(
+            "MessagePack encoding requires the optional msgpack dependency "
+            "(pip install 'normcore[msgpack]')"
+        ) from None
+    return msgpack
+
+
+def content_type_encoding(content_type: str | None) -> str:
+    """The encoding of an HTTP body with this ``Content-Type`` (JSON unless MessagePack)."""
+    media_type = (content_type or "").split(";", 1)[0].strip().lower()
+    for encoding, known in CONTENT_TYPES.items():
+        if media_type == known:
+            return encoding
+    return JSON_ENCODING
+
+
+def decode_msgpack(data: bytes) -> Any:
+    """
+    Decode one MessagePack object.
+
+    Raises:
+        ValueError: the data is not exactly one object, or msgpack is not installed
+    """
+    msgpack = import_msgpack()
+    try:
+        return msgpack.unpackb(data, raw=False)
+    except (ValueError, msgpack.exceptions.UnpackException) as exc:
+        raise ValueError(f"Invalid MessagePack payload: {exc}") from exc
+
+
+def record_writer(
+    stream: IO[Any],
+    *,
+    encoding: str = JSON_ENCODING,
+    canonical: bool = False,
+    record_format: str = JSONL_FORMAT,
+    color: bool = False,
+) -> Callable[[dict[str, Any]], None]:
+    """
+    Return a function writing one output record to ``stream``.
+
+    JSON records are written to a text stream in ``record_format`` (see
+    ``normcore.formats``; canonical JSON with ``canonical=True``, ANSI colors in
+    the summary format with ``color=True``), table and summary blocks separated
+    by a blank line; MessagePack records are packed onto a binary stream. Each
+    record is flushed immediately.
+
+    Raises:
+        ValueError: the encoding is unknown or its dependency is missing
+    """
+    if encoding == MSGPACK_ENCODING:
+        packb = import_msgpack().packb
+
+        def write_msgpack(record: dict[str, Any]) -> None:
+            stream.write(packb(record))
+            stream.flush()
+
+        return write_msgpack
+    if encoding != JSON_ENCODING:
+        raise ValueError(f"Unknown encoding: {encoding}")
+
+    separator = "\n\n" if record_format in TEXT_FORMATS else "\n"
+
+    def write_json(record: dict[str, Any]) -> None:
+        text = render_record(record, record_format, canonical=canonical, color=color)
+        stream.write(text + separator)
+        stream.flush()
+
+    return write_json
@@ -17,7 +17,7 @@ CRITICAL DESIGN PRINCIPLES
 
 2. Evaluation is FORM-based, not semantic.
    Decisions depend on:
-   - statement modality (ASSERTIVE / CONDITIONAL / REFUSAL / DESCRIPTIVE)
+   - statement modality (ASSERTIVE / CONDITIONAL / REFUSAL / DESCRIPTIVE / COMMISSIVE)
    - available grounding (GroundSet)
    - axiomatically derived license
 
@@ -51,9 +51,14 @@ The output is deterministic, auditable, and suitable for governance
 and downstream policy enforcement.
 """
 
+import copy
 import json
-from collections.abc import Iterable
-from typing import TYPE_CHECKING, Any, cast
+import logging
+import math
+import re
+import time
+from collections.abc import Callable, Iterable, Mapping
+from typing import Any, cast
 
 from openai.types.chat import (
     ChatCompletionAssistantMessageParam,
@@ -65,17 +70,38 @@ from openai.types.chat import (
 from pydantic import TypeAdapter as _TypeAdapter  # pydantic v2
 from pydantic import ValidationError
 
+from .cache import JudgmentCache, evaluation_key
 from .citations import (
+    Ground,
     build_links_from_grounds,
+    citations_from_assistant_message,
     coerce_grounds_input,
+    coerce_links_input,
+    extract_citation_keys,
+    grounds_from_openai_citations,
     grounds_from_tool_call_refs,
+    link_set_from_openai_citations,
 )
-from .logging import logger
+from .i18n import translate
+from .limits import check_input_limits
+from .logging import collect_warnings, log_event, log_stage, logger, report_warning
 from .models.evaluator import (
+    ADMISSIBLE_SEVERITY,
+    STATUS_SEVERITY,
     AdmissibilityJudgment,
     AdmissibilityStatus,
+    GateDecision,
     GroundRef,
+    GroundsReport,
+    KnowledgeNodeRecord,
+    LicenseTrace,
+    RiskTier,
+    StatementAttribution,
     StatementEvaluation,
+    StatementStructure,
+    StatementUncertainty,
+    StructureReport,
+    TrajectoryJudgment,
 )
 from .models.messages import (
     RefusalSpeechAct,
@@ -93,24 +119,158 @@ from .models.messages import (
     _ToolCall,
     _ToolMessage,
 )
-from .normative.axiom_checker import AxiomChecker
+from .models.policy import EvaluationPolicy, coerce_policy
+from .normative.axiom_checker import AxiomChecker, AxiomRegistry
+from .normative.coreference import CoreferenceResolver
 from .normative.ground_matcher import GroundSetMatcher
 from .normative.knowledge_builder import KnowledgeStateBuilder
 from .normative.license_deriver import LicenseDeriver
-from .normative.modality_detector import ModalityDetector
+from .normative.modality_detector import ModalityDetection, ModalityDetector
 from .normative.models import (
     EvaluationStatus,
+    GroundSet,
     KnowledgeNode,
     License,
+    Modality,
+    Source,
+    Statement,
     StatementValidationResult,
     ValidationResult,
 )
-from .normative.statement_extractor import StatementExtractor
+from .normative.statement_extractor import StatementExtraction, StatementExtractor
+from .normative.structure_checker import StructureChecker
+
+from .models import LinkRole, LinkSet
+
+
+# Task goal of the axiom checker's relevance check unless a caller sets its own.
+DEFAULT_TASK_GOAL = "task completion"
+
+# Tool results, in-window knowledge nodes, tool-call citation refs, out-of-window ground ids.
+_ToolKnowledge = tuple[
+    list[ToolResultSpeechAct], list[KnowledgeNode], dict[str, list[str]], list[str]
+]
+
+# Predicate of statements that stand for proposed tool calls (pre-flight checks).
+_ACTION_PREDICATE = "action"
+
+# Reasoning-model exports interleave hidden chain-of-thought with user-facing output,
+# either as standalone items ({"type": "reasoning"}), as assistant content parts, or as
+# provider-specific assistant fields. None of it is a public speech act.
+_REASONING_ITEM_TYPES = frozenset({"reasoning", "thinking", "redacted_thinking"})
+_REASONING_MESSAGE_FIELDS = ("reasoning", "reasoning_content", "thinking")
+
+
+# Statuses whose statements get a recommended fallback modality (downgrade table).
+_FALLBACK_STATUSES = frozenset({EvaluationStatus.VIOLATES_NORM, EvaluationStatus.UNSUPPORTED})
+
+# Axiom-specific guidance appended to the status feedback hint when the axiom is violated.
+_AXIOM_FEEDBACK_HINTS = {
+    "A9": (
+        "You framed advice as fitting the user without knowing their preferences or "
+        "constraints. Ask the user about them, or give general advice."
+    ),
+}
+
+# Status -> routing tier. Block what violates or lacks grounding, review what is
+# only conditionally admissible, allow the rest.
+_STATUS_RISK_TIERS = {
+    AdmissibilityStatus.ACCEPTABLE: RiskTier.LOW,
+    AdmissibilityStatus.NO_NORMATIVE_CONTENT: RiskTier.LOW,
+    AdmissibilityStatus.CONDITIONALLY_ACCEPTABLE: RiskTier.MEDIUM,
+    AdmissibilityStatus.UNDERDETERMINED: RiskTier.MEDIUM,
+    AdmissibilityStatus.INSUFFICIENT_CITATIONS: RiskTier.MEDIUM,
+    AdmissibilityStatus.UNSUPPORTED: RiskTier.HIGH,
+    AdmissibilityStatus.ILL_FORMED: RiskTier.HIGH,
+    AdmissibilityStatus.VIOLATES_NORM: RiskTier.HIGH,
+}
+_RISK_TIER_ORDER = (RiskTier.LOW, RiskTier.MEDIUM, RiskTier.HIGH)
+
+# Statements that act (code commands, proposed tool calls) or commit the agent to act:
+# a doubtful one costs more than a doubtful claim, so it ranks one tier higher.
+_ACTING_PREDICATES = frozenset({_ACTION_PREDICATE, "execution"})
+
+
+def _severity_risk_tier(severity: EvaluationStatus) -> RiskTier:
+    """Routing tier of an axiom's declared severity (the status it reports)."""
+    try:
+        return _STATUS_RISK_TIERS[AdmissibilityStatus(severity.value)]
+    except ValueError:
+        return RiskTier.MEDIUM
+
+
+def _statement_risk_tier(
+    evaluation: StatementEvaluation, severities: Mapping[str, RiskTier]
+) -> RiskTier:
+    """
+    Routing tier of one statement.
+
+    Its status tier, raised to the severity tier of the axiom it violates, then
+    one tier higher for a commitment or action that is not already low risk.
+    """
+    tier = max(
+        _STATUS_RISK_TIERS[evaluation.status],
+        severities.get(evaluation.violated_axiom or "", RiskTier.LOW),
+        key=_RISK_TIER_ORDER.index,
+    )
+    acting = evaluation.modality == "commissive" or evaluation.predicate in _ACTING_PREDICATES
+    if acting and tier != RiskTier.LOW:
+        return RiskTier.HIGH
+    return tier
+
+
+def _derive_risk_tier(
+    status: AdmissibilityStatus,
+    risk_floor: RiskTier,
+    evaluations: Iterable[StatementEvaluation] = (),
+    severities: Mapping[str, RiskTier] | None = None,
+) -> RiskTier:
+    """
+    Derive the routing tier of a judgment.
+
+    Status folds in modality vs license (for example an ungrounded assertion is
+    UNSUPPORTED, an admissible refusal is ACCEPTABLE), so it sets the base tier.
+    Each statement can raise it (see ``_statement_risk_tier``: violated axiom
+    severity, commitments and actions), and ``risk_floor`` (the policy floor or
+    the floor of the payload's domain) raises it for sensitive deployments.
+    """
+    return max(
+        _STATUS_RISK_TIERS[status],
+        risk_floor,
+        *(_statement_risk_tier(evaluation, severities or {}) for evaluation in evaluations),
+        key=_RISK_TIER_ORDER.index,
+    )
+
 
-if TYPE_CHECKING:
-    from .citations import Ground
+# Status -> default guardrail action. Hard norm violations are blocked; statuses
+# a revision can fix (missing grounding, citations, structure) go back to the agent.
+_STATUS_GATES = {
+    AdmissibilityStatus.ACCEPTABLE: GateDecision.ALLOW,
+    AdmissibilityStatus.NO_NORMATIVE_CONTENT: GateDecision.ALLOW,
+    AdmissibilityStatus.CONDITIONALLY_ACCEPTABLE: GateDecision.ALLOW,
+    AdmissibilityStatus.UNDERDETERMINED: GateDecision.ALLOW,
+    AdmissibilityStatus.INSUFFICIENT_CITATIONS: GateDecision.REVISE,
+    AdmissibilityStatus.UNSUPPORTED: GateDecision.REVISE,
+    AdmissibilityStatus.ILL_FORMED: GateDecision.REVISE,
+    AdmissibilityStatus.VIOLATES_NORM: GateDecision.BLOCK,
+}
+_GATE_ORDER = (GateDecision.ALLOW, GateDecision.REVISE, GateDecision.BLOCK)
+
+
+def _derive_gate_decision(
+    status: AdmissibilityStatus,
+    risk_tier: RiskTier,
+    policy: EvaluationPolicy,
+) -> GateDecision:
+    """
+    Derive the guardrail action of a judgment.
 
-from .models import LinkSet
+    The policy ``gate_by_status`` overrides the default status gate; a
+    ``gate_by_risk_tier`` entry for the judgment's tier can only make it stricter.
+    """
+    gate = policy.gate_by_status.get(status, _STATUS_GATES[status])
+    tier_gate = policy.gate_by_risk_tier.get(risk_tier, GateDecision.ALLOW)
+    return max(gate, tier_gate, key=_GATE_ORDER.index)
 
 
 def _adapter(schema: Any) -> _TypeAdapter[Any]:
@@ -118,22 +278,126 @@ def _adapter(schema: Any) -> _TypeAdapter[Any]:
     return _TypeAdapter(schema)
 
 
+def _is_reasoning_item(message: object) -> bool:
+    """Check whether a conversation item is a standalone reasoning item."""
+    if not isinstance(message, dict):
+        return False
+    return (
+        message.get("type") in _REASONING_ITEM_TYPES
+        or message.get("role") in _REASONING_ITEM_TYPES
+    )
+
+
+def _strip_reasoning_from_message(message: Any) -> tuple[Any, int]:
+    """Remove reasoning content parts and fields from an assistant message."""
+    if not isinstance(message, dict) or message.get("role") != "assistant":
+        return message, 0
+
+    removed = 0
+    cleaned = dict(message)
+    for field in _REASONING_MESSAGE_FIELDS:
+        if cleaned.pop(field, None):
+            removed += 1
+
+    content = cleaned.get("content")
+    if isinstance(content, list):
+        kept = [
+            part
+            for part in content
+            if not (isinstance(part, dict) and part.get("type") in _REASONING_ITEM_TYPES)
+        ]
+        removed += len(content) - len(kept)
+        cleaned["content"] = kept
+
+    return cleaned, removed
+
+
+def _strip_reasoning_items(messages: list[Any]) -> tuple[list[Any], int]:
+    """Exclude reasoning items from a trajectory, returning how many were dropped."""
+    stripped: list[Any] = []
+    removed = 0
+    for message in messages:
+        if _is_reasoning_item(message):
+            removed += 1
+            continue
+        cleaned, count = _strip_reasoning_from_message(message)
+        removed += count
+        stripped.append(cleaned)
+    return stripped, removed
+
+
 def evaluate(
     *,
     agent_output: str | None = None,
     conversation: list[ChatCompletionMessageParam] | None = None,
-    grounds: list["Ground"] | None = None,
+    grounds: list["Ground"] | dict[str, Any] | None = None,
+    policy: EvaluationPolicy | dict[str, Any] | None = None,
+    statement_ids: Iterable[str] | None = None,
+    span: tuple[int, int] | list[int] | None = None,
+    links: LinkSet | dict[str, Any] | list[Any] | None = None,
+    knowledge_nodes: Iterable[KnowledgeNode | dict[str, Any]] | None = None,
+    strict: bool = False,
+    cache: JudgmentCache | None = None,
+    trace: bool = False,
+    evaluator: "AdmissibilityEvaluator | None" = None,
+    domain: str | None = None,
     **kwargs: Any,
 ) -> AdmissibilityJudgment:
-    """Public evaluate contract aligned with CLI parameters."""
+    """Public evaluate contract aligned with CLI parameters.
+
+    ``statement_ids`` restricts evaluation to the listed statement ids and
+    ``span`` (``[start, end)`` character offsets into the assistant output)
+    restricts it to a slice of the text, so callers can skip sections they
+    have already validated.
+
+    ``links`` is a precomputed ``LinkSet`` (statement -> ground declarations).
+    When given, citation-key extraction is bypassed and these links drive
+    licensing; linked grounds must still be observed tool results or ``grounds``.
+
+    ``knowledge_nodes`` are prebuilt knowledge nodes (``KnowledgeNode`` or
+    records with its field names) for callers that maintain their own
+    knowledge state. They replace tool-result parsing of ``conversation``;
+    ``grounds`` are still merged in, and each node is citable as
+    ``[@<semantic_id or id>]``.
+
+    ``strict`` turns recoverable input issues (otherwise reported in
+    ``warnings`` and skipped) into ``ValueError``, for pipelines that would
+    rather fail than accept a judgment computed on partial input.
+
+    ``cache`` (a ``JudgmentCache``) returns the stored judgment when the same
+    inputs were already evaluated under an equal policy.
+
+    ``trace`` adds a ``license_trace`` to each statement evaluation: which
+    derivation rule applied and why the license permits what it does.
+
+    ``domain`` names the deployment domain of this input (for example
+    "medical"); the policy's ``domain_risk_floors`` entry for it raises the
+    judgment's ``risk_tier``.
+
+    Input over the policy's ``max_messages`` / ``max_content_bytes`` raises
+    ``InputLimitError`` (a ``ValueError``) before statements are extracted.
+
+    ``evaluator`` is a configured pipeline (see ``AdmissibilityEvaluator.builder()``)
+    used instead of the default stages; its policy applies, so ``policy`` must
+    not be given too, and its stages cannot be keyed, so neither can ``cache``.
+    """
     if agent_output is None and conversation is None:
         raise ValueError("evaluate requires agent_output or conversation")
+    if evaluator is not None:
+        if policy is not None:
+            raise ValueError("evaluate takes policy or evaluator, not both")
+        if cache is not None:
+            raise ValueError("cache cannot be combined with a custom evaluator")
 
     if conversation is not None:
         if not isinstance(conversation, list) or not conversation:
             raise ValueError("conversation must be a non-empty list")
         trajectory = conversation
-        agent_message = trajectory[-1]
+        # Trailing reasoning items are not the assistant's answer; look past them.
+        agent_message = next(
+            (item for item in reversed(trajectory) if not _is_reasoning_item(item)),
+            None,
+        )
         if not isinstance(agent_message, dict) or agent_message.get("role") != "assistant":
             raise ValueError("Last conversation item must be an assistant message")
         agent_message = cast(ChatCompletionAssistantMessageParam, agent_message)
@@ -153,12 +417,222 @@ def evaluate(
         }
         trajectory = [agent_message]
 
-    return AdmissibilityEvaluator.evaluate(
+    resolved_policy = evaluator.policy if evaluator is not None else coerce_policy(policy)
+    check_input_limits(trajectory, resolved_policy)
+    cache_key = None
+    if cache is not None:
+        # One-shot iterables are consumed by hashing; evaluate the materialized copies.
+        statement_ids = list(statement_ids) if statement_ids is not None else None
+        knowledge_nodes = list(knowledge_nodes) if knowledge_nodes is not None else None
+        cache_key = evaluation_key(
+            {
+                "agent_output": agent_output,
+                "trajectory": trajectory,
+                "grounds": grounds,
+                "statement_ids": statement_ids,
+                "span": span,
+                "links": links,
+                "knowledge_nodes": knowledge_nodes,
+                "strict": strict,
+                "trace": trace,
+                "domain": domain,
+                **kwargs,
+            },
+            resolved_policy,
+        )
+        cached = cache.get(cache_key)
+        if cached is not None:
+            return cached
+
+    if evaluator is not None:
+        if trace and not evaluator.trace_licenses:
+            evaluator = copy.copy(evaluator)
+            evaluator.trace_licenses = True
+        return evaluator.evaluate_message(
+            agent_message,
+            trajectory,
+            grounds=grounds,
+            statement_ids=statement_ids,
+            span=span,
+            links=links,
+            knowledge_nodes=knowledge_nodes,
+            strict=True if strict else None,
+            domain=domain,
+            **kwargs,
+        )
+
+    judgment = AdmissibilityEvaluator.evaluate(
         agent_message=agent_message,
         trajectory=trajectory,
         grounds=grounds,
+        policy=resolved_policy,
+        statement_ids=statement_ids,
+        span=span,
+        links=links,
+        knowledge_nodes=knowledge_nodes,
+        strict=strict,
+        trace=trace,
+        domain=domain,
         **kwargs,
     )
+    if cache is not None and cache_key is not None:
+        cache.put(cache_key, judgment)
+    return judgment
+
+
+def check_structure(
+    text: str,
+    *,
+    policy: EvaluationPolicy | dict[str, Any] | None = None,
+) -> StructureReport:
+    """Well-formedness-only check of assistant text.
+
+    Extracts statements and detects their modality exactly as ``evaluate()``
+    does, then reports structural issues. No grounding, licensing or axioms
+    are involved, so it is a cheap pre-filter before full evaluation.
+    """
+    if not isinstance(text, str):
+        raise ValueError("check_structure requires text as a string")
+    return AdmissibilityEvaluator(policy=coerce_policy(policy)).check_structure(text)
+
+
+def derive_grounds(
+    conversation: list[ChatCompletionMessageParam],
+    *,
+    policy: EvaluationPolicy | dict[str, Any] | None = None,
+) -> GroundsReport:
+    """Knowledge state derivable from a conversation's tool results.
+
+    Builds the knowledge nodes and tool-call citation refs exactly as
+    ``evaluate()`` does (including ``policy.ground_window_turns`` and
+    ``policy.unmatched_tool_results``), without extracting or judging any
+    statement. The conversation need not end with an assistant message.
+    """
+    if not isinstance(conversation, list) or not conversation:
+        raise ValueError("conversation must be a non-empty list")
+    return AdmissibilityEvaluator(policy=coerce_policy(policy)).derive_grounds(conversation)
+
+
+def evaluate_trajectory(
+    conversation: list[ChatCompletionMessageParam],
+    grounds: list["Ground"] | dict[str, Any] | None = None,
+    *,
+    policy: EvaluationPolicy | dict[str, Any] | None = None,
+    strict: bool = False,
+    trace: bool = False,
+    evaluator: "AdmissibilityEvaluator | None" = None,
+    **kwargs: Any,
+) -> TrajectoryJudgment:
+    """Judge every assistant turn of a conversation, not just the last one.
+
+    Each assistant message with content is evaluated as ``evaluate()`` would
+    evaluate it if the conversation ended there, so only tool results observed
+    up to that turn can ground it; ``grounds`` apply to every turn. Assistant
+    messages that only call tools are not judged. ``policy``, ``strict``,
+    ``trace``, ``evaluator`` and further options are passed to ``evaluate()``.
+
+    The aggregate reports the worst turn status, the strictest gate decision,
+    and the conversation index of the first failing turn (an underdetermined
+    turn is not failing: the evaluator has no rule for it).
+    """
+    if not isinstance(conversation, list) or not conversation:
+        raise ValueError("conversation must be a non-empty list")
+
+    judgments: list[AdmissibilityJudgment] = []
+    message_indices: list[int] = []
+    for index, message in enumerate(conversation):
+        if not isinstance(message, dict) or message.get("role") != "assistant":
+            continue
+        if not message.get("content") and not message.get("refusal"):
+            continue
+        judgments.append(
+            evaluate(
+                conversation=conversation[: index + 1],
+                grounds=grounds,
+                policy=policy,
+                strict=strict,
+                trace=trace,
+                evaluator=evaluator,
+                **kwargs,
+            )
+        )
+        message_indices.append(index)
+
+    # UNDERDETERMINED is outside the evaluator's jurisdiction, not a failure
+    first_failing_index = next(
+        (
+            index
+            for index, judgment in zip(message_indices, judgments)
+            if STATUS_SEVERITY[judgment.status] > ADMISSIBLE_SEVERITY
+            and judgment.status != AdmissibilityStatus.UNDERDETERMINED
+        ),
+        None,
+    )
+    return TrajectoryJudgment(
+        # Among equally severe statuses, a judged claim outranks "nothing to judge"
+        status=max(
+            (judgment.status for judgment in judgments),
+            key=lambda status: (
+                STATUS_SEVERITY[status],
+                status != AdmissibilityStatus.NO_NORMATIVE_CONTENT,
+            ),
+            default=AdmissibilityStatus.NO_NORMATIVE_CONTENT,
+        ),
+        gate_decision=max(
+            (judgment.gate_decision for judgment in judgments),
+            key=_GATE_ORDER.index,
+            default=GateDecision.ALLOW,
+        ),
+        judgments=judgments,
+        message_indices=message_indices,
+        first_failing_index=first_failing_index,
+    )
+
+
+def check_tool_call(
+    tool_call: dict[str, Any],
+    conversation: list[ChatCompletionMessageParam] | None = None,
+    *,
+    grounds: list["Ground"] | dict[str, Any] | None = None,
+    policy: EvaluationPolicy | dict[str, Any] | None = None,
+    knowledge_nodes: Iterable[KnowledgeNode | dict[str, Any]] | None = None,
+    strict: bool = False,
+    trace: bool = False,
+    evaluator: "AdmissibilityEvaluator | None" = None,
+) -> AdmissibilityJudgment:
+    """Pre-flight admissibility of a proposed tool call, before it is executed.
+
+    ``tool_call`` is an OpenAI tool call (``{"id", "type": "function",
+    "function": {"name", "arguments"}}``) and ``conversation`` the history it
+    would follow. A state-changing call (``policy.tool_effects``, else a verb
+    in the tool name) is an action that needs the same license as a categorical
+    recommendation: strong factual grounds observed in the conversation so far
+    (or in ``grounds``). Its statement id is ``tool_call:<id>``. A read-only
+    call gathers evidence and reports ``no_normative_content``.
+
+    ``strict``, ``trace`` and ``evaluator`` behave as in ``evaluate()``.
+    """
+    if not isinstance(tool_call, dict):
+        raise ValueError("tool_call must be an object")
+    if conversation is not None and not isinstance(conversation, list):
+        raise ValueError("conversation must be a list")
+    if evaluator is not None and policy is not None:
+        raise ValueError("check_tool_call takes policy or evaluator, not both")
+
+    if evaluator is None:
+        evaluator = AdmissibilityEvaluator(policy=coerce_policy(policy))
+    if trace and not evaluator.trace_licenses:
+        evaluator = copy.copy(evaluator)
+        evaluator.trace_licenses = True
+    trajectory = list(conversation or [])
+    check_input_limits(trajectory, evaluator.policy)
+    return evaluator.check_tool_call(
+        tool_call,
+        trajectory,
+        grounds=grounds,
+        knowledge_nodes=knowledge_nodes,
+        strict=True if strict else None,
+    )
 
 
 class AdmissibilityEvaluator:
@@ -178,24 +652,98 @@ class AdmissibilityEvaluator:
     5. Aggregate results into a single admissibility judgment
     """
 
-    def __init__(self) -> None:
-        """Initialize all components."""
-        self.extractor = StatementExtractor()
-        self.modality_detector = ModalityDetector()
-        self.knowledge_builder = KnowledgeStateBuilder()
-        self.ground_matcher = GroundSetMatcher()
-        self.license_deriver = LicenseDeriver()
-        self.axiom_checker = AxiomChecker()
+    def __init__(
+        self,
+        policy: EvaluationPolicy | None = None,
+        *,
+        extractor: StatementExtraction | None = None,
+        modality_detector: ModalityDetection | None = None,
+        knowledge_builder: KnowledgeStateBuilder | None = None,
+        ground_matcher: GroundSetMatcher | None = None,
+        license_deriver: LicenseDeriver | None = None,
+        axiom_checker: AxiomChecker | None = None,
+        task_goal: str = DEFAULT_TASK_GOAL,
+        strict: bool = False,
+    ) -> None:
+        """
+        Initialize all components.
+
+        A stage given explicitly replaces the one built from ``policy``; see
+        ``builder()`` for configuring stages one at a time.
+        """
+        if extractor is not None and not isinstance(extractor, StatementExtraction):
+            raise TypeError("extractor must define extract(text)")
+        if modality_detector is not None and not isinstance(modality_detector, ModalityDetection):
+            raise TypeError("modality_detector must define detect(text)")
+        self.policy = policy or EvaluationPolicy()
+        self.extractor = extractor or StatementExtractor(
+            protocol_prefix_phrases=self.policy.protocol_prefix_phrases,
+            protocol_suffix_phrases=self.policy.protocol_suffix_phrases,
+            flag_executable_code=self.policy.code_blocks == "flag_executable",
+            split_sentences=self.policy.statement_granularity == "sentence",
+        )
+        self.modality_detector = modality_detector or ModalityDetector(
+            keywords=self.policy.modality_keyword_table()
+        )
+        # Conditions, readings, hedges and attribution come from the formal
+        # indicators whichever detector decides the modality
+        self._indicator_detector = (
+            self.modality_detector
+            if isinstance(self.modality_detector, ModalityDetector)
+            else ModalityDetector(keywords=self.policy.modality_keyword_table())
+        )
+        self.knowledge_builder = knowledge_builder or KnowledgeStateBuilder(
+            strong_confidence_threshold=self.policy.strong_confidence_threshold,
+            non_epistemic_tools=self.policy.non_epistemic_tools,
+        )
+        self.ground_matcher = ground_matcher or GroundSetMatcher()
+        self.coreference_resolver = CoreferenceResolver()
+        self.license_deriver = license_deriver or LicenseDeriver(
+            modality_fallbacks=self.policy.modality_fallback_table()
+        )
+        self.axiom_checker = self._policy_axiom_checker(axiom_checker or AxiomChecker())
+        self.structure_checker = StructureChecker()
+        # Task goal handed to the axiom checker's relevance check (I3)
+        self.task_goal = task_goal
+        # Default of evaluate_message(strict=...)
+        self.strict = strict
+        # Attach LicenseDeriver.trace() to statement results (evaluate(trace=True))
+        self.trace_licenses = False
         self._message_adapter = _adapter(ChatCompletionMessageParam)
         self._assistant_adapter = _adapter(ChatCompletionAssistantMessageParam)
         self._content_parts_adapter = _adapter(list[_ContentPart])
 
+    def _policy_axiom_checker(self, axiom_checker: AxiomChecker) -> AxiomChecker:
+        """``axiom_checker`` with the policy's ``disabled_axioms`` unregistered."""
+        disabled = [code for code in self.policy.disabled_axioms if code in axiom_checker.registry]
+        if not disabled:
+            return axiom_checker
+        # Copy, so a checker shared across evaluators keeps its own registry
+        checker = copy.copy(axiom_checker)
+        checker.registry = AxiomRegistry(axiom_checker.registry)
+        for code in disabled:
+            checker.registry.unregister(code)
+        return checker
+
+    @classmethod
+    def builder(cls) -> "EvaluatorBuilder":
+        """Start configuring an evaluator stage by stage (see ``EvaluatorBuilder``)."""
+        return EvaluatorBuilder()
+
     @classmethod
     def evaluate(
         cls,
         agent_message: ChatCompletionAssistantMessageParam,
         trajectory: list[ChatCompletionMessageParam],
-        grounds: list["Ground"] | None = None,
+        grounds: list["Ground"] | dict[str, Any] | None = None,
+        policy: EvaluationPolicy | None = None,
+        statement_ids: Iterable[str] | None = None,
+        span: tuple[int, int] | list[int] | None = None,
+        links: LinkSet | dict[str, Any] | list[Any] | None = None,
+        knowledge_nodes: Iterable[KnowledgeNode | dict[str, Any]] | None = None,
+        strict: bool = False,
+        trace: bool = False,
+        domain: str | None = None,
         **kwargs: Any,
     ) -> AdmissibilityJudgment:
         """
@@ -206,75 +754,382 @@ class AdmissibilityEvaluator:
         Args:
             agent_message: Single agent message to validate
             trajectory: Full message history (for building knowledge state)
-            grounds: Optional grounds input (citation_key -> ground_id)
+            grounds: Optional grounds input (citation_key -> ground_id), flat or sectioned
+            policy: Optional evaluation policy (defaults reproduce built-in behavior)
+            statement_ids: Optional statement ids to evaluate (others are skipped)
+            span: Optional [start, end) character range of the assistant text to evaluate
+            links: Optional precomputed LinkSet; bypasses citation-key extraction
+            knowledge_nodes: Optional prebuilt knowledge nodes; bypasses tool-result parsing
+            strict: Raise ValueError on recoverable input issues instead of warning
+            trace: Report the license derivation trace per statement
+            domain: Optional deployment domain (selects a policy domain_risk_floors entry)
             **kwargs: Additional args (for compatibility)
 
         Returns:
             AdmissibilityJudgment with status and retry guidance for agent
         """
-        instance = cls()
+        instance = cls(policy=policy)
+        instance.trace_licenses = trace
+        return instance.evaluate_message(
+            agent_message,
+            trajectory,
+            grounds=grounds,
+            statement_ids=statement_ids,
+            span=span,
+            links=links,
+            knowledge_nodes=knowledge_nodes,
+            strict=strict,
+            domain=domain,
+            **kwargs,
+        )
 
-        # 1. Extract tool results from trajectory
-        tool_results = instance._extract_tool_results(trajectory)
+    def evaluate_message(
+        self,
+        agent_message: ChatCompletionAssistantMessageParam,
+        trajectory: list[ChatCompletionMessageParam],
+        grounds: list["Ground"] | dict[str, Any] | None = None,
+        statement_ids: Iterable[str] | None = None,
+        span: tuple[int, int] | list[int] | None = None,
+        links: LinkSet | dict[str, Any] | list[Any] | None = None,
+        knowledge_nodes: Iterable[KnowledgeNode | dict[str, Any]] | None = None,
+        strict: bool | None = None,
+        domain: str | None = None,
+        **kwargs: Any,
+    ) -> AdmissibilityJudgment:
+        """
+        Evaluate an agent message with this evaluator's policy and stages.
 
-        # 2. Build knowledge state + tool-call reference grounds
-        knowledge_nodes, tool_call_refs = instance.knowledge_builder.build_with_references(
-            tool_results
+        Takes the arguments of ``evaluate()`` except ``policy`` and ``trace``,
+        which belong to the evaluator; ``strict`` defaults to the evaluator's.
+        """
+        started = time.perf_counter()
+        with collect_warnings(strict=self.strict if strict is None else strict) as warnings:
+            judgment = self._evaluate_message(
+                agent_message,
+                trajectory,
+                grounds=grounds,
+                statement_ids=statement_ids,
+                span=span,
+                links=links,
+                knowledge_nodes=knowledge_nodes,
+                domain=domain,
+                **kwargs,
+            )
+        judgment.warnings = list(warnings)
+        log_event(
+            logging.INFO,
+            "AdmissibilityEvaluator: judgment ready",
+            status=judgment.status.value,
+            statements=len(judgment.statement_evaluations),
+            warnings=len(judgment.warnings),
+            duration_ms=round((time.perf_counter() - started) * 1000, 3),
         )
+        return judgment
+
+    def check_tool_call(
+        self,
+        tool_call: dict[str, Any],
+        trajectory: list[ChatCompletionMessageParam],
+        grounds: list["Ground"] | dict[str, Any] | None = None,
+        knowledge_nodes: Iterable[KnowledgeNode | dict[str, Any]] | None = None,
+        strict: bool | None = None,
+    ) -> AdmissibilityJudgment:
+        """
+        Pre-flight check of a proposed tool call against the conversation so far.
+
+        The call is judged as the tool-call-only assistant turn that would follow
+        ``trajectory``: a state-changing call is an ASSERTIVE action statement
+        (``tool_call:<id>``) licensed only by grounds observed before it; a
+        read-only call reports ``no_normative_content``.
+        """
+        agent_message = cast(
+            ChatCompletionAssistantMessageParam,
+            {"role": "assistant", "content": None, "tool_calls": [tool_call]},
+        )
+        return self.evaluate_message(
+            agent_message,
+            [*trajectory, agent_message],
+            grounds=grounds,
+            knowledge_nodes=knowledge_nodes,
+            strict=strict,
+            proposed_actions=True,
+        )
+
+    def _evaluate_message(
+        self,
+        agent_message: ChatCompletionAssistantMessageParam,
+        trajectory: list[ChatCompletionMessageParam],
+        grounds: list["Ground"] | dict[str, Any] | None = None,
+        statement_ids: Iterable[str] | None = None,
+        span: tuple[int, int] | list[int] | None = None,
+        links: LinkSet | dict[str, Any] | list[Any] | None = None,
+        knowledge_nodes: Iterable[KnowledgeNode | dict[str, Any]] | None = None,
+        proposed_actions: bool = False,
+        tool_knowledge: Callable[[], _ToolKnowledge] | None = None,
+        domain: str | None = None,
+        **kwargs: Any,
+    ) -> AdmissibilityJudgment:
+
+        # 0. Exclude hidden reasoning (chain-of-thought) from normative evaluation
+        agent_in_trajectory = any(item is agent_message for item in trajectory)
+        trajectory, reasoning_items = _strip_reasoning_items(trajectory)
+        agent_message, agent_reasoning_items = _strip_reasoning_from_message(agent_message)
+        if not agent_in_trajectory:
+            reasoning_items += agent_reasoning_items
+        if reasoning_items:
+            logger.debug(f"AdmissibilityEvaluator: Skipped {reasoning_items} reasoning item(s)")
+
+        # 1-2. Build knowledge state + tool-call reference grounds from tool results,
+        # unless the caller maintains its own knowledge state
+        out_of_window_ground_ids: list[str] = []
+        with log_stage("knowledge", precomputed=knowledge_nodes is not None):
+            if knowledge_nodes is not None:
+                tool_results = []
+                knowledge_nodes, tool_call_refs = self.knowledge_builder.from_precomputed(
+                    knowledge_nodes
+                )
+            elif tool_knowledge is not None:
+                # Tool results already parsed as they arrived (SessionEvaluator)
+                tool_results, knowledge_nodes, tool_call_refs, out_of_window_ground_ids = (
+                    tool_knowledge()
+                )
+            else:
+                tool_results, knowledge_nodes, tool_call_refs, out_of_window_ground_ids = (
+                    self._build_tool_knowledge(trajectory)
+                )
 
         # 3. Validate + map and get agent output
-        validated_agent_message = instance._assistant_adapter.validate_python(agent_message)
+        validated_agent_message = self._assistant_adapter.validate_python(agent_message)
         # AFTER THIS POINT: no OpenAI types allowed
-        assistant_message = instance._map_assistant_message(validated_agent_message)
-        speech_act = instance._to_speech_act(assistant_message)
+        assistant_message = self._map_assistant_message(validated_agent_message)
+        speech_act = self._to_speech_act(assistant_message)
+        mixed_refusal = self._mixed_refusal_text(assistant_message)
+        if span is not None and isinstance(speech_act, TextSpeechAct):
+            speech_act = TextSpeechAct(text=self._select_span(speech_act.text, span))
+        argument_claims = self._extract_argument_claims(assistant_message)
+        # Pre-flight: the message's state-changing tool calls are judged as actions
+        actions = self._action_statements(assistant_message) if proposed_actions else None
+        # Commitments are keepable only by an agent that can act (COMMISSIVE licensing)
+        tool_capable = (
+            bool(tool_results)
+            or bool(assistant_message.tool_calls)
+            or any(node.source == Source.OBSERVED for node in knowledge_nodes)
+        )
 
         provided_grounds = coerce_grounds_input(
             grounds=grounds,
             legacy_openai_citations=kwargs.get("openai_citations"),
-            legacy_links=kwargs.get("links"),
         )
-        knowledge_nodes = instance.knowledge_builder.materialize_external_grounds(
+        # Annotations on the assistant message are the model's own citations:
+        # each becomes a ground and a link scoped to the cited statement.
+        message_citations = [
+            citation
+            for citation in citations_from_assistant_message(agent_message)
+            if span is None or self._citation_in_span(citation, span)
+        ]
+        provided_grounds.extend(grounds_from_openai_citations(message_citations))
+        knowledge_nodes = self.knowledge_builder.materialize_external_grounds(
             knowledge_nodes,
             provided_grounds,
         )
+        # What the user said about themselves: context for "better for you" claims
+        user_texts = self._extract_user_texts(trajectory)
+        knowledge_nodes.extend(
+            self.knowledge_builder.build_user_context(user_texts, provided_grounds)
+        )
         combined_grounds = [*provided_grounds, *grounds_from_tool_call_refs(tool_call_refs)]
 
         statement_id = "refusal" if isinstance(speech_act, RefusalSpeechAct) else "final_response"
         text = speech_act.refusal if isinstance(speech_act, RefusalSpeechAct) else speech_act.text
-        links = build_links_from_grounds(
-            text=text,
-            grounds=combined_grounds,
-            statement_id=statement_id,
+        # Orchestrators that already know statement -> ground usage pass links
+        # directly; citation keys in the text are then not consulted.
+        precomputed_links = (
+            coerce_links_input(links=links, openai_citations=None) if links is not None else None
         )
-        accepted_ground_ids = {ground.ground_id for ground in combined_grounds}
-        cited_ground_ids = {link.ground_id for link in links.links}
+        with log_stage("links", precomputed=precomputed_links is not None):
+            if precomputed_links is not None:
+                links = precomputed_links
+            else:
+                links = build_links_from_grounds(
+                    text="\n".join(
+                        [text, *filter(None, [mixed_refusal]), *(c for _, c in argument_claims)]
+                    ),
+                    grounds=combined_grounds,
+                    statement_id=statement_id,
+                )
+                links.links.extend(
+                    link_set_from_openai_citations(
+                        message_citations, statement_id=statement_id
+                    ).links
+                )
+        accepted_ground_ids = sorted({ground.ground_id for ground in combined_grounds})
+        cited_ground_ids = sorted({link.ground_id for link in links.links})
+        citation_map: dict[str, list[str]] = {}
+        for ground in combined_grounds:
+            ground_ids = citation_map.setdefault(ground.citation_key, [])
+            if ground.ground_id not in ground_ids:
+                ground_ids.append(ground.ground_id)
 
         if isinstance(speech_act, RefusalSpeechAct):
-            internal_result = instance._evaluate_refusal(
+            internal_result = self._evaluate_refusal(
                 speech_act.refusal,
                 knowledge_nodes,
                 links,
             )
             internal_result.grounds_accepted = len(accepted_ground_ids)
             internal_result.grounds_cited = len(cited_ground_ids)
-            return instance._to_judgment(internal_result)
+            internal_result.accepted_ground_ids = accepted_ground_ids
+            internal_result.cited_ground_ids = cited_ground_ids
+            internal_result.citation_map = citation_map
+            internal_result.out_of_window_ground_ids = out_of_window_ground_ids
+            internal_result.reasoning_items_skipped = reasoning_items
+            return self._to_judgment(
+                internal_result,
+                self.policy,
+                severities=self._axiom_severities(),
+                risk_floor=self.policy.domain_risk_floor(domain),
+            )
         agent_output = speech_act.text
 
         # 4. Run evaluation core
-        internal_result = instance._evaluate_core(
+        internal_result = self._evaluate_core(
             agent_output=agent_output,
             knowledge_nodes=knowledge_nodes,
             links=links,
+            argument_claims=argument_claims,
+            statement_ids=set(statement_ids) if statement_ids is not None else None,
+            tool_capable=tool_capable,
+            refusal_text=mixed_refusal,
+            user_text=user_texts[-1] if user_texts else None,
+            citation_grounds=combined_grounds if precomputed_links is None else None,
+            actions=actions,
         )
         internal_result.grounds_accepted = len(accepted_ground_ids)
         internal_result.grounds_cited = len(cited_ground_ids)
-        return instance._to_judgment(internal_result)
+        internal_result.accepted_ground_ids = accepted_ground_ids
+        internal_result.cited_ground_ids = cited_ground_ids
+        internal_result.citation_map = citation_map
+        internal_result.out_of_window_ground_ids = out_of_window_ground_ids
+        internal_result.reasoning_items_skipped = reasoning_items
+        return self._to_judgment(
+            internal_result,
+            self.policy,
+            severities=self._axiom_severities(),
+            risk_floor=self.policy.domain_risk_floor(domain),
+        )
+
+    def _build_tool_knowledge(self, trajectory: list[ChatCompletionMessageParam]) -> _ToolKnowledge:
+        """
+        Build the knowledge state from the trajectory's tool results.
+
+        Returns:
+            Tool results, in-window knowledge nodes, tool-call citation refs, and
+            the ground ids of out-of-window results
+        """
+        tool_results = self._extract_tool_results(trajectory)
+        # Observations older than the policy's turn window cannot license current claims
+        window_start = self._ground_window_start(trajectory)
+        stale_nodes, _ = self.knowledge_builder.build_with_references(
+            [r for r in tool_results if r.position is not None and r.position < window_start]
+        )
+        out_of_window_ground_ids = sorted({node.semantic_id or node.id for node in stale_nodes})
+        knowledge_nodes, tool_call_refs = self.knowledge_builder.build_with_references(
+            [r for r in tool_results if r.position is None or r.position >= window_start]
+        )
+        return tool_results, knowledge_nodes, tool_call_refs, out_of_window_ground_ids
+
+    def derive_grounds(self, trajectory: list[ChatCompletionMessageParam]) -> GroundsReport:
+        """
+        Knowledge-state inspection: tool results -> knowledge nodes and citation refs.
+
+        Returns:
+            GroundsReport (no statement extraction, matching or axiom checks)
+        """
+        with collect_warnings() as warnings:
+            trajectory, _ = _strip_reasoning_items(trajectory)
+            _, knowledge_nodes, tool_call_refs, out_of_window_ground_ids = (
+                self._build_tool_knowledge(trajectory)
+            )
+        return GroundsReport(
+            knowledge_nodes=[
+                KnowledgeNodeRecord(
+                    id=node.id,
+                    source=node.source.value,
+                    status=node.status.value,
+                    confidence=node.confidence,
+                    scope=node.scope.value,
+                    strength=node.strength,
+                    semantic_id=node.semantic_id,
+                    payload=node.payload,
+                    derived_from=list(node.derived_from),
+                )
+                for node in knowledge_nodes
+            ],
+            tool_call_refs=tool_call_refs,
+            out_of_window_ground_ids=out_of_window_ground_ids,
+            warnings=list(warnings),
+        )
+
+    def check_structure(self, text: str) -> StructureReport:
+        """
+        Structural pre-check: extraction + modality detection + StructureChecker.
+
+        Returns:
+            StructureReport (no_normative_content when nothing is extracted)
+        """
+        statements = self._extract_statements(text) if text else []
+        if not statements:
+            return StructureReport(status="no_normative_content")
+
+        results = []
+        for statement in statements:
+            self._indicator_detector.detect_with_conditions(statement, self.modality_detector)
+            results.append(
+                StatementStructure(
+                    statement_id=statement.id,
+                    statement=statement.raw_text,
+                    modality=statement.modality.value if statement.modality else None,
+                    issues=self.structure_checker.check(statement),
+                )
+            )
+        ill_formed = any(result.issues for result in results)
+        return StructureReport(
+            status="ill_formed" if ill_formed else "well_formed",
+            statements=results,
+        )
+
+    def _extract_statements(self, text: str) -> list[Statement]:
+        """
+        Extract statements, checking for garbage before modality indicators apply.
+
+        Extraction drops text without normative indicators as protocol speech, so
+        gibberish or encoding noise would otherwise pass as ``no_normative_content``.
+        Such output is kept as one ``final_response`` statement for I1 to judge.
+        """
+        statements = self.extractor.extract(text)
+        if statements or not self.structure_checker.check_garbage(text):
+            return statements
+        return [
+            Statement(
+                id="final_response",
+                subject="agent",
+                predicate="participation",
+                raw_text=text.strip(),
+            )
+        ]
 
     def _evaluate_core(
         self,
         agent_output: str,
         knowledge_nodes: list[KnowledgeNode],
         links: LinkSet | None,
+        argument_claims: list[tuple[str, str]] | None = None,
+        statement_ids: set[str] | None = None,
+        tool_capable: bool = False,
+        refusal_text: str | None = None,
+        user_text: str | None = None,
+        citation_grounds: list[Ground] | None = None,
+        actions: list[Statement] | None = None,
     ) -> ValidationResult:
         """
         Evaluation core. All normative checking happens here.
@@ -297,12 +1152,34 @@ class AdmissibilityEvaluator:
             agent_output: Text to validate
             knowledge_nodes: Already built knowledge state
             links: Optional StatementGroundLinks
+            argument_claims: Optional (statement_id, text) claims taken from the
+                arguments of the final turn's tool calls (see policy.tool_claim_fields)
+            statement_ids: Optional selection; statements with other ids are skipped
+            tool_capable: Whether tool calls are present (licenses COMMISSIVE statements)
+            refusal_text: Optional refusal parts of mixed content, checked as the
+                ``refusal`` statement after the text statements
+            user_text: Optional last user message; antecedent context for pronoun
+                subjects (see policy.resolve_coreferences)
+            citation_grounds: Grounds the ``[@key]`` links were built from; with
+                multi-statement extraction those links are scoped to the citing
+                statement (None for caller-supplied links)
+            actions: Proposed state-changing tool calls (pre-flight check), judged
+                after the text statements; an empty list means only read-only calls
 
         Returns:
             ValidationResult with status, feedback_hint, violations
         """
         # 1. Extract statements
-        if not agent_output:
+        if statement_ids is not None and "refusal" not in statement_ids:
+            refusal_text = None
+        if not agent_output and not argument_claims and not refusal_text and not actions:
+            if actions is not None:
+                return ValidationResult(
+                    status=EvaluationStatus.NO_NORMATIVE_CONTENT,
+                    licensed=False,
+                    can_retry=False,
+                    explanation="Read-only tool call - observation needs no license",
+                )
             return ValidationResult(
                 status=EvaluationStatus.UNDERDETERMINED,
                 licensed=False,
@@ -310,9 +1187,34 @@ class AdmissibilityEvaluator:
                 explanation="No content to validate",
             )
 
-        statements = self.extractor.extract(agent_output)
-
-        if not statements:
+        with log_stage("extract"):
+            statements = self._extract_statements(agent_output) if agent_output else []
+
+            # Claims embedded in tool-call arguments reach users without ever appearing
+            # as assistant text, so they are extracted and judged like any other output.
+            for claim_id, claim_text in argument_claims or []:
+                claim_statements = self.extractor.extract(claim_text)
+                for idx, statement in enumerate(claim_statements, 1):
+                    statement.id = claim_id if len(claim_statements) == 1 else f"{claim_id}.{idx}"
+                    statements.append(statement)
+
+            if self.policy.resolve_coreferences:
+                self._resolve_coreferences(statements, agent_output, user_text)
+
+            statements.extend(actions or [])
+
+        # Multi-statement extraction: a citation licenses only the statement carrying it
+        scoped_ids: set[str] = set()
+        if (
+            citation_grounds is not None
+            and links is not None
+            and statements
+            and all(statement.id != "final_response" for statement in statements)
+        ):
+            links = self._scope_citation_links(statements, agent_output, links, citation_grounds)
+            scoped_ids = {statement.id for statement in statements}
+
+        if not statements and not refusal_text:
             # NEW v0.2.1: NO_NORMATIVE_CONTENT (per FORMAL_SPEC_v0.2.1 §0.4.5)
             #
             # Speech Act Segmentation Layer (§0.4) produced zero normative utterances.
@@ -345,41 +1247,102 @@ class AdmissibilityEvaluator:
 
         logger.info(f"AdmissibilityEvaluator: Extracted {len(statements)} statements")
 
+        if statement_ids is not None:
+            statements = [s for s in statements if s.id in statement_ids]
+            if not statements and not refusal_text:
+                logger.info("AdmissibilityEvaluator: No extracted statement matches statement_ids")
+                return ValidationResult(
+                    status=EvaluationStatus.UNDERDETERMINED,
+                    licensed=False,
+                    can_retry=False,
+                    explanation="No statements selected for evaluation (statement_ids filter)",
+                )
+
         # 2. Validate each statement
         statement_results = []
         axiom_results = []
 
-        from .normative.models import Modality
+        from .normative.models import ModalReading, Modality
 
         for statement in statements:
+            started = time.perf_counter()
             # Detect modality and extract conditions
-            self.modality_detector.detect_with_conditions(statement)
+            self._indicator_detector.detect_with_conditions(statement, self.modality_detector)
 
             # Find relevant grounds
             ground_set = self.ground_matcher.match(statement, knowledge_nodes)
+            statement_links = links
+            if scoped_ids and links is not None:
+                statement_links = LinkSet(
+                    links=[
+                        link
+                        for link in links.links
+                        if link.statement_id == statement.id or link.statement_id not in scoped_ids
+                    ]
+                )
 
-            # Derive license (ONLY for normative modalities)
-            # CRITICAL v0.2: DESCRIPTIVE does not require licensing
-            # Skip license derivation for DESCRIPTIVE, pass empty license to axiom checker
+            # Derive license
             # NEW v0.3.1: Pass links for usage-based licensing (if available)
-            if statement.modality == Modality.DESCRIPTIVE:
-                # DESCRIPTIVE statements evaluated directly by AxiomChecker
-                # No license needed (factual observation, not normative claim)
+            license_links = None
+            if statement.predicate == _ACTION_PREDICATE:
+                # Proposed tool calls cite nothing: grounds observed so far license them
+                # (presence = usage), like a categorical recommendation to act
+                rule = "normative"
+                license = self.license_deriver.derive(ground_set)
+            elif statement.modality == Modality.ASSERTIVE and statement.attribution is not None:
+                # Relayed claims ("The vendor claims ...") need grounding that the
+                # claim was made, like observations (strength-sensitive)
+                rule = "descriptive"
+                license = self.license_deriver.derive_descriptive(ground_set)
+            elif (
+                statement.modality == Modality.ASSERTIVE
+                and statement.reading == ModalReading.EPISTEMIC
+            ):
+                # Epistemic inferences ("X must have failed") are evaluated directly
+                # by AxiomChecker against factual grounding.
+                # No license needed (claim about the world, not normative claim)
+                rule = "epistemic"
                 license = License(permitted_modalities=set())
+            elif statement.modality == Modality.DESCRIPTIVE:
+                # Observations are licensed by matched factual grounds (strength-sensitive)
+                rule = "descriptive"
+                license = self.license_deriver.derive_descriptive(ground_set)
+            elif statement.modality == Modality.COMMISSIVE:
+                # Promises are licensed by capability to act, not by grounding
+                rule = "commissive"
+                license = self.license_deriver.derive_commissive(tool_capable)
+            elif statement.personalized:
+                # "Better for you" claims are about the user: they need user context
+                rule, license_links = "personalized", statement_links
+                license = self.license_deriver.derive_personalized(
+                    ground_set, links=statement_links
+                )
             else:
                 # ASSERTIVE/CONDITIONAL/REFUSAL require licensing
                 # v0.3.1: Pass links for usage-based mode (if available)
-                license = self.license_deriver.derive(ground_set, links=links)
+                rule, license_links = "normative", statement_links
+                license = self.license_deriver.derive(ground_set, links=statement_links)
 
-            # Check axioms
+            # I1 needs the structure issues, A8 the citation counts; disabled
+            # axioms are not in the checker's registry
+            required = self._required_citations(statement)
             result = self.axiom_checker.check(
                 statement,
                 license,
                 ground_set,
-                task_goal="task completion",
+                task_goal=self.task_goal,
+                structure_issues=self.structure_checker.check(statement),
+                citations=self._count_citations(statement, links) if required else 0,
+                required_citations=required,
             )
+
             axiom_results.append(result)
 
+            # Recommend a downgrade for failing statements (modality fallback table)
+            recommended = None
+            if result.status in _FALLBACK_STATUSES:
+                recommended = self.license_deriver.recommend_fallback(statement.modality, license)
+
             # Build detailed statement result
             stmt_result = StatementValidationResult(
                 statement=statement,
@@ -388,6 +1351,13 @@ class AdmissibilityEvaluator:
                 ground_set=ground_set,
                 violated_axiom=result.violated_axiom,
                 explanation=result.explanation,
+                recommended_modality=recommended,
+                confidence=self._statement_confidence(statement, ground_set, links),
+                license_trace=(
+                    self.license_deriver.trace(ground_set, license, license_links, rule=rule)
+                    if self.trace_licenses
+                    else None
+                ),
             )
             statement_results.append(stmt_result)
 
@@ -403,12 +1373,57 @@ class AdmissibilityEvaluator:
             )
             if result.violated_axiom:
                 logger.info(f"    Violated: {result.violated_axiom}")
+            log_event(
+                logging.DEBUG,
+                "AdmissibilityEvaluator: statement evaluated",
+                statement_id=statement.id,
+                modality=modality_label,
+                modality_confidence=statement.modality_confidence,
+                license_rule=rule,
+                license=sorted(m.value for m in license.permitted_modalities),
+                ground_set_size=len(ground_set.nodes),
+                status=result.status.value,
+                violated_axiom=result.violated_axiom,
+                duration_ms=round((time.perf_counter() - started) * 1000, 3),
+            )
+
+        if refusal_text:
+            result, stmt_result = self._check_refusal(refusal_text, knowledge_nodes, links)
+            axiom_results.append(result)
+            statement_results.append(stmt_result)
+            statements.append(stmt_result.statement)
+
+        for stmt_result, weight in zip(statement_results, self._statement_weights(statements)):
+            stmt_result.weight = weight
 
         # 3. Aggregate to ValidationResult (lexicographic logic)
-        return self._aggregate(
-            axiom_results,
-            statement_results,
-        )
+        with log_stage("aggregate"):
+            return self._aggregate(
+                axiom_results,
+                statement_results,
+            )
+
+    def _resolve_coreferences(
+        self, statements: list[Statement], agent_output: str, user_text: str | None
+    ) -> None:
+        """
+        Replace pronoun subjects ("It should ...") with the entity they refer to.
+
+        Tool-call claims look back at the response text first; every statement
+        then falls back to the user's last message. Table cells keep their row
+        label as subject.
+        """
+        for statement in statements:
+            if statement.id.startswith("table:"):
+                continue
+            context = [agent_output] if statement.id.startswith("tool_call:") else []
+            if user_text:
+                context.append(user_text)
+            if self.coreference_resolver.resolve(statement, context):
+                logger.debug(
+                    f"AdmissibilityEvaluator: Resolved subject of {statement.id} "
+                    f"to '{statement.subject}'"
+                )
 
     def _aggregate(
         self,
@@ -435,8 +1450,20 @@ class AdmissibilityEvaluator:
         - This is a NEUTRAL pass-through (no training signal)
         - Prevents RL from optimizing "say things evaluator cannot judge"
 
+        WEIGHTED MODE (policy aggregation="weighted"):
+        The same lexicographic order applies, but only to statuses whose
+        statements carry at least ``weighted_status_threshold`` of the total
+        statement weight, so a one-word aside cannot decide the whole act.
+        Discounted statements keep their own verdict in the per-statement trace.
+
         Returns ValidationResult with status, feedback_hint, violations.
         """
+        all_results = axiom_results
+        discounted = 0
+        if self.policy.aggregation == "weighted":
+            deciding = self._weighted_deciding_results(axiom_results, statement_results)
+            discounted = len(axiom_results) - len(deciding)
+            axiom_results = deciding
         violations = [r.violated_axiom for r in axiom_results if r.violated_axiom]
 
         # Lexicographic aggregation
@@ -477,6 +1504,16 @@ class AdmissibilityEvaluator:
             )
             explanation = "Statements lack required grounding (A4)"
 
+        elif any(r.status == EvaluationStatus.INSUFFICIENT_CITATIONS for r in axiom_results):
+            status = EvaluationStatus.INSUFFICIENT_CITATIONS
+            licensed = True
+            can_retry = True
+            feedback_hint = (
+                "Your assertive statements lack required citations. "
+                "Cite the evidence each claim relies on."
+            )
+            explanation = "Assertive statements below required citation density (A8)"
+
         elif all(r.status == EvaluationStatus.CONDITIONALLY_ACCEPTABLE for r in axiom_results):
             status = EvaluationStatus.CONDITIONALLY_ACCEPTABLE
             licensed = True
@@ -499,9 +1536,18 @@ class AdmissibilityEvaluator:
             feedback_hint = None
             explanation = "All statements are normatively acceptable"
 
+        if discounted:
+            explanation += f" ({discounted} low-weight statement(s) discounted)"
+        if feedback_hint:
+            hints = [feedback_hint]
+            hints.extend(
+                hint for axiom, hint in _AXIOM_FEEDBACK_HINTS.items() if axiom in violations
+            )
+            feedback_hint = " ".join(translate(hint, self.policy.locale) for hint in hints)
+
         num_acceptable = sum(
             1
-            for r in axiom_results
+            for r in all_results
             if r.status in {EvaluationStatus.ACCEPTABLE, EvaluationStatus.CONDITIONALLY_ACCEPTABLE}
         )
 
@@ -522,12 +1568,155 @@ class AdmissibilityEvaluator:
             num_acceptable=num_acceptable,
         )
 
+    def _statement_weights(self, statements: list[Statement]) -> list[float]:
+        """
+        Weight statements per policy and normalize to shares of the total.
+
+        Base weight comes from ``statement_weighting``; ``statement_class_weights``
+        multiplies it by statement class (statement id prefix; sentence ids ``s1``,
+        ``s2``, … are ``final_response``). If every weight is zero, statements fall
+        back to equal shares.
+        """
+        raw: list[float] = []
+        for position, statement in enumerate(statements, 1):
+            if self.policy.statement_weighting == "length":
+                base = float(max(len(statement.raw_text.split()), 1))
+            elif self.policy.statement_weighting == "position":
+                base = 1.0 / position
+            else:
+                base = 1.0
+            statement_class = statement.id.split(":", 1)[0].split(".", 1)[0]
+            if re.fullmatch(r"s\d+", statement_class):
+                statement_class = "final_response"
+            raw.append(base * self.policy.statement_class_weights.get(statement_class, 1.0))
+        total = sum(raw)
+        if not total:
+            return [1.0 / len(statements)] * len(statements) if statements else []
+        return [weight / total for weight in raw]
+
+    def _weighted_deciding_results(
+        self,
+        axiom_results: list,
+        statement_results: list[StatementValidationResult],
+    ) -> list:
+        """
+        Keep results whose status carries at least the policy weight threshold.
+
+        Falls back to all results when no status reaches the threshold, so a
+        fragmented response never aggregates to an unearned ACCEPTABLE.
+        """
+        share_by_status: dict[EvaluationStatus, float] = {}
+        for result, stmt in zip(axiom_results, statement_results):
+            share_by_status[result.status] = share_by_status.get(result.status, 0.0) + stmt.weight
+        threshold = self.policy.weighted_status_threshold
+        deciding = [r for r in axiom_results if share_by_status[r.status] >= threshold - 1e-9]
+        return deciding or axiom_results
+
+    def _required_citations(self, statement: Statement) -> int:
+        """Citations the policy requires of an assertive statement (0 = no requirement)."""
+        if statement.predicate == _ACTION_PREDICATE:
+            # Tool calls have no text to carry [@key] citations
+            return 0
+        per_statement = self.policy.min_citations_per_assertive
+        per_200_words = self.policy.min_citations_per_200_words
+        if not per_statement and not per_200_words:
+            return 0
+        words = len(statement.raw_text.split())
+        return max(per_statement, math.ceil(per_200_words * words / 200))
+
+    @staticmethod
+    def _statement_confidence(
+        statement: Statement, ground_set: GroundSet, links: LinkSet | None
+    ) -> float:
+        """
+        Confidence of a statement verdict, in [0, 1].
+
+        Product of:
+        - modality-detection confidence (lower for the ASSERTIVE default policy)
+        - mean confidence of matched knowledge nodes (1.0 without grounds:
+          a verdict on missing grounding does not rest on any node)
+        - link quality: share of the statement's SUPPORTS links that resolve
+          to a matched node (1.0 without links)
+        """
+        nodes = ground_set.nodes
+        ground_confidence = sum(k.confidence for k in nodes) / len(nodes) if nodes else 1.0
+        supports = [
+            link
+            for link in (links.links if links is not None else [])
+            if link.statement_id == statement.id and link.role == LinkRole.SUPPORTS
+        ]
+        resolved = sum(1 for link in supports if ground_set.resolve_ground(link.ground_id))
+        link_quality = resolved / len(supports) if supports else 1.0
+        return statement.modality_confidence * ground_confidence * link_quality
+
+    @staticmethod
+    def _scope_citation_links(
+        statements: list[Statement],
+        agent_output: str,
+        links: LinkSet,
+        citation_grounds: list[Ground],
+    ) -> LinkSet:
+        """
+        Re-address the response's ``[@key]`` links to the statements citing them.
+
+        Links from other sources (message annotations, argument claims) and keys
+        cited outside every statement stay response-wide.
+        """
+        response_links = build_links_from_grounds(
+            text=agent_output, grounds=citation_grounds, statement_id="final_response"
+        )
+        replaced = {(link.ground_id, link.role) for link in response_links.links}
+        scoped = build_links_from_grounds(
+            text=agent_output,
+            grounds=citation_grounds,
+            statement_id="final_response",
+            statement_texts={statement.id: statement.raw_text for statement in statements},
+        )
+        kept = [
+            link
+            for link in links.links
+            if link.statement_id != "final_response" or (link.ground_id, link.role) not in replaced
+        ]
+        return LinkSet(links=[*kept, *scoped.links])
+
+    @staticmethod
+    def _count_citations(statement: Statement, links: LinkSet | None) -> int:
+        """
+        Count distinct grounds a statement cites.
+
+        SUPPORTS links addressed to the statement id count first; statements
+        without their own links (table cells, split claims) count the ``[@key]``
+        citation keys in their text.
+        """
+        if links is not None:
+            cited = {
+                link.ground_id
+                for link in links.links
+                if link.statement_id == statement.id and link.role == LinkRole.SUPPORTS
+            }
+            if cited:
+                return len(cited)
+        return len(extract_citation_keys(statement.raw_text))
+
+    def _axiom_severities(self) -> dict[str, RiskTier]:
+        """Routing tier of each registered axiom's declared severity."""
+        return {
+            axiom.code: _severity_risk_tier(axiom.severity) for axiom in self.axiom_checker.registry
+        }
+
     @staticmethod
-    def _to_judgment(result: ValidationResult) -> AdmissibilityJudgment:
+    def _to_judgment(
+        result: ValidationResult,
+        policy: EvaluationPolicy | None = None,
+        *,
+        severities: Mapping[str, RiskTier] | None = None,
+        risk_floor: RiskTier | None = None,
+    ) -> AdmissibilityJudgment:
         """
         Convert internal ValidationResult into public AdmissibilityJudgment.
 
-        Public model is stable, minimal, and audited.
+        Public model is stable, minimal, and audited. ``severities`` (axiom code ->
+        tier) and ``risk_floor`` (default: the policy's) feed the risk tier.
         """
 
         def _status(s: EvaluationStatus) -> AdmissibilityStatus:
@@ -536,6 +1725,8 @@ class AdmissibilityEvaluator:
             except ValueError:
                 return AdmissibilityStatus.UNDERDETERMINED
 
+        policy = policy or EvaluationPolicy()
+        locale = policy.locale
         statement_evaluations: list[StatementEvaluation] = []
         violated_axioms: list[str] = []
 
@@ -552,6 +1743,7 @@ class AdmissibilityEvaluator:
                     confidence=k.confidence,
                     strength=k.strength,
                     semantic_id=k.semantic_id,
+                    derived_from=list(k.derived_from),
                 )
                 for k in stmt.ground_set.nodes
             ]
@@ -561,32 +1753,177 @@ class AdmissibilityEvaluator:
                     statement_id=stmt.statement.id,
                     statement=stmt.statement.raw_text,
                     modality=modality,
+                    reading=stmt.statement.reading.value if stmt.statement.reading else None,
+                    uncertainty=(
+                        StatementUncertainty(
+                            markers=stmt.statement.uncertainty.markers,
+                            probability=stmt.statement.uncertainty.probability,
+                        )
+                        if stmt.statement.uncertainty
+                        else None
+                    ),
+                    attribution=(
+                        StatementAttribution(
+                            source=stmt.statement.attribution.source,
+                            marker=stmt.statement.attribution.marker,
+                        )
+                        if stmt.statement.attribution
+                        else None
+                    ),
+                    personalized=stmt.statement.personalized,
                     license=permitted,
                     status=_status(stmt.status),
                     violated_axiom=stmt.violated_axiom,
-                    explanation=stmt.explanation,
+                    explanation=translate(stmt.explanation, locale),
+                    recommended_modality=(
+                        stmt.recommended_modality.value if stmt.recommended_modality else None
+                    ),
                     grounding_trace=grounding_trace,
+                    weight=round(stmt.weight, 4),
+                    confidence=round(stmt.confidence, 4),
                     subject=stmt.statement.subject,
                     predicate=stmt.statement.predicate,
+                    license_trace=(
+                        AdmissibilityEvaluator._license_trace(stmt.license_trace)
+                        if stmt.license_trace
+                        else None
+                    ),
                 )
             )
             if stmt.violated_axiom:
                 violated_axioms.append(stmt.violated_axiom)
 
+        status_counts: dict[AdmissibilityStatus, int] = {}
+        for evaluation in statement_evaluations:
+            status_counts[evaluation.status] = status_counts.get(evaluation.status, 0) + 1
+        # The verdict is only as certain as its least certain statement
+        confidence = min((e.confidence for e in statement_evaluations), default=1.0)
+
+        status = _status(result.status)
+        risk_tier = _derive_risk_tier(
+            status,
+            policy.risk_floor if risk_floor is None else risk_floor,
+            statement_evaluations,
+            severities,
+        )
         return AdmissibilityJudgment(
-            status=_status(result.status),
+            status=status,
             licensed=result.licensed,
             can_retry=result.can_retry,
             statement_evaluations=statement_evaluations,
             feedback_hint=result.feedback_hint,
             violated_axioms=violated_axioms,
-            explanation=result.explanation,
+            explanation=translate(result.explanation, locale),
             num_statements=result.num_statements,
             num_acceptable=result.num_acceptable,
+            status_counts=status_counts,
+            confidence=confidence,
             grounds_accepted=result.grounds_accepted,
             grounds_cited=result.grounds_cited,
+            accepted_ground_ids=result.accepted_ground_ids,
+            cited_ground_ids=result.cited_ground_ids,
+            citation_map=result.citation_map,
+            out_of_window_ground_ids=result.out_of_window_ground_ids,
+            risk_tier=risk_tier,
+            gate_decision=_derive_gate_decision(status, risk_tier, policy),
+            reasoning_items_skipped=result.reasoning_items_skipped,
+        )
+
+    @staticmethod
+    def _license_trace(trace: dict[str, Any]) -> LicenseTrace:
+        """Public form of a LicenseDeriver.trace() record (nodes are in grounding_trace)."""
+        return LicenseTrace(
+            rule=trace["rule"],
+            mode=trace["mode"],
+            ground_set_size=trace["ground_set_size"],
+            factual_present=trace["factual"]["present"],
+            factual_strength=trace["factual"]["strength"],
+            factual_has_strong=trace["factual"]["has_strong"],
+            non_factual_scopes=trace["non_factual_scopes_present"],
+            user_context=trace["user_context"],
+            supports_links_count=trace.get("supports_links_count") or 0,
+            permitted_modalities=trace["permitted_modalities"],
         )
 
+    @staticmethod
+    def _citation_in_span(citation: Any, span: tuple[int, int] | list[int]) -> bool:
+        """Whether an annotation's offsets fall inside the selected ``[start, end)`` span."""
+        start, end = span
+        if isinstance(getattr(citation, "start_index", None), int):
+            return start <= citation.start_index and citation.end_index <= end
+        if isinstance(getattr(citation, "index", None), int):
+            return start <= citation.index <= end
+        return True
+
+    @staticmethod
+    def _select_span(text: str, span: tuple[int, int] | list[int]) -> str:
+        """Slice assistant text to a caller-selected ``[start, end)`` range."""
+        if len(span) != 2 or not all(isinstance(bound, int) for bound in span):
+            raise ValueError("span must be a pair of integer offsets [start, end]")
+        start, end = span
+        if not 0 <= start <= end <= len(text):
+            raise ValueError(f"span {list(span)} is outside assistant text (length {len(text)})")
+        return text[start:end]
+
+    def _extract_argument_claims(
+        self, assistant_message: _AssistantMessage
+    ) -> list[tuple[str, str]]:
+        """
+        Collect claim texts from configured argument fields of assistant tool calls.
+
+        Only tools listed in ``policy.tool_claim_fields`` are inspected, and only
+        string values are treated as claims. Statement ids have the form
+        ``tool_call:<call_id>:<field>``.
+        """
+        claim_fields = self.policy.tool_claim_fields
+        if not claim_fields:
+            return []
+
+        claims: list[tuple[str, str]] = []
+        for tool_call in assistant_message.tool_calls:
+            if not isinstance(tool_call, _FunctionToolCall):
+                continue
+            fields = claim_fields.get(tool_call.name)
+            if not fields:
+                continue
+            arguments = self._parse_tool_args(tool_call.arguments, tool_call.id)
+            for field in fields:
+                value: Any = arguments
+                for part in field.split("."):
+                    value = value.get(part) if isinstance(value, dict) else None
+                if isinstance(value, str) and value.strip():
+                    claims.append((f"tool_call:{tool_call.id}:{field}", value))
+        return claims
+
+    def _action_statements(self, assistant_message: _AssistantMessage) -> list[Statement]:
+        """
+        Build ASSERTIVE action statements for the message's state-changing tool calls.
+
+        A call is an imperative by construction ("do this now"), so it needs the
+        same license as a categorical recommendation. Read-only calls (see
+        ``policy.tool_effect``) gather evidence and are skipped. Statement ids have
+        the form ``tool_call:<call_id>``; the text is ``name(arguments)``.
+        """
+        actions: list[Statement] = []
+        for tool_call in assistant_message.tool_calls:
+            if self.policy.tool_effect(tool_call.name) != "state_changing":
+                continue
+            arguments = (
+                tool_call.arguments
+                if isinstance(tool_call, _FunctionToolCall)
+                else tool_call.input_value
+            )
+            actions.append(
+                Statement(
+                    id=f"tool_call:{tool_call.id}",
+                    subject="agent",
+                    predicate=_ACTION_PREDICATE,
+                    raw_text=f"{tool_call.name}({arguments})",
+                    modality=Modality.ASSERTIVE,
+                )
+            )
+        return actions
+
     def _extract_tool_results(
         self,
         trajectory: list[ChatCompletionMessageParam],
@@ -609,44 +1946,101 @@ class AdmissibilityEvaluator:
         tool_call_by_id: dict[str, dict] = {}
         for message in trajectory:
             validated_message = self._validate_message(message)
-            mapped_message = self._map_message(validated_message)
-            if not isinstance(mapped_message, _AssistantMessage):
-                continue
-            for tool_call in mapped_message.tool_calls:
-                if isinstance(tool_call, _FunctionToolCall):
-                    args = self._parse_tool_args(tool_call.arguments)
-                    tool_call_by_id[tool_call.id] = {
-                        "name": tool_call.name,
-                        "arguments": args,
-                    }
+            self._register_tool_calls(self._map_message(validated_message), tool_call_by_id)
 
         # Method 2: Extract from separate tool messages (role='tool')
-        for message in trajectory:
+        for position, message in enumerate(trajectory):
             validated_message = self._validate_message(message)
-            mapped_message = self._map_message(validated_message)
-            if isinstance(mapped_message, _ToolMessage):
-                call_meta = tool_call_by_id.get(mapped_message.tool_call_id, {})
-                content = self._extract_text_content(mapped_message.content)
-                tool_results.append(
-                    ToolResultSpeechAct(
-                        tool_name=call_meta.get("name", "unknown"),
-                        tool_call_id=mapped_message.tool_call_id,
-                        arguments=call_meta.get("arguments", {}),
-                        result_text=content,
-                    )
-                )
-            elif isinstance(mapped_message, _FunctionMessage):
-                if mapped_message.name:
-                    content = self._extract_text_content(mapped_message.content)
-                    tool_results.append(
-                        ToolResultSpeechAct(
-                            tool_name=mapped_message.name,
-                            result_text=content,
-                        )
-                    )
+            tool_result = self._tool_result_at(
+                self._map_message(validated_message), position, tool_call_by_id
+            )
+            if tool_result is not None:
+                tool_results.append(tool_result)
 
         return tool_results
 
+    def _register_tool_calls(self, mapped_message: Any, tool_call_by_id: dict[str, dict]) -> None:
+        """Record the name and parsed arguments of an assistant message's tool calls."""
+        if not isinstance(mapped_message, _AssistantMessage):
+            return
+        for tool_call in mapped_message.tool_calls:
+            if isinstance(tool_call, _FunctionToolCall):
+                args = self._parse_tool_args(tool_call.arguments, tool_call.id)
+                tool_call_by_id[tool_call.id] = {
+                    "name": tool_call.name,
+                    "arguments": args,
+                }
+            elif isinstance(tool_call, _CustomToolCall):
+                tool_call_by_id[tool_call.id] = {"name": tool_call.name, "arguments": {}}
+
+    def _tool_result_at(
+        self, mapped_message: Any, position: int, tool_call_by_id: dict[str, dict]
+    ) -> ToolResultSpeechAct | None:
+        """Tool result carried by a tool or function message at ``position``, if any."""
+        if isinstance(mapped_message, _ToolMessage):
+            call_meta = tool_call_by_id.get(mapped_message.tool_call_id)
+            if call_meta is None:
+                # A result nobody asked for is a common injection vector
+                handling = self.policy.unmatched_tool_results
+                report_warning(
+                    f"Tool result '{mapped_message.tool_call_id}' matches no assistant "
+                    f"tool call ({handling})"
+                )
+                if handling == "ignore":
+                    return None
+            content = self._extract_text_content(mapped_message.content)
+            return ToolResultSpeechAct(
+                tool_name=(call_meta or {}).get("name", "unknown"),
+                tool_call_id=mapped_message.tool_call_id,
+                arguments=(call_meta or {}).get("arguments", {}),
+                result_text=content,
+                trusted=call_meta is not None or self.policy.unmatched_tool_results == "trust",
+                position=position,
+            )
+        if isinstance(mapped_message, _FunctionMessage) and mapped_message.name:
+            content = self._extract_text_content(mapped_message.content)
+            return ToolResultSpeechAct(
+                tool_name=mapped_message.name,
+                result_text=content,
+                position=position,
+            )
+        return None
+
+    def _ground_window_start(self, trajectory: list[ChatCompletionMessageParam]) -> int:
+        """
+        Trajectory index where the policy's ground window opens (0: no window).
+
+        The window starts at the ``policy.ground_window_turns``-th last user
+        message; tool results before it are out of window.
+        """
+        turns = self.policy.ground_window_turns
+        user_positions = [
+            position
+            for position, message in enumerate(trajectory)
+            if isinstance(message, Mapping) and message.get("role") == "user"
+        ]
+        if not turns or len(user_positions) < turns:
+            return 0
+        return user_positions[-turns]
+
+    @staticmethod
+    def _extract_user_texts(trajectory: list[ChatCompletionMessageParam]) -> list[str]:
+        """Collect the text of user messages (string content or text parts)."""
+        texts: list[str] = []
+        for message in trajectory:
+            if not isinstance(message, Mapping) or message.get("role") != "user":
+                continue
+            content = message.get("content")
+            if isinstance(content, str):
+                texts.append(content)
+            elif isinstance(content, Iterable):
+                texts.extend(
+                    part["text"]
+                    for part in content
+                    if isinstance(part, Mapping) and part.get("type") == "text"
+                )
+        return texts
+
     @staticmethod
     def _extract_text_content(content: str | list["_ContentPart"] | None) -> str:
         """Normalize message content into a plain text string."""
@@ -659,7 +2053,7 @@ class AdmissibilityEvaluator:
         raise ValueError(f"Unsupported content type: {type(content)}")
 
     @staticmethod
-    def _parse_tool_args(arguments: Any) -> dict[str, Any]:
+    def _parse_tool_args(arguments: Any, tool_call_id: str | None = None) -> dict[str, Any]:
         """Parse tool call arguments into a dict, handling JSON strings."""
         if arguments is None:
             return {}
@@ -668,9 +2062,14 @@ class AdmissibilityEvaluator:
         if isinstance(arguments, str):
             try:
                 parsed = json.loads(arguments)
-                return parsed if isinstance(parsed, dict) else {}
             except json.JSONDecodeError:
-                return {}
+                parsed = None
+            if isinstance(parsed, dict):
+                return parsed
+        if arguments != "":
+            report_warning(
+                f"Unparsable arguments of tool call '{tool_call_id or 'unknown'}' ignored"
+            )
         return {}
 
     def _validate_message(self, message: ChatCompletionMessageParam) -> ChatCompletionMessageParam:
@@ -756,12 +2155,25 @@ class AdmissibilityEvaluator:
             refusal_parts = [p.refusal for p in content if isinstance(p, _RefusalPart)]
             text_parts = [p.text for p in content if isinstance(p, _TextPart)]
             if refusal_parts and text_parts:
-                raise ValueError("Assistant content cannot mix text and refusal parts")
+                if self.policy.mixed_refusal_parts != "evaluate_parts":
+                    raise ValueError("Assistant content cannot mix text and refusal parts")
+                # Refusal parts are evaluated separately (see _mixed_refusal_text)
+                return TextSpeechAct(text="".join(text_parts).strip())
             if refusal_parts:
                 return RefusalSpeechAct(refusal="".join(refusal_parts).strip())
             return TextSpeechAct(text="".join(text_parts).strip())
         raise ValueError(f"Unsupported assistant content type: {type(content)}")
 
+    def _mixed_refusal_text(self, assistant_message: "_AssistantMessage") -> str | None:
+        """Refusal parts of content that also has text parts (policy ``evaluate_parts``)."""
+        content = assistant_message.content
+        if self.policy.mixed_refusal_parts != "evaluate_parts" or not isinstance(content, list):
+            return None
+        refusal_parts = [p.refusal for p in content if isinstance(p, _RefusalPart)]
+        if not refusal_parts or not any(isinstance(p, _TextPart) for p in content):
+            return None
+        return "".join(refusal_parts).strip()
+
     def _evaluate_refusal(
         self,
         refusal_text: str,
@@ -769,6 +2181,19 @@ class AdmissibilityEvaluator:
         links: LinkSet | None,
     ) -> ValidationResult:
         """Evaluate a refusal speech act using the same axioms."""
+        result, stmt_result = self._check_refusal(refusal_text, knowledge_nodes, links)
+        return self._aggregate(
+            [result],
+            [stmt_result],
+        )
+
+    def _check_refusal(
+        self,
+        refusal_text: str,
+        knowledge_nodes: list[KnowledgeNode],
+        links: LinkSet | None,
+    ) -> tuple[Any, StatementValidationResult]:
+        """Check a refusal statement (id ``refusal``); modality comes from the part type."""
         from .normative.models import Modality, Statement
 
         statement = Statement(
@@ -785,7 +2210,7 @@ class AdmissibilityEvaluator:
             statement,
             license,
             ground_set,
-            task_goal="task completion",
+            task_goal=self.task_goal,
         )
         stmt_result = StatementValidationResult(
             statement=statement,
@@ -794,8 +2219,101 @@ class AdmissibilityEvaluator:
             ground_set=ground_set,
             violated_axiom=result.violated_axiom,
             explanation=result.explanation,
+            license_trace=(
+                self.license_deriver.trace(ground_set, license, links)
+                if self.trace_licenses
+                else None
+            ),
         )
-        return self._aggregate(
-            [result],
-            [stmt_result],
+        return result, stmt_result
+
+
+class EvaluatorBuilder:
+    """
+    Fluent configuration of an ``AdmissibilityEvaluator``.
+
+    Each pipeline stage (statement extractor, modality detector, knowledge
+    builder, ground matcher, license deriver, axiom checker) can be replaced
+    by a configured instance; stages left unset are built from the policy as
+    ``AdmissibilityEvaluator(policy)`` would build them::
+
+        evaluator = (
+            AdmissibilityEvaluator.builder()
+            .policy(policy_pack("strict"))
+            .modality_detector(ModalityDetector(keywords=keywords))
+            .task_goal("triage CI failures")
+            .strict()
+            .build()
+        )
+        judgment = evaluate(conversation=conversation, evaluator=evaluator)
+    """
+
+    def __init__(self) -> None:
+        self._policy: EvaluationPolicy | None = None
+        self._stages: dict[str, Any] = {}
+        self._task_goal = DEFAULT_TASK_GOAL
+        self._strict = False
+        self._trace = False
+
+    def policy(self, policy: EvaluationPolicy | dict[str, Any] | None) -> "EvaluatorBuilder":
+        """Evaluation policy (an ``EvaluationPolicy`` or its fields as a dict)."""
+        self._policy = coerce_policy(policy)
+        return self
+
+    def extractor(self, extractor: StatementExtraction) -> "EvaluatorBuilder":
+        """Statement extraction stage (see ``StatementExtraction``)."""
+        self._stages["extractor"] = extractor
+        return self
+
+    def modality_detector(self, modality_detector: ModalityDetection) -> "EvaluatorBuilder":
+        """Modality detection stage (see ``ModalityDetection``)."""
+        self._stages["modality_detector"] = modality_detector
+        return self
+
+    def knowledge_builder(self, knowledge_builder: KnowledgeStateBuilder) -> "EvaluatorBuilder":
+        self._stages["knowledge_builder"] = knowledge_builder
+        return self
+
+    def ground_matcher(self, ground_matcher: GroundSetMatcher) -> "EvaluatorBuilder":
+        self._stages["ground_matcher"] = ground_matcher
+        return self
+
+    def license_deriver(self, license_deriver: LicenseDeriver) -> "EvaluatorBuilder":
+        self._stages["license_deriver"] = license_deriver
+        return self
+
+    def axiom_checker(self, axiom_checker: AxiomChecker) -> "EvaluatorBuilder":
+        self._stages["axiom_checker"] = axiom_checker
+        return self
+
+    def axiom_registry(self, registry: AxiomRegistry) -> "EvaluatorBuilder":
+        """Axioms to check, in order (shorthand for ``axiom_checker(AxiomChecker(registry))``)."""
+        self._stages["axiom_checker"] = AxiomChecker(registry)
+        return self
+
+    def task_goal(self, task_goal: str) -> "EvaluatorBuilder":
+        """Task goal handed to the axiom checker's relevance check."""
+        if not isinstance(task_goal, str) or not task_goal.strip():
+            raise ValueError("task_goal must be a non-empty string")
+        self._task_goal = task_goal
+        return self
+
+    def strict(self, strict: bool = True) -> "EvaluatorBuilder":
+        """Raise ValueError on recoverable input issues instead of warning."""
+        self._strict = strict
+        return self
+
+    def trace(self, trace: bool = True) -> "EvaluatorBuilder":
+        """Report the license derivation trace per statement."""
+        self._trace = trace
+        return self
+
+    def build(self) -> AdmissibilityEvaluator:
+        evaluator = AdmissibilityEvaluator(
+            self._policy,
+            task_goal=self._task_goal,
+            strict=self._strict,
+            **self._stages,
         )
+        evaluator.trace_licenses = self._trace
+        return evaluator
@@ -0,0 +1,193 @@
+"""
+Text formats for judgment output on the CLI.
+
+- ``json``: indented JSON, one object after another (``evaluate`` default)
+- ``jsonl``: one JSON object per line (pipeline default)
+- ``compact``: one JSON object per line without insignificant whitespace
+- ``table``: a human-readable summary for terminal triage (status, gate,
+  violated axioms, one row per statement); not meant for parsing
+- ``summary``: a verdict-first report for human review (status, statement
+  count, violating sentences with their axiom, resolved and unresolved
+  citations), optionally colorized with ANSI escapes; not meant for parsing
+
+Formats apply to output records: judgment records and per-line error records.
+"""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Mapping
+from typing import Any
+
+from .canonical import canonical_json
+from .citations.grounds import extract_citation_keys
+
+JSON_FORMAT = "json"
+JSONL_FORMAT = "jsonl"
+COMPACT_FORMAT = "compact"
+TABLE_FORMAT = "table"
+SUMMARY_FORMAT = "summary"
+RECORD_FORMATS = (JSON_FORMAT, JSONL_FORMAT, COMPACT_FORMAT, TABLE_FORMAT, SUMMARY_FORMAT)
+# Formats for people rather than parsers: blank-line separated, never canonical.
+TEXT_FORMATS = (TABLE_FORMAT, SUMMARY_FORMAT)
+
+# Longer statement texts are cut in table rows.
+TABLE_TEXT_WIDTH = 60
+
+
+def _cell(value: Any) -> str:
+    return "-" if value in (None, "") else str(value)
+
+
+def _truncate(text: str, width: int = TABLE_TEXT_WIDTH) -> str:
+    text = " ".join(text.split())
+    return text if len(text) <= width else text[: width - 3] + "..."
+
+
+def render_table(record: Mapping[str, Any]) -> str:
+    """Render one output record as a human-readable summary block."""
+    prefix = f"[{record['id']}] " if record.get("id") is not None else ""
+    if "error" in record:
+        return f"{prefix}error (line {_cell(record.get('line'))}): {record['error']}"
+    lines = [
+        f"{prefix}{record['status']} (gate: {_cell(record.get('gate_decision'))}, "
+        f"risk: {_cell(record.get('risk_tier'))}, "
+        f"confidence: {float(record.get('confidence', 1.0)):.2f})",
+        f"violated axioms: {', '.join(record.get('violated_axioms') or []) or '-'}",
+    ]
+    rows = [("STATEMENT", "STATUS", "MODALITY", "AXIOM", "TEXT")] + [
+        (
+            _cell(evaluation.get("statement_id")),
+            _cell(evaluation.get("status")),
+            _cell(evaluation.get("modality")),
+            _cell(evaluation.get("violated_axiom")),
+            _truncate(_cell(evaluation.get("statement"))),
+        )
+        for evaluation in record.get("statement_evaluations") or []
+    ]
+    if len(rows) > 1:
+        widths = [max(len(cell) for cell in column) for column in zip(*rows)]
+        lines.extend(
+            "  ".join(cell.ljust(width) for cell, width in zip(row, widths)).rstrip()
+            for row in rows
+        )
+    return "\n".join(lines)
+
+
+# ANSI SGR codes of the summary format.
+_GREEN = "32"
+_YELLOW = "33"
+_RED = "31"
+_BOLD = "1"
+_DIM = "2"
+
+# Statement statuses the summary does not list as violations.
+_PASSING_STATUSES = ("acceptable", "conditionally_acceptable", "no_normative_content")
+
+_STATUS_COLORS = {
+    "acceptable": _GREEN,
+    "no_normative_content": _GREEN,
+    "conditionally_acceptable": _YELLOW,
+    "underdetermined": _YELLOW,
+}
+
+
+def _paint(text: str, code: str, color: bool) -> str:
+    return f"\033[{code}m{text}\033[0m" if color else text
+
+
+def render_summary(record: Mapping[str, Any], *, color: bool = False) -> str:
+    """
+    Render one output record as a verdict-first report for human review.
+
+    The first line is the overall status with gate, risk and confidence,
+    followed by the statement count, every failing statement (sentence and
+    violated axiom) and the citation keys that resolved to admitted grounds or
+    did not. ``color=True`` adds ANSI colors.
+    """
+    prefix = f"[{record['id']}] " if record.get("id") is not None else ""
+    if "error" in record:
+        error = f"error (line {_cell(record.get('line'))})"
+        return f"{prefix}{_paint(error, _RED, color)}: {record['error']}"
+    status = str(record["status"])
+    lines = [
+        f"{prefix}{_paint(status.upper(), _BOLD + ';' + _STATUS_COLORS.get(status, _RED), color)}"
+        f"  gate: {_cell(record.get('gate_decision'))}, "
+        f"risk: {_cell(record.get('risk_tier'))}, "
+        f"confidence: {float(record.get('confidence', 1.0)):.2f}"
+    ]
+    evaluations = record.get("statement_evaluations") or []
+    counts = record.get("status_counts") or {}
+    breakdown = ", ".join(f"{count} {name}" for name, count in counts.items())
+    total = record.get("num_statements", len(evaluations))
+    lines.append(
+        f"{total} statement{'' if total == 1 else 's'}" + (f": {breakdown}" if breakdown else "")
+    )
+
+    failing = [
+        evaluation
+        for evaluation in evaluations
+        if evaluation.get("violated_axiom") or evaluation.get("status") not in _PASSING_STATUSES
+    ]
+    if failing:
+        lines.append("violations:")
+        for evaluation in failing:
+            axiom = _paint(_cell(evaluation.get("violated_axiom")), _RED, color)
+            lines.append(
+                f"  {axiom} {_cell(evaluation.get('statement_id'))} "
+                f"({_cell(evaluation.get('status'))}): "
+                f"\"{_truncate(_cell(evaluation.get('statement')))}\""
+            )
+            if evaluation.get("explanation"):
+                lines.append("    " + _paint(str(evaluation["explanation"]), _DIM, color))
+
+    citation_map = record.get("citation_map") or {}
+    cited = {
+        key
+        for evaluation in evaluations
+        for key in extract_citation_keys(evaluation.get("statement") or "")
+    }
+    resolved = sorted(key for key in cited if citation_map.get(key))
+    unresolved = sorted(key for key in cited if not citation_map.get(key))
+    if resolved or unresolved:
+        lines.append("citations:")
+        lines.extend(
+            f"  {_paint('resolved', _GREEN, color)}    [@{key}] -> "
+            f"{', '.join(citation_map[key])}"
+            for key in resolved
+        )
+        lines.extend(
+            f"  {_paint('unresolved', _RED, color)}  [@{key}]" for key in unresolved
+        )
+    return "\n".join(lines)
+
+
+def render_record(
+    record: Mapping[str, Any],
+    record_format: str = JSONL_FORMAT,
+    *,
+    canonical: bool = False,
+    color: bool = False,
+) -> str:
+    """
+    Render one output record in ``record_format`` (without a trailing newline).
+
+    ``canonical=True`` writes canonical JSON (see ``canonical_json``) for the
+    JSON formats; ``color=True`` colorizes the summary format.
+
+    Raises:
+        ValueError: the format is unknown
+    """
+    if record_format == TABLE_FORMAT:
+        return render_table(record)
+    if record_format == SUMMARY_FORMAT:
+        return render_summary(record, color=color)
+    if record_format not in RECORD_FORMATS:
+        raise ValueError(f"Unknown format: {record_format}")
+    if canonical:
+        return canonical_json(record)
+    if record_format == JSON_FORMAT:
+        return json.dumps(record, ensure_ascii=False, indent=2)
+    if record_format == COMPACT_FORMAT:
+        return json.dumps(record, ensure_ascii=False, separators=(",", ":"))
+    return json.dumps(record, ensure_ascii=False)
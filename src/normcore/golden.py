@@ -0,0 +1,217 @@
+"""
+Golden corpus regression runner.
+
+A golden scenario is a JSON file holding an evaluate payload and the judgment
+it is expected to produce::
+
+    {
+      "description": "optional free text",
+      "input": {"agent_output": "...", "grounds": [...]},
+      "expected": {<AdmissibilityJudgment JSON>}
+    }
+
+Scenarios may also be YAML files (``*.yaml`` / ``*.yml``, the same structure;
+``--update`` rewrites them whole, like JSON) or TOML files (``*.toml``) with
+``[input]`` and ``[expected]`` tables. TOML has no null, so null judgment fields are left out
+of TOML expectations and ignored when comparing. ``--update`` rewrites only
+the trailing ``expected`` tables, keeping the hand-written input and its
+comments, when they come last in the file.
+
+Files in the declarative scenario format (``turns`` and ``expect``; see
+``normcore.scenarios``) run alongside: only their expected fields are
+compared, and ``--update`` leaves their hand-written expectations alone.
+
+Running a directory of scenarios re-evaluates every input and reports a
+unified diff for each judgment that no longer matches, so policy and heuristic
+changes cannot silently flip verdicts on known cases.
+"""
+
+from __future__ import annotations
+
+import difflib
+import json
+import re
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from .inputs import TOML_SUFFIX, YAML_SUFFIXES, dump_yaml, load_structured_file, load_toml
+from .models import AdmissibilityJudgment, EvaluationPolicy
+from .payloads import evaluate_payload
+from .scenarios import CORPUS_SUFFIXES, is_scenario, scenario_from_dict
+from .scenarios import run_scenario as run_declarative_scenario
+
+_TOML_BARE_KEY = re.compile(r"[A-Za-z0-9_-]+")
+_TOML_EXPECTED_HEADER = re.compile(r"^\s*\[\[?\s*expected\s*[.\]]", re.MULTILINE)
+
+
+@dataclass
+class GoldenResult:
+    """Outcome of running one golden scenario."""
+
+    path: Path
+    passed: bool
+    diff: str = ""
+    error: str | None = None
+
+
+def judgment_to_golden(judgment: AdmissibilityJudgment) -> dict[str, Any]:
+    """Serialize a judgment deterministically (set-valued fields are sorted)."""
+    data = judgment.model_dump(mode="json")
+    for evaluation in data.get("statement_evaluations", []):
+        evaluation["license"] = sorted(evaluation.get("license", []))
+    return data
+
+
+def _drop_nulls(value: Any) -> Any:
+    if isinstance(value, dict):
+        return {k: _drop_nulls(v) for k, v in value.items() if v is not None}
+    if isinstance(value, list):
+        return [_drop_nulls(item) for item in value if item is not None]
+    return value
+
+
+def _toml_key(key: str) -> str:
+    return key if _TOML_BARE_KEY.fullmatch(key) else json.dumps(key, ensure_ascii=False)
+
+
+def _toml_value(value: Any) -> str:
+    if isinstance(value, bool):
+        return "true" if value else "false"
+    if isinstance(value, (int, float)):
+        return repr(value)
+    if isinstance(value, str):
+        return json.dumps(value, ensure_ascii=False)
+    if isinstance(value, list):
+        return "[" + ", ".join(_toml_value(item) for item in value) + "]"
+    if isinstance(value, dict):
+        items = ", ".join(f"{_toml_key(k)} = {_toml_value(v)}" for k, v in value.items())
+        return "{ " + items + " }" if items else "{}"
+    raise TypeError(f"{type(value).__name__} has no TOML form")
+
+
+def _is_table_array(value: Any) -> bool:
+    return isinstance(value, list) and bool(value) and all(isinstance(v, dict) for v in value)
+
+
+def _toml_table(table: dict[str, Any], path: str, lines: list[str]) -> None:
+    nested = []
+    for key, value in table.items():
+        if (isinstance(value, dict) and value) or _is_table_array(value):
+            nested.append((key, value))
+        else:
+            lines.append(f"{_toml_key(key)} = {_toml_value(value)}")
+    for key, value in nested:
+        name = f"{path}.{_toml_key(key)}" if path else _toml_key(key)
+        for item in value if isinstance(value, list) else [value]:
+            header = f"[[{name}]]" if isinstance(value, list) else f"[{name}]"
+            lines.extend(["", header])
+            _toml_table(item, name, lines)
+
+
+def _toml_dumps(data: dict[str, Any]) -> str:
+    lines: list[str] = []
+    _toml_table(_drop_nulls(data), "", lines)
+    return "\n".join(lines).lstrip("\n") + "\n"
+
+
+def _write_expected(path: Path, scenario: dict[str, Any], actual: dict[str, Any]) -> None:
+    scenario["expected"] = actual
+    if path.suffix.lower() in YAML_SUFFIXES:
+        path.write_text(dump_yaml(scenario), encoding="utf-8")
+        return
+    if path.suffix.lower() != TOML_SUFFIX:
+        path.write_text(json.dumps(scenario, ensure_ascii=False, indent=2) + "\n", encoding="utf-8")
+        return
+    text = path.read_text(encoding="utf-8")
+    match = _TOML_EXPECTED_HEADER.search(text)
+    head = text[: match.start()] if match else text
+    rest = {k: v for k, v in scenario.items() if k != "expected"}
+    try:
+        keeps_head = load_toml(head) == rest
+    except ValueError:
+        keeps_head = False
+    if not keeps_head:
+        head = _toml_dumps(rest)
+    expected = _toml_dumps({"expected": actual})
+    path.write_text(head.rstrip("\n") + "\n\n" + expected, encoding="utf-8")
+
+
+def _render(data: Any) -> list[str]:
+    return json.dumps(data, ensure_ascii=False, indent=2, sort_keys=True).splitlines()
+
+
+def _diff(path: Path, expected: Any, actual: Any) -> str:
+    return "\n".join(
+        difflib.unified_diff(
+            _render(expected),
+            _render(actual),
+            fromfile=f"{path.name} (expected)",
+            tofile=f"{path.name} (actual)",
+            lineterm="",
+        )
+    )
+
+
+def _run_declarative(
+    path: Path, data: dict[str, Any], policy: EvaluationPolicy | None
+) -> GoldenResult:
+    try:
+        scenario = scenario_from_dict(data, name=path.stem, path=path)
+        result = run_declarative_scenario(scenario, policy)
+    except ValueError as exc:
+        return GoldenResult(path=path, passed=False, error=str(exc))
+    if result.error is not None:
+        return GoldenResult(path=path, passed=False, error=result.error)
+    if result.passed:
+        return GoldenResult(path=path, passed=True)
+    expected = {key: pair[0] for key, pair in result.mismatches.items()}
+    actual = {key: pair[1] for key, pair in result.mismatches.items()}
+    return GoldenResult(path=path, passed=False, diff=_diff(path, expected, actual))
+
+
+def run_scenario(
+    path: Path, *, update: bool = False, policy: EvaluationPolicy | None = None
+) -> GoldenResult:
+    """
+    Evaluate one scenario file under ``policy`` and compare against its expected judgment.
+
+    With ``update=True`` the expected judgment is rewritten from the current
+    evaluator output and the scenario passes. Declarative scenarios are only
+    checked, never rewritten; their own ``policy`` fields override ``policy``.
+    """
+    try:
+        scenario = load_structured_file(path)
+        if is_scenario(scenario):
+            return _run_declarative(path, scenario, policy)
+        if not isinstance(scenario, dict) or not isinstance(scenario.get("input"), dict):
+            raise ValueError("Scenario must be a JSON object with an 'input' object")
+        actual = judgment_to_golden(evaluate_payload(scenario["input"], policy=policy))
+    except (OSError, ValueError) as exc:
+        return GoldenResult(path=path, passed=False, error=str(exc))
+
+    if update:
+        _write_expected(path, scenario, actual)
+        return GoldenResult(path=path, passed=True)
+    if path.suffix.lower() == TOML_SUFFIX:
+        actual = _drop_nulls(actual)
+
+    expected = scenario.get("expected")
+    if expected == actual:
+        return GoldenResult(path=path, passed=True)
+    return GoldenResult(path=path, passed=False, diff=_diff(path, expected, actual))
+
+
+def run_golden_corpus(
+    directory: Path, *, update: bool = False, policy: EvaluationPolicy | None = None
+) -> list[GoldenResult]:
+    """
+    Run every scenario file (JSON, YAML or TOML, as ``run-corpus`` accepts) under
+    ``directory``, recursively and sorted by path, under ``policy``.
+    """
+    paths = sorted(
+        path
+        for path in directory.rglob("*")
+        if path.is_file() and path.suffix.lower() in CORPUS_SUFFIXES
+    )
+    return [run_scenario(path, update=update, policy=policy) for path in paths]
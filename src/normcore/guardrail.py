@@ -0,0 +1,145 @@
+"""
+Streaming guardrail for partial assistant output.
+
+A gateway relaying a streamed response can only cut it off while it is still
+streaming. ``StreamGuard`` accepts the output chunk by chunk, re-judges the
+partial text at sentence granularity whenever a new sentence may have begun, and
+reports every completed sentence whose gate decision is not ``allow`` as a
+``GuardEvent`` - an ungrounded "you must ..." is flagged before the response
+completes. ``finish()`` returns the judgment of the complete response.
+"""
+
+from __future__ import annotations
+
+import re
+from typing import Any
+
+from .evaluator import _derive_gate_decision, _derive_risk_tier
+from .models import AdmissibilityJudgment, EvaluationPolicy, GateDecision, GuardEvent
+from .models.policy import coerce_policy
+from .session import SessionEvaluator
+
+# Text that may close a sentence; partial output is re-judged once text follows one
+_SENTENCE_END_PATTERN = re.compile(r"[.!?][\"')\]]*\s|\n")
+
+_SENTENCE_ID_PATTERN = re.compile(r"s(\d+)")
+
+
+class StreamGuard:
+    """
+    Incremental guardrail over one streamed assistant response.
+
+    The partial output is judged as the next assistant turn of ``conversation``
+    (tool results are parsed once, up front). A sentence is final once the
+    extractor sees a later sentence after it; the last sentence of the partial
+    text is still streaming and is only judged by ``finish()``.
+    """
+
+    def __init__(
+        self,
+        conversation: list[Any] | None = None,
+        *,
+        policy: EvaluationPolicy | dict[str, Any] | None = None,
+        grounds: list[Any] | dict[str, Any] | None = None,
+    ) -> None:
+        """
+        Start guarding a response.
+
+        Args:
+            conversation: Messages before the streamed response
+            policy: Evaluation policy; ``statement_granularity`` is forced to "sentence"
+            grounds: External grounds available to the response
+        """
+        policy = coerce_policy(policy).model_copy(update={"statement_granularity": "sentence"})
+        self.session = SessionEvaluator(policy, grounds=grounds)
+        self.session.extend(conversation or [])
+        self.events: list[GuardEvent] = []
+        self._text = ""
+        self._scanned_chars = 0
+        self._boundary: int | None = None
+        self._final_ids: set[str] = set()
+        self._judgment: AdmissibilityJudgment | None = None
+
+    @property
+    def text(self) -> str:
+        """Output received so far."""
+        return self._text
+
+    @property
+    def blocked(self) -> bool:
+        """True once a completed sentence was gated ``block``."""
+        return any(event.gate_decision == GateDecision.BLOCK for event in self.events)
+
+    def feed(self, chunk: str) -> list[GuardEvent]:
+        """
+        Append a chunk of streamed output.
+
+        Returns:
+            Events for the sentences completed by this chunk that are not allowed
+
+        Raises:
+            ValueError: the response was already finished
+        """
+        if self._judgment is not None:
+            raise ValueError("StreamGuard already finished")
+        self._text += chunk
+        ends = list(_SENTENCE_END_PATTERN.finditer(self._text, max(self._scanned_chars - 1, 0)))
+        self._scanned_chars = len(self._text)
+        if ends:
+            self._boundary = ends[-1].end()
+        if self._boundary is None or not self._text[self._boundary :].strip():
+            return []
+        self._boundary = None
+        return self._record(self._judge(), final=False)
+
+    def finish(self) -> AdmissibilityJudgment:
+        """
+        Judge the complete response; events for its remaining sentences are recorded.
+        """
+        if self._judgment is None:
+            self._judgment = self._judge()
+            self._record(self._judgment, final=True)
+        return self._judgment
+
+    def _judge(self) -> AdmissibilityJudgment:
+        """Judge the output received so far as the next assistant turn."""
+        message = {"role": "assistant", "content": self._text}
+        return self.session._judge(message, [*self.session.messages, message])
+
+    def _record(self, judgment: AdmissibilityJudgment, *, final: bool) -> list[GuardEvent]:
+        """Events for newly final sentences of ``judgment`` whose gate is not allow."""
+        sentences = [
+            evaluation
+            for evaluation in judgment.statement_evaluations
+            if _SENTENCE_ID_PATTERN.fullmatch(evaluation.statement_id)
+        ]
+        if not final:
+            # The last sentence may still be streaming
+            sentences = sorted(sentences, key=lambda e: int(e.statement_id[1:]))[:-1]
+
+        policy = self.session.evaluator.policy
+        severities = self.session.evaluator._axiom_severities()
+        events = []
+        for evaluation in sentences:
+            if evaluation.statement_id in self._final_ids:
+                continue
+            self._final_ids.add(evaluation.statement_id)
+            risk_tier = _derive_risk_tier(
+                evaluation.status, policy.risk_floor, [evaluation], severities
+            )
+            gate_decision = _derive_gate_decision(evaluation.status, risk_tier, policy)
+            if gate_decision == GateDecision.ALLOW:
+                continue
+            events.append(
+                GuardEvent(
+                    statement_id=evaluation.statement_id,
+                    statement=evaluation.statement,
+                    status=evaluation.status,
+                    gate_decision=gate_decision,
+                    violated_axiom=evaluation.violated_axiom,
+                    explanation=evaluation.explanation,
+                    chars_received=len(self._text),
+                )
+            )
+        self.events.extend(events)
+        return events
@@ -0,0 +1,83 @@
+"""
+Authentication and CORS for NormCore HTTP endpoints.
+
+HTTP endpoints of long-running CLI modes expose data derived from full
+conversation contents, so they must not be left open on an internal network:
+
+- API keys: when any key is configured, requests must present one as
+  ``Authorization: Bearer <key>`` or ``X-API-Key: <key>``; others get 401.
+  Keys are compared in constant time.
+- CORS: browser origins listed in ``cors_origins`` (``*`` for any) receive
+  ``Access-Control-Allow-*`` headers, and ``OPTIONS`` preflight requests are
+  answered without authentication. No origin is allowed by default.
+"""
+
+from __future__ import annotations
+
+import hmac
+from collections.abc import Mapping
+from dataclasses import dataclass
+from pathlib import Path
+
+API_KEY_HEADER = "X-API-Key"
+CORS_ALLOW_HEADERS = f"Authorization, Content-Type, {API_KEY_HEADER}"
+CORS_ALLOW_METHODS = "GET, POST, OPTIONS"
+
+
+def load_api_keys(path: str | Path) -> list[str]:
+    """Read API keys from a file: one per line, blank lines and ``#`` comments ignored."""
+    keys = []
+    for line in Path(path).read_text(encoding="utf-8").splitlines():
+        key = line.strip()
+        if key and not key.startswith("#"):
+            keys.append(key)
+    if not keys:
+        raise ValueError(f"No API keys found in {path}")
+    return keys
+
+
+@dataclass(frozen=True)
+class HttpAccessPolicy:
+    """Who may call an HTTP endpoint, and from which browser origins."""
+
+    api_keys: tuple[str, ...] = ()
+    cors_origins: tuple[str, ...] = ()
+
+    def authorized(self, headers: Mapping[str, str]) -> bool:
+        """
+        Check request credentials.
+
+        Returns:
+            True when no key is configured or the request presents a configured key
+        """
+        if not self.api_keys:
+            return True
+        presented = headers.get(API_KEY_HEADER)
+        if presented is None:
+            scheme, _, token = (headers.get("Authorization") or "").partition(" ")
+            presented = token.strip() if scheme.lower() == "bearer" else None
+        if not presented:
+            return False
+        candidate = presented.encode("utf-8")
+        # Check every key so timing does not reveal which one matched.
+        matches = [hmac.compare_digest(candidate, key.encode("utf-8")) for key in self.api_keys]
+        return any(matches)
+
+    def cors_headers(self, origin: str | None) -> dict[str, str]:
+        """Response headers granting ``origin`` cross-origin access (empty if not allowed)."""
+        if not origin or not self.cors_origins:
+            return {}
+        if "*" in self.cors_origins:
+            allowed = "*"
+        elif origin in self.cors_origins:
+            allowed = origin
+        else:
+            return {}
+        headers = {
+            "Access-Control-Allow-Origin": allowed,
+            "Access-Control-Allow-Headers": CORS_ALLOW_HEADERS,
+            "Access-Control-Allow-Methods": CORS_ALLOW_METHODS,
+        }
+        if allowed != "*":
+            headers["Vary"] = "Origin"
+        return headers
@@ -0,0 +1,294 @@
+"""
+HTTP API for evaluating payloads from other services.
+
+Services that cannot spawn a process per request call a long-running
+``normcore serve`` instead:
+
+- ``POST /evaluate``: the request body is one evaluate payload (the same JSON
+  object as a ``stream`` line, see ``payloads``, or a MessagePack map with
+  ``Content-Type: application/msgpack``); the response is the judgment
+  record, in the request's encoding, with the echoed ``id`` and the
+  ``signature`` when signing is configured. Invalid payloads get ``400``,
+  bodies over the size limit or input over the policy's ``max_messages`` /
+  ``max_content_bytes`` ``413``, MessagePack bodies without the ``msgpack``
+  extra ``415``, and evaluations over the timeout ``504``, each with an
+  ``error`` message object.
+- ``GET /healthz``: ``{"status": "ok"}`` while the server accepts requests;
+  answered without authentication so load balancers can probe it.
+- ``GET /metrics``: Prometheus metrics, when a metrics registry is given.
+
+Other endpoints get ``404``. ``access`` adds API key authentication and CORS
+(see ``http_access``).
+"""
+
+from __future__ import annotations
+
+import json
+import threading
+import time
+from dataclasses import dataclass
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+from typing import Any
+
+from .audit import AuditLog
+from .canonical import canonical_json
+from .encodings import (
+    CONTENT_TYPES,
+    JSON_ENCODING,
+    MSGPACK_ENCODING,
+    content_type_encoding,
+    decode_msgpack,
+    import_msgpack,
+)
+from .http_access import HttpAccessPolicy
+from .limits import InputLimitError
+from .logging import logger
+from .metrics import CONTENT_TYPE as METRICS_CONTENT_TYPE
+from .metrics import METRICS_PATH, EvaluationMetrics
+from .models.policy import EvaluationPolicy
+from .payloads import (
+    PAYLOAD_AGENT_FIELD,
+    PAYLOAD_ID_FIELD,
+    evaluate_payload,
+    parse_payload_line,
+    payload_from_object,
+    render_result_record,
+)
+from .serving import EvaluationPool, EvaluationTimeoutError
+from .signing import JudgmentSigner
+from .webhooks import WebhookNotifier
+
+DEFAULT_PORT = 8080
+EVALUATE_PATH = "/evaluate"
+HEALTH_PATH = "/healthz"
+JSON_CONTENT_TYPE = CONTENT_TYPES[JSON_ENCODING]
+
+
+class _RequestError(Exception):
+    """A request answered with an error status and message."""
+
+    def __init__(self, status: int, message: str) -> None:
+        super().__init__(message)
+        self.status = status
+
+
+@dataclass
+class EvaluationService:
+    """
+    What ``POST /evaluate`` does with a payload besides evaluating it.
+
+    Args:
+        pool: Bounds concurrent evaluations and applies the per-payload timeout
+        strict: Reject input with recoverable issues
+        policy: Evaluation policy (default: built-in policy)
+        signer: Adds a detached ``signature`` to each judgment record
+        audit_log: Receives one hash-chained record per evaluation
+        metrics: Records judgments and rejected payloads
+        notifier: Queues each judgment for webhook alerting (delivered in the background)
+        max_payload_bytes: Larger request bodies are rejected unread
+        canonical: Write response bodies as canonical JSON
+    """
+
+    pool: EvaluationPool
+    strict: bool = False
+    policy: EvaluationPolicy | None = None
+    signer: JudgmentSigner | None = None
+    audit_log: AuditLog | None = None
+    metrics: EvaluationMetrics | None = None
+    notifier: WebhookNotifier | None = None
+    max_payload_bytes: int | None = None
+    canonical: bool = False
+
+    def _evaluate(self, payload: dict[str, Any]) -> tuple[Any, float]:
+        started = time.perf_counter()
+        judgment = evaluate_payload(payload, strict=self.strict, policy=self.policy)
+        return judgment, time.perf_counter() - started
+
+    def handle(self, body: bytes, encoding: str = JSON_ENCODING) -> dict[str, Any]:
+        """
+        Evaluate one request body in ``encoding`` and return the judgment record.
+
+        Raises:
+            _RequestError: the payload is invalid or its evaluation timed out (4xx/504),
+                or anything else failed: evaluation, audit log or metrics (500, logged)
+        """
+        try:
+            return self._handle(body, encoding)
+        except _RequestError:
+            raise
+        except Exception as exc:
+            logger.exception("serve: request failed")
+            raise _RequestError(500, "Internal error while evaluating the payload") from exc
+
+    def _handle(self, body: bytes, encoding: str) -> dict[str, Any]:
+        try:
+            if encoding == MSGPACK_ENCODING:
+                payload = payload_from_object(decode_msgpack(body))
+            else:
+                payload = parse_payload_line(body.decode("utf-8"))
+        except (UnicodeDecodeError, ValueError) as exc:
+            raise _RequestError(400, str(exc)) from exc
+        payload_id = payload.get(PAYLOAD_ID_FIELD)
+        try:
+            judgment, latency = self.pool.submit(self._evaluate, payload).result()
+        except InputLimitError as exc:
+            raise _RequestError(413, str(exc)) from exc
+        except ValueError as exc:
+            raise _RequestError(400, str(exc)) from exc
+        except EvaluationTimeoutError as exc:
+            raise _RequestError(504, str(exc)) from exc
+        if self.audit_log is not None:
+            self.audit_log.append(payload, judgment)
+        if self.metrics is not None:
+            agent = payload.get(PAYLOAD_AGENT_FIELD)
+            self.metrics.observe(
+                judgment, latency_seconds=latency, agent=str(agent) if agent is not None else None
+            )
+        if self.notifier is not None:
+            self.notifier.notify(judgment, payload_id=payload_id, payload=payload)
+        return render_result_record(
+            judgment, payload_id=payload_id, signer=self.signer, payload=payload
+        )
+
+    def render(self, record: dict[str, Any], encoding: str = JSON_ENCODING) -> bytes:
+        """Encode a response body."""
+        if encoding == MSGPACK_ENCODING:
+            return import_msgpack().packb(record)
+        if self.canonical:
+            return canonical_json(record).encode("utf-8")
+        return json.dumps(record, ensure_ascii=False).encode("utf-8")
+
+
+def evaluation_handler(
+    service: EvaluationService, access: HttpAccessPolicy | None = None
+) -> type[BaseHTTPRequestHandler]:
+    """
+    Build the request handler class that answers the evaluation API for ``service``.
+
+    ``start_evaluation_server`` serves it; any ``http.server`` server can.
+    """
+    access = access or HttpAccessPolicy()
+
+    class _EvaluationHandler(BaseHTTPRequestHandler):
+        def end_headers(self) -> None:
+            for name, value in access.cors_headers(self.headers.get("Origin")).items():
+                self.send_header(name, value)
+            super().end_headers()
+
+        def _send(self, status: int, body: bytes, content_type: str = JSON_CONTENT_TYPE) -> None:
+            self.send_response(status)
+            self.send_header("Content-Type", content_type)
+            self.send_header("Content-Length", str(len(body)))
+            self.end_headers()
+            self.wfile.write(body)
+
+        def _send_record(
+            self, status: int, record: dict[str, Any], encoding: str = JSON_ENCODING
+        ) -> None:
+            self._send(status, service.render(record, encoding), CONTENT_TYPES[encoding])
+
+        def _send_error_record(
+            self, status: int, message: str, encoding: str = JSON_ENCODING
+        ) -> None:
+            self._send_record(status, {"error": message}, encoding)
+
+        def _authorize(self) -> bool:
+            if access.authorized(self.headers):
+                return True
+            self.send_response(401)
+            self.send_header("WWW-Authenticate", 'Bearer realm="normcore"')
+            self.send_header("Content-Length", "0")
+            self.end_headers()
+            return False
+
+        def _path(self) -> str:
+            return self.path.split("?", 1)[0]
+
+        def do_OPTIONS(self) -> None:
+            self.send_response(204)
+            self.send_header("Content-Length", "0")
+            self.end_headers()
+
+        def do_GET(self) -> None:
+            path = self._path()
+            if path == HEALTH_PATH:
+                self._send_record(200, {"status": "ok"})
+                return
+            if not self._authorize():
+                return
+            if path == METRICS_PATH and service.metrics is not None:
+                body = service.metrics.render().encode("utf-8")
+                self._send(200, body, METRICS_CONTENT_TYPE)
+            elif path == EVALUATE_PATH:
+                self._send_error_record(405, f"Use POST {EVALUATE_PATH}")
+            else:
+                self._send_error_record(404, f"Unknown endpoint: {path}")
+
+        def do_POST(self) -> None:
+            if not self._authorize():
+                return
+            path = self._path()
+            if path != EVALUATE_PATH:
+                self._send_error_record(404, f"Unknown endpoint: {path}")
+                return
+            encoding = content_type_encoding(self.headers.get("Content-Type"))
+            if encoding != JSON_ENCODING:
+                try:
+                    import_msgpack()
+                except ValueError as exc:
+                    self.close_connection = True
+                    self._send_error_record(415, str(exc))
+                    return
+            try:
+                length = int(self.headers.get("Content-Length", ""))
+            except ValueError:
+                self.close_connection = True
+                self._send_error_record(411, "Content-Length is required", encoding)
+                return
+            if length < 0:
+                self.close_connection = True
+                self._send_error_record(400, "Content-Length must not be negative", encoding)
+                return
+            limit = service.max_payload_bytes
+            if limit is not None and length > limit:
+                # The body is left unread, so the connection cannot be reused.
+                self.close_connection = True
+                if service.metrics is not None:
+                    service.metrics.observe_payload_error()
+                self._send_error_record(413, f"Payload exceeds {limit} bytes", encoding)
+                return
+            try:
+                record = service.handle(self.rfile.read(length), encoding)
+            except _RequestError as exc:
+                logger.warning(f"serve: request rejected: {exc}")
+                if service.metrics is not None:
+                    service.metrics.observe_payload_error()
+                self._send_error_record(exc.status, str(exc), encoding)
+                return
+            self._send_record(200, record, encoding)
+
+        def log_message(self, format: str, *args: object) -> None:
+            return
+
+    return _EvaluationHandler
+
+
+def start_evaluation_server(
+    service: EvaluationService,
+    host: str = "127.0.0.1",
+    port: int = 0,
+    access: HttpAccessPolicy | None = None,
+) -> ThreadingHTTPServer:
+    """
+    Serve the evaluation API from a daemon thread.
+
+    Returns the running server (``server.server_address`` holds the bound port;
+    call ``server.shutdown()`` to stop accepting requests and ``server.server_close()``
+    to wait for in-flight ones).
+    """
+    server = ThreadingHTTPServer((host, port), evaluation_handler(service, access))
+    # ``server_close()`` then waits for in-flight requests.
+    server.daemon_threads = False
+    server.block_on_close = True
+    threading.Thread(target=server.serve_forever, name="normcore-http", daemon=True).start()
+    return server
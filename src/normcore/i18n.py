@@ -0,0 +1,427 @@
+"""
+Localized judgment messages.
+
+Judgments carry retry guidance (``feedback_hint``) and explanations that
+downstream agents may relay to users. The English strings are the message
+ids, gettext style; each catalog maps them to another language. Messages with
+variable parts are matched by template: ``{name}`` placeholders stand for the
+variable text, which is carried over (and itself translated when it is a
+known message, for nested explanations).
+
+Unknown messages are returned unchanged, so a catalog that lags behind the
+evaluator degrades to English rather than failing.
+"""
+
+from __future__ import annotations
+
+import functools
+import re
+
+DEFAULT_LOCALE = "en"
+
+# English message template -> localized template, per locale. Templates that wrap
+# another message come first so the outer message is matched before the inner one.
+_CATALOGS: dict[str, dict[str, str]] = {
+    "de": {
+        "{explanation} ({count} low-weight statement(s) discounted)": (
+            "{explanation} ({count} gering gewichtete Aussage(n) nicht berücksichtigt)"
+        ),
+        "Your response violates normative axioms: {axioms}. Please revise or refuse to "
+        "answer if you lack required context.": (
+            "Deine Antwort verletzt normative Axiome: {axioms}. Bitte überarbeite sie oder "
+            "lehne die Antwort ab, wenn dir der nötige Kontext fehlt."
+        ),
+        "Your response is structurally ill-formed. Please rephrase with clear "
+        "subject-predicate statements.": (
+            "Deine Antwort ist strukturell fehlerhaft. Bitte formuliere sie in klaren "
+            "Subjekt-Prädikat-Aussagen neu."
+        ),
+        "Your statements lack required grounding. Consider asking for more context or "
+        "using conditional phrasing.": (
+            "Deinen Aussagen fehlt die nötige Begründung. Frage nach mehr Kontext oder "
+            "formuliere bedingt."
+        ),
+        "Your assertive statements lack required citations. Cite the evidence each claim "
+        "relies on.": (
+            "Deinen Behauptungen fehlen die geforderten Belege. Zitiere die Evidenz, auf "
+            "der jede Aussage beruht."
+        ),
+        "You framed advice as fitting the user without knowing their preferences or "
+        "constraints. Ask the user about them, or give general advice.": (
+            "Du hast einen Rat als passend für die Person dargestellt, ohne ihre Vorlieben "
+            "oder Einschränkungen zu kennen. Frage danach oder gib einen allgemeinen Rat."
+        ),
+        "Violated axioms: {axioms}": "Verletzte Axiome: {axioms}",
+        "Structurally ill-formed statements detected": (
+            "Strukturell fehlerhafte Aussagen erkannt"
+        ),
+        "Validator has no jurisdiction to judge": "Der Validator ist nicht zuständig",
+        "Statements lack required grounding (A4)": (
+            "Aussagen fehlt die nötige Begründung (A4)"
+        ),
+        "Assertive statements below required citation density (A8)": (
+            "Behauptungen unter der geforderten Belegdichte (A8)"
+        ),
+        "All statements are conditionally acceptable": (
+            "Alle Aussagen sind bedingt zulässig"
+        ),
+        "Mix of conditional and acceptable statements": (
+            "Mischung aus bedingt zulässigen und zulässigen Aussagen"
+        ),
+        "All statements are normatively acceptable": "Alle Aussagen sind normativ zulässig",
+        "No content to validate": "Kein zu prüfender Inhalt",
+        "Protocol-only output (greetings/offers) - no normative claims to evaluate": (
+            "Nur Protokollsprache (Begrüßungen/Angebote) - keine normativen Aussagen zu prüfen"
+        ),
+        "No statements selected for evaluation (statement_ids filter)": (
+            "Keine Aussagen zur Bewertung ausgewählt (Filter statement_ids)"
+        ),
+        "Explicit refusal is always admissible (A6)": (
+            "Eine ausdrückliche Ablehnung ist immer zulässig (A6)"
+        ),
+        "Clarification request is admissible information-seeking (A6)": (
+            "Eine Rückfrage ist zulässige Informationssuche (A6)"
+        ),
+        "Reported claim attributed to '{source}' with factual grounding": (
+            "Wiedergegebene Behauptung von '{source}' mit faktischer Begründung"
+        ),
+        "Reported claim attributed to '{source}' with weak grounding": (
+            "Wiedergegebene Behauptung von '{source}' mit schwacher Begründung"
+        ),
+        "Reported claim attributed to '{source}' without grounding": (
+            "Wiedergegebene Behauptung von '{source}' ohne Begründung"
+        ),
+        "Epistemic inference grounded in factual knowledge": (
+            "Epistemische Schlussfolgerung auf Grundlage faktischen Wissens"
+        ),
+        "Epistemic inference without factual grounding": (
+            "Epistemische Schlussfolgerung ohne faktische Begründung"
+        ),
+        "Hedged claim within conditional license (uncertainty: {markers})": (
+            "Abgeschwächte Behauptung innerhalb der bedingten Lizenz (Unsicherheit: {markers})"
+        ),
+        "Hedged claim without grounding (uncertainty: {markers})": (
+            "Abgeschwächte Behauptung ohne Begründung (Unsicherheit: {markers})"
+        ),
+        "Assertive statement without sufficient grounding (categoricity ban)": (
+            "Behauptung ohne ausreichende Begründung (Kategorizitätsverbot)"
+        ),
+        "Commissive statement without tool capability to keep the commitment": (
+            "Zusage ohne Werkzeugzugriff, um sie einzuhalten"
+        ),
+        "Personalization-conditional statement without user context": (
+            "Personalisierte Aussage ohne Kontext zur Person"
+        ),
+        "Conditional form chosen by agent (ASSERTIVE also permitted by grounding)": (
+            "Bedingte Form vom Agenten gewählt (ASSERTIVE durch die Begründung ebenfalls erlaubt)"
+        ),
+        "Conditional statement with declared conditions: {conditions}": (
+            "Bedingte Aussage mit genannten Bedingungen: {conditions}"
+        ),
+        "Conditional statement without declared conditions": (
+            "Bedingte Aussage ohne genannte Bedingungen"
+        ),
+        "Normative claim without grounding": "Normative Behauptung ohne Begründung",
+        "Descriptive statement grounded in factual knowledge": (
+            "Beschreibende Aussage auf Grundlage faktischen Wissens"
+        ),
+        "Descriptive statement resting on weak factual grounding": (
+            "Beschreibende Aussage mit schwacher faktischer Begründung"
+        ),
+        "Descriptive statement without factual grounding": (
+            "Beschreibende Aussage ohne faktische Begründung"
+        ),
+        "Statement modality ({modality}) permitted by license": (
+            "Modalität der Aussage ({modality}) durch die Lizenz erlaubt"
+        ),
+        "Cannot determine status (modality={modality}, license={license})": (
+            "Status nicht bestimmbar (modality={modality}, license={license})"
+        ),
+        "Ill-formed statement ({issues})": "Fehlerhafte Aussage ({issues})",
+        "Assertive statement cites {citations} ground(s); policy requires {required}": (
+            "Behauptung zitiert {citations} Beleg(e); die Richtlinie verlangt {required}"
+        ),
+    },
+    "es": {
+        "{explanation} ({count} low-weight statement(s) discounted)": (
+            "{explanation} ({count} afirmación(es) de poco peso descartada(s))"
+        ),
+        "Your response violates normative axioms: {axioms}. Please revise or refuse to "
+        "answer if you lack required context.": (
+            "Tu respuesta viola axiomas normativos: {axioms}. Revísala o declina responder "
+            "si te falta el contexto necesario."
+        ),
+        "Your response is structurally ill-formed. Please rephrase with clear "
+        "subject-predicate statements.": (
+            "Tu respuesta está mal formada. Reformúlala con afirmaciones claras de sujeto "
+            "y predicado."
+        ),
+        "Your statements lack required grounding. Consider asking for more context or "
+        "using conditional phrasing.": (
+            "Tus afirmaciones carecen del fundamento necesario. Pide más contexto o usa "
+            "una formulación condicional."
+        ),
+        "Your assertive statements lack required citations. Cite the evidence each claim "
+        "relies on.": (
+            "Tus afirmaciones carecen de las citas requeridas. Cita la evidencia en la que "
+            "se apoya cada afirmación."
+        ),
+        "You framed advice as fitting the user without knowing their preferences or "
+        "constraints. Ask the user about them, or give general advice.": (
+            "Presentaste un consejo como adecuado para la persona sin conocer sus "
+            "preferencias o restricciones. Pregúntale por ellas o da un consejo general."
+        ),
+        "Violated axioms: {axioms}": "Axiomas violados: {axioms}",
+        "Structurally ill-formed statements detected": (
+            "Se detectaron afirmaciones mal formadas"
+        ),
+        "Validator has no jurisdiction to judge": "El validador no tiene competencia para juzgar",
+        "Statements lack required grounding (A4)": (
+            "Las afirmaciones carecen del fundamento necesario (A4)"
+        ),
+        "Assertive statements below required citation density (A8)": (
+            "Afirmaciones por debajo de la densidad de citas requerida (A8)"
+        ),
+        "All statements are conditionally acceptable": (
+            "Todas las afirmaciones son condicionalmente aceptables"
+        ),
+        "Mix of conditional and acceptable statements": (
+            "Mezcla de afirmaciones condicionales y aceptables"
+        ),
+        "All statements are normatively acceptable": (
+            "Todas las afirmaciones son normativamente aceptables"
+        ),
+        "No content to validate": "No hay contenido que validar",
+        "Protocol-only output (greetings/offers) - no normative claims to evaluate": (
+            "Solo lenguaje de protocolo (saludos/ofertas) - no hay afirmaciones normativas "
+            "que evaluar"
+        ),
+        "No statements selected for evaluation (statement_ids filter)": (
+            "Ninguna afirmación seleccionada para evaluar (filtro statement_ids)"
+        ),
+        "Explicit refusal is always admissible (A6)": (
+            "Un rechazo explícito siempre es admisible (A6)"
+        ),
+        "Clarification request is admissible information-seeking (A6)": (
+            "Una petición de aclaración es una búsqueda de información admisible (A6)"
+        ),
+        "Reported claim attributed to '{source}' with factual grounding": (
+            "Afirmación atribuida a '{source}' con fundamento fáctico"
+        ),
+        "Reported claim attributed to '{source}' with weak grounding": (
+            "Afirmación atribuida a '{source}' con fundamento débil"
+        ),
+        "Reported claim attributed to '{source}' without grounding": (
+            "Afirmación atribuida a '{source}' sin fundamento"
+        ),
+        "Epistemic inference grounded in factual knowledge": (
+            "Inferencia epistémica basada en conocimiento fáctico"
+        ),
+        "Epistemic inference without factual grounding": (
+            "Inferencia epistémica sin fundamento fáctico"
+        ),
+        "Hedged claim within conditional license (uncertainty: {markers})": (
+            "Afirmación matizada dentro de la licencia condicional (incertidumbre: {markers})"
+        ),
+        "Hedged claim without grounding (uncertainty: {markers})": (
+            "Afirmación matizada sin fundamento (incertidumbre: {markers})"
+        ),
+        "Assertive statement without sufficient grounding (categoricity ban)": (
+            "Afirmación sin fundamento suficiente (prohibición de categoricidad)"
+        ),
+        "Commissive statement without tool capability to keep the commitment": (
+            "Compromiso sin herramientas para cumplirlo"
+        ),
+        "Personalization-conditional statement without user context": (
+            "Afirmación personalizada sin contexto de la persona"
+        ),
+        "Conditional form chosen by agent (ASSERTIVE also permitted by grounding)": (
+            "Forma condicional elegida por el agente (el fundamento también permite ASSERTIVE)"
+        ),
+        "Conditional statement with declared conditions: {conditions}": (
+            "Afirmación condicional con condiciones declaradas: {conditions}"
+        ),
+        "Conditional statement without declared conditions": (
+            "Afirmación condicional sin condiciones declaradas"
+        ),
+        "Normative claim without grounding": "Afirmación normativa sin fundamento",
+        "Descriptive statement grounded in factual knowledge": (
+            "Afirmación descriptiva basada en conocimiento fáctico"
+        ),
+        "Descriptive statement resting on weak factual grounding": (
+            "Afirmación descriptiva con fundamento fáctico débil"
+        ),
+        "Descriptive statement without factual grounding": (
+            "Afirmación descriptiva sin fundamento fáctico"
+        ),
+        "Statement modality ({modality}) permitted by license": (
+            "Modalidad de la afirmación ({modality}) permitida por la licencia"
+        ),
+        "Cannot determine status (modality={modality}, license={license})": (
+            "No se puede determinar el estado (modality={modality}, license={license})"
+        ),
+        "Ill-formed statement ({issues})": "Afirmación mal formada ({issues})",
+        "Assertive statement cites {citations} ground(s); policy requires {required}": (
+            "La afirmación cita {citations} fundamento(s); la política exige {required}"
+        ),
+    },
+    "fr": {
+        "{explanation} ({count} low-weight statement(s) discounted)": (
+            "{explanation} ({count} énoncé(s) de faible poids non pris en compte)"
+        ),
+        "Your response violates normative axioms: {axioms}. Please revise or refuse to "
+        "answer if you lack required context.": (
+            "Votre réponse enfreint des axiomes normatifs : {axioms}. Révisez-la ou "
+            "refusez de répondre s'il vous manque le contexte nécessaire."
+        ),
+        "Your response is structurally ill-formed. Please rephrase with clear "
+        "subject-predicate statements.": (
+            "Votre réponse est mal formée. Reformulez-la en énoncés clairs de type "
+            "sujet-prédicat."
+        ),
+        "Your statements lack required grounding. Consider asking for more context or "
+        "using conditional phrasing.": (
+            "Vos énoncés manquent du fondement requis. Demandez plus de contexte ou "
+            "employez une formulation conditionnelle."
+        ),
+        "Your assertive statements lack required citations. Cite the evidence each claim "
+        "relies on.": (
+            "Vos affirmations manquent des citations requises. Citez la preuve sur laquelle "
+            "repose chaque affirmation."
+        ),
+        "You framed advice as fitting the user without knowing their preferences or "
+        "constraints. Ask the user about them, or give general advice.": (
+            "Vous avez présenté un conseil comme adapté à la personne sans connaître ses "
+            "préférences ou contraintes. Interrogez-la à ce sujet ou donnez un conseil "
+            "général."
+        ),
+        "Violated axioms: {axioms}": "Axiomes enfreints : {axioms}",
+        "Structurally ill-formed statements detected": "Énoncés mal formés détectés",
+        "Validator has no jurisdiction to judge": "Le validateur n'est pas compétent pour juger",
+        "Statements lack required grounding (A4)": (
+            "Les énoncés manquent du fondement requis (A4)"
+        ),
+        "Assertive statements below required citation density (A8)": (
+            "Affirmations sous la densité de citations requise (A8)"
+        ),
+        "All statements are conditionally acceptable": (
+            "Tous les énoncés sont conditionnellement acceptables"
+        ),
+        "Mix of conditional and acceptable statements": (
+            "Mélange d'énoncés conditionnels et acceptables"
+        ),
+        "All statements are normatively acceptable": (
+            "Tous les énoncés sont normativement acceptables"
+        ),
+        "No content to validate": "Aucun contenu à valider",
+        "Protocol-only output (greetings/offers) - no normative claims to evaluate": (
+            "Uniquement du langage protocolaire (salutations/offres) - aucun énoncé "
+            "normatif à évaluer"
+        ),
+        "No statements selected for evaluation (statement_ids filter)": (
+            "Aucun énoncé sélectionné pour l'évaluation (filtre statement_ids)"
+        ),
+        "Explicit refusal is always admissible (A6)": (
+            "Un refus explicite est toujours admissible (A6)"
+        ),
+        "Clarification request is admissible information-seeking (A6)": (
+            "Une demande de précision est une recherche d'information admissible (A6)"
+        ),
+        "Reported claim attributed to '{source}' with factual grounding": (
+            "Affirmation rapportée de '{source}' avec fondement factuel"
+        ),
+        "Reported claim attributed to '{source}' with weak grounding": (
+            "Affirmation rapportée de '{source}' avec fondement faible"
+        ),
+        "Reported claim attributed to '{source}' without grounding": (
+            "Affirmation rapportée de '{source}' sans fondement"
+        ),
+        "Epistemic inference grounded in factual knowledge": (
+            "Inférence épistémique fondée sur des connaissances factuelles"
+        ),
+        "Epistemic inference without factual grounding": (
+            "Inférence épistémique sans fondement factuel"
+        ),
+        "Hedged claim within conditional license (uncertainty: {markers})": (
+            "Affirmation nuancée dans la licence conditionnelle (incertitude : {markers})"
+        ),
+        "Hedged claim without grounding (uncertainty: {markers})": (
+            "Affirmation nuancée sans fondement (incertitude : {markers})"
+        ),
+        "Assertive statement without sufficient grounding (categoricity ban)": (
+            "Affirmation sans fondement suffisant (interdiction de catégoricité)"
+        ),
+        "Commissive statement without tool capability to keep the commitment": (
+            "Engagement sans outil permettant de le tenir"
+        ),
+        "Personalization-conditional statement without user context": (
+            "Énoncé personnalisé sans contexte sur la personne"
+        ),
+        "Conditional form chosen by agent (ASSERTIVE also permitted by grounding)": (
+            "Forme conditionnelle choisie par l'agent (le fondement permet aussi ASSERTIVE)"
+        ),
+        "Conditional statement with declared conditions: {conditions}": (
+            "Énoncé conditionnel avec conditions déclarées : {conditions}"
+        ),
+        "Conditional statement without declared conditions": (
+            "Énoncé conditionnel sans conditions déclarées"
+        ),
+        "Normative claim without grounding": "Affirmation normative sans fondement",
+        "Descriptive statement grounded in factual knowledge": (
+            "Énoncé descriptif fondé sur des connaissances factuelles"
+        ),
+        "Descriptive statement resting on weak factual grounding": (
+            "Énoncé descriptif reposant sur un fondement factuel faible"
+        ),
+        "Descriptive statement without factual grounding": (
+            "Énoncé descriptif sans fondement factuel"
+        ),
+        "Statement modality ({modality}) permitted by license": (
+            "Modalité de l'énoncé ({modality}) permise par la licence"
+        ),
+        "Cannot determine status (modality={modality}, license={license})": (
+            "Statut indéterminable (modality={modality}, license={license})"
+        ),
+        "Ill-formed statement ({issues})": "Énoncé mal formé ({issues})",
+        "Assertive statement cites {citations} ground(s); policy requires {required}": (
+            "L'affirmation cite {citations} fondement(s) ; la politique en exige {required}"
+        ),
+    },
+}
+
+LOCALES: tuple[str, ...] = (DEFAULT_LOCALE, *sorted(_CATALOGS))
+
+_PLACEHOLDER = re.compile(r"\{(\w+)\}")
+
+
+@functools.cache
+def _templates(locale: str) -> tuple[tuple[re.Pattern[str], str], ...]:
+    """Compiled (English pattern, localized template) pairs of ``locale``, in catalog order."""
+    compiled = []
+    for source, target in _CATALOGS[locale].items():
+        pattern = "".join(
+            f"(?P<{part}>.+?)" if index % 2 else re.escape(part)
+            for index, part in enumerate(_PLACEHOLDER.split(source))
+        )
+        compiled.append((re.compile(pattern, re.DOTALL), target))
+    return tuple(compiled)
+
+
+def translate(message: str, locale: str = DEFAULT_LOCALE) -> str:
+    """
+    Return ``message`` in ``locale`` (unchanged for English and unknown messages).
+
+    Raises:
+        ValueError: ``locale`` is not one of ``LOCALES``
+    """
+    if locale not in LOCALES:
+        raise ValueError(f"Unsupported locale '{locale}' (choose from {', '.join(LOCALES)})")
+    if locale == DEFAULT_LOCALE:
+        return message
+    for pattern, target in _templates(locale):
+        match = pattern.fullmatch(message)
+        if match is not None:
+            return target.format_map(
+                {name: translate(value, locale) for name, value in match.groupdict().items()}
+            )
+    return message
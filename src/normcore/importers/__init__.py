@@ -0,0 +1,25 @@
+"""Importers that convert external transcript formats into evaluate payloads."""
+
+from .agent_transcript import (
+    conversation_from_agent_transcript,
+    load_agent_transcript,
+    payload_from_agent_transcript,
+)
+from .chatgpt import conversation_from_chatgpt, payloads_from_chatgpt_export
+from .openai_batch import load_batch_requests, payload_from_batch_output
+from .openai_logs import payload_from_request_log
+from .openai_evals import EvalsRecorder, payload_from_evals_record, payloads_from_evals_jsonl
+
+__all__ = [
+    "EvalsRecorder",
+    "conversation_from_agent_transcript",
+    "conversation_from_chatgpt",
+    "load_agent_transcript",
+    "load_batch_requests",
+    "payload_from_agent_transcript",
+    "payload_from_batch_output",
+    "payload_from_evals_record",
+    "payload_from_request_log",
+    "payloads_from_chatgpt_export",
+    "payloads_from_evals_jsonl",
+]
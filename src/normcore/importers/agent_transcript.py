@@ -0,0 +1,171 @@
+"""
+Agent transcript importer (content-block message logs).
+
+Agentic coding tools log sessions as messages whose ``content`` is a list of
+typed blocks instead of a string:
+
+- assistant ``text`` blocks: the user-facing answer
+- assistant ``tool_use`` blocks (``id``, ``name``, ``input``): tool invocations
+- user ``tool_result`` blocks (``tool_use_id``, ``content``): tool outputs
+- ``thinking`` / ``redacted_thinking`` blocks: model reasoning (skipped)
+
+The importer reconstructs the Chat Completions trajectory the evaluator reads:
+``tool_use`` blocks become assistant ``tool_calls`` and each ``tool_result``
+becomes a ``tool`` message, so claims about the codebase in the final answer are
+judged against what the tools actually returned.
+
+Accepted file shapes:
+
+- a JSON array of messages, or an object with a ``messages`` array
+- JSONL with one message per line, or one envelope per line carrying the message
+  under ``message`` (non-message envelope lines are ignored)
+"""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Iterable, Mapping
+from pathlib import Path
+from typing import Any
+
+_MESSAGE_ROLES = frozenset({"user", "assistant"})
+
+
+def _block_text(content: Any) -> str:
+    """Flatten string or text-block content into plain text."""
+    if isinstance(content, str):
+        return content
+    if isinstance(content, list):
+        return "\n".join(
+            block.get("text", "")
+            for block in content
+            if isinstance(block, Mapping) and block.get("type") == "text"
+        )
+    return ""
+
+
+def _blocks(content: Any) -> list[Mapping[str, Any]]:
+    if isinstance(content, str):
+        return [{"type": "text", "text": content}]
+    if isinstance(content, list):
+        return [block for block in content if isinstance(block, Mapping)]
+    return []
+
+
+def _unwrap(entry: Any) -> Mapping[str, Any] | None:
+    """Return the message in a log entry (bare message or envelope), if any."""
+    if not isinstance(entry, Mapping):
+        return None
+    message = entry.get("message", entry)
+    if isinstance(message, Mapping) and message.get("role") in _MESSAGE_ROLES:
+        return message
+    return None
+
+
+def conversation_from_agent_transcript(
+    entries: Iterable[Mapping[str, Any]],
+) -> list[dict[str, Any]]:
+    """Convert content-block transcript messages into Chat Completions messages."""
+    trajectory: list[dict[str, Any]] = []
+
+    for entry in entries:
+        message = _unwrap(entry)
+        if message is None:
+            continue
+        blocks = _blocks(message.get("content"))
+
+        if message["role"] == "assistant":
+            texts = [b.get("text", "") for b in blocks if b.get("type") == "text"]
+            tool_calls = [
+                {
+                    "id": str(b.get("id")),
+                    "type": "function",
+                    "function": {
+                        "name": str(b.get("name")),
+                        "arguments": json.dumps(b.get("input") or {}, ensure_ascii=False),
+                    },
+                }
+                for b in blocks
+                if b.get("type") == "tool_use"
+            ]
+            text = "\n".join(t for t in texts if t)
+            if not text and not tool_calls:
+                continue
+            previous = trajectory[-1] if trajectory else None
+            if previous is not None and previous["role"] == "assistant":
+                # Logs often record one assistant turn as several block-sized entries.
+                if text:
+                    previous["content"] = "\n".join(
+                        part for part in (previous.get("content"), text) if part
+                    )
+                if tool_calls:
+                    previous.setdefault("tool_calls", []).extend(tool_calls)
+                continue
+            assistant: dict[str, Any] = {"role": "assistant", "content": text or None}
+            if tool_calls:
+                assistant["tool_calls"] = tool_calls
+            trajectory.append(assistant)
+            continue
+
+        for block in blocks:
+            if block.get("type") == "tool_result":
+                trajectory.append(
+                    {
+                        "role": "tool",
+                        "tool_call_id": str(block.get("tool_use_id")),
+                        "content": _block_text(block.get("content")),
+                    }
+                )
+        user_text = "\n".join(
+            b.get("text", "") for b in blocks if b.get("type") == "text" and b.get("text")
+        )
+        if user_text:
+            trajectory.append({"role": "user", "content": user_text})
+    return trajectory
+
+
+def load_agent_transcript(text: str) -> list[Any]:
+    """Decode a transcript file body (JSON document or JSONL) into entries."""
+    stripped = text.strip()
+    if not stripped:
+        return []
+    try:
+        document = json.loads(stripped)
+    except json.JSONDecodeError:
+        document = None
+    if isinstance(document, list):
+        return document
+    if isinstance(document, Mapping) and isinstance(document.get("messages"), list):
+        return list(document["messages"])
+
+    entries = []
+    for line_number, raw in enumerate(stripped.splitlines(), 1):
+        if not raw.strip():
+            continue
+        try:
+            entries.append(json.loads(raw))
+        except json.JSONDecodeError as exc:
+            raise ValueError(f"Invalid transcript line {line_number}: {exc}") from exc
+    return entries
+
+
+def payload_from_agent_transcript(path: str | Path) -> dict[str, Any]:
+    """
+    Read one transcript file into an evaluate payload.
+
+    The payload ``id`` is the session id recorded in the log, or the file stem.
+    """
+    path = Path(path)
+    entries = load_agent_transcript(path.read_text(encoding="utf-8"))
+    session_id = next(
+        (
+            entry.get("sessionId") or entry.get("session_id")
+            for entry in entries
+            if isinstance(entry, Mapping) and (entry.get("sessionId") or entry.get("session_id"))
+        ),
+        None,
+    )
+    return {
+        "id": session_id or path.stem,
+        "conversation": conversation_from_agent_transcript(entries),
+    }
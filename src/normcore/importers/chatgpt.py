@@ -0,0 +1,123 @@
+"""
+ChatGPT data export importer.
+
+``conversations.json`` from a ChatGPT export is a list of conversations, each
+storing its messages as a tree (``mapping``: node id -> node with ``parent``,
+``children`` and ``message``). Regenerated answers and edited prompts create
+sibling branches; ``current_node`` marks the leaf of the branch the user last saw.
+
+The importer walks ``current_node`` back to the root and emits that branch as a
+linear Chat Completions trajectory:
+
+- user / assistant text -> ``{"role": ..., "content": text}``
+- assistant messages addressed to a tool (``recipient`` != "all") ->
+  assistant ``tool_calls`` entries
+- tool outputs (author role ``tool``) -> ``{"role": "tool", "tool_call_id": ...}``
+
+System prompts, hidden messages and model reasoning are skipped.
+"""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Iterator, Mapping
+from typing import Any
+
+# Content types that carry model reasoning, not public speech.
+_REASONING_CONTENT_TYPES = frozenset({"thoughts", "reasoning_recap"})
+
+
+def _content_text(content: Mapping[str, Any]) -> str:
+    """Flatten export content into plain text (non-text parts are dropped)."""
+    parts = content.get("parts")
+    if isinstance(parts, list):
+        return "\n".join(part for part in parts if isinstance(part, str) and part)
+    for key in ("text", "result"):
+        value = content.get(key)
+        if isinstance(value, str):
+            return value
+    return ""
+
+
+def _branch(conversation: Mapping[str, Any]) -> list[Mapping[str, Any]]:
+    """Return messages on the ``current_node`` branch, root first."""
+    mapping = conversation.get("mapping")
+    if not isinstance(mapping, Mapping):
+        raise ValueError("ChatGPT conversation has no 'mapping' tree")
+    node_id = conversation.get("current_node")
+    if node_id is None:
+        # Fall back to the last leaf in export order.
+        leaves = [key for key, node in mapping.items() if not node.get("children")]
+        node_id = leaves[-1] if leaves else None
+
+    messages: list[Mapping[str, Any]] = []
+    seen: set[str] = set()
+    while node_id is not None and node_id in mapping and node_id not in seen:
+        seen.add(node_id)
+        node = mapping[node_id]
+        if isinstance(node.get("message"), Mapping):
+            messages.append(node["message"])
+        node_id = node.get("parent")
+    return list(reversed(messages))
+
+
+def conversation_from_chatgpt(conversation: Mapping[str, Any]) -> list[dict[str, Any]]:
+    """Linearize one exported ChatGPT conversation into Chat Completions messages."""
+    trajectory: list[dict[str, Any]] = []
+    pending_call_id: str | None = None
+
+    for message in _branch(conversation):
+        metadata = message.get("metadata") or {}
+        if metadata.get("is_visually_hidden_from_conversation"):
+            continue
+        role = (message.get("author") or {}).get("role")
+        content = message.get("content") or {}
+        if content.get("content_type") in _REASONING_CONTENT_TYPES:
+            continue
+        text = _content_text(content)
+        recipient = message.get("recipient") or "all"
+
+        if role == "user" and text:
+            trajectory.append({"role": "user", "content": text})
+        elif role == "assistant" and recipient != "all":
+            pending_call_id = str(message.get("id") or f"call_{len(trajectory)}")
+            trajectory.append(
+                {
+                    "role": "assistant",
+                    "content": None,
+                    "tool_calls": [
+                        {
+                            "id": pending_call_id,
+                            "type": "function",
+                            "function": {
+                                "name": recipient,
+                                "arguments": json.dumps({"input": text}, ensure_ascii=False),
+                            },
+                        }
+                    ],
+                }
+            )
+        elif role == "assistant" and text:
+            trajectory.append({"role": "assistant", "content": text})
+        elif role == "tool" and pending_call_id is not None:
+            trajectory.append({"role": "tool", "tool_call_id": pending_call_id, "content": text})
+            pending_call_id = None
+    return trajectory
+
+
+def payloads_from_chatgpt_export(export: Any) -> Iterator[dict[str, Any]]:
+    """
+    Yield one evaluate payload per exported conversation.
+
+    The payload ``id`` is the export's conversation id (title as fallback).
+    """
+    conversations = export if isinstance(export, list) else [export]
+    for conversation in conversations:
+        if not isinstance(conversation, Mapping):
+            raise ValueError("ChatGPT export must be a list of conversation objects")
+        payload_id = (
+            conversation.get("conversation_id")
+            or conversation.get("id")
+            or conversation.get("title")
+        )
+        yield {"id": payload_id, "conversation": conversation_from_chatgpt(conversation)}
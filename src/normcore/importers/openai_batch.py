@@ -0,0 +1,105 @@
+"""
+OpenAI Batch API importer.
+
+Batch output files are JSONL with one request/response pair per line::
+
+    {"id": "batch_req_...", "custom_id": "req-1",
+     "response": {"status_code": 200, "body": {<chat.completion>}},
+     "error": null}
+
+Each successful line becomes one evaluate payload: the assistant message of the
+first choice, preceded by the request messages (user turns, tool calls and tool
+results) when the matching batch *input* file is supplied. ``custom_id`` is used
+as the payload ``id`` so judgments can be joined back to the batch.
+"""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Iterable, Mapping
+from typing import Any
+
+# Assistant message keys understood by the evaluator (Chat Completions param shape).
+_ASSISTANT_KEYS = ("content", "refusal", "tool_calls", "annotations")
+
+
+def load_batch_requests(lines: Iterable[str]) -> dict[str, list[dict[str, Any]]]:
+    """
+    Index a batch input file by ``custom_id``.
+
+    Returns:
+        custom_id -> request ``messages`` (Chat Completions requests only)
+    """
+    requests: dict[str, list[dict[str, Any]]] = {}
+    for line_number, raw in enumerate(lines, 1):
+        if not raw.strip():
+            continue
+        try:
+            record = json.loads(raw)
+        except json.JSONDecodeError as exc:
+            raise ValueError(f"Invalid batch request line {line_number}: {exc}") from exc
+        if not isinstance(record, dict):
+            raise ValueError(f"Batch request line {line_number} must be a JSON object")
+        custom_id = record.get("custom_id")
+        body = record.get("body")
+        messages = body.get("messages") if isinstance(body, dict) else None
+        if isinstance(custom_id, str) and isinstance(messages, list):
+            requests[custom_id] = messages
+    return requests
+
+
+def assistant_from_completion(body: Any, label: str) -> dict[str, Any]:
+    """
+    Return the first choice's assistant message of a ``chat.completion`` object.
+
+    Raises:
+        ValueError: ``body`` (named ``label`` in the message) has no assistant message
+    """
+    choices = body.get("choices") if isinstance(body, Mapping) else None
+    if not isinstance(choices, list) or not choices:
+        raise ValueError(f"{label} is not a chat completion (no choices in body)")
+    message = choices[0].get("message") if isinstance(choices[0], Mapping) else None
+    if not isinstance(message, Mapping):
+        raise ValueError(f"{label} has no assistant message")
+
+    assistant: dict[str, Any] = {"role": "assistant"}
+    for key in _ASSISTANT_KEYS:
+        if message.get(key) not in (None, []):
+            assistant[key] = message[key]
+    return assistant
+
+
+def payload_from_batch_output(
+    record: Mapping[str, Any],
+    requests: Mapping[str, list[dict[str, Any]]] | None = None,
+) -> dict[str, Any]:
+    """
+    Convert one batch output record into an evaluate payload.
+
+    Args:
+        record: Decoded batch output line
+        requests: Optional ``load_batch_requests`` index supplying tool context
+
+    Returns:
+        Payload with ``id`` (custom_id) and ``conversation``
+
+    Raises:
+        ValueError: If the line records a failed request or is not a chat completion
+    """
+    custom_id = record.get("custom_id")
+    if record.get("error"):
+        raise ValueError(f"Batch request {custom_id!r} failed: {record['error']}")
+
+    response = record.get("response")
+    if not isinstance(response, Mapping):
+        raise ValueError(f"Batch record {custom_id!r} has no response")
+    status_code = response.get("status_code")
+    if status_code != 200:
+        raise ValueError(f"Batch request {custom_id!r} returned HTTP {status_code}")
+
+    assistant = assistant_from_completion(response.get("body"), f"Batch response {custom_id!r}")
+    context = list(requests.get(custom_id, [])) if requests and custom_id is not None else []
+    payload: dict[str, Any] = {"conversation": [*context, assistant]}
+    if custom_id is not None:
+        payload["id"] = custom_id
+    return payload
@@ -0,0 +1,176 @@
+"""
+OpenAI evals compatibility (``openai/evals`` JSONL formats).
+
+Input: each line is either
+
+- a sample (``{"input": [messages] | "prompt", "ideal": ..., "completion": "..."}``)
+  whose completion is under ``completion``, ``output`` or ``sampled``, or
+- a recorder ``sampling`` event
+  (``{"type": "sampling", "sample_id": ..., "data": {"prompt": ..., "sampled": [...]}}``).
+
+The prompt messages become conversation context and the completion the final
+assistant message. Lines without a completion (plain samples, non-sampling
+events) are skipped.
+
+Output: judgments rendered as an evals recorder log (``spec`` line, one
+``metrics`` event per sample, then ``final_report``), so normcore can run as a
+``graded_by_normcore`` eval and its results can be read by evals reporting tools.
+"""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Iterable, Iterator, Mapping
+from datetime import datetime, timezone
+from typing import Any
+
+from ..logging import logger
+from ..models import AdmissibilityJudgment, AdmissibilityStatus
+
+EVAL_NAME = "graded_by_normcore"
+# Choice recorded for samples that could not be graded (evals' modelgraded convention).
+INVALID_CHOICE = "__invalid__"
+
+# Statuses that count as a passing sample (score 1.0) in evals reports.
+PASSING_STATUSES = frozenset(
+    {
+        AdmissibilityStatus.ACCEPTABLE,
+        AdmissibilityStatus.CONDITIONALLY_ACCEPTABLE,
+        AdmissibilityStatus.NO_NORMATIVE_CONTENT,
+    }
+)
+
+_COMPLETION_FIELDS = ("completion", "output", "sampled")
+
+
+def _prompt_messages(prompt: Any) -> list[dict[str, Any]]:
+    if isinstance(prompt, str):
+        return [{"role": "user", "content": prompt}]
+    if isinstance(prompt, list):
+        return [dict(message) for message in prompt if isinstance(message, Mapping)]
+    return []
+
+
+def _completion_text(value: Any) -> str | None:
+    if isinstance(value, list):
+        value = value[0] if value else None
+    return value if isinstance(value, str) else None
+
+
+def payload_from_evals_record(record: Mapping[str, Any], index: int) -> dict[str, Any] | None:
+    """
+    Convert one evals sample or recorder event into an evaluate payload.
+
+    Returns:
+        Payload (``id`` = sample_id, or ``sample.<index>``), or None when the
+        line carries no completion to grade.
+    """
+    if record.get("type") == "sampling":
+        data = record.get("data") or {}
+        prompt = data.get("prompt")
+        completion = _completion_text(data.get("sampled"))
+    else:
+        prompt = record.get("input")
+        completion = next(
+            (
+                text
+                for text in (_completion_text(record.get(f)) for f in _COMPLETION_FIELDS)
+                if text is not None
+            ),
+            None,
+        )
+    if completion is None:
+        return None
+    return {
+        "id": record.get("sample_id") or f"sample.{index}",
+        "conversation": [
+            *_prompt_messages(prompt),
+            {"role": "assistant", "content": completion},
+        ],
+    }
+
+
+def payloads_from_evals_jsonl(lines: Iterable[str]) -> Iterator[dict[str, Any]]:
+    """Yield payloads for every gradable line of an evals JSONL file."""
+    for index, raw in enumerate(lines):
+        if not raw.strip():
+            continue
+        try:
+            record = json.loads(raw)
+        except json.JSONDecodeError as exc:
+            raise ValueError(f"Invalid evals line {index + 1}: {exc}") from exc
+        payload = (
+            payload_from_evals_record(record, index) if isinstance(record, Mapping) else None
+        )
+        if payload is None:
+            logger.debug(f"openai-evals: line {index + 1} has no completion, skipped")
+            continue
+        yield payload
+
+
+class EvalsRecorder:
+    """
+    Render judgments as an evals recorder log (JSONL records).
+
+    Call ``spec()`` once, ``record()`` per judgment, then ``final_report()``.
+    """
+
+    def __init__(self, run_id: str | None = None) -> None:
+        """Initialize recorder with an evals-style run id (timestamp-based by default)."""
+        now = datetime.now(timezone.utc)
+        self.run_id = run_id or now.strftime("%y%m%d%H%M%S") + "NORMCORE"
+        self._event_id = 0
+        self._counts: dict[str, int] = {}
+        self._passed = 0
+
+    def spec(self) -> dict[str, Any]:
+        """Return the leading ``spec`` record."""
+        return {
+            "spec": {
+                "eval_name": EVAL_NAME,
+                "base_eval": EVAL_NAME,
+                "split": "normcore",
+                "run_id": self.run_id,
+                "created_at": datetime.now(timezone.utc).isoformat(),
+            }
+        }
+
+    def record(self, sample_id: Any, judgment: AdmissibilityJudgment) -> dict[str, Any]:
+        """Return a ``metrics`` event for one graded sample."""
+        passed = judgment.status in PASSING_STATUSES
+        self._passed += int(passed)
+        return self._metrics_event(
+            sample_id,
+            judgment.status.value,
+            {
+                "score": 1.0 if passed else 0.0,
+                "violated_axioms": judgment.violated_axioms,
+                "explanation": judgment.explanation,
+            },
+        )
+
+    def record_error(self, sample_id: Any, message: str) -> dict[str, Any]:
+        """Return a ``metrics`` event for a sample that could not be graded."""
+        return self._metrics_event(sample_id, INVALID_CHOICE, {"score": 0.0, "error": message})
+
+    def _metrics_event(self, sample_id: Any, choice: str, data: dict[str, Any]) -> dict[str, Any]:
+        self._counts[choice] = self._counts.get(choice, 0) + 1
+        event = {
+            "run_id": self.run_id,
+            "event_id": self._event_id,
+            "sample_id": sample_id,
+            "type": "metrics",
+            "data": {"choice": choice, **data},
+            "created_by": "normcore",
+            "created_at": datetime.now(timezone.utc).isoformat(),
+        }
+        self._event_id += 1
+        return event
+
+    def final_report(self) -> dict[str, Any]:
+        """Return the trailing ``final_report`` record (mean score and choice counts)."""
+        total = sum(self._counts.values())
+        report: dict[str, Any] = {"score": self._passed / total if total else 0.0}
+        for status, count in sorted(self._counts.items()):
+            report[f"counts/{status}"] = count
+        return {"final_report": report}
@@ -0,0 +1,59 @@
+"""
+OpenAI request log importer.
+
+Request logs (API gateway, proxy or SDK hook exports) record each Chat
+Completions call as one JSONL line with the request body and its response::
+
+    {"id": "turn-7", "request": {"model": "...", "messages": [...]},
+     "response": {<chat.completion>}}
+
+The response may also be wrapped as ``{"status_code": 200, "body": {...}}``
+(the Batch API shape). Chat Completions is stateless, so every request carries
+the conversation so far: user turns, earlier assistant tool calls and their
+tool results. Each line therefore replays as one evaluate payload, the request
+messages followed by the first choice's assistant message, and every assistant
+turn of an agent loop is judged with exactly the context the model saw. The
+payload ``id`` is the line's ``id`` (or ``custom_id``), else the completion id.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Mapping
+from typing import Any
+
+from .openai_batch import assistant_from_completion
+
+
+def payload_from_request_log(record: Mapping[str, Any]) -> dict[str, Any]:
+    """
+    Convert one request log record into an evaluate payload.
+
+    Returns:
+        Payload with ``conversation`` and, when known, ``id``
+
+    Raises:
+        ValueError: the record has no request messages, or the call failed or
+            did not return a chat completion
+    """
+    request = record.get("request")
+    messages = request.get("messages") if isinstance(request, Mapping) else None
+    if not isinstance(messages, list):
+        raise ValueError("Request log record has no request.messages list")
+
+    response = record.get("response")
+    if record.get("error"):
+        raise ValueError(f"Logged request failed: {record['error']}")
+    if isinstance(response, Mapping) and "status_code" in response:
+        if response["status_code"] != 200:
+            raise ValueError(f"Logged request returned HTTP {response['status_code']}")
+        response = response.get("body")
+    if not isinstance(response, Mapping):
+        raise ValueError("Request log record has no response object")
+
+    payload_id = record.get("id", record.get("custom_id", response.get("id")))
+    label = "Logged response" if payload_id is None else f"Logged response {payload_id!r}"
+    assistant = assistant_from_completion(response, label)
+    payload: dict[str, Any] = {"conversation": [*messages, assistant]}
+    if payload_id is not None:
+        payload["id"] = payload_id
+    return payload
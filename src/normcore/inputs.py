@@ -0,0 +1,121 @@
+"""
+Structured CLI inputs: inline JSON or files.
+
+Options that take structured data (conversation, grounds, links, knowledge
+nodes, policy) accept either inline JSON or ``@PATH`` to read the value from
+a file. The file format follows the suffix:
+
+- ``.yaml`` / ``.yml``: YAML (requires the optional ``yaml`` extra,
+  ``pip install 'normcore[yaml]'``)
+- ``.toml``: TOML
+- anything else: JSON
+
+YAML is parsed with the safe loader, so files cannot construct Python objects.
+A TOML document is always a table, so array inputs are stored under a key
+named after the input (``conversation = [...]``, ``grounds = [...]``) and
+unwrapped by the caller-supplied ``key``.
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+from pathlib import Path
+from typing import Any
+
+if sys.version_info >= (3, 11):
+    import tomllib
+else:  # pragma: no cover - Python 3.10
+    import tomli as tomllib
+
+FILE_PREFIX = "@"
+YAML_SUFFIXES = frozenset({".yaml", ".yml"})
+TOML_SUFFIX = ".toml"
+
+
+def _yaml() -> Any:
+    try:
+        import yaml
+    except ImportError:
+        raise ValueError(
+            "YAML input requires the optional PyYAML dependency "
+            "(pip install 'normcore[yaml]')"
+        ) from None
+    return yaml
+
+
+def _load_yaml(text: str) -> Any:
+    yaml = _yaml()
+    try:
+        return yaml.safe_load(text)
+    except yaml.YAMLError as exc:
+        raise ValueError(f"Invalid YAML: {exc}") from exc
+
+
+def dump_yaml(data: Any) -> str:
+    """
+    Serialize ``data`` as YAML, keeping mapping order.
+
+    Raises:
+        ValueError: PyYAML is not installed
+    """
+    return _yaml().safe_dump(data, sort_keys=False, allow_unicode=True)
+
+
+def load_toml(text: str, *, key: str | None = None) -> Any:
+    """
+    Parse TOML text, unwrapping a table whose only entry is ``key``.
+
+    Raises:
+        ValueError: the text is not valid TOML
+    """
+    try:
+        data = tomllib.loads(text)
+    except tomllib.TOMLDecodeError as exc:
+        raise ValueError(f"Invalid TOML: {exc}") from exc
+    if key is not None and list(data) == [key]:
+        return data[key]
+    return data
+
+
+def load_structured_file(path: str | Path, *, key: str | None = None) -> Any:
+    """
+    Parse a structured input file according to its suffix.
+
+    Args:
+        path: JSON, YAML or TOML file
+        key: For TOML, return the value of a table whose only entry is ``key``
+
+    Raises:
+        OSError: the file cannot be read
+        ValueError: the content is not valid for its format
+    """
+    path = Path(path)
+    text = path.read_text(encoding="utf-8")
+    suffix = path.suffix.lower()
+    if suffix in YAML_SUFFIXES:
+        return _load_yaml(text)
+    if suffix == TOML_SUFFIX:
+        return load_toml(text, key=key)
+    try:
+        return json.loads(text)
+    except json.JSONDecodeError as exc:
+        raise ValueError(f"Invalid JSON: {exc}") from exc
+
+
+def load_structured(value: str, *, key: str | None = None) -> Any:
+    """
+    Parse an option value: ``@PATH`` reads a file, anything else is inline JSON.
+
+    ``key`` unwraps TOML array inputs (see ``load_structured_file``).
+
+    Raises:
+        OSError: the file cannot be read
+        ValueError: the content is not valid for its format
+    """
+    if value.startswith(FILE_PREFIX):
+        return load_structured_file(value[len(FILE_PREFIX) :], key=key)
+    try:
+        return json.loads(value)
+    except json.JSONDecodeError as exc:
+        raise ValueError(f"Invalid JSON: {exc}") from exc
@@ -0,0 +1,142 @@
+"""
+Labeled-dataset harness for measuring the evaluator itself.
+
+A labeled dataset is JSONL where every line is an evaluate payload (see
+``normcore.payloads``) plus an ``expected_status`` label. Running the evaluator
+over it yields per-status precision/recall and a confusion matrix, so changes
+to the form-based heuristics can be measured against a labeled corpus instead
+of judged by spot checks.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Iterable
+from typing import Any
+
+from pydantic import BaseModel, Field
+
+from .models import AdmissibilityStatus, EvaluationPolicy
+from .payloads import PAYLOAD_ID_FIELD, evaluate_payload, parse_payload_line
+
+LABEL_FIELD = "expected_status"
+
+
+class StatusMetrics(BaseModel):
+    """
+    Precision/recall for a single admissibility status.
+    """
+
+    support: int = Field(description="Number of cases labeled with this status.")
+    predicted: int = Field(description="Number of cases the evaluator assigned this status.")
+    true_positives: int = Field(description="Cases both labeled and predicted with this status.")
+    precision: float | None = Field(
+        description="true_positives / predicted (None when nothing was predicted)."
+    )
+    recall: float | None = Field(
+        description="true_positives / support (None when nothing is labeled)."
+    )
+
+
+class CaseMismatch(BaseModel):
+    """
+    A labeled case whose predicted status differs from its label.
+    """
+
+    line: int = Field(description="1-based dataset line number.")
+    id: Any = Field(default=None, description="Echoed payload id, if any.")
+    expected: AdmissibilityStatus
+    actual: AdmissibilityStatus
+
+
+class CaseError(BaseModel):
+    """
+    A dataset line that could not be evaluated.
+    """
+
+    line: int = Field(description="1-based dataset line number.")
+    id: Any = Field(default=None, description="Echoed payload id, if any.")
+    error: str
+
+
+class DatasetReport(BaseModel):
+    """
+    Evaluator quality over a labeled dataset.
+    """
+
+    cases: int = Field(default=0, description="Number of evaluated (labeled) cases.")
+    correct: int = Field(default=0, description="Cases whose predicted status matched.")
+    accuracy: float | None = Field(default=None, description="correct / cases.")
+    per_status: dict[str, StatusMetrics] = Field(default_factory=dict)
+    confusion_matrix: dict[str, dict[str, int]] = Field(
+        default_factory=dict,
+        description="expected status -> predicted status -> count.",
+    )
+    mismatches: list[CaseMismatch] = Field(default_factory=list)
+    errors: list[CaseError] = Field(default_factory=list)
+
+
+def _ratio(numerator: int, denominator: int) -> float | None:
+    return round(numerator / denominator, 4) if denominator else None
+
+
+def evaluate_dataset(
+    lines: Iterable[str], *, policy: EvaluationPolicy | None = None
+) -> DatasetReport:
+    """
+    Evaluate every labeled line under ``policy`` and score predictions against labels.
+
+    Lines that fail to parse, lack a valid label, or are rejected by
+    ``evaluate()`` are reported in ``errors`` and excluded from the metrics.
+    """
+    report = DatasetReport()
+    statuses = [status.value for status in AdmissibilityStatus]
+    confusion = {expected: dict.fromkeys(statuses, 0) for expected in statuses}
+
+    for line_number, raw in enumerate(lines, 1):
+        if not raw.strip():
+            continue
+        payload_id = None
+        try:
+            payload = parse_payload_line(raw)
+            payload_id = payload.get(PAYLOAD_ID_FIELD)
+            label = payload.pop(LABEL_FIELD, None)
+            if label is None:
+                raise ValueError(f"Missing {LABEL_FIELD!r} label")
+            try:
+                expected = AdmissibilityStatus(label)
+            except ValueError:
+                raise ValueError(f"Unknown {LABEL_FIELD!r} label: {label!r}")
+            actual = evaluate_payload(payload, policy=policy).status
+        except ValueError as exc:
+            report.errors.append(CaseError(line=line_number, id=payload_id, error=str(exc)))
+            continue
+
+        report.cases += 1
+        confusion[expected.value][actual.value] += 1
+        if actual == expected:
+            report.correct += 1
+        else:
+            report.mismatches.append(
+                CaseMismatch(line=line_number, id=payload_id, expected=expected, actual=actual)
+            )
+
+    report.accuracy = _ratio(report.correct, report.cases)
+    for status in statuses:
+        support = sum(confusion[status].values())
+        predicted = sum(row[status] for row in confusion.values())
+        if not support and not predicted:
+            continue
+        true_positives = confusion[status][status]
+        report.per_status[status] = StatusMetrics(
+            support=support,
+            predicted=predicted,
+            true_positives=true_positives,
+            precision=_ratio(true_positives, predicted),
+            recall=_ratio(true_positives, support),
+        )
+    report.confusion_matrix = {
+        expected: {actual: count for actual, count in row.items() if count}
+        for expected, row in confusion.items()
+        if any(row.values())
+    }
+    return report
@@ -0,0 +1,73 @@
+"""
+Input size guardrails.
+
+``EvaluationPolicy.max_messages`` and ``max_content_bytes`` bound what
+``evaluate()`` accepts, so a multi-megabyte tool payload is rejected up front
+with ``InputLimitError`` instead of stalling extraction and matching.
+"""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Iterable
+from typing import Any
+
+from .models.policy import EvaluationPolicy
+
+
+class InputLimitError(ValueError):
+    """Input exceeds a ``max_messages`` / ``max_content_bytes`` policy limit."""
+
+    def __init__(self, limit: str, maximum: int, actual: int) -> None:
+        """Record which limit was exceeded, its value and the measured size."""
+        self.limit = limit
+        self.maximum = maximum
+        self.actual = actual
+        super().__init__(f"Input exceeds {limit}: {actual} > {maximum}")
+
+    def __reduce__(self) -> tuple[type[InputLimitError], tuple[str, int, int]]:
+        """Pickle by constructor arguments (errors cross process-pool boundaries)."""
+        return type(self), (self.limit, self.maximum, self.actual)
+
+
+def _size(value: Any) -> int:
+    if isinstance(value, str):
+        return len(value.encode("utf-8"))
+    return len(json.dumps(value, ensure_ascii=False, default=str).encode("utf-8"))
+
+
+def content_bytes(message: Any) -> int:
+    """UTF-8 size of a message's text or content parts, refusal and tool call arguments."""
+    if not isinstance(message, dict):
+        return _size(message)
+    total = 0
+    for field in ("content", "refusal"):
+        if message.get(field) is not None:
+            total += _size(message[field])
+    calls = message.get("tool_calls") or []
+    if message.get("function_call") is not None:
+        calls = [*calls, {"function": message["function_call"]}]
+    for call in calls:
+        function = call.get("function") if isinstance(call, dict) else None
+        arguments = function.get("arguments") if isinstance(function, dict) else None
+        if arguments is not None:
+            total += _size(arguments)
+    return total
+
+
+def check_input_limits(messages: Iterable[Any], policy: EvaluationPolicy) -> None:
+    """
+    Reject ``messages`` (the trajectory) when they exceed the policy's limits.
+
+    Raises:
+        InputLimitError: too many messages, or too many content bytes in total
+    """
+    if policy.max_messages is None and policy.max_content_bytes is None:
+        return
+    messages = list(messages)
+    if policy.max_messages is not None and len(messages) > policy.max_messages:
+        raise InputLimitError("max_messages", policy.max_messages, len(messages))
+    if policy.max_content_bytes is not None:
+        total = sum(content_bytes(message) for message in messages)
+        if total > policy.max_content_bytes:
+            raise InputLimitError("max_content_bytes", policy.max_content_bytes, total)
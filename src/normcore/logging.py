@@ -3,23 +3,154 @@
 This package is a library first. By default it emits no logs unless the host
 application configures logging. CLI users can opt into logs via
 ``NORMCORE_LOG_LEVEL``.
+
+Diagnostic events carry structured fields (``log_event``): pipeline stage
+timings (``log_stage``, DEBUG), extractor decisions and per-statement modality,
+license and axiom outcomes. ``configure_logging`` renders them as
+``key=value`` pairs after the message, or with ``log_format="json"`` as one
+JSON object per line for log collectors.
+
+Recoverable input issues (ignored grounds, unparsable tool arguments, ...) go
+through ``report_warning``: they are logged and, while ``collect_warnings`` is
+active (one ``evaluate()`` call), also collected into the judgment's
+``warnings`` so callers see them without configuring logging. In strict mode
+the first such issue raises ``ValueError`` instead.
 """
 
 from __future__ import annotations
 
+import json
 import logging
 import os
+import time
+from collections.abc import Iterator
+from contextlib import contextmanager
+from contextvars import ContextVar
+from datetime import datetime, timezone
+from typing import Any
 
 LOGGER_NAME = "normcore"
 logger = logging.getLogger(LOGGER_NAME)
 logger.addHandler(logging.NullHandler())
 
+TEXT_LOG_FORMAT = "text"
+JSON_LOG_FORMAT = "json"
+LOG_FORMATS = (TEXT_LOG_FORMAT, JSON_LOG_FORMAT)
+# LogRecord attribute holding the structured fields of an event.
+_FIELDS_ATTR = "normcore_fields"
+
+# Active collector: (collected messages, strict).
+_collected_warnings: ContextVar[tuple[list[str], bool] | None] = ContextVar(
+    "normcore_collected_warnings", default=None
+)
+
+
+@contextmanager
+def collect_warnings(*, strict: bool = False) -> Iterator[list[str]]:
+    """
+    Collect ``report_warning`` messages raised in this context (deduplicated, in order).
+
+    With ``strict``, ``report_warning`` raises ``ValueError`` instead of collecting.
+    """
+    collected: list[str] = []
+    token = _collected_warnings.set((collected, strict))
+    try:
+        yield collected
+    finally:
+        _collected_warnings.reset(token)
+
+
+def report_warning(message: str) -> None:
+    """Log a recoverable input issue and record it for the active collector."""
+    active = _collected_warnings.get()
+    if active is not None and active[1]:
+        raise ValueError(f"strict mode: {message}")
+    logger.warning(message)
+    if active is not None and message not in active[0]:
+        active[0].append(message)
+
+
+def log_event(level: int, message: str, **fields: Any) -> None:
+    """Log ``message`` with structured ``fields`` (skipped when ``level`` is disabled)."""
+    if logger.isEnabledFor(level):
+        logger.log(level, message, extra={_FIELDS_ATTR: fields})
+
+
+@contextmanager
+def log_stage(stage: str, **fields: Any) -> Iterator[None]:
+    """Log the duration of a pipeline stage at DEBUG (``stage``, ``duration_ms``)."""
+    if not logger.isEnabledFor(logging.DEBUG):
+        yield
+        return
+    started = time.perf_counter()
+    try:
+        yield
+    finally:
+        duration_ms = round((time.perf_counter() - started) * 1000, 3)
+        log_event(
+            logging.DEBUG,
+            f"stage {stage} finished",
+            stage=stage,
+            duration_ms=duration_ms,
+            **fields,
+        )
+
+
+def _fields(record: logging.LogRecord) -> dict[str, Any]:
+    return getattr(record, _FIELDS_ATTR, None) or {}
+
+
+def _text_value(value: Any) -> str:
+    # Bare words and numbers stay readable; anything else is JSON (quoted, unambiguous).
+    if isinstance(value, (int, float)) and not isinstance(value, bool):
+        return str(value)
+    if isinstance(value, str) and value and not any(c.isspace() or c in "\"=" for c in value):
+        return value
+    return json.dumps(value, ensure_ascii=False, default=str)
+
+
+class TextLogFormatter(logging.Formatter):
+    """``asctime | level | logger | message`` followed by ``key=value`` fields."""
+
+    def __init__(self) -> None:
+        """Initialize with the pipe-separated line layout."""
+        super().__init__("%(asctime)s | %(levelname)s | %(name)s | %(message)s")
+
+    def formatMessage(self, record: logging.LogRecord) -> str:  # noqa: N802 - logging API
+        """Format the line and append the event fields."""
+        line = super().formatMessage(record)
+        fields = _fields(record)
+        if not fields:
+            return line
+        pairs = " ".join(f"{key}={_text_value(value)}" for key, value in fields.items())
+        return f"{line} | {pairs}"
+
+
+class JsonLogFormatter(logging.Formatter):
+    """One JSON object per record: timestamp, level, logger, message, then the fields."""
+
+    def format(self, record: logging.LogRecord) -> str:
+        """Render the record as a single JSON line."""
+        entry: dict[str, Any] = {
+            "timestamp": datetime.fromtimestamp(record.created, timezone.utc).isoformat(),
+            "level": record.levelname,
+            "logger": record.name,
+            "message": record.getMessage(),
+        }
+        for key, value in _fields(record).items():
+            entry.setdefault(key, value)
+        if record.exc_info:
+            entry["exception"] = self.formatException(record.exc_info)
+        return json.dumps(entry, ensure_ascii=False, default=str)
+
 
-def configure_logging(level: str | None = None) -> None:
+def configure_logging(level: str | None = None, log_format: str | None = None) -> None:
     """Configure package logging for CLI/runtime diagnostics.
 
     This is intentionally opt-in. If neither ``level`` nor
     ``NORMCORE_LOG_LEVEL`` is provided, configuration is skipped.
+    ``log_format`` (``text`` or ``json``; fallback ``NORMCORE_LOG_FORMAT``,
+    then ``text``) selects how records are rendered on stderr.
     """
     env_level = os.getenv("NORMCORE_LOG_LEVEL", "")
     raw_level = level if level is not None else (env_level or "")
@@ -34,8 +165,11 @@ def configure_logging(level: str | None = None) -> None:
         pkg_logger.propagate = False
         return
 
+    resolved_format = (log_format or os.getenv("NORMCORE_LOG_FORMAT", "")).strip().lower()
     handler = logging.StreamHandler()
-    handler.setFormatter(logging.Formatter("%(asctime)s | %(levelname)s | %(name)s | %(message)s"))
+    handler.setFormatter(
+        JsonLogFormatter() if resolved_format == JSON_LOG_FORMAT else TextLogFormatter()
+    )
     pkg_logger.addHandler(handler)
     pkg_logger.setLevel(getattr(logging, resolved_level.upper(), logging.INFO))
     pkg_logger.propagate = False
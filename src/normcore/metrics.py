@@ -0,0 +1,210 @@
+"""
+Prometheus metrics for long-running CLI modes.
+
+Exposes evaluator outcomes in the Prometheus text exposition format so
+operators can alert on, for example, a spike of ungrounded assertions from a
+particular agent:
+
+- ``normcore_judgments_total{agent,status}``: judgments per admissibility status
+- ``normcore_violated_axioms_total{agent,axiom}``: violated axioms
+- ``normcore_evaluation_seconds{agent}``: evaluation latency histogram
+- ``normcore_grounds_cited{agent}``: grounds-cited distribution per judgment
+- ``normcore_payload_errors_total``: payloads rejected before evaluation
+
+The exposition format is rendered directly; ``prometheus_client`` is not required.
+"""
+
+from __future__ import annotations
+
+import threading
+from collections import defaultdict
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+
+from .http_access import HttpAccessPolicy
+from .models import AdmissibilityJudgment
+
+METRICS_PATH = "/metrics"
+UNKNOWN_AGENT = "unknown"
+CONTENT_TYPE = "text/plain; version=0.0.4; charset=utf-8"
+
+LATENCY_BUCKETS = (0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0)
+GROUNDS_CITED_BUCKETS = (0.0, 1.0, 2.0, 3.0, 5.0, 10.0, 20.0)
+
+
+def _escape_label(value: str) -> str:
+    return value.replace("\\", "\\\\").replace("\n", "\\n").replace('"', '\\"')
+
+
+def _labels(**labels: str) -> str:
+    return ",".join(f'{key}="{_escape_label(value)}"' for key, value in labels.items())
+
+
+class _Histogram:
+    """Cumulative-bucket histogram for a single label set."""
+
+    def __init__(self, buckets: tuple[float, ...]) -> None:
+        """Initialize empty buckets with the given upper bounds."""
+        self.buckets = buckets
+        self.counts = [0] * len(buckets)
+        self.total = 0.0
+        self.count = 0
+
+    def observe(self, value: float) -> None:
+        for idx, bound in enumerate(self.buckets):
+            if value <= bound:
+                self.counts[idx] += 1
+        self.total += value
+        self.count += 1
+
+    def render(self, name: str, labels: str) -> list[str]:
+        prefix = f"{labels}," if labels else ""
+        lines = [
+            f'{name}_bucket{{{prefix}le="{bound:g}"}} {count}'
+            for bound, count in zip(self.buckets, self.counts)
+        ]
+        lines.append(f'{name}_bucket{{{prefix}le="+Inf"}} {self.count}')
+        lines.append(f"{name}_sum{{{labels}}} {self.total:g}")
+        lines.append(f"{name}_count{{{labels}}} {self.count}")
+        return lines
+
+
+class EvaluationMetrics:
+    """
+    Thread-safe registry of evaluator metrics.
+
+    ``observe()`` is called once per judgment by the pipeline; ``render()`` is
+    called by the scrape endpoint.
+    """
+
+    def __init__(self) -> None:
+        """Initialize empty counters and histograms."""
+        self._lock = threading.Lock()
+        self._judgments: dict[tuple[str, str], int] = defaultdict(int)
+        self._axioms: dict[tuple[str, str], int] = defaultdict(int)
+        self._latency: dict[str, _Histogram] = {}
+        self._grounds_cited: dict[str, _Histogram] = {}
+        self._payload_errors = 0
+
+    def observe(
+        self,
+        judgment: AdmissibilityJudgment,
+        *,
+        latency_seconds: float,
+        agent: str | None = None,
+    ) -> None:
+        """Record one judgment and the time it took to produce."""
+        agent_label = agent or UNKNOWN_AGENT
+        with self._lock:
+            self._judgments[(agent_label, judgment.status.value)] += 1
+            for axiom in judgment.violated_axioms:
+                self._axioms[(agent_label, axiom)] += 1
+            self._latency.setdefault(agent_label, _Histogram(LATENCY_BUCKETS)).observe(
+                latency_seconds
+            )
+            self._grounds_cited.setdefault(agent_label, _Histogram(GROUNDS_CITED_BUCKETS)).observe(
+                float(judgment.grounds_cited)
+            )
+
+    def observe_payload_error(self) -> None:
+        """Record a payload rejected before evaluation."""
+        with self._lock:
+            self._payload_errors += 1
+
+    def render(self) -> str:
+        """Render all metrics in the Prometheus text exposition format."""
+        with self._lock:
+            lines = [
+                "# HELP normcore_judgments_total Judgments per admissibility status.",
+                "# TYPE normcore_judgments_total counter",
+            ]
+            for (agent, status), value in sorted(self._judgments.items()):
+                lines.append(
+                    f"normcore_judgments_total{{{_labels(agent=agent, status=status)}}} {value}"
+                )
+            lines += [
+                "# HELP normcore_violated_axioms_total Violated axioms across judgments.",
+                "# TYPE normcore_violated_axioms_total counter",
+            ]
+            for (agent, axiom), value in sorted(self._axioms.items()):
+                lines.append(
+                    f"normcore_violated_axioms_total{{{_labels(agent=agent, axiom=axiom)}}} {value}"
+                )
+            lines += [
+                "# HELP normcore_evaluation_seconds Evaluation latency in seconds.",
+                "# TYPE normcore_evaluation_seconds histogram",
+            ]
+            for agent, histogram in sorted(self._latency.items()):
+                lines += histogram.render("normcore_evaluation_seconds", _labels(agent=agent))
+            lines += [
+                "# HELP normcore_grounds_cited Grounds cited per judgment.",
+                "# TYPE normcore_grounds_cited histogram",
+            ]
+            for agent, histogram in sorted(self._grounds_cited.items()):
+                lines += histogram.render("normcore_grounds_cited", _labels(agent=agent))
+            lines += [
+                "# HELP normcore_payload_errors_total Payloads rejected before evaluation.",
+                "# TYPE normcore_payload_errors_total counter",
+                f"normcore_payload_errors_total {self._payload_errors}",
+            ]
+        return "\n".join(lines) + "\n"
+
+
+def metrics_handler(
+    metrics: EvaluationMetrics, access: HttpAccessPolicy | None = None
+) -> type[BaseHTTPRequestHandler]:
+    """
+    Build the request handler class that answers ``GET /metrics`` from ``metrics``.
+
+    ``access`` adds API key authentication and CORS (see ``http_access``).
+    """
+    access = access or HttpAccessPolicy()
+
+    class _MetricsHandler(BaseHTTPRequestHandler):
+        def end_headers(self) -> None:
+            for name, value in access.cors_headers(self.headers.get("Origin")).items():
+                self.send_header(name, value)
+            super().end_headers()
+
+        def do_OPTIONS(self) -> None:
+            self.send_response(204)
+            self.send_header("Content-Length", "0")
+            self.end_headers()
+
+        def do_GET(self) -> None:
+            if not access.authorized(self.headers):
+                self.send_response(401)
+                self.send_header("WWW-Authenticate", 'Bearer realm="normcore"')
+                self.send_header("Content-Length", "0")
+                self.end_headers()
+                return
+            if self.path.split("?", 1)[0] != METRICS_PATH:
+                self.send_error(404)
+                return
+            body = metrics.render().encode("utf-8")
+            self.send_response(200)
+            self.send_header("Content-Type", CONTENT_TYPE)
+            self.send_header("Content-Length", str(len(body)))
+            self.end_headers()
+            self.wfile.write(body)
+
+        def log_message(self, format: str, *args: object) -> None:
+            return
+
+    return _MetricsHandler
+
+
+def start_metrics_server(
+    metrics: EvaluationMetrics,
+    host: str = "127.0.0.1",
+    port: int = 0,
+    access: HttpAccessPolicy | None = None,
+) -> ThreadingHTTPServer:
+    """
+    Serve ``GET /metrics`` (see ``metrics_handler``) from a daemon thread.
+
+    Returns the running server (``server.server_address`` holds the bound port;
+    call ``server.shutdown()`` to stop it).
+    """
+    server = ThreadingHTTPServer((host, port), metrics_handler(metrics, access))
+    threading.Thread(target=server.serve_forever, name="normcore-metrics", daemon=True).start()
+    return server
@@ -1,9 +1,25 @@
 from .evaluator import (
+    ADMISSIBLE_SEVERITY,
+    STATUS_SEVERITY,
     AdmissibilityJudgment,
     AdmissibilityStatus,
     EvaluationResult,
+    GateDecision,
     GroundRef,
+    GroundsReport,
+    GuardEvent,
+    KnowledgeNodeRecord,
+    LicenseTrace,
+    PayloadIssue,
+    PayloadValidationReport,
+    RevisionDelta,
+    RiskTier,
+    StatementAttribution,
     StatementEvaluation,
+    StatementStructure,
+    StatementUncertainty,
+    StructureReport,
+    TrajectoryJudgment,
 )
 from .links import (
     CreatorType,
@@ -24,13 +40,31 @@ from .openai_citations import (
     link_set_from_openai_citations,
     parse_openai_citations,
 )
+from .policy import EvaluationPolicy
 
 __all__ = [
+    "ADMISSIBLE_SEVERITY",
+    "STATUS_SEVERITY",
     "AdmissibilityJudgment",
     "AdmissibilityStatus",
+    "EvaluationPolicy",
     "EvaluationResult",
+    "GateDecision",
     "GroundRef",
+    "GroundsReport",
+    "GuardEvent",
+    "KnowledgeNodeRecord",
+    "LicenseTrace",
+    "PayloadIssue",
+    "PayloadValidationReport",
+    "RevisionDelta",
+    "RiskTier",
+    "StatementAttribution",
     "StatementEvaluation",
+    "StatementStructure",
+    "StatementUncertainty",
+    "StructureReport",
+    "TrajectoryJudgment",
     "AssistantSpeechAct",
     "RefusalSpeechAct",
     "TextSpeechAct",
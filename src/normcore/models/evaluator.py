@@ -5,6 +5,7 @@ Public models for the normative admissibility evaluator.
 from __future__ import annotations
 
 from enum import Enum
+from typing import Any, Literal
 
 from pydantic import BaseModel, Field
 
@@ -18,11 +19,47 @@ class AdmissibilityStatus(str, Enum):
     CONDITIONALLY_ACCEPTABLE = "conditionally_acceptable"
     VIOLATES_NORM = "violates_norm"
     UNSUPPORTED = "unsupported"
+    INSUFFICIENT_CITATIONS = "insufficient_citations"
     ILL_FORMED = "ill_formed"
     UNDERDETERMINED = "underdetermined"
     NO_NORMATIVE_CONTENT = "no_normative_content"
 
 
+# Aggregation order (see AdmissibilityEvaluator._aggregate); higher is worse.
+STATUS_SEVERITY: dict[AdmissibilityStatus, int] = {
+    AdmissibilityStatus.ACCEPTABLE: 0,
+    AdmissibilityStatus.NO_NORMATIVE_CONTENT: 0,
+    AdmissibilityStatus.CONDITIONALLY_ACCEPTABLE: 1,
+    AdmissibilityStatus.INSUFFICIENT_CITATIONS: 2,
+    AdmissibilityStatus.UNSUPPORTED: 3,
+    AdmissibilityStatus.UNDERDETERMINED: 4,
+    AdmissibilityStatus.ILL_FORMED: 5,
+    AdmissibilityStatus.VIOLATES_NORM: 6,
+}
+# Highest severity that still admits the output (conditionally acceptable)
+ADMISSIBLE_SEVERITY = 1
+
+
+class RiskTier(str, Enum):
+    """
+    Routing tier derived from the judgment (auto-allow / human review / block).
+    """
+
+    LOW = "low"
+    MEDIUM = "medium"
+    HIGH = "high"
+
+
+class GateDecision(str, Enum):
+    """
+    Guardrail action for a judgment (deliver / ask the agent to revise / stop).
+    """
+
+    ALLOW = "allow"
+    REVISE = "revise"
+    BLOCK = "block"
+
+
 class GroundRef(BaseModel):
     """
     A single admitted knowledge atom included in the grounding trace.
@@ -38,6 +75,80 @@ class GroundRef(BaseModel):
         default=None,
         description="Optional semantic/external identifier used for link resolution.",
     )
+    derived_from: list[str] = Field(
+        default_factory=list,
+        description="Parent ground ids for derived evidence (provenance graph edges).",
+    )
+
+
+class StatementUncertainty(BaseModel):
+    """
+    Hedges and probability expressions found in a statement.
+    """
+
+    markers: list[str] = Field(
+        default_factory=list,
+        description="Matched hedge phrases (for example 'almost certainly', '30% chance').",
+    )
+    probability: float | None = Field(
+        default=None,
+        description="Explicit or verbal probability estimate in [0, 1], if expressed.",
+    )
+
+
+class StatementAttribution(BaseModel):
+    """
+    Quoted or reported speech found in a statement.
+    """
+
+    source: str = Field(description="Who the relayed claim is attributed to.")
+    marker: str = Field(description="Reporting phrase (for example 'claims', 'according to').")
+
+
+class LicenseTrace(BaseModel):
+    """
+    How a statement's license was derived (reported with ``evaluate(trace=True)``).
+    """
+
+    rule: Literal["normative", "descriptive", "commissive", "personalized", "epistemic"] = (
+        Field(
+            description=(
+                "Derivation rule applied: normative (grounding strength, links-aware), "
+                "descriptive (matched factual grounds), commissive (tool capability), "
+                "personalized (user context required), epistemic (no license; judged "
+                "against factual grounding)."
+            )
+        )
+    )
+    mode: Literal["links", "conservative"] = Field(
+        description=(
+            "links: only grounds with a SUPPORTS link count; conservative: every matched "
+            "ground counts."
+        )
+    )
+    ground_set_size: int = Field(description="Number of knowledge nodes matched to the statement.")
+    factual_present: bool = Field(description="Whether any factual ground was matched.")
+    factual_strength: str | None = Field(
+        default=None,
+        description="Strength of the matched factual grounding (strong or weak), if any.",
+    )
+    factual_has_strong: bool = Field(description="Whether a strong factual ground was matched.")
+    non_factual_scopes: list[str] = Field(
+        default_factory=list,
+        description="Scopes of matched non-factual grounds (these never license alone).",
+    )
+    user_context: bool = Field(
+        default=False,
+        description="Whether user-stated context was matched (needed by personalized claims).",
+    )
+    supports_links_count: int = Field(
+        default=0,
+        description="Number of SUPPORTS links considered (links mode only).",
+    )
+    permitted_modalities: list[str] = Field(
+        default_factory=list,
+        description="Resulting license (sorted modality names).",
+    )
 
 
 class StatementEvaluation(BaseModel):
@@ -50,7 +161,37 @@ class StatementEvaluation(BaseModel):
     )
     statement: str = Field(description="Statement text that was evaluated.")
     modality: str = Field(description="Detected modality for the statement.")
-    license: set[str] = Field(description="Modalities permitted by current grounding.")
+    reading: str | None = Field(
+        default=None,
+        description=(
+            "Reading of must/should in the statement: epistemic (inference, needs factual "
+            "grounding) or deontic (obligation, needs a license); null without a modal verb."
+        ),
+    )
+    uncertainty: StatementUncertainty | None = Field(
+        default=None,
+        description=(
+            "Hedges/probabilities in the statement; hedged assertive claims are judged "
+            "against the conditional license instead of the categoricity ban."
+        ),
+    )
+    attribution: StatementAttribution | None = Field(
+        default=None,
+        description=(
+            "Quoted/reported speech source; relayed assertive claims need grounding that "
+            "the claim was made, not an assertive license."
+        ),
+    )
+    personalized: bool = Field(
+        default=False,
+        description=(
+            "Whether the statement is framed relative to the user (\"better for you\"); "
+            "such statements are licensed only with user context."
+        ),
+    )
+    license: set[str] = Field(
+        description="Modalities permitted by current grounding (tool capability for commissive)."
+    )
     status: AdmissibilityStatus = Field(description="Per-statement admissibility status.")
     violated_axiom: str | None = Field(
         default=None,
@@ -60,10 +201,31 @@ class StatementEvaluation(BaseModel):
         default="",
         description="Human-readable reason for the per-statement verdict.",
     )
+    recommended_modality: str | None = Field(
+        default=None,
+        description=(
+            "First fallback modality the license permits, reported for failing statements "
+            "(see policy modality_fallbacks)."
+        ),
+    )
     grounding_trace: list[GroundRef] = Field(
         default_factory=list,
         description="Ground nodes considered for this statement.",
     )
+    weight: float = Field(
+        default=1.0,
+        description=(
+            "Share of total statement weight (policy statement_weighting / "
+            "statement_class_weights); used by weighted aggregation."
+        ),
+    )
+    confidence: float = Field(
+        default=1.0,
+        description=(
+            "Confidence of the verdict: modality-detection confidence x mean confidence of "
+            "matched knowledge nodes x share of SUPPORTS links that resolve."
+        ),
+    )
 
     subject: str | None = Field(
         default=None,
@@ -73,6 +235,20 @@ class StatementEvaluation(BaseModel):
         default=None,
         description="Normalized statement predicate used in internal statement model.",
     )
+    license_trace: LicenseTrace | None = Field(
+        default=None,
+        description="License derivation trace; present only with evaluate(trace=True).",
+    )
+
+    def __str__(self) -> str:
+        """Concise human summary: status, id, modality and text, then the reason."""
+        lines = [f"[{self.status.value}] {self.statement_id} ({self.modality}): {self.statement}"]
+        if self.violated_axiom or self.explanation:
+            prefix = f"{self.violated_axiom}: " if self.violated_axiom else ""
+            lines.append(f"  {prefix}{self.explanation}".rstrip())
+        if self.recommended_modality:
+            lines.append(f"  recommended modality: {self.recommended_modality}")
+        return "\n".join(lines)
 
 
 EvaluationResult = StatementEvaluation
@@ -113,6 +289,17 @@ class AdmissibilityJudgment(BaseModel):
         default=0,
         description="Count of statements with acceptable or conditionally acceptable outcomes.",
     )
+    status_counts: dict[AdmissibilityStatus, int] = Field(
+        default_factory=dict,
+        description="Number of evaluated statements per status (statuses seen only).",
+    )
+    confidence: float = Field(
+        default=1.0,
+        description=(
+            "Overall confidence of the judgment (lowest statement confidence); route "
+            "low-confidence verdicts to human review."
+        ),
+    )
     grounds_accepted: int = Field(
         default=0,
         description="Number of grounds admitted into the evaluation evidence pool.",
@@ -121,3 +308,274 @@ class AdmissibilityJudgment(BaseModel):
         default=0,
         description="Number of admitted grounds actually cited in assistant text.",
     )
+    accepted_ground_ids: list[str] = Field(
+        default_factory=list,
+        description="Ids of grounds admitted into the evidence pool (sorted).",
+    )
+    cited_ground_ids: list[str] = Field(
+        default_factory=list,
+        description="Ids of grounds actually linked to statements (sorted).",
+    )
+    citation_map: dict[str, list[str]] = Field(
+        default_factory=dict,
+        description="Citation key -> ground ids it resolves to, for every admitted ground.",
+    )
+    out_of_window_ground_ids: list[str] = Field(
+        default_factory=list,
+        description=(
+            "Ids of tool-result grounds excluded by the policy's ground_window_turns "
+            "(observed too long ago to license current claims; sorted)."
+        ),
+    )
+    risk_tier: RiskTier = Field(
+        default=RiskTier.LOW,
+        description=(
+            "Routing tier derived from status, raised to the policy risk_floor "
+            "(low: auto-allow, medium: human review, high: block)."
+        ),
+    )
+    gate_decision: GateDecision = Field(
+        default=GateDecision.ALLOW,
+        description=(
+            "Guardrail action from the policy gate mapping: the stricter of the status "
+            "gate and the risk-tier gate (allow, revise, block)."
+        ),
+    )
+    reasoning_items_skipped: int = Field(
+        default=0,
+        description=(
+            "Number of reasoning/thinking items excluded from evaluation "
+            "(hidden chain-of-thought is not a public speech act)."
+        ),
+    )
+    warnings: list[str] = Field(
+        default_factory=list,
+        description=(
+            "Recoverable input issues met during parsing and evaluation (ignored grounds, "
+            "unparsable tool arguments, ...); the judgment was computed without that input."
+        ),
+    )
+
+    def __str__(self) -> str:
+        """Concise multi-line human summary of the verdict and its statements."""
+        lines = [
+            f"{self.status.value} (gate: {self.gate_decision.value}, risk: "
+            f"{self.risk_tier.value}, confidence: {self.confidence:.2f})"
+        ]
+        if self.explanation:
+            lines.append(self.explanation)
+        if self.feedback_hint:
+            lines.append(f"hint: {self.feedback_hint}")
+        lines.extend(str(evaluation) for evaluation in self.statement_evaluations)
+        lines.extend(f"warning: {warning}" for warning in self.warnings)
+        return "\n".join(lines)
+
+
+class StatementStructure(BaseModel):
+    """
+    Structural check result for one extracted statement.
+    """
+
+    statement_id: str = Field(description="Stable statement identifier.")
+    statement: str = Field(description="Statement text that was checked.")
+    modality: str | None = Field(
+        default=None,
+        description="Detected modality (form only; no grounding analysis).",
+    )
+    issues: list[str] = Field(
+        default_factory=list,
+        description="Structural issue codes (empty when the statement is well-formed).",
+    )
+
+
+class StructureReport(BaseModel):
+    """
+    Well-formedness-only report for assistant text (no grounding analysis).
+    """
+
+    status: Literal["well_formed", "ill_formed", "no_normative_content"] = Field(
+        description=(
+            "well_formed when every extracted statement is judgeable, ill_formed when any "
+            "has structural issues, no_normative_content when nothing was extracted."
+        )
+    )
+    statements: list[StatementStructure] = Field(
+        default_factory=list,
+        description="Per-statement structural results.",
+    )
+
+    @property
+    def well_formed(self) -> bool:
+        """Whether the text can proceed to full evaluation without structural failures."""
+        return self.status != "ill_formed"
+
+
+class PayloadIssue(BaseModel):
+    """
+    One structural problem found in an evaluate payload.
+    """
+
+    path: str = Field(
+        description=(
+            "Location of the problem (for example conversation[2].tool_call_id); empty "
+            "for the payload object itself."
+        )
+    )
+    message: str = Field(description="What is wrong at that location.")
+
+
+class PayloadValidationReport(BaseModel):
+    """
+    Structural check of an evaluate payload (no evaluation).
+    """
+
+    valid: bool = Field(description="True when no issues were found.")
+    issues: list[PayloadIssue] = Field(
+        default_factory=list,
+        description="Every problem found, in payload order.",
+    )
+
+
+class KnowledgeNodeRecord(BaseModel):
+    """
+    Knowledge node as admitted into the knowledge state (KnowledgeNode field names).
+
+    Records in this form are accepted back as ``knowledge_nodes`` input.
+    """
+
+    id: str = Field(description="Internal knowledge-node identifier.")
+    source: str = Field(description="Ground source label (for example observed).")
+    status: str = Field(description="Ground node status label (for example confirmed).")
+    confidence: float = Field(description="Confidence score attached to the ground node.")
+    scope: str = Field(description="Ground scope label (for example factual).")
+    strength: str = Field(description="Strength label used by licensing logic.")
+    semantic_id: str | None = Field(
+        default=None,
+        description="Semantic identifier used for citation keys and links.",
+    )
+    payload: Any = Field(
+        default=None,
+        description="Parsed originating tool result (the item, for array results).",
+    )
+    derived_from: list[str] = Field(
+        default_factory=list,
+        description="Parent ground ids of derived evidence (empty for observations).",
+    )
+
+
+class GroundsReport(BaseModel):
+    """
+    Grounds derivable from a conversation's tool results (no statement evaluation).
+    """
+
+    knowledge_nodes: list[KnowledgeNodeRecord] = Field(
+        default_factory=list,
+        description="Knowledge nodes built from in-window tool results, in trajectory order.",
+    )
+    tool_call_refs: dict[str, list[str]] = Field(
+        default_factory=dict,
+        description=(
+            "Tool call id -> ground ids citable as [@<tool_call_id>] (the semantic id or "
+            "id of each node the result produced)."
+        ),
+    )
+    out_of_window_ground_ids: list[str] = Field(
+        default_factory=list,
+        description="Ground ids from tool results outside policy.ground_window_turns.",
+    )
+    warnings: list[str] = Field(
+        default_factory=list,
+        description="Recoverable input issues (unmatched tool results, unparsable arguments).",
+    )
+
+
+class RevisionDelta(BaseModel):
+    """
+    Improvement delta between a response's judgment and the judgment of its retry.
+    """
+
+    status_before: AdmissibilityStatus = Field(description="Status of the original response.")
+    status_after: AdmissibilityStatus = Field(description="Status of the retried response.")
+    status_change: Literal["improved", "regressed", "unchanged"] = Field(
+        description="Direction of the status change in aggregation severity order."
+    )
+    resolved_violations: list[str] = Field(
+        default_factory=list,
+        description="Violated axioms of the original that the retry no longer violates.",
+    )
+    new_violations: list[str] = Field(
+        default_factory=list,
+        description="Violated axioms the retry introduced.",
+    )
+    resolved_statements: list[str] = Field(
+        default_factory=list,
+        description="Statement ids that failed originally and no longer fail (or were dropped).",
+    )
+    new_failing_statements: list[str] = Field(
+        default_factory=list,
+        description="Statement ids that fail in the retry but did not fail originally.",
+    )
+    converged: bool = Field(
+        description="Whether the retry is admissible (acceptable, conditional, or no content)."
+    )
+    retry_worthwhile: bool = Field(
+        description=(
+            "Whether another retry is likely to help: the retry is not yet admissible, "
+            "can be retried, and made progress (a better status, or fixes without new "
+            "failures at the same status)."
+        ),
+    )
+
+
+class TrajectoryJudgment(BaseModel):
+    """
+    Judgments of every assistant turn of a conversation, each made with the
+    knowledge available at that turn.
+    """
+
+    status: AdmissibilityStatus = Field(
+        description=(
+            "Worst turn status in aggregation order (no_normative_content when no turn "
+            "was judged)."
+        )
+    )
+    gate_decision: GateDecision = Field(
+        default=GateDecision.ALLOW,
+        description="Strictest gate decision of any turn.",
+    )
+    judgments: list[AdmissibilityJudgment] = Field(
+        default_factory=list,
+        description="One judgment per judged assistant message, in conversation order.",
+    )
+    message_indices: list[int] = Field(
+        default_factory=list,
+        description="Conversation index of the assistant message behind each judgment.",
+    )
+    first_failing_index: int | None = Field(
+        default=None,
+        description=(
+            "Conversation index of the first assistant message that fails (not acceptable, "
+            "conditional, no content, or underdetermined); None if no turn does."
+        ),
+    )
+
+
+class GuardEvent(BaseModel):
+    """
+    Early verdict on a completed sentence of a streaming response.
+    """
+
+    statement_id: str = Field(description="Sentence statement id (s1, s2, ... in reading order).")
+    statement: str = Field(description="Sentence text that was evaluated.")
+    status: AdmissibilityStatus = Field(description="Per-sentence admissibility status.")
+    gate_decision: GateDecision = Field(
+        description="Guardrail action for the sentence under the policy's status gates."
+    )
+    violated_axiom: str | None = Field(
+        default=None,
+        description="Violated axiom code when the sentence is inadmissible (for example A5).",
+    )
+    explanation: str = Field(default="", description="Human-readable verdict rationale.")
+    chars_received: int = Field(
+        description="Length of the streamed output when the sentence was judged."
+    )
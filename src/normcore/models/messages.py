@@ -25,6 +25,8 @@ class ToolResultSpeechAct(BaseModel):
     tool_call_id: str | None = None
     arguments: dict = Field(default_factory=dict)
     result_text: str
+    trusted: bool = True  # False: admitted as weak knowledge (e.g. unmatched tool_call_id)
+    position: int | None = None  # Trajectory index of the result message
 
 
 class _TextPart(BaseModel):
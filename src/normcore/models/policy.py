@@ -0,0 +1,423 @@
+"""
+Public evaluation policy model.
+
+A policy carries caller-tunable evaluation settings. Defaults reproduce the
+built-in behavior, so an empty policy never changes a verdict.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import json
+import re
+from typing import Any, Literal
+
+from pydantic import BaseModel, Field, field_validator
+
+from ..i18n import DEFAULT_LOCALE, LOCALES
+from ..normative.models import Modality
+from .evaluator import AdmissibilityStatus, GateDecision, RiskTier
+
+
+# Statement classes for statement_class_weights (statement id prefix before ':').
+STATEMENT_CLASSES = frozenset({"final_response", "refusal", "tool_call", "table", "code"})
+# Axioms that can be disabled: those that report a violated_axiom.
+DISABLEABLE_AXIOMS = frozenset({"I1", "A4", "A5", "A7", "A8", "A9"})
+# Name words that mark a tool as state-changing when tool_effects does not list it.
+STATE_CHANGING_TOOL_VERBS = frozenset(
+    {
+        "add",
+        "apply",
+        "approve",
+        "archive",
+        "assign",
+        "book",
+        "cancel",
+        "charge",
+        "close",
+        "commit",
+        "create",
+        "delete",
+        "deploy",
+        "destroy",
+        "disable",
+        "drop",
+        "edit",
+        "enable",
+        "erase",
+        "execute",
+        "grant",
+        "insert",
+        "install",
+        "invite",
+        "kill",
+        "merge",
+        "modify",
+        "move",
+        "pay",
+        "post",
+        "publish",
+        "purge",
+        "push",
+        "put",
+        "refund",
+        "remove",
+        "rename",
+        "reset",
+        "restart",
+        "revert",
+        "revoke",
+        "rm",
+        "rollback",
+        "run",
+        "send",
+        "set",
+        "start",
+        "stop",
+        "submit",
+        "terminate",
+        "transfer",
+        "truncate",
+        "uninstall",
+        "update",
+        "upload",
+        "wipe",
+        "write",
+    }
+)
+# Modalities modality_keywords can select (ModalityDetector.KEYWORD_MODALITIES).
+KEYWORD_MODALITIES = frozenset(
+    {"refusal", "clarification", "commissive", "conditional", "descriptive", "assertive"}
+)
+
+
+class EvaluationPolicy(BaseModel):
+    """
+    Caller-tunable settings for the admissibility evaluator.
+    """
+
+    model_config = {
+        "extra": "forbid",
+    }
+
+    tool_claim_fields: dict[str, list[str]] = Field(
+        default_factory=dict,
+        description=(
+            "Tool name -> argument fields whose string values are evaluated as assistant "
+            "claims when the final assistant turn calls that tool (dotted paths address "
+            "nested fields, for example 'message.body')."
+        ),
+    )
+    tool_effects: dict[str, Literal["read_only", "state_changing"]] = Field(
+        default_factory=dict,
+        description=(
+            "Tool name -> effect for tool-call pre-flight checks: 'state_changing' calls "
+            "need grounding from the conversation so far, 'read_only' calls need none. "
+            "Unlisted tools are state-changing when a word of their name is an action verb "
+            "(delete, create, send, ...)."
+        ),
+    )
+    protocol_prefix_phrases: list[str] = Field(
+        default_factory=list,
+        description=(
+            "Extra greeting/sign-on phrases stripped from the start of assistant text "
+            "as protocol speech (matched literally, case-insensitive)."
+        ),
+    )
+    protocol_suffix_phrases: list[str] = Field(
+        default_factory=list,
+        description=(
+            "Extra sign-off phrases; when one opens a trailing sentence, it and "
+            "everything after it are stripped as protocol speech."
+        ),
+    )
+    resolve_coreferences: bool = Field(
+        default=False,
+        description=(
+            "Replace a leading pronoun subject ('It should be deployed first') with the last "
+            "entity identifier (issue key, '<type>_id: <value>', URL or inline code span) "
+            "mentioned earlier in the response or in the user's last message."
+        ),
+    )
+    risk_floor: RiskTier = Field(
+        default=RiskTier.LOW,
+        description=(
+            "Minimum risk tier reported for this deployment; sensitive domains "
+            "(for example medical or financial) can route every judgment to review."
+        ),
+    )
+    domain_risk_floors: dict[str, RiskTier] = Field(
+        default_factory=dict,
+        description=(
+            "Domain -> minimum risk tier for payloads that declare that domain (for example "
+            "{'medical': 'high'}); raises risk_floor, never lowers it. Empty by default."
+        ),
+    )
+    gate_by_status: dict[AdmissibilityStatus, GateDecision] = Field(
+        default_factory=dict,
+        description=(
+            "Status -> gate decision overrides (defaults: violates_norm blocks; "
+            "unsupported, ill_formed and insufficient_citations revise; the rest allow)."
+        ),
+    )
+    gate_by_risk_tier: dict[RiskTier, GateDecision] = Field(
+        default_factory=dict,
+        description=(
+            "Risk tier -> minimum gate decision (for example {'high': 'block'}); "
+            "the stricter of the status and tier gates wins. Empty by default."
+        ),
+    )
+    code_blocks: Literal["exclude", "flag_executable"] = Field(
+        default="exclude",
+        description=(
+            "Fenced code block handling: 'exclude' drops them from extraction; "
+            "'flag_executable' also evaluates each command line of shell/SQL fences as an "
+            "imperative statement that requires grounding (ids 'code:<block>:<line>')."
+        ),
+    )
+    statement_granularity: Literal["response", "sentence"] = Field(
+        default="response",
+        description=(
+            "Prose extraction: 'response' judges the cleaned response as one statement "
+            "(id 'final_response'); 'sentence' judges each sentence or bullet on its own "
+            "(ids 's1', 's2', ... in reading order)."
+        ),
+    )
+    ground_window_turns: int = Field(
+        default=0,
+        ge=0,
+        description=(
+            "Only tool results observed in the last N user turns (since the Nth last user "
+            "message) can ground statements; older ones are reported in "
+            "out_of_window_ground_ids. 1 admits results since the last user message; 0 "
+            "admits all."
+        ),
+    )
+    mixed_refusal_parts: Literal["reject", "evaluate_parts"] = Field(
+        default="reject",
+        description=(
+            "Assistant content mixing text and refusal parts: 'reject' raises an error; "
+            "'evaluate_parts' evaluates the text parts as usual and the refusal parts as "
+            "a refusal statement (id 'refusal')."
+        ),
+    )
+    unmatched_tool_results: Literal["trust", "weak", "ignore"] = Field(
+        default="trust",
+        description=(
+            "Tool messages whose tool_call_id matches no assistant tool call: 'trust' "
+            "admits them as strong knowledge, 'weak' as weak knowledge (conditional "
+            "license at most), 'ignore' drops them. Always reported in warnings."
+        ),
+    )
+    strong_confidence_threshold: float = Field(
+        default=0.0,
+        ge=0.0,
+        le=1.0,
+        description=(
+            "Minimum knowledge-node confidence for strong evidence; nodes below it are "
+            "admitted as weak (conditional license at most). Only downgrades; 0 keeps "
+            "built-in strengths."
+        ),
+    )
+    min_citations_per_assertive: int = Field(
+        default=0,
+        ge=0,
+        description=(
+            "Citations every assertive statement must carry (axiom A8); 0 disables. "
+            "Failing statements get status insufficient_citations."
+        ),
+    )
+    min_citations_per_200_words: float = Field(
+        default=0.0,
+        ge=0.0,
+        description=(
+            "Citation density required of assertive content (axiom A8): an assertive "
+            "statement of W words needs ceil(N * W / 200) citations; 0 disables."
+        ),
+    )
+    aggregation: Literal["lexicographic", "weighted"] = Field(
+        default="lexicographic",
+        description=(
+            "Judgment aggregation: 'lexicographic' lets any single statement decide the "
+            "status; 'weighted' lets a status decide only if its statements carry at least "
+            "weighted_status_threshold of the total statement weight."
+        ),
+    )
+    statement_weighting: Literal["uniform", "length", "position"] = Field(
+        default="uniform",
+        description=(
+            "Base statement weight: 'uniform' (1), 'length' (word count) or 'position' "
+            "(1 / position, so earlier statements weigh more)."
+        ),
+    )
+    statement_class_weights: dict[str, float] = Field(
+        default_factory=dict,
+        description=(
+            "Statement class -> weight multiplier (classes: final_response, refusal, "
+            "tool_call, table, code; sentence statements are final_response); unlisted "
+            "classes use 1."
+        ),
+    )
+    weighted_status_threshold: float = Field(
+        default=0.25,
+        gt=0.0,
+        le=1.0,
+        description="Weight share a status needs to decide a weighted aggregation.",
+    )
+    modality_fallbacks: dict[str, list[str]] = Field(
+        default_factory=dict,
+        description=(
+            "Modality -> ordered fallback modalities recommended when that modality is "
+            "not licensed; entries replace the built-in chain for that modality "
+            "(defaults: assertive -> conditional, refusal; conditional -> refusal; "
+            "descriptive -> refusal; commissive -> refusal)."
+        ),
+    )
+
+    non_epistemic_tools: list[str] = Field(
+        default_factory=list,
+        description=(
+            "Extra tool names (case-insensitive) treated like memory/personalization tools: "
+            "their results never become knowledge nodes and cannot ground statements."
+        ),
+    )
+    modality_keywords: dict[str, list[str]] = Field(
+        default_factory=dict,
+        description=(
+            "Modality -> extra phrases (whole words, case-insensitive) that mark a "
+            "statement's core assertion with that modality, extending the built-in "
+            "indicators (modalities: refusal, clarification, commissive, conditional, "
+            "descriptive, assertive); detection priority is unchanged."
+        ),
+    )
+    disabled_axioms: list[str] = Field(
+        default_factory=list,
+        description=(
+            "Axioms removed from the evaluator's axiom registry (I1, A4, A5, A7, A8, A9): "
+            "statements are judged by the remaining axioms, as if the disabled ones were "
+            "never registered."
+        ),
+    )
+
+    max_messages: int | None = Field(
+        default=None,
+        gt=0,
+        description=(
+            "Reject conversations with more messages than this (InputLimitError) instead "
+            "of evaluating them; None disables the limit."
+        ),
+    )
+    max_content_bytes: int | None = Field(
+        default=None,
+        gt=0,
+        description=(
+            "Reject input whose message content (text, content parts, refusals and tool "
+            "call arguments) totals more UTF-8 bytes than this (InputLimitError); None "
+            "disables the limit."
+        ),
+    )
+
+    locale: str = Field(
+        default=DEFAULT_LOCALE,
+        description=(
+            f"Language of feedback_hint and explanation strings ({', '.join(LOCALES)}); "
+            "statuses, axiom codes and other fields are unaffected."
+        ),
+    )
+
+    @field_validator("locale")
+    @classmethod
+    def _validate_locale(cls, value: str) -> str:
+        if value not in LOCALES:
+            raise ValueError(f"Unsupported locale '{value}' (choose from {', '.join(LOCALES)})")
+        return value
+
+    @field_validator("modality_keywords")
+    @classmethod
+    def _validate_modality_keywords(cls, value: dict[str, list[str]]) -> dict[str, list[str]]:
+        unknown = sorted(set(value) - KEYWORD_MODALITIES)
+        if unknown:
+            raise ValueError(f"Unknown modalities in modality_keywords: {', '.join(unknown)}")
+        return value
+
+    @field_validator("disabled_axioms")
+    @classmethod
+    def _validate_disabled_axioms(cls, value: list[str]) -> list[str]:
+        unknown = sorted(set(value) - DISABLEABLE_AXIOMS)
+        if unknown:
+            raise ValueError(f"Axioms cannot be disabled: {', '.join(unknown)}")
+        return value
+
+    @field_validator("statement_class_weights")
+    @classmethod
+    def _validate_statement_class_weights(cls, value: dict[str, float]) -> dict[str, float]:
+        unknown = sorted(set(value) - STATEMENT_CLASSES)
+        if unknown:
+            raise ValueError(f"Unknown statement classes: {', '.join(unknown)}")
+        if any(weight < 0 for weight in value.values()):
+            raise ValueError("statement_class_weights must be non-negative")
+        return value
+
+    @field_validator("modality_fallbacks")
+    @classmethod
+    def _validate_modality_fallbacks(cls, value: dict[str, list[str]]) -> dict[str, list[str]]:
+        known = {m.value for m in Modality}
+        for modality, fallbacks in value.items():
+            unknown = sorted({modality, *fallbacks} - known)
+            if unknown:
+                raise ValueError(f"Unknown modalities in modality_fallbacks: {', '.join(unknown)}")
+        return value
+
+    def fingerprint(self) -> str:
+        """
+        Return a stable SHA-256 fingerprint of the effective settings.
+
+        Equal policies share a fingerprint regardless of field order, so audit
+        records can show which configuration produced a verdict.
+        """
+        canonical = json.dumps(self.model_dump(mode="json"), sort_keys=True, separators=(",", ":"))
+        return hashlib.sha256(canonical.encode("utf-8")).hexdigest()
+
+    def requires_attribution(self) -> bool:
+        """Return whether the policy axiom A8 (attribution density) is enforced."""
+        return self.min_citations_per_assertive > 0 or self.min_citations_per_200_words > 0
+
+    def axiom_enabled(self, code: str, *, policy_axiom: bool = False) -> bool:
+        """Return whether axiom ``code`` can fail statements under this policy."""
+        if code in self.disabled_axioms:
+            return False
+        return not policy_axiom or self.requires_attribution()
+
+    def domain_risk_floor(self, domain: str | None) -> RiskTier:
+        """Return the minimum risk tier for ``domain`` (never below ``risk_floor``)."""
+        floor = self.domain_risk_floors.get(domain, RiskTier.LOW) if domain else RiskTier.LOW
+        return max(self.risk_floor, floor, key=list(RiskTier).index)
+
+    def tool_effect(self, name: str) -> Literal["read_only", "state_changing"]:
+        """Return the effect of tool ``name``: ``tool_effects`` entry, else its name verbs."""
+        if name in self.tool_effects:
+            return self.tool_effects[name]
+        words = re.split(r"[^a-z0-9]+", re.sub(r"([a-z0-9])([A-Z])", r"\1_\2", name).lower())
+        return "state_changing" if STATE_CHANGING_TOOL_VERBS.intersection(words) else "read_only"
+
+    def modality_keyword_table(self) -> dict[Modality, list[str]]:
+        """Return ``modality_keywords`` keyed by ``Modality``."""
+        return {Modality(modality): phrases for modality, phrases in self.modality_keywords.items()}
+
+    def modality_fallback_table(self) -> dict[Modality, list[Modality]]:
+        """Return ``modality_fallbacks`` keyed and valued by ``Modality``."""
+        return {
+            Modality(modality): [Modality(m) for m in fallbacks]
+            for modality, fallbacks in self.modality_fallbacks.items()
+        }
+
+
+def coerce_policy(policy: EvaluationPolicy | dict[str, Any] | None) -> EvaluationPolicy:
+    """Normalize caller policy input into an ``EvaluationPolicy``."""
+    if policy is None:
+        return EvaluationPolicy()
+    if isinstance(policy, EvaluationPolicy):
+        return policy
+    if isinstance(policy, dict):
+        return EvaluationPolicy.model_validate(policy)
+    raise ValueError(f"Unsupported policy type: {type(policy)}")
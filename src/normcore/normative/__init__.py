@@ -10,17 +10,36 @@ from .models import (
     GroundSet,
     KnowledgeNode,
     License,
+    ModalReading,
     Modality,
     Scope,
     Source,
     Statement,
     StatementValidationResult,
     Status,
+    Uncertainty,
     ValidationResult,
 )
+from .axiom_checker import (
+    Axiom,
+    AxiomContext,
+    AxiomRegistry,
+    RuleAxiom,
+    register_axiom,
+    unregister_axiom,
+)
+from .modality_detector import ModalityDetection, ModalityDetector
+from .statement_extractor import StatementExtraction, StatementExtractor
+from .tool_parsers import (
+    ToolResultParser,
+    ToolResultParserRegistry,
+    register_tool_result_parser,
+    unregister_tool_result_parser,
+)
 
 __all__ = [
     "Statement",
+    "Uncertainty",
     "KnowledgeNode",
     "GroundSet",
     "License",
@@ -28,8 +47,23 @@ __all__ = [
     "StatementValidationResult",
     "ValidationResult",
     "Modality",
+    "ModalReading",
     "Source",
     "Status",
     "Scope",
     "EvaluationStatus",
+    "Axiom",
+    "AxiomContext",
+    "AxiomRegistry",
+    "RuleAxiom",
+    "register_axiom",
+    "unregister_axiom",
+    "ModalityDetection",
+    "ModalityDetector",
+    "StatementExtraction",
+    "StatementExtractor",
+    "ToolResultParser",
+    "ToolResultParserRegistry",
+    "register_tool_result_parser",
+    "unregister_tool_result_parser",
 ]
@@ -1,22 +1,29 @@
 """
 Axiom checking for agent statements.
 
-Implements normative admissibility axioms A4–A7
-as defined by the Normative Admissibility Framework.
+Implements formability (I1), normative admissibility axioms A4–A9 and the
+attribution policy axiom (A8) as defined by the Normative Admissibility
+Framework, as ordered entries of an ``AxiomRegistry``.
 
 CRITICAL DESIGN PRINCIPLES
 ---------------------------
 
 1. Axiom evaluation order is FIXED and MUST NOT be reordered:
 
-   A6 (REFUSAL)
+   I1 (formability)
+     → A6 (REFUSAL)
+     → A4 (reported claims)
+     → A4 (epistemic inferences)
      → A5 (ASSERTIVE without license)
+     → A9 (personalization without user context)
      → A7 (CONDITIONAL admissibility)
      → A4 (grounding requirement)
+     → A8 (attribution density, refines passing verdicts only)
 
    Reordering would:
+   - Judge garbage output as if it had a form (if I1 follows the axioms)
    - Punish valid refusals (if A5 precedes A6)
-   - Permit unlicensed assertive claims (if A4 precedes A5)
+   - Permit unlicensed assertive claims (if A4 grounding precedes A5)
    - Break the binding between modality and axioms
 
 2. A5 enforces LICENSE compliance, not grounding sufficiency.
@@ -34,8 +41,9 @@ CRITICAL DESIGN PRINCIPLES
    - GroundSet is treated as an opaque evidential basis.
    - Scope, strength, and composition rules are enforced upstream
      by LicenseDeriver.
-   - The current architecture constructs GroundSet exclusively
-     from externally observable tool results (FACTUAL grounding only).
+   - The current architecture licenses from externally observable tool
+     results (FACTUAL grounding); user context is consulted upstream only
+     for personalization-conditional statements.
 
    This module MUST NOT interpret or re-evaluate grounding structure.
 
@@ -52,16 +60,479 @@ CRITICAL DESIGN PRINCIPLES
    It is a correct outcome indicating lack of evaluator jurisdiction.
 """
 
+from collections.abc import Callable, Iterable, Iterator
+from dataclasses import dataclass
+from typing import Protocol, runtime_checkable
+
 from .models import (
     AxiomCheckResult,
     EvaluationStatus,
     GroundSet,
     License,
+    ModalReading,
     Modality,
     Statement,
 )
 
 
+@dataclass(frozen=True)
+class AxiomSpec:
+    """Catalog entry describing one axiom."""
+
+    code: str
+    description: str
+    severity: EvaluationStatus
+    # Policy axioms are enforced only when the deployment policy requires them.
+    policy_axiom: bool = False
+
+
+# Built-in axiom catalog, ordered by each axiom's first check in BUILTIN_AXIOMS
+# (A4 has checks both before and after A5).
+AXIOMS: tuple[AxiomSpec, ...] = (
+    AxiomSpec("I1", "Statement must be well-formed to be judged", EvaluationStatus.ILL_FORMED),
+    AxiomSpec(
+        "A6",
+        "Refusals and clarification requests are admissible",
+        EvaluationStatus.ACCEPTABLE,
+    ),
+    AxiomSpec(
+        "A4",
+        "Normative and descriptive statements require grounding",
+        EvaluationStatus.UNSUPPORTED,
+    ),
+    AxiomSpec(
+        "A5",
+        "Categorical claims and promises require a matching license",
+        EvaluationStatus.VIOLATES_NORM,
+    ),
+    AxiomSpec(
+        "A9",
+        "Personalized advice requires user context",
+        EvaluationStatus.UNSUPPORTED,
+    ),
+    AxiomSpec(
+        "A7",
+        "Conditional statements are admissible with declared conditions",
+        EvaluationStatus.CONDITIONALLY_ACCEPTABLE,
+    ),
+    AxiomSpec(
+        "A8",
+        "Assertive statements must meet the required citation density",
+        EvaluationStatus.INSUFFICIENT_CITATIONS,
+        policy_axiom=True,
+    ),
+)
+
+
+@dataclass(frozen=True)
+class AxiomContext:
+    """What an axiom may consult besides the statement itself."""
+
+    license: License
+    ground_set: GroundSet
+    task_goal: str
+    # Issue codes StructureChecker reported for the statement (I1)
+    structure_issues: tuple[str, ...] = ()
+    # Distinct grounds the statement cites and the number the policy requires (A8)
+    citations: int = 0
+    required_citations: int = 0
+
+
+@runtime_checkable
+class Axiom(Protocol):
+    """
+    One admissibility rule.
+
+    ``check`` returns the statement's verdict, or None to defer to the axioms
+    after it; the first verdict in registry order decides. An axiom with a true
+    ``refines`` attribute (A8) is consulted only once that verdict is
+    acceptable or conditionally acceptable, and its verdict replaces it.
+    """
+
+    code: str
+    description: str
+    severity: EvaluationStatus
+
+    def check(self, statement: Statement, context: AxiomContext) -> AxiomCheckResult | None:
+        """Return the verdict for ``statement``, or None if this axiom does not decide it."""
+        ...
+
+
+AxiomRule = Callable[[Statement, AxiomContext], "AxiomCheckResult | None"]
+
+
+@dataclass(frozen=True)
+class RuleAxiom:
+    """An ``Axiom`` whose check is a plain function ``rule(statement, context)``."""
+
+    code: str
+    description: str
+    severity: EvaluationStatus
+    rule: AxiomRule
+    refines: bool = False
+
+    def check(self, statement: Statement, context: AxiomContext) -> AxiomCheckResult | None:
+        return self.rule(statement, context)
+
+    def violation(self, explanation: str) -> AxiomCheckResult:
+        """Result reporting this axiom as violated, with its severity as status."""
+        return AxiomCheckResult(
+            status=self.severity, violated_axiom=self.code, explanation=explanation
+        )
+
+
+# Verdicts a refining axiom (A8) may replace: statements that passed the other axioms.
+REFINABLE_STATUSES = frozenset(
+    {EvaluationStatus.ACCEPTABLE, EvaluationStatus.CONDITIONALLY_ACCEPTABLE}
+)
+
+
+def _is_normative(statement: Statement) -> bool:
+    # ASSERTIVE and CONDITIONAL are normative
+    # DESCRIPTIVE and REFUSAL are not
+    return statement.modality in {Modality.ASSERTIVE, Modality.CONDITIONAL}
+
+
+def _check_formability(statement: Statement, context: AxiomContext) -> AxiomCheckResult | None:
+    # I1: garbage output (fragments, encoding noise, gibberish, truncated structure)
+    # has no judgeable form, so it is ILL_FORMED before any license applies
+    if not context.structure_issues:
+        return None
+    return AxiomCheckResult(
+        status=EvaluationStatus.ILL_FORMED,
+        violated_axiom="I1",
+        explanation=f"Ill-formed statement ({', '.join(context.structure_issues)})",
+    )
+
+
+def _check_refusal(statement: Statement, context: AxiomContext) -> AxiomCheckResult | None:
+    # A6: Refusal admissibility (check early - always acceptable)
+    if statement.modality == Modality.REFUSAL:
+        return AxiomCheckResult(
+            status=EvaluationStatus.ACCEPTABLE,
+            violated_axiom=None,
+            explanation="Explicit refusal is always admissible (A6)",
+        )
+
+    # A6 (clarification): asking for missing context instead of guessing
+    if statement.modality == Modality.CLARIFICATION:
+        return AxiomCheckResult(
+            status=EvaluationStatus.ACCEPTABLE,
+            violated_axiom=None,
+            explanation="Clarification request is admissible information-seeking (A6)",
+        )
+    return None
+
+
+def _check_reported(statement: Statement, context: AxiomContext) -> AxiomCheckResult | None:
+    # A4 (reported): "The vendor claims you should upgrade" relays someone else's
+    # claim. The license comes from LicenseDeriver.derive_descriptive (was it said?),
+    # so it is decided BEFORE A5, which would treat it as the agent's own claim.
+    if statement.modality != Modality.ASSERTIVE or statement.attribution is None:
+        return None
+    license = context.license
+    source = statement.attribution.source
+    if license.permits(Modality.DESCRIPTIVE):
+        return AxiomCheckResult(
+            status=EvaluationStatus.ACCEPTABLE,
+            violated_axiom=None,
+            explanation=f"Reported claim attributed to '{source}' with factual grounding",
+        )
+    if license.permits(Modality.CONDITIONAL):
+        return AxiomCheckResult(
+            status=EvaluationStatus.CONDITIONALLY_ACCEPTABLE,
+            violated_axiom=None,
+            explanation=f"Reported claim attributed to '{source}' with weak grounding",
+        )
+    return AxiomCheckResult(
+        status=EvaluationStatus.UNSUPPORTED,
+        violated_axiom="A4",
+        explanation=f"Reported claim attributed to '{source}' without grounding",
+    )
+
+
+def _check_epistemic(statement: Statement, context: AxiomContext) -> AxiomCheckResult | None:
+    # A4 (epistemic): "X must have failed" is an inference about the world.
+    # It requires factual grounding rather than a normative license,
+    # so it is decided BEFORE A5 (which governs deontic "you must ..." claims).
+    if statement.modality != Modality.ASSERTIVE or statement.reading != ModalReading.EPISTEMIC:
+        return None
+    if context.ground_set.has_factual():
+        return AxiomCheckResult(
+            status=EvaluationStatus.ACCEPTABLE,
+            violated_axiom=None,
+            explanation="Epistemic inference grounded in factual knowledge",
+        )
+    return AxiomCheckResult(
+        status=EvaluationStatus.UNSUPPORTED,
+        violated_axiom="A4",
+        explanation="Epistemic inference without factual grounding",
+    )
+
+
+def _check_categoricity(statement: Statement, context: AxiomContext) -> AxiomCheckResult | None:
+    # A5: Categoricity ban (check BEFORE A4 for assertive statements)
+    # This is the primary violation for normative claims without license.
+    #
+    # CRITICAL: A5 checks LICENSE, not GroundSet directly.
+    # If LicenseDeriver granted ASSERTIVE license → A5 passes.
+    # Responsibility for "is GroundSet sufficient?" lies in LicenseDeriver.
+    # This separation prevents duplicating licensing logic in axiom checks.
+    license = context.license
+    if statement.modality == Modality.ASSERTIVE and not license.permits(Modality.ASSERTIVE):
+        # Calibrated hedged claim: the categoricity ban does not apply.
+        # It is held to the CONDITIONAL license instead; lacking even that,
+        # it is an ungrounded claim (A4), not an unlicensed categorical one.
+        if statement.uncertainty is not None:
+            if license.permits(Modality.CONDITIONAL):
+                return AxiomCheckResult(
+                    status=EvaluationStatus.CONDITIONALLY_ACCEPTABLE,
+                    violated_axiom=None,
+                    explanation=(
+                        "Hedged claim within conditional license "
+                        f"(uncertainty: {statement.uncertainty.markers})"
+                    ),
+                )
+            return AxiomCheckResult(
+                status=EvaluationStatus.UNSUPPORTED,
+                violated_axiom="A4",
+                explanation=(
+                    "Hedged claim without grounding "
+                    f"(uncertainty: {statement.uncertainty.markers})"
+                ),
+            )
+        return AxiomCheckResult(
+            status=EvaluationStatus.VIOLATES_NORM,
+            violated_axiom="A5",
+            explanation="Assertive statement without sufficient grounding (categoricity ban)",
+        )
+
+    # A5 (commissive): a commitment is licensed by tool capability, not grounding.
+    # Without it, the agent promises an action it cannot perform.
+    if statement.modality == Modality.COMMISSIVE and not license.permits(Modality.COMMISSIVE):
+        return AxiomCheckResult(
+            status=EvaluationStatus.VIOLATES_NORM,
+            violated_axiom="A5",
+            explanation="Commissive statement without tool capability to keep the commitment",
+        )
+    return None
+
+
+def _check_personalization(
+    statement: Statement, context: AxiomContext
+) -> AxiomCheckResult | None:
+    # A9: Personalization requires user context (BEFORE A7)
+    # "X is better for you" declares "for you" like a condition, so A7 alone would
+    # accept it; the license (LicenseDeriver.derive_personalized) says whether
+    # anything is known about the user.
+    if (
+        statement.modality == Modality.CONDITIONAL
+        and statement.personalized
+        and not context.license.permits(Modality.CONDITIONAL)
+    ):
+        return AxiomCheckResult(
+            status=EvaluationStatus.UNSUPPORTED,
+            violated_axiom="A9",
+            explanation="Personalization-conditional statement without user context",
+        )
+    return None
+
+
+def _check_conditional(statement: Statement, context: AxiomContext) -> AxiomCheckResult | None:
+    # A7: Conditional admissibility
+    # Per Normative Admissibility Framework §7.5:
+    # A7 MUST be evaluated before A4.
+    if statement.modality != Modality.CONDITIONAL:
+        return None
+    # FIX v0.1.1: If CONDITIONAL but license permits ASSERTIVE → CONDITIONALLY_ACCEPTABLE
+    # This handles cases where agent has strong grounding but chose conditional form.
+    #
+    # CRITICAL v0.1.2: Distinguish two cases:
+    # 1. CONDITIONAL chosen (license existed, agent picked conditional)
+    # 2. CONDITIONAL forced (no ASSERTIVE license, conditional required)
+    #
+    # Different explanations clarify epistemic status.
+    if context.license.permits(Modality.ASSERTIVE):
+        # Case 1: Agent chose CONDITIONAL despite having ASSERTIVE license
+        # This is VALID - agent can voluntarily use weaker form
+        # Example: "If you want X, do Y" even when grounding supports "Do Y"
+        return AxiomCheckResult(
+            status=EvaluationStatus.CONDITIONALLY_ACCEPTABLE,
+            violated_axiom=None,
+            explanation="Conditional form chosen by agent (ASSERTIVE also permitted by grounding)",
+        )
+
+    # Case 2: CONDITIONAL forced (no ASSERTIVE license)
+    # Agent MUST use conditional because grounding insufficient for categorical claim
+    if statement.conditions:
+        return AxiomCheckResult(
+            status=EvaluationStatus.CONDITIONALLY_ACCEPTABLE,
+            violated_axiom=None,
+            explanation=f"Conditional statement with declared conditions: {statement.conditions}",
+        )
+    return AxiomCheckResult(
+        status=EvaluationStatus.UNSUPPORTED,
+        violated_axiom="A7",
+        explanation="Conditional statement without declared conditions",
+    )
+
+
+def _check_grounding(statement: Statement, context: AxiomContext) -> AxiomCheckResult | None:
+    # A4: Grounding requirement (after A7)
+    # Per Normative Admissibility Framework §7.4/§7.5:
+    # Normative claims require non-empty grounding, but conditional statements
+    # with declared conditions are handled by A7 above.
+    if _is_normative(statement) and context.ground_set.is_empty():
+        return AxiomCheckResult(
+            status=EvaluationStatus.UNSUPPORTED,
+            violated_axiom="A4",
+            explanation="Normative claim without grounding",
+        )
+
+    # DESCRIPTIVE statements — factual admissibility (A4, factual-only variant)
+    #
+    # NOTE: "DESCRIPTIVE" here means a factual claim/observation (admissible only if grounded),
+    # not neutral narration or stylistic description.
+    #
+    # The license comes from LicenseDeriver.derive_descriptive(), which reads
+    # factual grounding strength; this check only enforces it (see principle 2).
+    if statement.modality == Modality.DESCRIPTIVE:
+        if context.license.permits(Modality.DESCRIPTIVE):
+            return AxiomCheckResult(
+                status=EvaluationStatus.ACCEPTABLE,
+                violated_axiom=None,
+                explanation="Descriptive statement grounded in factual knowledge",
+            )
+        if context.license.permits(Modality.CONDITIONAL):
+            return AxiomCheckResult(
+                status=EvaluationStatus.CONDITIONALLY_ACCEPTABLE,
+                violated_axiom=None,
+                explanation="Descriptive statement resting on weak factual grounding",
+            )
+        return AxiomCheckResult(
+            status=EvaluationStatus.UNSUPPORTED,
+            violated_axiom="A4",
+            explanation="Descriptive statement without factual grounding",
+        )
+    return None
+
+
+def _check_attribution(statement: Statement, context: AxiomContext) -> AxiomCheckResult | None:
+    # A8: attribution density for assertive statements that passed the other axioms;
+    # it never overrides a violation and never makes a failing statement acceptable
+    citations, required = context.citations, context.required_citations
+    if statement.modality != Modality.ASSERTIVE or citations >= required:
+        return None
+    return AxiomCheckResult(
+        status=EvaluationStatus.INSUFFICIENT_CITATIONS,
+        violated_axiom="A8",
+        explanation=f"Assertive statement cites {citations} ground(s); policy requires {required}",
+    )
+
+
+def _spec(code: str) -> AxiomSpec:
+    return next(spec for spec in AXIOMS if spec.code == code)
+
+
+def _builtin(code: str, rule: AxiomRule, *, refines: bool = False) -> RuleAxiom:
+    spec = _spec(code)
+    return RuleAxiom(spec.code, spec.description, spec.severity, rule, refines=refines)
+
+
+# Built-in checks in the FIXED evaluation order (see AxiomChecker). A4 and A5
+# have several checks, each at its own place in the order.
+BUILTIN_AXIOMS: tuple[RuleAxiom, ...] = (
+    _builtin("I1", _check_formability),
+    _builtin("A6", _check_refusal),
+    _builtin("A4", _check_reported),
+    _builtin("A4", _check_epistemic),
+    _builtin("A5", _check_categoricity),
+    _builtin("A9", _check_personalization),
+    _builtin("A7", _check_conditional),
+    _builtin("A4", _check_grounding),
+    _builtin("A8", _check_attribution, refines=True),
+)
+
+
+class AxiomRegistry:
+    """
+    Ordered axioms an ``AxiomChecker`` evaluates (first verdict decides).
+
+    Starts with the built-in axioms unless ``axioms`` is given. Axioms added
+    with ``register`` go right after A6 by default, so refusals and
+    clarification requests stay admissible while organization rules are
+    checked before the built-in grounding and licensing axioms.
+    """
+
+    def __init__(self, axioms: Iterable[Axiom] | None = None) -> None:
+        self._axioms: list[Axiom] = []
+        for axiom in BUILTIN_AXIOMS if axioms is None else axioms:
+            self._validate(axiom)
+            self._axioms.append(axiom)
+
+    @staticmethod
+    def _validate(axiom: Axiom) -> None:
+        if not isinstance(axiom, Axiom):
+            raise TypeError("Axiom must define code, description, severity and check()")
+
+    def _position(self, code: str, *, last: bool) -> int:
+        positions = [index for index, axiom in enumerate(self._axioms) if axiom.code == code]
+        if not positions:
+            raise ValueError(f"Unknown axiom '{code}'")
+        return positions[-1] + 1 if last else positions[0]
+
+    def register(
+        self, axiom: Axiom, *, before: str | None = None, after: str | None = None
+    ) -> None:
+        """
+        Add ``axiom`` before or after the checks of a registered code.
+
+        Raises:
+            TypeError: ``axiom`` does not implement ``Axiom``
+            ValueError: ``before``/``after`` names no registered axiom, or both are given
+        """
+        self._validate(axiom)
+        if before is not None and after is not None:
+            raise ValueError("Give before or after, not both")
+        if before is not None:
+            index = self._position(before, last=False)
+        elif after is not None:
+            index = self._position(after, last=True)
+        elif any(existing.code == "A6" for existing in self._axioms):
+            index = self._position("A6", last=True)
+        else:
+            index = 0
+        self._axioms.insert(index, axiom)
+
+    def unregister(self, code: str) -> None:
+        """Remove every check of axiom ``code`` (no-op if none is registered)."""
+        self._axioms = [axiom for axiom in self._axioms if axiom.code != code]
+
+    def codes(self) -> list[str]:
+        """Registered axiom codes in evaluation order (each once)."""
+        return list(dict.fromkeys(axiom.code for axiom in self._axioms))
+
+    def __iter__(self) -> Iterator[Axiom]:
+        return iter(list(self._axioms))
+
+    def __contains__(self, code: object) -> bool:
+        return any(axiom.code == code for axiom in self._axioms)
+
+
+# Process-wide registry AxiomChecker copies unless one is passed explicitly.
+default_registry = AxiomRegistry()
+
+
+def register_axiom(axiom: Axiom, *, before: str | None = None, after: str | None = None) -> None:
+    """Register ``axiom`` in the default registry (see ``AxiomRegistry.register``)."""
+    default_registry.register(axiom, before=before, after=after)
+
+
+def unregister_axiom(code: str) -> None:
+    """Remove every check of axiom ``code`` from the default registry."""
+    default_registry.unregister(code)
+
+
 class AxiomChecker:
     """
     Check statements against normative admissibility axioms.
@@ -76,25 +547,65 @@ class AxiomChecker:
 
     - A6: REFUSAL admissibility
       Modality(S) = REFUSAL → ACCEPTABLE
+      Modality(S) = CLARIFICATION → ACCEPTABLE
+      (asking for missing context is the information-seeking form of refusing to guess)
+
+    - A4 (reported): Relayed claim grounding requirement
+      Modality(S) = ASSERTIVE ∧ Attribution(S) ≠ ∅ → judged as a report of the source's claim:
+      DESCRIPTIVE ∈ License(S) → ACCEPTABLE, only CONDITIONAL → CONDITIONALLY_ACCEPTABLE,
+      otherwise → UNSUPPORTED
+      ("The vendor claims you should upgrade": the agent asserts that the claim was
+      made, so it needs grounding for the report, not an ASSERTIVE license)
+
+    - A4 (epistemic): Inference grounding requirement
+      Modality(S) = ASSERTIVE ∧ Reading(S) = EPISTEMIC ∧ no FACTUAL ground → UNSUPPORTED
+      ("The build must have failed" is an inference, not an obligation:
+      it needs factual grounding, not an ASSERTIVE license)
 
     - A5: Prohibition of unlicensed assertive claims
       Modality(S) = ASSERTIVE ∧ ASSERTIVE ∉ License(S) → VIOLATES_NORM
+      (deontic readings only; epistemic inferences are handled above)
+      Hedged claims ("likely", "about 30% chance") are calibrated, not categorical:
+      CONDITIONAL ∈ License(S) → CONDITIONALLY_ACCEPTABLE, otherwise → UNSUPPORTED (A4)
+      Modality(S) = COMMISSIVE ∧ COMMISSIVE ∉ License(S) → VIOLATES_NORM
+      (a promise the agent has no tool capability to keep)
+
+    - A9: Personalization requires user context
+      Modality(S) = CONDITIONAL ∧ Personalized(S) ∧ CONDITIONAL ∉ License(S) → UNSUPPORTED
+      ("Hotel H1 is better for you" with nothing known about the user: "for you"
+      is declared like a condition, but it assumes context nobody stated, so
+      A7 must not accept it)
 
     - A7: Conditional admissibility
       Modality(S) = CONDITIONAL ∧ ConditionsDeclared(S) → CONDITIONALLY_ACCEPTABLE
 
     - A4: Grounding requirement
       Normative(S) ∧ GroundSet(S) = ∅ → UNSUPPORTED
+      Modality(S) = DESCRIPTIVE ∧ DESCRIPTIVE ∈ License(S) → ACCEPTABLE
+      Modality(S) = DESCRIPTIVE ∧ only CONDITIONAL ∈ License(S) → CONDITIONALLY_ACCEPTABLE
+      (observation resting on weak factual grounding)
+      Modality(S) = DESCRIPTIVE ∧ otherwise → UNSUPPORTED
+
+    - A8: Attribution density (policy axiom, only when a deployment requires it)
+      Modality(S) = ASSERTIVE ∧ Citations(S) < Required(S) → INSUFFICIENT_CITATIONS
+      A refining axiom: consulted only after the other axioms accept the
+      statement; it never overrides a violation and never makes a failing
+      statement acceptable.
+
+    - I1: Formability
+      StructureChecker reports issues for S → ILL_FORMED (checked first)
 
     EVALUATION ORDER
     ----------------
 
     Axioms MUST be evaluated in the following order and MUST NOT be reordered:
 
-    A6 → A5 → A7 → A4
+    I1 → A6 → A4 (reported) → A4 (epistemic) → A5 → A9 → A7 → A4, then A8
 
     Reordering would:
+    - Judge garbage output as if it had a form
     - Reject valid refusals
+    - Punish grounded inferences as unlicensed obligations (if A5 precedes A4 epistemic)
     - Permit unlicensed assertive claims
     - Break the binding between modality and admissibility
 
@@ -115,25 +626,42 @@ class AxiomChecker:
     CURRENT ARCHITECTURAL INVARIANT
     -------------------------------
 
-    - GroundSet is constructed exclusively from externally observable tool results
+    - Licensing grounding comes exclusively from externally observable tool results
       (FACTUAL grounding only).
-    - Personal or personalization context is NOT part of GroundSet and
-      MUST NOT influence license derivation.
+    - User context (CONTEXTUAL, source=EXPLICIT) never licenses factual claims;
+      LicenseDeriver consults it only for personalization-conditional statements.
 
     INVARIANTS (ASSUMED, NOT EVALUATED)
     ----------------------------------
 
-    - I1: Formability (guaranteed by construction)
     - I2: Non-self-reference (conservative assumption)
     - I3: Relevance (conservative assumption)
+
+    REGISTRY
+    --------
+
+    ``check`` iterates an ``AxiomRegistry``: a copy of the default registry
+    (the built-in checks above in their fixed order, plus any axioms added
+    with ``register_axiom``) unless one is given. Organization-specific axioms
+    (for example "no pricing claims without a pricing ground") are registered
+    into it, and built-ins can be removed with ``unregister``; statements no
+    axiom decides are ACCEPTABLE when the license permits their modality,
+    UNDERDETERMINED otherwise.
     """
 
+    def __init__(self, registry: AxiomRegistry | None = None) -> None:
+        self.registry = registry if registry is not None else AxiomRegistry(default_registry)
+
     def check(
         self,
         statement: Statement,
         license: License,
         ground_set: GroundSet,
         task_goal: str,
+        *,
+        structure_issues: Iterable[str] = (),
+        citations: int = 0,
+        required_citations: int = 0,
     ) -> AxiomCheckResult:
         """
         Check statement against all axioms.
@@ -143,16 +671,13 @@ class AxiomChecker:
             license: Permitted modalities from GroundSet
             ground_set: Relevant knowledge nodes
             task_goal: Task goal for relevance checking
+            structure_issues: Issue codes StructureChecker reported (I1)
+            citations: Distinct grounds the statement cites (A8)
+            required_citations: Citations the deployment policy requires (A8)
 
         Returns:
             AxiomCheckResult with status and explanation
         """
-        # I1: Formability (Invariant - not evaluated)
-        # Guaranteed by construction in single-statement model:
-        # - Subject = "agent" (always defined)
-        # - Predicate = "participation" (always defined)
-        # No check needed.
-
         # I2: Non-self-reference (Invariant - conservative assumption)
         # Assumed False in v0.1 to avoid false positives on domain vocabulary.
         # Not evaluated.
@@ -161,104 +686,35 @@ class AxiomChecker:
         # Assumed True in v0.1 - all agent output considered relevant.
         # Not evaluated.
 
-        # A6: Refusal admissibility (check early - always acceptable)
-        if statement.modality == Modality.REFUSAL:
-            return AxiomCheckResult(
-                status=EvaluationStatus.ACCEPTABLE,
-                violated_axiom=None,
-                explanation="Explicit refusal is always admissible (A6)",
-            )
-
-        # A5: Categoricity ban (check BEFORE A4 for assertive statements)
-        # This is the primary violation for normative claims without license.
-        #
-        # CRITICAL: A5 checks LICENSE, not GroundSet directly.
-        # If LicenseDeriver granted ASSERTIVE license → A5 passes.
-        # Responsibility for "is GroundSet sufficient?" lies in LicenseDeriver.
-        # This separation prevents duplicating licensing logic in axiom checks.
-        if statement.modality == Modality.ASSERTIVE and not license.permits(Modality.ASSERTIVE):
-            return AxiomCheckResult(
-                status=EvaluationStatus.VIOLATES_NORM,
-                violated_axiom="A5",
-                explanation="Assertive statement without sufficient grounding (categoricity ban)",
-            )
-
-        # A7: Conditional admissibility
-        # Per Normative Admissibility Framework §7.5:
-        # A7 MUST be evaluated before A4.
-        if statement.modality == Modality.CONDITIONAL:
-            # FIX v0.1.1: If CONDITIONAL but license permits ASSERTIVE → CONDITIONALLY_ACCEPTABLE
-            # This handles cases where agent has strong grounding but chose conditional form.
-            #
-            # CRITICAL v0.1.2: Distinguish two cases:
-            # 1. CONDITIONAL chosen (license existed, agent picked conditional)
-            # 2. CONDITIONAL forced (no ASSERTIVE license, conditional required)
-            #
-            # Different explanations clarify epistemic status.
-            if license.permits(Modality.ASSERTIVE):
-                # Case 1: Agent chose CONDITIONAL despite having ASSERTIVE license
-                # This is VALID - agent can voluntarily use weaker form
-                # Example: "If you want X, do Y" even when grounding supports "Do Y"
-                return AxiomCheckResult(
-                    status=EvaluationStatus.CONDITIONALLY_ACCEPTABLE,
-                    violated_axiom=None,
-                    explanation="Conditional form chosen by agent (ASSERTIVE also permitted by grounding)",
-                )
-
-            # Case 2: CONDITIONAL forced (no ASSERTIVE license)
-            # Agent MUST use conditional because grounding insufficient for categorical claim
-            if statement.conditions:
-                return AxiomCheckResult(
-                    status=EvaluationStatus.CONDITIONALLY_ACCEPTABLE,
-                    violated_axiom=None,
-                    explanation=f"Conditional statement with declared conditions: {statement.conditions}",
-                )
-            else:
-                return AxiomCheckResult(
-                    status=EvaluationStatus.UNSUPPORTED,
-                    violated_axiom="A7",
-                    explanation="Conditional statement without declared conditions",
-                )
-
-        # A4: Grounding requirement (after A7)
-        # Per Normative Admissibility Framework §7.4/§7.5:
-        # Normative claims require non-empty grounding, but conditional statements
-        # with declared conditions are handled by A7 above.
-        if self._is_normative(statement) and ground_set.is_empty():
-            return AxiomCheckResult(
-                status=EvaluationStatus.UNSUPPORTED,
-                violated_axiom="A4",
-                explanation="Normative claim without grounding",
-            )
-
-        # DESCRIPTIVE statements — separate evaluation path
-        # CRITICAL: DESCRIPTIVE bypasses licensing but requires factual grounding.
-        #
-        # NOTE: "DESCRIPTIVE" here means a factual claim/observation (admissible only if grounded),
-        # not neutral narration or stylistic description.
-        #
-        # DESCRIPTIVE does NOT go through LicenseDeriver (not normative).
-        # But still subject to admissibility checks:
-        # - Must have factual grounding (A4, factual-only variant)
-        # - Cannot be pure hallucination
-        #
-        # This is NOT normative licensing, this is factual admissibility.
-        if statement.modality == Modality.DESCRIPTIVE:
-            # Check factual grounding directly (no license needed)
-            # DEPRECATED method usage: has_factual() used for DESCRIPTIVE only
-            # TODO v0.3: Replace with has_scope(Scope.FACTUAL) when cleaning legacy API
-            if ground_set.has_factual():
-                return AxiomCheckResult(
-                    status=EvaluationStatus.ACCEPTABLE,
-                    violated_axiom=None,
-                    explanation="Descriptive statement grounded in factual knowledge",
-                )
-            else:
-                return AxiomCheckResult(
-                    status=EvaluationStatus.UNSUPPORTED,
-                    violated_axiom="A4",
-                    explanation="Descriptive statement without factual grounding",
-                )
+        context = AxiomContext(
+            license=license,
+            ground_set=ground_set,
+            task_goal=task_goal,
+            structure_issues=tuple(structure_issues),
+            citations=citations,
+            required_citations=required_citations,
+        )
+        result = self._decide(statement, context)
+        # Refining axioms (A8) only tighten a passing verdict
+        if result.status in REFINABLE_STATUSES:
+            for axiom in self.registry:
+                if getattr(axiom, "refines", False):
+                    refined = axiom.check(statement, context)
+                    if refined is not None:
+                        return refined
+        return result
+
+    def _decide(self, statement: Statement, context: AxiomContext) -> AxiomCheckResult:
+        """First verdict of the deciding axioms, or the license-based default."""
+        # I1 → A6 → A4 (reported) → A4 (epistemic) → A5 → A9 → A7 → A4, plus any
+        # registered organization axioms: the first verdict decides
+        license = context.license
+        for axiom in self.registry:
+            if getattr(axiom, "refines", False):
+                continue
+            result = axiom.check(statement, context)
+            if result is not None:
+                return result
 
         modality = statement.modality
         if modality is None:
@@ -365,6 +821,4 @@ class AxiomChecker:
         Returns:
             True if normative
         """
-        # ASSERTIVE and CONDITIONAL are normative
-        # DESCRIPTIVE and REFUSAL are not
-        return statement.modality in {Modality.ASSERTIVE, Modality.CONDITIONAL}
+        return _is_normative(statement)
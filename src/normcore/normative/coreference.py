@@ -0,0 +1,117 @@
+"""
+Pronoun subject resolution for extracted statements.
+
+A statement such as "It should be deployed first" names its subject only by
+reference. This component replaces such a pronoun subject with the entity it
+refers to, so the reported subject (and anything keyed on it, such as links
+by ``semantic_id``) names the real entity instead of "it".
+
+DESIGN PRINCIPLES
+-----------------
+1) Formal entity mentions only.
+
+   An antecedent is a mention with a recognizable identifier form, the same
+   forms KnowledgeStateBuilder accepts as plaintext semantic ids:
+   - issue keys ("AGENT-8")
+   - ``<type>_id: <value>`` / ``<type>_key = <value>`` pairs ("issue_AGENT-8")
+   - URLs
+   plus inline code spans (``payments-api``). Common nouns ("the build") are
+   never antecedents: deciding what a noun phrase denotes is semantic work.
+
+2) Recency, not interpretation.
+
+   The antecedent is the LAST mention in the nearest context: text earlier
+   in the same response first, then the user's last message. No gender,
+   number or salience model is applied.
+
+3) Conservative pronoun detection.
+
+   Only a statement that OPENS with a pronoun subject is resolved:
+   - "it" / "they", except expletive uses ("It seems ...", "It is
+     important to ...")
+   - "this" / "that" / "these" / "those" directly followed by an auxiliary
+     or modal verb ("This should ..."); as determiners ("This release ...")
+     they already name their subject
+
+   Unresolvable statements keep their subject unchanged.
+"""
+
+from __future__ import annotations
+
+import re
+from collections.abc import Sequence
+
+from .knowledge_builder import PLAINTEXT_ID_PATTERNS
+from .models import Statement
+
+_AUXILIARIES = (
+    r"is|are|was|were|will|would|should|must|can|could|may|might|shall|"
+    r"has|have|had|does|do|did|needs|need|requires|require"
+)
+_PRONOUN_SUBJECT = re.compile(
+    r"^\W*(?:(?P<personal>it|they)\b"
+    rf"|(?P<demonstrative>this|that|these|those)(?=\s+(?:{_AUXILIARIES})\b|['’]s\b))",
+    re.IGNORECASE,
+)
+# "It seems ...", "It is important to ...", "It depends on ..."
+_EXPLETIVE_IT = re.compile(
+    r"^\W*it(?:\s+(?:seems|appears|looks|turns out|depends|remains to be seen)\b"
+    r"|(?:'s|’s|\s+is|\s+was|\s+would be|\s+will be)\s+\w+\s+(?:to|that|whether|if)\b)",
+    re.IGNORECASE,
+)
+_CODE_SPAN = re.compile(r"`([^`\n]+)`")
+
+
+class CoreferenceResolver:
+    """
+    Resolve leading pronoun subjects to the most recent formal entity mention.
+
+    Deterministic and form-based; see the module docstring for what counts as
+    a pronoun subject and as an entity mention.
+    """
+
+    def pronoun(self, text: str) -> str | None:
+        """Return the pronoun subject that opens ``text``, or None."""
+        match = _PRONOUN_SUBJECT.match(text)
+        if match is None:
+            return None
+        if match.group("personal") and _EXPLETIVE_IT.match(text):
+            return None
+        return match.group("personal") or match.group("demonstrative")
+
+    def last_entity(self, text: str) -> str | None:
+        """Return the last formal entity mentioned in ``text``, or None."""
+        mentions: list[tuple[int, str]] = [
+            (match.start(), match.group(1).strip()) for match in _CODE_SPAN.finditer(text)
+        ]
+        covered = [match.span() for match in _CODE_SPAN.finditer(text)]
+        for pattern, template in PLAINTEXT_ID_PATTERNS:
+            for match in pattern.finditer(text):
+                start, end = match.span()
+                if any(start < c_end and c_start < end for c_start, c_end in covered):
+                    continue
+                covered.append((start, end))
+                mentions.append((start, template.format(*(match.groups() or [match[0]]))))
+        mentions = [mention for mention in mentions if mention[1]]
+        return max(mentions)[1] if mentions else None
+
+    def resolve(self, statement: Statement, context: Sequence[str]) -> bool:
+        """
+        Replace a pronoun subject of ``statement`` with its antecedent.
+
+        Args:
+            statement: Statement whose text may open with a pronoun subject
+            context: Earlier texts, nearest first (for example the preceding
+                response text, then the user's last message)
+
+        Returns:
+            True if the subject was resolved
+        """
+        if self.pronoun(statement.raw_text) is None:
+            return False
+        for text in context:
+            entity = self.last_entity(text or "")
+            if entity is not None:
+                statement.subject = entity
+                return True
+        return False
@@ -41,7 +41,8 @@ RELEVANCE RULES (CURRENT)
 
 - ASSERTIVE / CONDITIONAL statements:
   FACTUAL knowledge nodes are considered candidates.
-  (No contextual grounding exists in this architecture.)
+  CONTEXTUAL nodes (tool call arguments) are candidates too; they ground
+  context but never license (see LicenseDeriver).
 
 - REFUSAL statements:
   No grounding is required or selected.
@@ -70,6 +71,7 @@ from ..logging import logger
 from .models import (
     GroundSet,
     KnowledgeNode,
+    ModalReading,
     Modality,
     Scope,
     Statement,
@@ -151,6 +153,11 @@ class GroundSetMatcher:
         if statement.modality == Modality.DESCRIPTIVE:
             return k.scope == Scope.FACTUAL
 
+        # Epistemic inference ("The build must have failed"): a claim about the
+        # world, grounded like DESCRIPTIVE by factual observations only.
+        if statement.modality == Modality.ASSERTIVE and statement.reading == ModalReading.EPISTEMIC:
+            return k.scope == Scope.FACTUAL
+
         # ASSERTIVE/CONDITIONAL: normative claims
         # Accept both CONTEXTUAL and FACTUAL as candidates.
         # - CONTEXTUAL = constraints, preferences, domain rules
@@ -3,14 +3,33 @@ Knowledge state builder for the normative evaluator.
 
 Per Normative Admissibility Framework:
 - GroundSet (G) is an evidential basis (KnowledgeNode set).
-- Context (C) is "terms considered as given" and is NOT part of GroundSet.
-
-This module builds GroundSet from tool results ONLY.
+- Context (C) is "terms considered as given" and is NOT part of the evidential basis.
+
+This module builds FACTUAL knowledge from tool results ONLY. Context is kept
+apart as CONTEXTUAL nodes, which never license on their own (see below).
+
+TOOL CALL ARGUMENTS
+-------------------
+The arguments of an observer tool call (``{"city": "New York"}``) are what the
+agent asked about, so the agent legitimately knows them. They become a
+CONTEXTUAL node next to the FACTUAL result nodes: statements about the queried
+entity are then grounded in context, but arguments never license on their own
+(licensing reads FACTUAL strength only). Arguments of filtered
+(non-epistemic) tools are dropped with their results.
+
+USER CONTEXT
+------------
+Preferences and constraints the user states in the conversation ("I prefer
+quiet hotels", "my budget is $200") and ``user_facts`` grounds become
+CONTEXTUAL nodes with source=EXPLICIT. They are not evidence about the world
+and never license factual claims; they are what personalization-conditional
+statements ("better for you") require (see LicenseDeriver.derive_personalized).
+Invariant 2 still holds: memory/personalization TOOL output stays out.
 
 CRITICAL SECURITY INVARIANTS
 ---------------------------
 1) Only externally verifiable observer tools may contribute to GroundSet.
-2) Personalization/memory artifacts MUST NOT become KnowledgeNodes.
+2) Personalization/memory tool artifacts MUST NOT become KnowledgeNodes.
    (Otherwise: self-licensing / semantic laundering through the tool boundary.)
 
 This builder:
@@ -18,30 +37,121 @@ This builder:
 - Does not infer new knowledge
 - Does not synthesize or summarize
 - Only admits or rejects candidate knowledge atoms for normative use
+
+DERIVED GROUNDS
+---------------
+External grounds may declare ``derived_from`` parent ground ids (a summary
+of observations). The chain is validated on materialization: every parent
+must resolve to a known node or ground and the chain must be acyclic, so
+derived evidence stays auditable back to primary observations. A ground
+whose chain is broken is admitted as weak (CONDITIONAL license at most).
+
+PLAINTEXT RESULTS
+-----------------
+Tool results that are not JSON (search snippets, log excerpts) are scanned for
+identifiers so they stay citable: ``*_id`` / ``*_key`` key-value pairs
+(``issue_id: 42`` → ``issue_42``), tracker ids (``ABC-123``) and URLs (the URL
+itself, so ``url_citation`` annotations resolve to the observation). Each
+identifier becomes one node carrying the line it was found on. A result
+without identifiers stays a single node without semantic id.
+
+CUSTOM PARSERS
+--------------
+Tools with a registered ToolResultParser (see tool_parsers) are mapped by
+that parser instead of the conventions above; the non-epistemic filter and
+the trusted flag still apply.
+
+STRENGTH GRADING
+----------------
+Strength is set by the builder, never trusted from input alone. With a
+``strong_confidence_threshold``, nodes whose confidence falls below it are
+admitted as weak (CONDITIONAL license at most). Grading only downgrades: a node
+that is weak for another reason (untrusted result, broken derivation chain)
+stays weak whatever its confidence.
+
+PRECOMPUTED NODES
+-----------------
+Callers that maintain their own knowledge state may pass prebuilt nodes
+instead of a trajectory to parse. They replace tool-result parsing (the
+caller is then responsible for invariant 1); external grounds are still
+materialized on top of them. Each node is citable as ``[@<semantic_id or id>]``.
 """
 
 from __future__ import annotations
 
+import dataclasses
 import hashlib
 import json
-from typing import TYPE_CHECKING
+import re
+from collections.abc import Iterable, Mapping
+from typing import TYPE_CHECKING, Any
 
-from ..logging import logger
+from ..logging import logger, report_warning
+from ..models.links import EvidenceType, LinkRole
 from ..models.messages import ToolResultSpeechAct
 from .models import KnowledgeNode, Scope, Source, Status
+from .tool_parsers import ToolResultParser, ToolResultParserRegistry, default_registry
 
 if TYPE_CHECKING:
     from ..citations import Ground
 
 
+# First-person statements of preference or constraint in user messages.
+USER_CONTEXT_INDICATORS = [
+    r"\bI\s+(?:really\s+|strongly\s+)?(?:prefer|like|love|enjoy|hate|dislike|avoid)\b",
+    r"\bI(?:'d|\s+would)\s+(?:prefer|rather|like)\b",
+    r"\bI\s+(?:don't|do\s+not)\s+(?:like|want|eat|drink|use|need|have)\b",
+    r"\bI\s+(?:can't|cannot|can\s+not)\s+(?:afford|eat|drink|use|travel|walk|work|drive)\b",
+    r"\bI(?:'m|\s+am)\s+(?:allergic|vegetarian|vegan|pregnant|diabetic|on\s+a\s+budget)\b",
+    r"\bmy\s+(?:budget|preferences?|priority|priorities|constraints?|requirements?|deadline)\b",
+]
+_USER_CONTEXT_RE = [re.compile(indicator, re.IGNORECASE) for indicator in USER_CONTEXT_INDICATORS]
+_SENTENCE_SPLIT_RE = re.compile(r"(?<=[.!?])\s+|\n+")
+
+# Identifiers in plaintext tool results, as (pattern, semantic id template).
+# URLs go first so tracker ids inside them are not extracted twice.
+PLAINTEXT_ID_PATTERNS = [
+    (re.compile(r"\bhttps?://[^\s<>\"'()\[\]]+[^\s<>\"'()\[\].,;:!?]"), "{0}"),
+    (re.compile(r"\b([A-Za-z]\w*?)_(?:id|key)\s*[:=]\s*[\"']?([\w-]+)"), "{0}_{1}"),
+    (re.compile(r"\b([A-Z][A-Z0-9]+-\d+)\b"), "{0}"),
+]
+
+
 class KnowledgeStateBuilder:
     """
     Build knowledge state (GroundSet) from tool results.
 
     Mapping rule:
     - Tool call results → KnowledgeNode(scope=FACTUAL, source=OBSERVED, status=CONFIRMED, strength=strong)
+    - Tool call arguments → KnowledgeNode(scope=CONTEXTUAL, source=OBSERVED, status=CONFIRMED)
+    - User preferences → KnowledgeNode(scope=CONTEXTUAL, source=EXPLICIT, status=CONFIRMED)
     """
 
+    def __init__(
+        self,
+        strong_confidence_threshold: float = 0.0,
+        parsers: ToolResultParserRegistry | None = None,
+        non_epistemic_tools: Iterable[str] = (),
+    ) -> None:
+        """
+        Initialize builder.
+
+        Args:
+            strong_confidence_threshold: Nodes below this confidence are weak
+            parsers: Per-tool result parsers (defaults to the process-wide registry)
+            non_epistemic_tools: Extra tool names (case-insensitive) whose results
+                never become knowledge nodes, like memory/personalization tools
+        """
+        self.strong_confidence_threshold = strong_confidence_threshold
+        self.parsers = parsers if parsers is not None else default_registry
+        self.non_epistemic_tools = frozenset(name.lower() for name in non_epistemic_tools)
+
+    def _graded(self, node: KnowledgeNode) -> KnowledgeNode:
+        """Downgrade a node whose confidence is below the strong threshold."""
+        if node.strength == "weak" or node.confidence >= self.strong_confidence_threshold:
+            return node
+        return dataclasses.replace(node, strength="weak")
+
     def build(self, tool_results: list[ToolResultSpeechAct]) -> list[KnowledgeNode]:
         nodes, _ = self.build_with_references(tool_results)
         return nodes
@@ -60,6 +170,9 @@ class KnowledgeStateBuilder:
             produced_nodes: list[KnowledgeNode] = k if isinstance(k, list) else [k]
 
             nodes.extend(produced_nodes)
+            context = self._arguments_to_context(result)
+            if context is not None:
+                nodes.append(context)
 
             if result.tool_call_id:
                 refs = [node.semantic_id or node.id for node in produced_nodes]
@@ -69,36 +182,193 @@ class KnowledgeStateBuilder:
         logger.debug(f"KnowledgeStateBuilder: Built {len(nodes)} knowledge nodes from tool results")
         return nodes, tool_call_refs
 
+    def build_user_context(
+        self,
+        user_texts: Iterable[str],
+        grounds: Iterable[Ground] = (),
+    ) -> list[KnowledgeNode]:
+        """
+        Build user context nodes from user messages and ``user_facts`` grounds.
+
+        Each user sentence stating a preference or constraint (see
+        USER_CONTEXT_INDICATORS) becomes one node carrying the sentence as
+        payload; each contextualizing ground becomes one node under its ground id.
+        """
+        nodes: list[KnowledgeNode] = []
+        for text in user_texts:
+            for sentence in _SENTENCE_SPLIT_RE.split(text):
+                sentence = sentence.strip()
+                if not any(pattern.search(sentence) for pattern in _USER_CONTEXT_RE):
+                    continue
+                nodes.append(
+                    KnowledgeNode(
+                        id=f"user_{self._stable_id_fragment(sentence)}",
+                        source=Source.EXPLICIT,
+                        status=Status.CONFIRMED,
+                        confidence=1.0,
+                        scope=Scope.CONTEXTUAL,
+                        payload={"text": sentence},
+                    )
+                )
+        for ground in grounds:
+            if ground.role != LinkRole.CONTEXTUALIZES:
+                continue
+            nodes.append(
+                KnowledgeNode(
+                    id=ground.ground_id,
+                    source=Source.EXPLICIT,
+                    status=Status.CONFIRMED,
+                    confidence=1.0,
+                    scope=Scope.CONTEXTUAL,
+                    semantic_id=ground.ground_id,
+                )
+            )
+        logger.debug(f"KnowledgeStateBuilder: Built {len(nodes)} user context nodes")
+        return nodes
+
+    def from_precomputed(
+        self, nodes: Iterable[KnowledgeNode | Mapping[str, Any]]
+    ) -> tuple[list[KnowledgeNode], dict[str, list[str]]]:
+        """
+        Admit caller-built knowledge nodes in place of parsed tool results.
+
+        Mappings use KnowledgeNode field names with enum values as strings
+        (``{"id": ..., "source": "observed", "status": "confirmed", ...}``).
+
+        Returns:
+            Nodes and citation-key reference mapping (each node under its
+            semantic id, or id)
+
+        Raises:
+            ValueError: If a node record is malformed
+        """
+        admitted: list[KnowledgeNode] = []
+        for index, node in enumerate(nodes):
+            if not isinstance(node, KnowledgeNode):
+                try:
+                    node = self._node_from_record(node)
+                except (KeyError, TypeError, ValueError) as exc:
+                    raise ValueError(f"Invalid knowledge node at index {index}: {exc}") from exc
+            admitted.append(self._graded(node))
+        refs = {
+            (node.semantic_id or node.id): [node.semantic_id or node.id] for node in admitted
+        }
+        logger.debug(f"KnowledgeStateBuilder: Admitted {len(admitted)} precomputed knowledge nodes")
+        return admitted, refs
+
+    @staticmethod
+    def _node_from_record(record: Mapping[str, Any]) -> KnowledgeNode:
+        if not isinstance(record, Mapping):
+            raise TypeError(f"expected an object, got {type(record).__name__}")
+        unknown = sorted(set(record) - set(KnowledgeNode.__dataclass_fields__))
+        if unknown:
+            raise ValueError(f"unknown fields: {', '.join(unknown)}")
+        return KnowledgeNode(
+            id=str(record["id"]),
+            source=Source(record["source"]),
+            status=Status(record["status"]),
+            confidence=float(record.get("confidence", 1.0)),
+            scope=Scope(record.get("scope", Scope.FACTUAL.value)),
+            strength=record.get("strength", "strong"),
+            semantic_id=record.get("semantic_id"),
+            payload=record.get("payload"),
+            derived_from=list(record.get("derived_from") or []),
+        )
+
     def materialize_external_grounds(
         self,
         knowledge_nodes: list[KnowledgeNode],
         grounds: list[Ground],
     ) -> list[KnowledgeNode]:
-        """Inject external grounds as factual observed nodes when missing."""
+        """
+        Inject external grounds as factual nodes when missing.
+
+        Explicitly stated grounds (evidence_type=explicit) keep source=explicit;
+        contextualizing grounds (personalization) are not materialized.
+        """
         if not grounds:
             return knowledge_nodes
 
         existing_ids = {node.id for node in knowledge_nodes}
         existing_semantic_ids = {node.semantic_id for node in knowledge_nodes if node.semantic_id}
         expanded = list(knowledge_nodes)
+        # Grounds that may act as parents in derivation chains (personalization never does).
+        parents_by_id = {
+            ground.ground_id: ground.derived_from
+            for ground in grounds
+            if ground.role != LinkRole.CONTEXTUALIZES
+        }
+        primary_ids = existing_ids | existing_semantic_ids
 
         for ground in grounds:
             if ground.ground_id in existing_ids or ground.ground_id in existing_semantic_ids:
                 continue
+            if ground.role == LinkRole.CONTEXTUALIZES:
+                # Personalization context is never part of GroundSet (see module invariants).
+                continue
+            chain_error = self._derivation_error(ground.ground_id, parents_by_id, primary_ids)
+            if chain_error:
+                report_warning(
+                    f"Derived ground '{ground.ground_id}' admitted as weak: {chain_error}"
+                )
             expanded.append(
-                KnowledgeNode(
-                    id=ground.ground_id,
-                    source=Source.OBSERVED,
-                    status=Status.CONFIRMED,
-                    confidence=1.0,
-                    scope=Scope.FACTUAL,
-                    strength="strong",
-                    semantic_id=ground.ground_id,
+                self._graded(
+                    KnowledgeNode(
+                        id=ground.ground_id,
+                        source=(
+                            Source.EXPLICIT
+                            if ground.evidence_type == EvidenceType.EXPLICIT
+                            else Source.OBSERVED
+                        ),
+                        status=Status.CONFIRMED,
+                        confidence=ground.confidence,
+                        scope=Scope.FACTUAL,
+                        strength="weak" if chain_error else "strong",
+                        semantic_id=ground.ground_id,
+                        derived_from=list(ground.derived_from),
+                    )
                 )
             )
 
         return expanded
 
+    @staticmethod
+    def _derivation_error(
+        ground_id: str,
+        parents_by_id: dict[str, list[str]],
+        primary_ids: set[str],
+    ) -> str | None:
+        """
+        Validate the derivation chain of a ground.
+
+        Walks ``derived_from`` edges up to primary observations (tool nodes or
+        grounds without parents).
+
+        Returns:
+            Reason the chain is broken (unknown parent, cycle), or None if valid
+        """
+        visiting: set[str] = set()
+        done: set[str] = set()
+
+        def _walk(node_id: str) -> str | None:
+            if node_id in done:
+                return None
+            if node_id in visiting:
+                return f"derivation cycle through '{node_id}'"
+            parents = parents_by_id.get(node_id)
+            if parents is None:
+                return None if node_id in primary_ids else f"unknown parent '{node_id}'"
+            visiting.add(node_id)
+            for parent_id in parents:
+                error = _walk(parent_id)
+                if error:
+                    return error
+            visiting.discard(node_id)
+            done.add(node_id)
+            return None
+
+        return _walk(ground_id)
+
     def _tool_result_to_knowledge(
         self, tool_result: ToolResultSpeechAct
     ) -> KnowledgeNode | list[KnowledgeNode] | None:
@@ -109,19 +379,52 @@ class KnowledgeStateBuilder:
         - This assumes observer tools return externally verifiable facts.
         - It explicitly filters out non-epistemic tools (memory/personalization/state).
         - This module intentionally does not process Context (C).
+        - Untrusted results (``trusted=False``) become weak nodes.
         """
         tool_name = tool_result.tool_name or "unknown"
         if self._is_non_epistemic_tool(tool_name):
             logger.debug(f"Filtering non-epistemic tool result from GroundSet: {tool_name}")
             return None
 
+        parser = self.parsers.get(tool_name)
+        if parser is not None:
+            return self._parsed_nodes(parser, tool_result)
+
         # NEW v0.3.1: Extract semantic_id(s) for LinkSet integration.
         result = self._extract_semantic_id(tool_result)
+        payload = self._parse_result_payload(tool_result)
+        strength = "strong" if tool_result.trusted else "weak"
+
+        # Plaintext results (search snippets, log excerpts)
+        if result is None and not isinstance(payload, (dict, list)):
+            text_nodes: list[KnowledgeNode] = []
+            for idx, (sid, line) in enumerate(self._extract_plaintext_ids(tool_result.result_text)):
+                stable = self._stable_id_fragment(f"{tool_name}:{sid}")
+                text_nodes.append(
+                    KnowledgeNode(
+                        id=f"tool_{tool_name}_text{idx}_{stable}",
+                        source=Source.OBSERVED,
+                        status=Status.CONFIRMED,
+                        confidence=1.0,
+                        scope=Scope.FACTUAL,
+                        strength=strength,
+                        semantic_id=sid,
+                        payload={"text": line},
+                    )
+                )
+            if text_nodes:
+                return text_nodes
 
         # Array results (search_issues, search_transactions, etc.)
         if isinstance(result, list):
+            # Same filter as _extract_semantic_id, so items pair up with semantic ids.
+            items = [
+                item
+                for item in payload
+                if isinstance(item, dict) and self._extract_entity_id(item)
+            ]
             nodes: list[KnowledgeNode] = []
-            for idx, sid in enumerate(result):
+            for idx, (sid, item) in enumerate(zip(result, items)):
                 stable = self._stable_id_fragment(f"{tool_name}:{sid}")
                 nodes.append(
                     KnowledgeNode(
@@ -130,8 +433,9 @@ class KnowledgeStateBuilder:
                         status=Status.CONFIRMED,
                         confidence=1.0,
                         scope=Scope.FACTUAL,
-                        strength="strong",
+                        strength=strength,
                         semantic_id=sid,
+                        payload=item,
                     )
                 )
             return nodes if nodes else None
@@ -155,20 +459,84 @@ class KnowledgeStateBuilder:
             status=Status.CONFIRMED,
             confidence=1.0,
             scope=Scope.FACTUAL,
-            strength="strong",
+            strength=strength,
             semantic_id=semantic_id,
+            payload=payload,
+        )
+
+    def _arguments_to_context(self, tool_result: ToolResultSpeechAct) -> KnowledgeNode | None:
+        """
+        Map the arguments of an admitted tool call to a CONTEXTUAL node.
+
+        The node is not registered under the tool_call_id citation key: citing a
+        call cites its result, not its query.
+        """
+        if not tool_result.arguments:
+            return None
+        tool_name = tool_result.tool_name or "unknown"
+        stable = self._stable_id_fragment(
+            json.dumps(
+                {
+                    "tool": tool_name,
+                    "tool_call_id": tool_result.tool_call_id,
+                    "arguments": tool_result.arguments,
+                },
+                sort_keys=True,
+                separators=(",", ":"),
+                ensure_ascii=False,
+                default=str,
+            )
         )
+        return KnowledgeNode(
+            id=f"args_{tool_name}_{stable}",
+            source=Source.OBSERVED,
+            status=Status.CONFIRMED,
+            confidence=1.0,
+            scope=Scope.CONTEXTUAL,
+            strength="strong" if tool_result.trusted else "weak",
+            payload=dict(tool_result.arguments),
+        )
+
+    def _parsed_nodes(
+        self, parser: ToolResultParser, tool_result: ToolResultSpeechAct
+    ) -> list[KnowledgeNode] | None:
+        """Run a registered parser; untrusted results and low confidence stay weak."""
+        nodes: list[KnowledgeNode] = []
+        for node in parser.parse(tool_result) or []:
+            if not isinstance(node, KnowledgeNode):
+                raise TypeError(
+                    f"Parser for '{tool_result.tool_name}' returned {type(node).__name__}, "
+                    "expected KnowledgeNode"
+                )
+            if not tool_result.trusted and node.strength != "weak":
+                node = dataclasses.replace(node, strength="weak")
+            nodes.append(self._graded(node))
+        return nodes or None
 
     @staticmethod
-    def _is_non_epistemic_tool(tool_name: str) -> bool:
+    def _parse_result_payload(tool_result: ToolResultSpeechAct) -> Any:
+        """Parse tool result text as JSON; None when it is not JSON."""
+        content = tool_result.result_text
+        if not content:
+            return None
+        try:
+            return json.loads(content)
+        except (json.JSONDecodeError, TypeError):
+            return None
+
+    def _is_non_epistemic_tool(self, tool_name: str) -> bool:
         """
         Best-effort guard: exclude tools that traffic in personalization/memory/state.
 
         This is intentionally conservative until tool definitions carry explicit
         epistemic typing (e.g., tool.epistemic_class = "observer"|"llm_proxy").
+        Deployments list their own such tools in ``non_epistemic_tools``.
         """
         name = (tool_name or "").lower()
 
+        if name in self.non_epistemic_tools:
+            return True
+
         # Legacy cognitive context tool
         if name == "get_user_cognitive_context":
             return True
@@ -225,6 +593,32 @@ class KnowledgeStateBuilder:
         except (json.JSONDecodeError, TypeError, AttributeError):
             return None
 
+    @staticmethod
+    def _extract_plaintext_ids(text: str) -> list[tuple[str, str]]:
+        """
+        Extract semantic ids from plaintext, in order of appearance.
+
+        Returns:
+            (semantic id, line it was found on) pairs, first occurrence only
+        """
+        found: list[tuple[str, str]] = []
+        seen: set[str] = set()
+        for line in (text or "").splitlines():
+            matches: list[tuple[int, str]] = []
+            covered: list[tuple[int, int]] = []
+            for pattern, template in PLAINTEXT_ID_PATTERNS:
+                for match in pattern.finditer(line):
+                    start, end = match.span()
+                    if any(start < c_end and c_start < end for c_start, c_end in covered):
+                        continue
+                    covered.append((start, end))
+                    matches.append((start, template.format(*(match.groups() or [match[0]]))))
+            for _, sid in sorted(matches):
+                if sid not in seen:
+                    seen.add(sid)
+                    found.append((sid, line.strip()))
+        return found
+
     @staticmethod
     def _extract_entity_id(data: dict) -> str | None:
         """
@@ -10,20 +10,26 @@ Per Normative Admissibility Framework:
 
 DESIGN PRINCIPLES
 -----------------
-1) License regulates NORMATIVE speech acts only.
-   - ASSERTIVE and CONDITIONAL are subject to licensing.
-   - DESCRIPTIVE statements do NOT require a license (factual observation).
+1) License regulates NORMATIVE speech acts and factual claims.
+   - ASSERTIVE and CONDITIONAL are subject to normative licensing: see derive().
+   - DESCRIPTIVE statements (factual observations) are licensed by the factual
+     grounds matched to them, not by normative usage: see derive_descriptive().
    - REFUSAL is always permitted (A6) and does not require licensing.
 
-   INVARIANT: The evaluator MUST NOT call LicenseDeriver for DESCRIPTIVE modality.
+   COMMISSIVE statements (promises about the agent's own future action) are
+   licensed by capability, not by grounding: see derive_commissive().
+
+   Personalization-conditional statements ("X is better for you") are relative
+   to the user, so they additionally require user context: see derive_personalized().
 
 2) LicenseDeriver is the sole authority for grounding sufficiency and permission rules.
    AxiomChecker must only enforce license compliance, not re-derive sufficiency.
 
-3) Current architecture: GroundSet contains FACTUAL grounding only.
-   - GroundSet is constructed exclusively from externally observable tool results.
+3) Current architecture: only FACTUAL grounding licenses.
+   - GroundSet is constructed exclusively from externally observable tool calls.
    - Personalization / personal context is NOT part of GroundSet.
-   - CONTEXTUAL grounding is intentionally not represented.
+   - CONTEXTUAL nodes (tool call arguments: what the agent asked about) may be
+     present, but never license on their own.
 
    Therefore, licensing depends solely on factual grounding strength.
 
@@ -43,6 +49,15 @@ When StatementGroundLinks are provided:
 
 When links are absent:
 - Conservative mode applies: all grounds are treated as potentially used.
+
+MODALITY FALLBACKS (DOWNGRADE TABLE)
+------------------------------------
+When a statement's modality is not licensed, the acceptable rewrite is a
+weaker modality the license does permit. The table maps each modality to its
+ordered fallback candidates; the first permitted candidate is recommended.
+REFUSAL is always permitted (A6), so it terminates every default chain.
+A DESCRIPTIVE claim on weak grounding is already conditionally acceptable, so
+a failing DESCRIPTIVE claim has no grounding at all: its chain is REFUSAL only.
 """
 
 from __future__ import annotations
@@ -53,15 +68,99 @@ from ..logging import logger
 from .models import GroundSet, License, Modality, Scope
 
 if TYPE_CHECKING:
+    from collections.abc import Mapping, Sequence
+
     from ..models import LinkSet
 
 
+DEFAULT_MODALITY_FALLBACKS: dict[Modality, tuple[Modality, ...]] = {
+    Modality.ASSERTIVE: (Modality.CONDITIONAL, Modality.REFUSAL),
+    Modality.CONDITIONAL: (Modality.REFUSAL,),
+    Modality.DESCRIPTIVE: (Modality.REFUSAL,),
+    Modality.REFUSAL: (),
+    Modality.COMMISSIVE: (Modality.REFUSAL,),
+    Modality.CLARIFICATION: (),
+}
+
+
 class LicenseDeriver:
+    def __init__(
+        self, modality_fallbacks: Mapping[Modality, Sequence[Modality]] | None = None
+    ) -> None:
+        # Caller entries replace the default chain for that modality only.
+        self.modality_fallbacks: dict[Modality, tuple[Modality, ...]] = {
+            **DEFAULT_MODALITY_FALLBACKS,
+            **{k: tuple(v) for k, v in (modality_fallbacks or {}).items()},
+        }
+
+    def recommend_fallback(self, modality: Modality | None, license: License) -> Modality | None:
+        """Return the first fallback modality for ``modality`` that ``license`` permits."""
+        if modality is None:
+            return None
+        for candidate in self.modality_fallbacks.get(modality, ()):
+            if candidate == Modality.REFUSAL or license.permits(candidate):
+                return candidate
+        return None
+
     def derive(self, ground_set: GroundSet, links: LinkSet | None = None) -> License:
         if links is not None:
             return self._derive_with_links(ground_set, links)
         return self._derive_conservative(ground_set)
 
+    def derive_descriptive(self, ground_set: GroundSet) -> License:
+        """
+        Derive the license for a DESCRIPTIVE statement.
+
+        A factual observation ("The deployment is blocked") is licensed by the
+        factual grounds matched to it, regardless of citation usage:
+        - strong factual grounding → {DESCRIPTIVE, CONDITIONAL, REFUSAL}
+        - weak factual grounding only → {CONDITIONAL, REFUSAL}
+        - no factual grounding → {REFUSAL}
+        """
+        factual_strength = ground_set.get_scope_strength(Scope.FACTUAL)
+        if factual_strength == "strong":
+            logger.debug(
+                "License (descriptive): DESCRIPTIVE, CONDITIONAL, REFUSAL (strong factual)"
+            )
+            return License(
+                permitted_modalities={Modality.DESCRIPTIVE, Modality.CONDITIONAL, Modality.REFUSAL}
+            )
+        if factual_strength == "weak":
+            logger.debug("License (descriptive): CONDITIONAL, REFUSAL (weak factual)")
+            return License(permitted_modalities={Modality.CONDITIONAL, Modality.REFUSAL})
+        logger.debug("License (descriptive): REFUSAL only (no factual grounding)")
+        return License(permitted_modalities={Modality.REFUSAL})
+
+    def derive_personalized(self, ground_set: GroundSet, links: LinkSet | None = None) -> License:
+        """
+        Derive the license for a personalization-conditional statement.
+
+        "Hotel B is better for you" is a claim about the user. Factual grounds
+        say nothing about the user, so they cannot license it alone:
+        - user context (explicit CONTEXTUAL nodes) → derive() plus CONDITIONAL
+        - no user context → {REFUSAL}
+        """
+        if not ground_set.has_user_context():
+            logger.debug("License (personalized): REFUSAL only (no user context)")
+            return License(permitted_modalities={Modality.REFUSAL})
+        license = self.derive(ground_set, links=links)
+        logger.debug("License (personalized): CONDITIONAL added (user context)")
+        return License(permitted_modalities=license.permitted_modalities | {Modality.CONDITIONAL})
+
+    def derive_commissive(self, tool_capable: bool) -> License:
+        """
+        Derive the license for a COMMISSIVE statement.
+
+        A promise ("I will file the ticket") is keepable only if the agent can act:
+        tool calls present in the conversation → {COMMISSIVE, REFUSAL},
+        otherwise → {REFUSAL}. GroundSet is not consulted.
+        """
+        if tool_capable:
+            logger.debug("License (commissive): COMMISSIVE, REFUSAL (tool capability)")
+            return License(permitted_modalities={Modality.COMMISSIVE, Modality.REFUSAL})
+        logger.debug("License (commissive): REFUSAL only (no tool capability)")
+        return License(permitted_modalities={Modality.REFUSAL})
+
     def _derive_conservative(self, ground_set: GroundSet) -> License:
         if ground_set.is_empty():
             logger.debug("License (conservative): REFUSAL only (empty GroundSet)")
@@ -129,12 +228,29 @@ class LicenseDeriver:
         self, ground_set: GroundSet, links: LinkSet | None = None
     ) -> tuple[License, dict]:
         license = self.derive(ground_set, links=links)
-
+        return license, self.trace(ground_set, license, links=links)
+
+    def trace(
+        self,
+        ground_set: GroundSet,
+        license: License,
+        links: LinkSet | None = None,
+        *,
+        rule: str = "normative",
+    ) -> dict:
+        """
+        Describe how ``license`` follows from ``ground_set`` (and ``links``).
+
+        ``rule`` names the derivation that produced the license: ``normative``
+        (derive), ``descriptive``, ``commissive``, ``personalized``, or
+        ``epistemic`` (no license; judged directly against factual grounding).
+        """
         non_factual_scopes = sorted(
             {k.scope.value for k in ground_set.nodes if k.scope != Scope.FACTUAL}
         )
 
         trace: dict = {
+            "rule": rule,
             "mode": "links" if links is not None else "conservative",
             "ground_set_size": len(ground_set.nodes),
             "is_empty": ground_set.is_empty(),
@@ -144,7 +260,8 @@ class LicenseDeriver:
                 "has_strong": ground_set.has_strong_in_scope(Scope.FACTUAL),
             },
             "non_factual_scopes_present": non_factual_scopes,
-            "permitted_modalities": [m.value for m in license.permitted_modalities],
+            "user_context": ground_set.has_user_context(),
+            "permitted_modalities": sorted(m.value for m in license.permitted_modalities),
         }
 
         if links is not None:
@@ -166,4 +283,4 @@ class LicenseDeriver:
                 for k in ground_set.nodes
             ]
 
-        return license, trace
+        return trace
@@ -11,7 +11,8 @@ It is a DERIVED characteristic established by formal analysis.
 
 Formally:
     Statement := ⟨Subject, Predicate⟩
-    Modality : Statement → {ASSERTIVE, CONDITIONAL, REFUSAL, DESCRIPTIVE}
+    Modality : Statement → {ASSERTIVE, CONDITIONAL, REFUSAL, DESCRIPTIVE, COMMISSIVE,
+                            CLARIFICATION}
 
 This module implements the Modality function via FORMAL INDICATORS.
 
@@ -34,6 +35,8 @@ DETERMINATION METHOD
 
 2. Detection priority (fixed):
    REFUSAL
+   > CLARIFICATION
+   > COMMISSIVE
    > GOAL-CONDITIONAL
    > PERSONALIZATION-CONDITIONAL
    > ASSERTIVE (recommendation)
@@ -54,6 +57,37 @@ SEPARATION OF CONCERNS
 - ModalityDetector: classifies form only
 - Grounding and licensing are handled elsewhere
 
+Modal reading (for statements whose core contains must / should / ought to):
+- EPISTEMIC: modal + perfect or explanatory frame ("must have failed",
+  "should be because of ...") → inference about the world
+- DEONTIC: every other modal use ("You must rotate the key") → obligation
+- Detected from the core assertion, like modality itself
+
+Uncertainty (hedges and probability expressions):
+- "almost certainly", "likely", "roughly", "30% chance", "I think" ...
+- Extracted from the core into Statement.uncertainty (markers + probability)
+- Does NOT change modality: a hedged claim stays ASSERTIVE (anti-evasion),
+  but AxiomChecker judges it as a calibrated claim rather than a flat one
+
+Attribution (quoted and reported speech):
+- "The vendor claims you should upgrade", "According to the docs, ...",
+  "\"Upgrade now,\" said the vendor"
+- Extracted from the core into Statement.attribution (source + marker)
+- Does NOT change modality: the relayed claim keeps its form, but AxiomChecker
+  judges it as a report (was it said?) rather than the agent's own claim
+- First- and second-person sources ("I claim", "you said") are not reports
+
+Personalization (PERSONALIZATION-CONDITIONAL statements):
+- "X is better for you", "Given your constraints, ..."
+- Flagged as Statement.personalized: the claim is relative to the user, so
+  LicenseDeriver requires context the user stated about themselves
+
+Modality confidence:
+- 1.0 when a formal indicator decided the modality
+- DEFAULT_POLICY_CONFIDENCE when ASSERTIVE is only the anti-evasion default
+  and the core carries no normative indicator, so downstream confidence
+  reflects the guess
+
 Condition extraction (for CONDITIONAL):
 - Extracted from full text
 - Treated as declarative flags, not logical premises
@@ -63,12 +97,34 @@ LIMITATIONS
 - English-only
 - Formal-indicator based
 - No semantic inference by design
+
+PLUGGABILITY
+------------
+The evaluator asks a ``ModalityDetection`` (``detect(text) -> Modality``) for
+the modality of each statement; ``ModalityDetector`` is its default
+implementation. With another detector plugged in (an ML classifier, a domain
+lexicon), conditions, modal reading, hedges and attribution are still taken
+from the formal indicators here.
 """
 
 import re
+from collections.abc import Iterable, Mapping
+from typing import Protocol, runtime_checkable
 
 from ..logging import logger
-from .models import Modality, Statement
+from .models import Attribution, ModalReading, Modality, Statement, Uncertainty
+
+# Confidence of a modality assigned by the ASSERTIVE default policy rather than an indicator.
+DEFAULT_POLICY_CONFIDENCE = 0.6
+
+
+@runtime_checkable
+class ModalityDetection(Protocol):
+    """Decide the modality of one statement text."""
+
+    def detect(self, text: str) -> Modality:
+        """Return the modality of ``text``."""
+        ...
 
 
 class ModalityDetector:
@@ -78,7 +134,8 @@ class ModalityDetector:
     Implements the Modality function as defined in
     draft-romanchuk-normative-admissibility-00.
 
-        Modality : Statement → {ASSERTIVE, CONDITIONAL, REFUSAL, DESCRIPTIVE}
+        Modality : Statement → {ASSERTIVE, CONDITIONAL, REFUSAL, DESCRIPTIVE, COMMISSIVE,
+                                CLARIFICATION}
 
     Modality is a DERIVED property of a statement's FORM,
     not an intrinsic attribute of the Statement object itself.
@@ -88,6 +145,17 @@ class ModalityDetector:
        Explicit admission of inability to determine.
        Always admissible (A6).
 
+    1.2. CLARIFICATION
+       Information-seeking question ("Which environment are you deploying to?").
+       Admissible like a refusal (A6): the agent asks instead of guessing.
+       Only when the statement carries no normative indicator, so a leading
+       question cannot shield a claim ("Which env? You should use X.").
+
+    1.5. COMMISSIVE
+       First-person commitment to future action ("I will file the ticket").
+       A promise, not an assertion: licensed by tool capability, not grounding.
+       Checked after REFUSAL so "I will not ..." stays a refusal.
+
     2. GOAL-CONDITIONAL
        Goal-conditional framing (e.g. "If your goal is X…").
        Syntactic subclass of CONDITIONAL.
@@ -120,6 +188,17 @@ class ModalityDetector:
     - Normative evasion via vague language is prevented
     """
 
+    # Modalities whose indicators accept extra keywords (assertive extends the
+    # recommendation indicators; the default ASSERTIVE fallback needs none).
+    KEYWORD_MODALITIES = (
+        Modality.REFUSAL,
+        Modality.CLARIFICATION,
+        Modality.COMMISSIVE,
+        Modality.CONDITIONAL,
+        Modality.DESCRIPTIVE,
+        Modality.ASSERTIVE,
+    )
+
     # Formal indicators for REFUSAL modality
     # CRITICAL: These detect EXPLICIT admission of inability to determine.
     # NOT "polite uncertainty" (maybe, possibly, likely) - those default to ASSERTIVE.
@@ -136,6 +215,27 @@ class ModalityDetector:
         r"^i\s+(?:wouldn't|won't)\s+\w+",
     ]
 
+    # Formal indicators for CLARIFICATION modality (information-seeking question).
+    # Anchored to the start of the core; offers ("Would you like ...", "Do you want me
+    # to ...") and help prompts stay protocol speech.
+    CLARIFICATION_FORM_INDICATORS = [
+        r"^(?:which|what|where|when|who|whose|how\s+(?:many|much|long|often|old|big)"
+        r"|do\s+you|does\s+(?:your|the)|are\s+you|is\s+(?:your|the|this|it)|have\s+you"
+        r"|did\s+you|(?:could|can)\s+you\s+(?:tell|confirm|share|specify))\b"
+        r"(?![^?]*\b(?:can\s+i|help|assist|like\s+me\s+to|want\s+me\s+to"
+        r"|would\s+you\s+like|anything\s+else)\b)[^.!?]*\?",
+    ]
+
+    # Formal indicators for COMMISSIVE modality (first-person commitment).
+    # Anchored to the start of the core assertion: the agent itself promises an action.
+    # Negated forms ("I will not", "I won't") never match here; REFUSAL runs first.
+    COMMISSIVE_FORM_INDICATORS = [
+        r"^(?:i|we)\s+(?:will|shall)\s+(?!not\b)\w+",
+        r"^(?:i|we)['’]ll\s+\w+",
+        r"^(?:i|we)\s+(?:promise|commit)\s+to\s+\w+",
+        r"^(?:i\s+am|i['’]m|we\s+are|we['’]re)\s+going\s+to\s+\w+",
+    ]
+
     # Formal indicators for CONDITIONAL modality
     CONDITIONAL_FORM_INDICATORS = [
         r"\b(?:if|unless|assuming|given\s+that|provided)\s+",
@@ -188,6 +288,68 @@ class ModalityDetector:
         r"\badvise",
     ]
 
+    # Modal verbs that admit both epistemic and deontic readings
+    MODAL_VERB_INDICATORS = [
+        r"\bmust\b",
+        r"\bshould\b",
+        r"\bought\s+to\b",
+        r"\bhas\s+to\b",
+        r"\bhave\s+to\b",
+    ]
+
+    # Epistemic frames: the modal marks an inference, not an obligation.
+    # "must have failed" / "should have finished" (perfect aspect)
+    # "must be due to X" / "must be because X" (explanatory frame)
+    # Second-person subjects stay deontic ("you must have 2FA enabled").
+    EPISTEMIC_FORM_INDICATORS = [
+        r"(?<!\byou\s)\b(?:must|should|ought\s+to)\s+(?:not\s+)?have\s+(?:been\s+)?\w+(?:ed|en|ne|wn|t)\b",
+        r"\b(?:must|should)\s+(?:not\s+)?be\s+(?:due\s+to|because|caused\s+by|related\s+to)\b",
+    ]
+
+    # Verbal probability expressions → probability estimate (first match wins,
+    # so longer phrases come first)
+    VERBAL_PROBABILITY_INDICATORS = [
+        (r"\balmost\s+certainly\b", 0.95),
+        (r"\b(?:very|highly)\s+unlikely\b", 0.1),
+        (r"\b(?:very|highly)\s+likely\b", 0.9),
+        (r"\bunlikely\b", 0.2),
+        (r"\b(?:most\s+)?(?:likely|probably)\b", 0.7),
+        (r"\b(?:possibly|perhaps|maybe)\b", 0.5),
+    ]
+
+    # Explicit probabilities: "30% chance", "probability of about 30%"
+    PERCENT_PROBABILITY_INDICATORS = [
+        r"\b(\d{1,3}(?:\.\d+)?)\s*(?:%|percent)\s+(?:chance|probability|likelihood|likely|confiden\w*|sure)\b",
+        r"\b(?:chance|probability|likelihood|confidence)\s+(?:of\s+|is\s+|at\s+)?(?:about\s+|around\s+|roughly\s+|~)?(\d{1,3}(?:\.\d+)?)\s*(?:%|percent)",
+    ]
+
+    # Hedges without a probability: approximators and epistemic stance
+    HEDGE_INDICATORS = [
+        r"\b(?:about|around|approximately|circa)\s+(?=\d)",
+        r"\broughly\b",
+        r"~(?=\d)",
+        r"\bit\s+(?:seems|appears)\b",
+        r"\bi\s+(?:think|believe|suspect)\b",
+    ]
+
+    # Reported speech: the core relays a claim of a named third party.
+    # Anchored to the start of the core (or a leading quotation); pronoun subjects
+    # ("I", "we", "you", "it") never count as a source.
+    _REPORTING_VERBS = (
+        r"claims?|claimed|says?|said|states?|stated|reports?|reported|argues?|argued"
+        r"|insists?|insisted|warns?|warned|writes|wrote|told\s+(?:us|you|me)"
+    )
+    _NON_SOURCE_SUBJECTS = r"(?!(?:i|we|you|it|this|that|there|they)\b)"
+    REPORTED_SPEECH_INDICATORS = [
+        r"^(?P<marker>according\s+to)\s+(?P<source>(?!your?\b)[^,]{1,60}?),\s*\S",
+        r"^" + _NON_SOURCE_SUBJECTS + r"(?P<source>(?:(?:the|a|an|our|their|its|my)\s+)?"
+        r"[a-z][\w.'&-]*(?:\s+[\w.'&-]+){0,4}?)"
+        r"\s+(?P<marker>" + _REPORTING_VERBS + r")(?:\s+that\b|\s*:|\s*,)?\s+\S",
+        r"^[\"“][^\"”]+[\"”],?\s+(?P<marker>says|said|writes|wrote|warns|warned)\s+"
+        + _NON_SOURCE_SUBJECTS
+        + r"(?P<source>[^.!?]{1,60}?)[.!?]*$",
+    ]
+
     # Recommendation indicators (for ASSERTIVE override)
     # If these present in core assertion, statement is ASSERTIVE even if conditional indicators in tail
     RECOMMENDATION_FORM_INDICATORS = [
@@ -200,9 +362,22 @@ class ModalityDetector:
         r"\b(?:finish|complete)\s+\w+\s+first\b",  # "Finish X first"
     ]
 
-    def __init__(self) -> None:
-        """Initialize detector with compiled formal indicators."""
+    def __init__(self, keywords: Mapping[Modality, Iterable[str]] | None = None) -> None:
+        """
+        Initialize detector with compiled formal indicators.
+
+        Args:
+            keywords: Extra phrases per modality (see KEYWORD_MODALITIES), matched as
+                whole words, case-insensitive, in the core assertion; they extend the
+                built-in indicators without changing the detection priority
+        """
         self._refusal_re = [re.compile(ind, re.IGNORECASE) for ind in self.REFUSAL_FORM_INDICATORS]
+        self._clarification_re = [
+            re.compile(ind, re.IGNORECASE) for ind in self.CLARIFICATION_FORM_INDICATORS
+        ]
+        self._commissive_re = [
+            re.compile(ind, re.IGNORECASE) for ind in self.COMMISSIVE_FORM_INDICATORS
+        ]
         self._conditional_re = [
             re.compile(ind, re.IGNORECASE) for ind in self.CONDITIONAL_FORM_INDICATORS
         ]
@@ -222,6 +397,38 @@ class ModalityDetector:
         self._recommendation_re = [
             re.compile(ind, re.IGNORECASE) for ind in self.RECOMMENDATION_FORM_INDICATORS
         ]
+        self._verbal_probability_re = [
+            (re.compile(ind, re.IGNORECASE), p) for ind, p in self.VERBAL_PROBABILITY_INDICATORS
+        ]
+        self._percent_probability_re = [
+            re.compile(ind, re.IGNORECASE) for ind in self.PERCENT_PROBABILITY_INDICATORS
+        ]
+        self._hedge_re = [re.compile(ind, re.IGNORECASE) for ind in self.HEDGE_INDICATORS]
+        self._modal_verb_re = [
+            re.compile(ind, re.IGNORECASE) for ind in self.MODAL_VERB_INDICATORS
+        ]
+        self._epistemic_re = [
+            re.compile(ind, re.IGNORECASE) for ind in self.EPISTEMIC_FORM_INDICATORS
+        ]
+        self._reported_speech_re = [
+            re.compile(ind, re.IGNORECASE) for ind in self.REPORTED_SPEECH_INDICATORS
+        ]
+        lexicons = {
+            Modality.REFUSAL: self._refusal_re,
+            Modality.CLARIFICATION: self._clarification_re,
+            Modality.COMMISSIVE: self._commissive_re,
+            Modality.CONDITIONAL: self._conditional_re,
+            Modality.DESCRIPTIVE: self._descriptive_re,
+            Modality.ASSERTIVE: self._recommendation_re,
+        }
+        for modality, phrases in (keywords or {}).items():
+            if modality not in lexicons:
+                raise ValueError(f"Keywords cannot select modality: {modality.value}")
+            lexicons[modality].extend(
+                re.compile(r"(?<!\w)" + re.escape(phrase.strip()) + r"(?!\w)", re.IGNORECASE)
+                for phrase in phrases
+                if phrase.strip()
+            )
 
     def detect(self, text: str) -> Modality:
         """
@@ -239,10 +446,12 @@ class ModalityDetector:
         v0.1.2: HEAD-DRIVEN detection + GOAL-CONDITIONAL priority
 
         Detection priority (FIXED, do not reorder):
-        REFUSAL > GOAL-CONDITIONAL > PERSONALIZATION-CONDITIONAL > ASSERTIVE (recommendation) > CONDITIONAL > DESCRIPTIVE > ASSERTIVE (default)
+        REFUSAL > CLARIFICATION > COMMISSIVE > GOAL-CONDITIONAL > PERSONALIZATION-CONDITIONAL > ASSERTIVE (recommendation) > CONDITIONAL > DESCRIPTIVE > ASSERTIVE (default)
 
         Why this order:
         - REFUSAL: Always acceptable (A6)
+        - CLARIFICATION: Asking instead of guessing is admissible (A6-adjacent)
+        - COMMISSIVE: Commitment, not assertion; must not fall through to ASSERTIVE (default)
         - GOAL-CONDITIONAL: Deontic advice, not epistemic assertion
         - ASSERTIVE (recommendation): Categorical recommendation with grounding
         - CONDITIONAL: General conditional structure
@@ -277,6 +486,20 @@ class ModalityDetector:
             logger.debug(f"Modality: REFUSAL for: {text[:60]}...")
             return Modality.REFUSAL
 
+        # 1.2. Check CLARIFICATION ("Which environment are you deploying to?")
+        # Information-seeking move, admissible like a refusal. Normative indicators
+        # anywhere in the text disqualify it (anti-evasion: no question-shielded claims).
+        if self._is_clarification(core) and not self._is_normative(text_lower):
+            logger.debug(f"Modality: CLARIFICATION for: {text[:60]}...")
+            return Modality.CLARIFICATION
+
+        # 1.5. Check COMMISSIVE ("I will file the ticket and email you by Friday")
+        # A promise about the agent's own future action. Licensed by tool capability
+        # (see LicenseDeriver.derive_commissive), not by factual grounding.
+        if self._is_commissive(core):
+            logger.debug(f"Modality: COMMISSIVE for: {text[:60]}...")
+            return Modality.COMMISSIVE
+
         # 2. Check GOAL-CONDITIONAL (BEFORE recommendation override)
         # CRITICAL v0.1.2: Goal-conditional MUST override recommendation markers
         #
@@ -326,13 +549,15 @@ class ModalityDetector:
         logger.debug(f"Modality: ASSERTIVE (default policy) for: {text[:60]}...")
         return Modality.ASSERTIVE
 
-    def detect_with_conditions(self, statement: Statement) -> Statement:
+    def detect_with_conditions(
+        self, statement: Statement, detector: ModalityDetection | None = None
+    ) -> Statement:
         """
         Detect modality and extract conditions if CONDITIONAL.
 
         CRITICAL SEPARATION OF CONCERNS:
 
-        1. Modality detection (ASSERTIVE/CONDITIONAL/REFUSAL/DESCRIPTIVE)
+        1. Modality detection (ASSERTIVE/CONDITIONAL/REFUSAL/DESCRIPTIVE/COMMISSIVE/CLARIFICATION)
            → determined by CORE assertion only (head-driven)
 
         2. Condition extraction (if CONDITIONAL)
@@ -344,15 +569,29 @@ class ModalityDetector:
         - CONDITIONAL → conditions extracted from full text (may include tail)
 
         Mutates statement.modality and statement.conditions.
+        A modality preset by the extractor (executable code commands) is kept:
+        those statements are imperatives by construction, not by form.
 
         Args:
             statement: Statement to analyze
+            detector: Decides the modality instead of ``self.detect`` (a plugged-in
+                ``ModalityDetection``); everything else still comes from this detector
 
         Returns:
             Updated statement
         """
-        modality = self.detect(statement.raw_text)
+        preset = statement.modality is not None
+        modality = statement.modality or (detector or self).detect(statement.raw_text)
         statement.modality = modality
+        statement.modality_confidence = (
+            1.0 if preset else self.modality_confidence(statement.raw_text, modality)
+        )
+        statement.reading = self.detect_reading(statement.raw_text)
+        statement.uncertainty = self.extract_uncertainty(statement.raw_text)
+        statement.attribution = self.extract_attribution(statement.raw_text)
+        statement.personalized = modality == Modality.CONDITIONAL and self.is_personalized(
+            statement.raw_text
+        )
 
         # If conditional, extract conditions
         # CRITICAL: Only called when modality == CONDITIONAL
@@ -362,10 +601,134 @@ class ModalityDetector:
 
         return statement
 
+    def modality_confidence(self, text: str, modality: Modality) -> float:
+        """
+        Confidence of a detected modality.
+
+        ASSERTIVE comes either from recommendation markers or from the
+        anti-evasion default; the default is a guess unless the core at least
+        carries a normative indicator ("should", "must", ...).
+        """
+        core = self._extract_core_assertion(text.lower())
+        if (
+            modality == Modality.ASSERTIVE
+            and not self._has_recommendation(core)
+            and not self._is_normative(core)
+        ):
+            return DEFAULT_POLICY_CONFIDENCE
+        return 1.0
+
+    def is_personalized(self, text: str) -> bool:
+        """
+        Check if the core assertion is framed relative to the user.
+
+        "Hotel B is better for you"          → True
+        "If your goal is speed, use Hotel B" → False (goal-conditional: the
+                                               condition is declared, not assumed)
+        """
+        core = self._extract_core_assertion(text.lower())
+        return not self._is_goal_conditional(core) and self._is_personalization_conditional(core)
+
+    def detect_reading(self, text: str) -> ModalReading | None:
+        """
+        Distinguish epistemic from deontic readings of modal verbs in the core.
+
+        "The build must have failed" → EPISTEMIC (inference about the world)
+        "You must rotate the key"    → DEONTIC (obligation)
+
+        Returns:
+            ModalReading, or None if the core contains no modal verb
+        """
+        core = self._extract_core_assertion(text.lower())
+        if not any(indicator.search(core) for indicator in self._modal_verb_re):
+            return None
+        if any(indicator.search(core) for indicator in self._epistemic_re):
+            logger.debug(f"Modal reading: EPISTEMIC for: {text[:60]}...")
+            return ModalReading.EPISTEMIC
+        return ModalReading.DEONTIC
+
+    def extract_uncertainty(self, text: str) -> Uncertainty | None:
+        """
+        Extract hedges and probability expressions from the core assertion.
+
+        "The outage is almost certainly the cache" → markers=["almost certainly"], p=0.95
+        "There is a 30% chance the job fails"      → markers=["30% chance"], p=0.3
+        "It takes roughly 5 minutes"               → markers=["roughly"], p=None
+
+        An explicit percentage wins over a verbal estimate.
+
+        Returns:
+            Uncertainty, or None if the core carries no hedge
+        """
+        core = self._extract_core_assertion(text.lower())
+        markers: list[str] = []
+        covered: list[tuple[int, int]] = []
+
+        def _add(match: re.Match[str]) -> bool:
+            start, end = match.span()
+            if any(start < c_end and c_start < end for c_start, c_end in covered):
+                return False
+            covered.append((start, end))
+            marker = match.group(0).strip()
+            if marker:
+                markers.append(marker)
+            return True
+
+        probability: float | None = None
+        for pattern in self._percent_probability_re:
+            for match in pattern.finditer(core):
+                if _add(match) and probability is None:
+                    value = float(match.group(1))
+                    if value <= 100:
+                        probability = value / 100
+
+        for pattern, estimate in self._verbal_probability_re:
+            for match in pattern.finditer(core):
+                if _add(match) and probability is None:
+                    probability = estimate
+
+        for pattern in self._hedge_re:
+            for match in pattern.finditer(core):
+                _add(match)
+
+        if not markers:
+            return None
+        logger.debug(f"Uncertainty: {markers} (p={probability}) for: {text[:60]}...")
+        return Uncertainty(markers=markers, probability=probability)
+
+    def extract_attribution(self, text: str) -> Attribution | None:
+        """
+        Detect quoted or reported speech in the core assertion.
+
+        "The vendor claims you should upgrade"   → source="The vendor", marker="claims"
+        "According to the AWS docs, use gp3."    → source="the AWS docs", marker="according to"
+        "\"Upgrade now,\" said the vendor."       → source="the vendor", marker="said"
+
+        Returns:
+            Attribution, or None if the core is the agent's own claim
+        """
+        core = self._extract_core_assertion(text.strip())
+        for pattern in self._reported_speech_re:
+            match = pattern.search(core)
+            if match:
+                source = match.group("source").strip()
+                marker = " ".join(match.group("marker").lower().split())
+                logger.debug(f"Attribution: {source!r} ({marker}) for: {text[:60]}...")
+                return Attribution(source=source, marker=marker)
+        return None
+
     def _is_refusal(self, text: str) -> bool:
         """Check if text contains refusal form indicators."""
         return any(indicator.search(text) for indicator in self._refusal_re)
 
+    def _is_clarification(self, text: str) -> bool:
+        """Check if core assertion opens with an information-seeking question."""
+        return any(indicator.search(text) for indicator in self._clarification_re)
+
+    def _is_commissive(self, text: str) -> bool:
+        """Check if core assertion opens with a first-person commitment."""
+        return any(indicator.search(text) for indicator in self._commissive_re)
+
     def _is_conditional(self, text: str) -> bool:
         """Check if text contains conditional form indicators."""
         return any(indicator.search(text) for indicator in self._conditional_re)
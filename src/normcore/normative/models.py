@@ -6,6 +6,7 @@ Per FORMAL_SPEC_v0.2.md and LOGICAL_DAG_SPEC_v0.1.md.
 
 from dataclasses import dataclass, field
 from enum import Enum
+from typing import Any
 
 
 class Modality(Enum):
@@ -19,6 +20,49 @@ class Modality(Enum):
     CONDITIONAL = "conditional"  # "If A, then X should Y"
     REFUSAL = "refusal"  # "Cannot determine X"
     DESCRIPTIVE = "descriptive"  # "X blocks Y" (factual)
+    COMMISSIVE = "commissive"  # "I will do X" (commitment to future action)
+    CLARIFICATION = "clarification"  # "Which environment are you deploying to?"
+
+
+class ModalReading(Enum):
+    """
+    Reading of a modal verb (must / should / ought to) in a statement.
+
+    - EPISTEMIC: inference about the world ("The build must have failed")
+    - DEONTIC: obligation or advice ("You must rotate the key")
+
+    Readings carry different grounding requirements: an epistemic inference is a
+    factual claim and needs factual grounding; a deontic claim needs a license.
+    """
+
+    EPISTEMIC = "epistemic"
+    DEONTIC = "deontic"
+
+
+@dataclass
+class Uncertainty:
+    """
+    Hedges and probability expressions found in a statement's core.
+
+    markers: matched hedge phrases ("almost certainly", "roughly", "about 30%")
+    probability: explicit ("30% chance") or verbal ("likely" → 0.7) estimate, if any
+    """
+
+    markers: list[str] = field(default_factory=list)
+    probability: float | None = None
+
+
+@dataclass
+class Attribution:
+    """
+    Reported speech found in a statement's core.
+
+    source: who the relayed claim is attributed to ("the vendor", "AWS docs")
+    marker: the reporting phrase ("claims", "according to", "said")
+    """
+
+    source: str
+    marker: str
 
 
 class Source(Enum):
@@ -81,6 +125,7 @@ class EvaluationStatus(Enum):
     UNDERDETERMINED = "underdetermined"
     CONDITIONALLY_ACCEPTABLE = "conditionally_acceptable"
     VIOLATES_NORM = "violates_norm"
+    INSUFFICIENT_CITATIONS = "insufficient_citations"  # Policy axiom A8 (attribution)
     ACCEPTABLE = "acceptable"
     NO_NORMATIVE_CONTENT = "no_normative_content"  # NEW v0.2.1: Pre-evaluation filter result
 
@@ -99,6 +144,11 @@ class Statement:
     raw_text: str  # Original text span
     modality: Modality | None = None
     conditions: list[str] = field(default_factory=list)  # If CONDITIONAL
+    reading: ModalReading | None = None  # Set when the core contains a modal verb
+    uncertainty: Uncertainty | None = None  # Set when the core is hedged
+    attribution: Attribution | None = None  # Set when the core relays another source
+    personalized: bool = False  # Core framed relative to the user ("better for you")
+    modality_confidence: float = 1.0  # Lower when modality is the default-policy fallback
 
 
 @dataclass
@@ -122,6 +172,13 @@ class KnowledgeNode:
 
     LinkMatcher uses semantic_id format (deterministic from domain entities).
     GroundSet.resolve() tries both id and semantic_id.
+
+    payload carries the parsed JSON of the originating tool result (the item,
+    for array results) so verification checks can inspect the raw evidence.
+    It never affects licensing and is excluded from equality.
+
+    derived_from lists the parent ground ids of derived evidence (a summary of
+    observations). Empty for primary observations.
     """
 
     id: str
@@ -131,6 +188,8 @@ class KnowledgeNode:
     scope: Scope
     strength: str = "strong"  # "strong" | "weak"
     semantic_id: str | None = None  # NEW v0.3.1: For LinkSet integration
+    payload: Any = field(default=None, compare=False, repr=False)
+    derived_from: list[str] = field(default_factory=list)  # Parent ground ids (provenance)
 
     def __post_init__(self) -> None:
         """Validate confidence range and strength."""
@@ -257,6 +316,16 @@ class GroundSet:
         # Only weak nodes in scope
         return "weak"
 
+    def has_user_context(self) -> bool:
+        """
+        Check if GroundSet contains context the user stated about themselves.
+
+        User context is CONTEXTUAL knowledge with source=EXPLICIT (preferences,
+        constraints). Other contextual nodes (tool call arguments) say nothing
+        about the user.
+        """
+        return any(k.scope == Scope.CONTEXTUAL and k.source == Source.EXPLICIT for k in self.nodes)
+
     def get_nodes_by_scope(self, scope: Scope) -> list[KnowledgeNode]:
         """
         Get all nodes with given scope.
@@ -316,6 +385,20 @@ class GroundSet:
 
         return None
 
+    def payload_for(self, ground_id: str) -> Any:
+        """
+        Return the parsed tool-result payload behind a ground (canonical or semantic ID).
+
+        Returns None when the ground is unknown or carries no structured payload
+        (external grounds, non-JSON tool output).
+        """
+        node = self.resolve_ground(ground_id)
+        return node.payload if node is not None else None
+
+    def payloads(self) -> dict[str, Any]:
+        """Map canonical node IDs to their structured payloads (nodes without one are omitted)."""
+        return {node.id: node.payload for node in self.nodes if node.payload is not None}
+
 
 @dataclass
 class License:
@@ -359,6 +442,10 @@ class StatementValidationResult:
     ground_set: "GroundSet"
     violated_axiom: str | None = None
     explanation: str = ""
+    recommended_modality: Modality | None = None
+    weight: float = 1.0  # Share of total statement weight (weighted aggregation)
+    confidence: float = 1.0  # Modality x ground x link confidence of the verdict
+    license_trace: dict | None = None  # LicenseDeriver.trace(), when tracing is requested
 
 
 @dataclass
@@ -401,3 +488,8 @@ class ValidationResult:
     num_acceptable: int = 0
     grounds_accepted: int = 0
     grounds_cited: int = 0
+    accepted_ground_ids: list[str] = field(default_factory=list)
+    cited_ground_ids: list[str] = field(default_factory=list)
+    citation_map: dict[str, list[str]] = field(default_factory=dict)
+    out_of_window_ground_ids: list[str] = field(default_factory=list)
+    reasoning_items_skipped: int = 0
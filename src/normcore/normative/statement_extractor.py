@@ -39,6 +39,10 @@ DESIGN PRINCIPLES
    Protocol speech is NOT subject to normative axioms (A4–A7)
    and MUST be removed before evaluation.
 
+   EXPRESSIVE speech (sympathy, praise, thanks, apology: "I'm sorry to hear
+   that", "Great choice!") is likewise outside normative jurisdiction. Unlike
+   protocol speech it is not position-bounded, so it is removed per sentence.
+
    This component performs boundary-based filtering to:
    - Strip protocol-only prefixes and suffixes
    - Preserve only normative participation
@@ -61,20 +65,61 @@ DESIGN PRINCIPLES
    Such statements are preserved so that ModalityDetector can classify
    them as CONDITIONAL, without granting epistemic grounding.
 
+6) Table cells are claims too.
+
+   Markdown tables (for example a comparison recommending option A) are cut
+   out of the prose before protocol filtering, and each cell that carries a
+   normative or recommendation indicator becomes its own Statement
+   (id ``table:<table>:<row>:<column>``, subject = row label, predicate =
+   column header). Tabular recommendations therefore cannot bypass evaluation.
+
+7) Fenced code blocks are not prose.
+
+   Code fences are removed before extraction. Optionally (policy
+   ``code_blocks="flag_executable"``) each command line of a shell/SQL fence
+   becomes an ASSERTIVE Statement (id ``code:<block>:<line>``): executable
+   advice ("rm -rf /data") is an imperative that requires grounding.
+
+8) Sentence granularity is opt-in.
+
+   By default the cleaned prose is ONE Statement (``final_response``).
+   With ``split_sentences`` (policy ``statement_granularity="sentence"``)
+   each sentence or bullet becomes its own Statement (ids ``s1``, ``s2``, …
+   in reading order), so a judgment names the sentence that fails.
+
 NON-GOALS
 ---------
-- Multi-statement extraction
+- Semantic sentence segmentation (boundaries are punctuation and bullets)
 - Semantic interpretation
 - Capability or tool-availability validation
 - Truth assessment
 
 These concerns are explicitly out of scope.
+
+PLUGGABILITY
+------------
+The evaluator depends only on the ``StatementExtraction`` protocol
+(``extract(text) -> list[Statement]``); ``StatementExtractor`` is its default,
+English keyword-based implementation. Domain extractors (medical, legal)
+return Statements with ``modality`` unset; modality is detected afterwards.
 """
 
+import logging
 import re
+from collections.abc import Iterable
+from typing import Protocol, runtime_checkable
 
-from ..logging import logger
-from .models import Statement
+from ..logging import log_event, logger
+from .models import Modality, Statement
+
+
+@runtime_checkable
+class StatementExtraction(Protocol):
+    """Split agent output into the statements that are evaluated."""
+
+    def extract(self, text: str) -> list[Statement]:
+        """Return the normative statements of ``text`` (empty for protocol-only text)."""
+        ...
 
 
 class StatementExtractor:
@@ -127,7 +172,8 @@ class StatementExtractor:
        to normative admissibility axioms (A4–A7).
 
        This component removes protocol-only prefixes and suffixes
-       before normative evaluation.
+       before normative evaluation, and pure expressive sentences
+       wherever they occur.
 
     3) Boundary-based, deterministic implementation.
 
@@ -147,6 +193,7 @@ class StatementExtractor:
     OUTPUT CONTRACT
     ---------------
     - Returns a list with a single Statement if normative participation exists
+      (one per sentence or bullet with ``split_sentences``)
     - Returns an empty list if the output contains only protocol speech
 
     An empty result signals that the evaluator has no jurisdiction
@@ -175,7 +222,8 @@ class StatementExtractor:
     #
     # IMPLEMENTATION INVARIANT (crucial):
     # If ModalityDetector uses a formal indicator to classify a statement as
-    # normative participation (ASSERTIVE/CONDITIONAL/REFUSAL/DESCRIPTIVE),
+    # normative participation (ASSERTIVE/CONDITIONAL/REFUSAL/DESCRIPTIVE/COMMISSIVE/
+    # CLARIFICATION),
     # StatementExtractor MUST treat that indicator as sufficient to keep the
     # utterance for evaluation. Otherwise the evaluator can silently drop
     # normative participation before modality detection runs.
@@ -198,6 +246,18 @@ class StatementExtractor:
         r"\b(?:need|require)\s+(?:more|additional)\b",
         r"\bi\s+(?:would|will)\s+not\s+\w+",
         r"\bi\s+(?:wouldn't|won't)\s+\w+",
+        # Commitment markers (COMMISSIVE)
+        r"\b(?:i|we)\s+(?:will|shall)\s+\w+",
+        r"\b(?:i|we)['’]ll\s+\w+",
+        r"\b(?:i|we)\s+(?:promise|commit)\s+to\b",
+        r"\b(?:i\s+am|i['’]m|we\s+are|we['’]re)\s+going\s+to\b",
+        # Clarification questions (CLARIFICATION), sentence-initial; offers excluded
+        r"(?:^|(?<=[.!?]\s))(?:which|what|where|when|who|whose"
+        r"|how\s+(?:many|much|long|often|old|big)|do\s+you|does\s+(?:your|the)|are\s+you"
+        r"|is\s+(?:your|the|this|it)|have\s+you|did\s+you"
+        r"|(?:could|can)\s+you\s+(?:tell|confirm|share|specify))\b"
+        r"(?![^?]*\b(?:can\s+i|help|assist|like\s+me\s+to|want\s+me\s+to"
+        r"|would\s+you\s+like|anything\s+else)\b)[^.!?]*\?",
     ]
 
     # Personalization framing (non-epistemic context markers).
@@ -225,10 +285,126 @@ class StatementExtractor:
         r"\s*[^.!?]*(?:help|assist|can\s+i|would\s+you\s+like)\s*[^.!?]*\?\s*$",
     ]
 
+    # EXPRESSIVE SENTENCE PATTERNS (applied per sentence, any position)
+    # A sentence is dropped only if one pattern matches it IN FULL and it carries
+    # no normative indicator ("Sorry, you should restart it" is kept).
+    EXPRESSIVE_SENTENCE_PATTERNS = [
+        # Sympathy / emotion: "I'm sorry to hear that", "So glad that worked"
+        r"(?:i'?m|i\s+am|so|really)?\s*(?:so\s+|very\s+|really\s+)?(?:sorry|glad|happy|sad|thrilled|delighted)\s+(?:to\s+hear|that|about|for)\b[^.!?]*",
+        # Praise: "Great choice!", "That's a good question."
+        r"(?:(?:that'?s|that\s+is|what)\s+)?(?:an?\s+)?(?:great|good|excellent|nice|awesome|perfect|wonderful|fair)\s+(?:choice|question|idea|point|call|catch|news)",
+        # Thanks: "Thanks for your patience", "Thank you so much"
+        r"(?:thank\s+you|thanks)(?:\s+(?:so|very)\s+much)?(?:\s+for\s+[^.!?]*)?",
+        # Apology / empathy: "I apologize for the confusion", "I understand your frustration"
+        r"(?:i\s+apologi[sz]e|my\s+apologies|apologies)\b[^.!?]*",
+        r"i\s+(?:understand|hear)\s+(?:your\s+)?(?:frustration|concerns?)",
+        # Bare interjections: "Congratulations!", "Sorry!", "Awesome."
+        r"(?:congratulations|congrats|sorry|oops|wow|great|awesome|perfect|excellent)",
+    ]
+
+    # TABLE CELL INDICATORS (cell-level claims, in addition to NORMATIVE_INDICATORS)
+    # Comparison tables state verdicts tersely ("Recommended", "Best for analytics").
+    TABLE_CLAIM_INDICATORS = [
+        r"\brecommended\b",
+        r"\bbest\b",
+        r"\bwinner\b",
+        r"\bavoid\b",
+    ]
+
+    # Fenced code blocks (``` or ~~~), with optional info string (language)
+    CODE_FENCE_PATTERN = r"^[ \t]*(```|~~~)[ \t]*([\w+-]*)[^\n]*\n(.*?)^[ \t]*\1[ \t]*$"
+
+    # Fence languages whose lines are commands a user may execute
+    EXECUTABLE_FENCE_LANGUAGES = frozenset(
+        {
+            "sh",
+            "bash",
+            "shell",
+            "zsh",
+            "fish",
+            "console",
+            "terminal",
+            "powershell",
+            "pwsh",
+            "ps1",
+            "cmd",
+            "bat",
+            "sql",
+        }
+    )
+
+    # Sentence boundary: terminal punctuation, whitespace, then a capital, digit,
+    # quote or parenthesis (a trailing "[@key]" citation stays with its sentence)
+    SENTENCE_BOUNDARY_PATTERN = r"(?<=[.!?])\s+(?=[\"'(]?[A-Z0-9])"
+
+    # Bullet and numbered list markers that start their own sentence
+    BULLET_PATTERN = r"^\s*(?:(?:[-*+\u2022]|\d+[.)])\s+)+"
+
+    # Abbreviations whose period does not end a sentence
+    ABBREVIATIONS = frozenset(
+        {"e.g.", "i.e.", "etc.", "vs.", "approx.", "mr.", "ms.", "dr.", "no."}
+    )
+
+    # Markdown table delimiter row: | --- | :---: | ---: |
+    TABLE_DELIMITER_PATTERN = r"^\s*\|?\s*:?-{3,}:?\s*(?:\|\s*:?-{3,}:?\s*)*\|?\s*$"
+
     # PREFIX PATTERNS (work from START - protocol header detection)
     # Single-pass fat regex (not iterative strip)
     PROTOCOL_PREFIX_PATTERN = r"^(?:hello|hi|hey|greetings|good\s+(?:morning|afternoon|evening)|thanks\s+for\s+asking|i'?m\s+doing\s+(?:well|fine|good|great|okay|ok)|i'?m\s+(?:here|ready|available)|hope\s+you'?re\s+doing\s+well)[!,.\s—-]*"
 
+    def __init__(
+        self,
+        protocol_prefix_phrases: Iterable[str] = (),
+        protocol_suffix_phrases: Iterable[str] = (),
+        flag_executable_code: bool = False,
+        split_sentences: bool = False,
+    ) -> None:
+        """
+        Initialize extractor with optional deployment-specific protocol phrases.
+
+        Phrases are matched literally and case-insensitively. They EXTEND the
+        built-in boundary patterns (never replace them):
+        - prefix phrases (greetings, sign-ons) are stripped from the start
+        - suffix phrases (sign-offs) are stripped, with everything after them,
+          when they open a sentence at the end of the output
+
+        Args:
+            protocol_prefix_phrases: Extra greeting/sign-on phrases
+            protocol_suffix_phrases: Extra sign-off phrases
+            flag_executable_code: Emit command lines of shell/SQL code fences as
+                ASSERTIVE statements instead of only excluding code blocks
+            split_sentences: Emit one statement per sentence or bullet (ids
+                ``s1``, ``s2``, …) instead of one ``final_response`` statement
+        """
+        self.flag_executable_code = flag_executable_code
+        self.split_sentences = split_sentences
+        self._code_fence_re = re.compile(self.CODE_FENCE_PATTERN, re.MULTILINE | re.DOTALL)
+        prefix_alternation = self._phrase_alternation(protocol_prefix_phrases)
+        suffix_alternation = self._phrase_alternation(protocol_suffix_phrases)
+
+        self._expressive_re = [
+            re.compile(rf"^{pattern}[\s!.?]*$", re.IGNORECASE)
+            for pattern in self.EXPRESSIVE_SENTENCE_PATTERNS
+        ]
+        self._prefix_patterns = [self.PROTOCOL_PREFIX_PATTERN]
+        self._suffix_patterns = list(self.PROTOCOL_SUFFIX_PATTERNS)
+        self._protocol_phrase_markers: list[str] = []
+        if prefix_alternation:
+            self._prefix_patterns.append(rf"^(?:{prefix_alternation})(?!\w)[!,.\s—-]*")
+            self._protocol_phrase_markers.append(rf"^(?:{prefix_alternation})(?!\w)")
+        if suffix_alternation:
+            self._suffix_patterns.append(
+                rf"(?:^|(?<=[.!?\n]))\s*(?:{suffix_alternation})(?!\w).*$"
+            )
+            self._protocol_phrase_markers.append(rf"^(?:{suffix_alternation})(?!\w)")
+
+    @staticmethod
+    def _phrase_alternation(phrases: Iterable[str]) -> str:
+        """Build a literal regex alternation (longest phrase first) from phrases."""
+        cleaned = {" ".join(phrase.split()).lower() for phrase in phrases if phrase.strip()}
+        ordered = sorted(cleaned, key=len, reverse=True)
+        return "|".join(re.escape(phrase).replace(r"\ ", r"\s+") for phrase in ordered)
+
     def extract(self, text: str) -> list[Statement]:
         """
         Extract statement from agent output.
@@ -253,18 +429,36 @@ class StatementExtractor:
             logger.warning("StatementExtractor: Empty text provided")
             return []
 
+        # Cut code fences out of the prose; commands are optionally judged one by one
+        prose, code_blocks = self._split_code_blocks(text)
+        code_statements = (
+            self._extract_executable_commands(code_blocks) if self.flag_executable_code else []
+        )
+
+        # Cut markdown tables out of the prose; their cells are judged one by one
+        prose, tables = self._split_markdown_tables(prose)
+        extra_statements = [*self._extract_table_claims(tables), *code_statements]
+
         # Strip greeting prefix (protocol-level meta-communication)
-        cleaned_text = self._strip_greeting(text)
+        cleaned_text = self._strip_greeting(prose) if prose.strip() else ""
 
         # If nothing remains after stripping → no normative content
         # Return empty list → evaluator will report NO_NORMATIVE_CONTENT (no jurisdiction)
         if not cleaned_text.strip():
+            self._log_decision(extra_statements, prose, "", code_blocks, tables)
+            if extra_statements:
+                return extra_statements
             logger.info(
                 "StatementExtractor: Only greeting/protocol speech detected, "
                 "no normative content to evaluate"
             )
             return []
 
+        if self.split_sentences:
+            statements = [*self._extract_sentences(cleaned_text), *extra_statements]
+            self._log_decision(statements, prose, cleaned_text, code_blocks, tables)
+            return statements
+
         # Create single statement representing agent's normative participation
         statement = Statement(
             id="final_response",
@@ -278,7 +472,223 @@ class StatementExtractor:
             f"(length={len(cleaned_text)} chars, preview: {cleaned_text[:80]}...)"
         )
 
-        return [statement]
+        statements = [statement, *extra_statements]
+        self._log_decision(statements, prose, cleaned_text, code_blocks, tables)
+        return statements
+
+    def _extract_sentences(self, text: str) -> list[Statement]:
+        """
+        Build one Statement per sentence or bullet of cleaned prose (ids ``s1``, ``s2``, …).
+
+        Bullets and numbered items are units of their own (marker removed);
+        other lines of a paragraph are joined before splitting at sentence
+        punctuation. Lines without a letter or digit (blank lines, rules)
+        end the current unit and are skipped.
+        """
+        units: list[str] = []
+        paragraph: list[str] = []
+        for line in [*text.split("\n"), ""]:
+            bullet = re.match(self.BULLET_PATTERN, line)
+            blank = not re.search(r"\w", line)
+            if bullet or blank:
+                if paragraph:
+                    units.append(" ".join(paragraph))
+                    paragraph = []
+            if bullet:
+                paragraph = [line[bullet.end() :].strip()]
+            elif not blank:
+                paragraph.append(line.strip())
+
+        sentences: list[str] = []
+        for unit in units:
+            parts = re.split(self.SENTENCE_BOUNDARY_PATTERN, unit)
+            merged = parts[:1]
+            for part in parts[1:]:
+                if merged[-1].split()[-1].lower() in self.ABBREVIATIONS:
+                    merged[-1] = f"{merged[-1]} {part}"
+                else:
+                    merged.append(part)
+            sentences.extend(part.strip() for part in merged if re.search(r"\w", part))
+
+        return [
+            Statement(
+                id=f"s{index}",
+                subject="agent",
+                predicate="participation",
+                raw_text=sentence,
+            )
+            for index, sentence in enumerate(sentences, 1)
+        ]
+
+    @staticmethod
+    def _log_decision(
+        statements: list[Statement],
+        prose: str,
+        cleaned_text: str,
+        code_blocks: list[tuple[str, str]],
+        tables: list[list[list[str]]],
+    ) -> None:
+        """Log what extraction kept and cut, as structured DEBUG fields."""
+        log_event(
+            logging.DEBUG,
+            "StatementExtractor: extraction decided",
+            statement_ids=[s.id for s in statements],
+            protocol_chars_stripped=len(prose.strip()) - len(cleaned_text.strip()),
+            code_blocks=len(code_blocks),
+            tables=len(tables),
+        )
+
+    def _split_code_blocks(self, text: str) -> tuple[str, list[tuple[str, str]]]:
+        """
+        Separate fenced code blocks from prose.
+
+        Returns:
+            (prose, blocks) where each block is (language, body); the language
+            is the lowercased first word of the fence info string ("" if none)
+        """
+        blocks: list[tuple[str, str]] = []
+
+        def _cut(match: re.Match[str]) -> str:
+            blocks.append((match.group(2).lower(), match.group(3)))
+            return ""
+
+        prose = self._code_fence_re.sub(_cut, text)
+        if blocks:
+            logger.debug(f"StatementExtractor: Excluded {len(blocks)} code block(s)")
+        return prose.strip(), blocks
+
+    def _extract_executable_commands(self, blocks: list[tuple[str, str]]) -> list[Statement]:
+        """
+        Build one ASSERTIVE Statement per command line of executable code fences.
+
+        A command is an imperative ("run this"), whatever words it contains, so
+        modality is fixed here and not re-detected. Comment lines are skipped;
+        ``console``/``terminal`` blocks count only ``$ `` prompt lines (the rest
+        is output); backslash continuations are joined.
+        """
+        statements: list[Statement] = []
+        for b_idx, (language, body) in enumerate(blocks, 1):
+            if language not in self.EXECUTABLE_FENCE_LANGUAGES:
+                continue
+            prompt_only = language in {"console", "terminal"}
+            commands: list[str] = []
+            pending = ""
+            for line in body.split("\n"):
+                stripped = line.strip()
+                if pending:
+                    stripped = f"{pending} {stripped}"
+                    pending = ""
+                elif prompt_only:
+                    if not stripped.startswith(("$ ", "# ", "> ")):
+                        continue
+                    stripped = stripped[2:].strip()
+                elif stripped.startswith(("$ ", "> ")):
+                    stripped = stripped[2:].strip()
+                if stripped.endswith("\\"):
+                    pending = stripped[:-1].strip()
+                    continue
+                if not stripped or stripped.startswith(("#", "--", "//", "::", "rem ", "REM ")):
+                    continue
+                commands.append(stripped)
+            if pending:
+                commands.append(pending)
+            for l_idx, command in enumerate(commands, 1):
+                statements.append(
+                    Statement(
+                        id=f"code:{b_idx}:{l_idx}",
+                        subject="agent",
+                        predicate="execution",
+                        raw_text=command,
+                        modality=Modality.ASSERTIVE,
+                    )
+                )
+        return statements
+
+    def _split_markdown_tables(self, text: str) -> tuple[str, list[list[list[str]]]]:
+        """
+        Separate markdown tables from prose.
+
+        A table is a header row, a delimiter row (``| --- | --- |``) and the
+        pipe rows that follow. Table lines are removed from the prose (a blank
+        line is left in their place so paragraphs stay separated).
+
+        Args:
+            text: Agent output
+
+        Returns:
+            (prose, tables) where each table is a list of rows (header first)
+            and each row is a list of cell texts
+        """
+        lines = text.split("\n")
+        prose_lines: list[str] = []
+        tables: list[list[list[str]]] = []
+        i = 0
+        while i < len(lines):
+            is_table = (
+                i + 1 < len(lines)
+                and "|" in lines[i]
+                and re.match(self.TABLE_DELIMITER_PATTERN, lines[i + 1])
+            )
+            if not is_table:
+                prose_lines.append(lines[i])
+                i += 1
+                continue
+
+            rows = [self._split_table_row(lines[i])]
+            i += 2
+            while i < len(lines) and "|" in lines[i] and lines[i].strip():
+                rows.append(self._split_table_row(lines[i]))
+                i += 1
+            tables.append(rows)
+            prose_lines.append("")
+
+        if tables:
+            logger.debug(f"StatementExtractor: Found {len(tables)} markdown table(s)")
+        return "\n".join(prose_lines).strip(), tables
+
+    @staticmethod
+    def _split_table_row(line: str) -> list[str]:
+        """Split a markdown table row into cell texts (escaped pipes stay in cells)."""
+        row = line.strip()
+        if row.startswith("|"):
+            row = row[1:]
+        if row.endswith("|") and not row.endswith("\\|"):
+            row = row[:-1]
+        return [cell.strip().replace("\\|", "|") for cell in re.split(r"(?<!\\)\|", row)]
+
+    def _extract_table_claims(self, tables: list[list[list[str]]]) -> list[Statement]:
+        """
+        Build one Statement per table cell that carries a claim indicator.
+
+        Statement ids are ``table:<table>:<row>:<column>`` (1-based; rows count
+        body rows only). Subject is the row label (first cell), predicate the
+        column header, so the judgment shows which option the verdict concerns.
+        """
+        statements: list[Statement] = []
+        for t_idx, rows in enumerate(tables, 1):
+            header, body = rows[0], rows[1:]
+            for r_idx, row in enumerate(body, 1):
+                row_label = row[0] if row else ""
+                for c_idx, cell in enumerate(row, 1):
+                    if not cell or not self._is_table_claim(cell):
+                        continue
+                    column = header[c_idx - 1] if c_idx <= len(header) else ""
+                    statements.append(
+                        Statement(
+                            id=f"table:{t_idx}:{r_idx}:{c_idx}",
+                            subject=row_label or "agent",
+                            predicate=column or "participation",
+                            raw_text=cell,
+                        )
+                    )
+        return statements
+
+    def _is_table_claim(self, cell: str) -> bool:
+        """Check if a table cell carries a normative or recommendation indicator."""
+        cell_lower = cell.lower()
+        return self._contains_normative_indicators(cell) or any(
+            re.search(pattern, cell_lower) for pattern in self.TABLE_CLAIM_INDICATORS
+        )
 
     def _contains_normative_indicators(self, text: str) -> bool:
         """
@@ -344,7 +754,7 @@ class StatementExtractor:
             r"\bhope\s+you\b",
         ]
 
-        for pattern in protocol_markers:
+        for pattern in [*protocol_markers, *self._protocol_phrase_markers]:
             if re.search(pattern, s_lower):
                 return True
 
@@ -373,16 +783,17 @@ class StatementExtractor:
         Returns:
             Text with protocol prefix sentences removed
         """
-        # Split on sentence boundaries (. ! ?)
-        sentences = re.split(r"(?<=[.!?])\s+", text)
+        # Split on sentence boundaries (. ! ?), remembering the whitespace after each
+        parts = re.split(r"((?<=[.!?])\s+)", text)
+        sentences, separators = parts[0::2], parts[1::2]
 
-        kept = []
+        kept: list[int] = []
         found_normative = False
 
         for i, sentence in enumerate(sentences):
             # If we already found normative content, keep everything after
             if found_normative:
-                kept.append(sentence)
+                kept.append(i)
                 continue
 
             has_strong_normative = self._contains_strong_normative_indicators(sentence)
@@ -395,15 +806,57 @@ class StatementExtractor:
 
             # First normative sentence → keep it and everything after
             if has_any_normative:
-                kept.extend(sentences[i:])
+                kept.extend(range(i, len(sentences)))
                 found_normative = True
                 break
 
             # Sentence is neither clearly protocol nor clearly normative
             # Conservative: keep it (might be normative without indicators)
-            kept.append(sentence)
+            kept.append(i)
+
+        if not self.split_sentences:
+            return " ".join(sentences[i] for i in kept).strip()
+        # Sentence granularity needs line breaks (bullets, paragraphs) kept intact
+        joined = ""
+        for position, i in enumerate(kept):
+            if position:
+                joined += separators[i - 1] if kept[position - 1] == i - 1 else " "
+            joined += sentences[i]
+        return joined.strip()
+
+    def _is_expressive_sentence(self, sentence: str) -> bool:
+        """Check if sentence is pure expressive speech (no normative indicators)."""
+        s = sentence.strip()
+        if not s or self._contains_normative_indicators(s):
+            return False
+        return any(pattern.match(s) for pattern in self._expressive_re)
+
+    def _strip_expressive_sentences(self, text: str) -> str:
+        """
+        Remove pure expressive sentences from any position.
 
-        return " ".join(kept).strip()
+        "I'm sorry to hear that. If the router is down, restart it."
+        → "If the router is down, restart it."
+
+        Sentence separators of kept sentences are preserved, so paragraph
+        breaks still drive head-driven modality detection.
+
+        Args:
+            text: Text to process
+
+        Returns:
+            Text with expressive sentences removed
+        """
+        # Split keeping separators: [sentence, separator, sentence, ...]
+        parts = re.split(r"((?<=[.!?])\s+)", text)
+        kept: list[str] = []
+        for i in range(0, len(parts), 2):
+            sentence = parts[i]
+            separator = parts[i + 1] if i + 1 < len(parts) else ""
+            if self._is_expressive_sentence(sentence):
+                continue
+            kept.append(sentence + separator)
+        return "".join(kept).strip()
 
     def _strip_protocol_suffix(self, text: str) -> str:
         """
@@ -434,7 +887,7 @@ class StatementExtractor:
         while prev != current and iteration < max_iterations:
             prev = current
 
-            for pattern in self.PROTOCOL_SUFFIX_PATTERNS:
+            for pattern in self._suffix_patterns:
                 # CRITICAL: All patterns MUST have $ anchor
                 # If pattern doesn't end with $, add it
                 anchored_pattern = pattern if pattern.endswith("$") else pattern + r"$"
@@ -463,6 +916,9 @@ class StatementExtractor:
         - Capability lists and examples
         - Open-ended questions without normative content
 
+        Expressive speech includes:
+        - Sympathy, praise, thanks, apology ("I'm sorry to hear that", "Great choice!")
+
         Normative speech includes:
         - Assertions, recommendations, refusals
         - Context-relative claims ("for you", "given your preferences")
@@ -484,6 +940,9 @@ class StatementExtractor:
            Remove greetings and protocol headers, including multi-sentence
            conversational preambles.
 
+        3.5) Expressive stripping (any position):
+           Remove sentences that are pure expressives.
+
         4) Final guard:
            If the remaining text ends with a question mark and contains
            no normative indicators, it is treated as protocol-only.
@@ -529,7 +988,12 @@ class StatementExtractor:
         cleaned = self._strip_protocol_prefix_sentences(cleaned)
 
         # STEP 3: Strip PREFIX tokens (single-pass fat regex for remaining greeting tokens)
-        cleaned = re.sub(self.PROTOCOL_PREFIX_PATTERN, "", cleaned, flags=re.IGNORECASE).strip()
+        for prefix_pattern in self._prefix_patterns:
+            cleaned = re.sub(prefix_pattern, "", cleaned, flags=re.IGNORECASE).strip()
+
+        # STEP 3.5: Strip EXPRESSIVE sentences (per sentence, any position)
+        # "I'm sorry to hear that." must not become the core assertion (→ ASSERTIVE default)
+        cleaned = self._strip_expressive_sentences(cleaned)
 
         # STEP 4: Hard invariant - question tail rejection
         # Questions are generally protocol speech (continuation invites), not activity participation.
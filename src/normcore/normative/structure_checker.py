@@ -0,0 +1,168 @@
+"""
+Structural (well-formedness) checking for extracted statements.
+
+This component decides whether a statement has a shape the normative core can
+judge at all. It runs without grounding, licensing or axioms, so it can be
+used as a cheap pre-filter (``check_structure``) before full evaluation.
+
+DESIGN PRINCIPLES
+-----------------
+1) Structure only.
+   - Reads the statement text, subject/predicate and detected modality.
+   - MUST NOT consult GroundSet, License or links.
+
+2) Issues, not verdicts.
+   - Each problem is reported as a stable issue code.
+   - A statement without issues is WELL_FORMED; mapping issues to evaluation
+     statuses is the caller's decision.
+
+3) Conservative text checks.
+   - Text-level checks flag output that is garbage by construction (truncation,
+     mojibake, symbol soup), never unusual but legible prose.
+   - Code commands and table cells are not sentences; they are exempt from
+     text-level checks.
+
+ISSUE CODES
+-----------
+- empty_text: nothing left to judge after extraction
+- missing_subject / missing_predicate: statement shape is not formable (I1)
+- undetermined_modality: no modality could be assigned
+- fragment: a single word, or text cut off mid-clause (no terminal punctuation,
+  ends on a function word such as "the" or "should")
+- encoding_noise: replacement/control characters or UTF-8 mojibake ("â€™")
+- gibberish: mostly symbols, or mostly unpronounceable letter runs (no vowels,
+  or keyboard-row mashes such as "asdf")
+- unparseable_structure: brackets opened and never closed (truncated JSON/lists)
+"""
+
+from __future__ import annotations
+
+import re
+
+from .models import Statement
+from .statement_extractor import StatementExtractor
+
+# Words a complete clause does not end on (articles, prepositions, conjunctions, modals).
+DANGLING_WORDS = frozenset(
+    {
+        "a",
+        "an",
+        "the",
+        "to",
+        "of",
+        "in",
+        "on",
+        "for",
+        "with",
+        "and",
+        "or",
+        "but",
+        "because",
+        "if",
+        "that",
+        "should",
+        "must",
+        "will",
+        "would",
+        "can",
+        "could",
+        "is",
+        "are",
+    }
+)
+TERMINAL_PUNCTUATION = tuple(".!?:;)]}\"'`*_>…")
+ENCODING_NOISE_PATTERN = re.compile(
+    r"[\ufffd\x00-\x08\x0b\x0c\x0e-\x1f\x7f]|Ã[\u0080-\u00bf]|â€|Â[\u00a0-\u00bf]"
+)
+LATIN_WORD_PATTERN = re.compile(r"\b[A-Za-z]{4,}\b")
+VOWELS = frozenset("aeiouyAEIOUY")
+KEYBOARD_ROWS = ("qwertyuiop", "asdfghjkl", "zxcvbnm")
+# Issues that make text garbage whatever its modality; checked before extraction
+# drops indicator-free text as protocol speech.
+GARBAGE_ISSUES = ("encoding_noise", "gibberish")
+CODE_FENCE_PATTERN = re.compile(StatementExtractor.CODE_FENCE_PATTERN, re.MULTILINE | re.DOTALL)
+TABLE_ROW_PATTERN = re.compile(r"^[ \t]*\|.*$", re.MULTILINE)
+BRACKET_PAIRS = (("(", ")"), ("[", "]"), ("{", "}"))
+# Statement id prefixes of non-sentence statements (code commands, table cells).
+TEXT_CHECK_EXEMPT_PREFIXES = ("code:", "table:")
+
+
+class StructureChecker:
+    """
+    Report structural issues of a statement.
+    """
+
+    def check(self, statement: Statement) -> list[str]:
+        """
+        Check statement structure.
+
+        Args:
+            statement: Statement after modality detection
+
+        Returns:
+            Issue codes in check order (empty if well-formed)
+        """
+        issues: list[str] = []
+        if not statement.raw_text.strip():
+            issues.append("empty_text")
+        if not statement.subject:
+            issues.append("missing_subject")
+        if not statement.predicate:
+            issues.append("missing_predicate")
+        if statement.modality is None:
+            issues.append("undetermined_modality")
+        text = statement.raw_text.strip()
+        if text and not statement.id.startswith(TEXT_CHECK_EXEMPT_PREFIXES):
+            issues.extend(self._text_issues(text))
+        return issues
+
+    def check_garbage(self, text: str) -> list[str]:
+        """
+        Check raw output for garbage that no modality can make judgeable.
+
+        Runs before statement extraction, so gibberish without a normative
+        indicator is reported instead of being dropped as protocol speech.
+        Code fences and table rows are exempt, like code and table statements.
+
+        Args:
+            text: Raw agent output
+
+        Returns:
+            Garbage issue codes (subset of GARBAGE_ISSUES; empty if legible)
+        """
+        prose = TABLE_ROW_PATTERN.sub("", CODE_FENCE_PATTERN.sub("", text)).strip()
+        issues = self._text_issues(prose) if prose else []
+        return [issue for issue in issues if issue in GARBAGE_ISSUES]
+
+    def _text_issues(self, text: str) -> list[str]:
+        issues: list[str] = []
+        words = re.findall(r"[^\W\d_]+(?:'[^\W\d_]+)?", text)
+        last_word = words[-1].lower() if words else ""
+        truncated = not text.endswith(TERMINAL_PUNCTUATION) and last_word in DANGLING_WORDS
+        if len(words) < 2 or truncated:
+            issues.append("fragment")
+        if ENCODING_NOISE_PATTERN.search(text):
+            issues.append("encoding_noise")
+        if self._is_gibberish(text):
+            issues.append("gibberish")
+        if any(text.count(open_) > text.count(close) for open_, close in BRACKET_PAIRS):
+            issues.append("unparseable_structure")
+        return issues
+
+    @staticmethod
+    def _is_gibberish(text: str) -> bool:
+        """Mostly symbols, or at least two long Latin words and most unpronounceable."""
+        visible = [ch for ch in text if not ch.isspace()]
+        letters = sum(ch.isalpha() for ch in visible)
+        if visible and letters / len(visible) < 0.5:
+            return True
+        # All-caps words are acronyms (HTTP, SMTP), not letter mashes
+        long_words = [word for word in LATIN_WORD_PATTERN.findall(text) if not word.isupper()]
+        mashed = [word for word in long_words if StructureChecker._is_unpronounceable(word)]
+        return len(mashed) >= 2 and len(mashed) * 2 >= len(long_words)
+
+    @staticmethod
+    def _is_unpronounceable(word: str) -> bool:
+        """No vowels at all, or a run along one keyboard row ("qwer", "zxcv")."""
+        lowered = word.lower()
+        return not VOWELS.intersection(lowered) or any(lowered in row for row in KEYBOARD_ROWS)
@@ -0,0 +1,72 @@
+"""
+Per-tool knowledge extraction for tool results.
+
+KnowledgeStateBuilder maps tool results to knowledge nodes by convention
+(``*_id`` / ``*_key`` fields, plaintext identifiers). Integrators whose tools
+return other shapes (a GraphQL response holding several entities) register a
+ToolResultParser for the tool name instead of modifying the builder.
+
+A registered parser replaces the convention for that tool only:
+- it runs AFTER the non-epistemic filter, so memory/personalization tools
+  stay out of the GroundSet whatever is registered
+- an untrusted result (unmatched tool_call_id) keeps its nodes weak
+- returning None or no nodes admits nothing for that result
+"""
+
+from __future__ import annotations
+
+from collections.abc import Iterable
+from typing import Protocol, runtime_checkable
+
+from ..models.messages import ToolResultSpeechAct
+from .models import KnowledgeNode
+
+
+@runtime_checkable
+class ToolResultParser(Protocol):
+    """Extract knowledge nodes from the result of one tool."""
+
+    def parse(self, tool_result: ToolResultSpeechAct) -> Iterable[KnowledgeNode] | None:
+        """Return the nodes the result establishes, or None to admit nothing."""
+        ...
+
+
+class ToolResultParserRegistry:
+    """
+    Tool name → ToolResultParser mapping (names are matched case-insensitively).
+    """
+
+    def __init__(self) -> None:
+        self._parsers: dict[str, ToolResultParser] = {}
+
+    def register(self, tool_name: str, parser: ToolResultParser) -> None:
+        """Register ``parser`` for ``tool_name``, replacing any previous one."""
+        if not isinstance(parser, ToolResultParser):
+            raise TypeError(f"Parser for '{tool_name}' must define parse(tool_result)")
+        self._parsers[tool_name.lower()] = parser
+
+    def unregister(self, tool_name: str) -> None:
+        """Remove the parser for ``tool_name`` (no-op if none is registered)."""
+        self._parsers.pop(tool_name.lower(), None)
+
+    def get(self, tool_name: str) -> ToolResultParser | None:
+        """Parser registered for ``tool_name``, if any."""
+        return self._parsers.get(tool_name.lower())
+
+    def __contains__(self, tool_name: object) -> bool:
+        """Whether a parser is registered for ``tool_name``."""
+        return isinstance(tool_name, str) and tool_name.lower() in self._parsers
+
+
+# Process-wide registry used by KnowledgeStateBuilder unless one is passed explicitly.
+default_registry = ToolResultParserRegistry()
+
+
+def register_tool_result_parser(tool_name: str, parser: ToolResultParser) -> None:
+    """Register ``parser`` for ``tool_name`` in the default registry."""
+    default_registry.register(tool_name, parser)
+
+
+def unregister_tool_result_parser(tool_name: str) -> None:
+    """Remove the parser for ``tool_name`` from the default registry."""
+    default_registry.unregister(tool_name)
@@ -0,0 +1,139 @@
+"""
+JSON payload helpers shared by the CLI pipeline modes.
+
+An evaluate payload is a JSON object carrying the same fields as the public
+``evaluate()`` contract:
+
+- ``agent_output`` (optional): assistant output string
+- ``conversation`` (optional): OpenAI Chat Completions message list
+- ``grounds`` (optional): external grounds
+- ``statement_ids`` / ``span`` (optional): restrict evaluation to a subset
+- ``links`` (optional): precomputed LinkSet, bypassing citation-key extraction
+- ``knowledge_nodes`` (optional): prebuilt knowledge nodes, bypassing tool-result parsing
+- ``domain`` (optional): deployment domain, selecting a policy ``domain_risk_floors`` entry
+
+Pipeline callers may also attach an ``id`` field. It is not used for evaluation
+and is echoed back next to the judgment so results can be correlated with inputs.
+An optional ``agent`` field names the producing agent; it is not used for
+evaluation either and only labels operational metrics.
+"""
+
+from __future__ import annotations
+
+import json
+from typing import Any
+
+from .canonical import canonical_json
+from .evaluator import evaluate
+from .models import AdmissibilityJudgment
+from .models.policy import EvaluationPolicy
+from .signing import SIGNATURE_FIELD, JudgmentSigner
+
+PAYLOAD_FIELDS = (
+    "agent_output",
+    "conversation",
+    "grounds",
+    "statement_ids",
+    "span",
+    "links",
+    "knowledge_nodes",
+    "domain",
+)
+PAYLOAD_ID_FIELD = "id"
+PAYLOAD_AGENT_FIELD = "agent"
+
+
+def _reject_constant(name: str) -> Any:
+    raise ValueError(f"{name} is not valid JSON")
+
+
+def parse_payload_line(line: str) -> dict[str, Any]:
+    """Parse one NDJSON line into an evaluate payload object."""
+    try:
+        payload = json.loads(line, parse_constant=_reject_constant)
+    except ValueError as exc:
+        raise ValueError(f"Invalid JSON payload: {exc}") from exc
+    if not isinstance(payload, dict):
+        raise ValueError("Payload must be a JSON object")
+    return payload
+
+
+def payload_from_object(value: Any) -> dict[str, Any]:
+    """Check that an already decoded value (e.g. from MessagePack) is a payload object."""
+    if not isinstance(value, dict):
+        raise ValueError("Payload must be a map")
+    return value
+
+
+def evaluate_payload(
+    payload: dict[str, Any], *, strict: bool = False, policy: EvaluationPolicy | None = None
+) -> AdmissibilityJudgment:
+    """Evaluate a decoded payload object via the public ``evaluate()`` contract."""
+    unknown = sorted(set(payload) - {*PAYLOAD_FIELDS, PAYLOAD_ID_FIELD, PAYLOAD_AGENT_FIELD})
+    if unknown:
+        raise ValueError(f"Unknown payload fields: {', '.join(unknown)}")
+    return evaluate(
+        **{field: payload.get(field) for field in PAYLOAD_FIELDS}, policy=policy, strict=strict
+    )
+
+
+def render_result_line(
+    judgment: AdmissibilityJudgment,
+    *,
+    payload_id: Any = None,
+    signer: JudgmentSigner | None = None,
+    payload: dict[str, Any] | None = None,
+    canonical: bool = False,
+) -> str:
+    """
+    Render a judgment as a single NDJSON line (with optional echoed ``id``).
+
+    With ``canonical=True`` the line is canonical JSON (sorted keys, normalized
+    numbers; see ``canonical_json``) instead of record field order.
+    """
+    record = render_result_record(judgment, payload_id=payload_id, signer=signer, payload=payload)
+    if canonical:
+        return canonical_json(record)
+    return json.dumps(record, ensure_ascii=False)
+
+
+def render_result_record(
+    judgment: AdmissibilityJudgment,
+    *,
+    payload_id: Any = None,
+    signer: JudgmentSigner | None = None,
+    payload: dict[str, Any] | None = None,
+) -> dict[str, Any]:
+    """
+    Build the output record for a judgment.
+
+    The echoed ``id`` comes first, then the judgment fields, then the detached
+    ``signature`` when a signer is configured; it binds the id and ``payload``
+    (the evaluated input) when given.
+    """
+    record: dict[str, Any] = {}
+    if payload_id is not None:
+        record[PAYLOAD_ID_FIELD] = payload_id
+    record.update(judgment.model_dump(mode="json"))
+    if signer is not None:
+        signature = signer.sign(judgment, payload_id=payload_id, payload=payload)
+        record[SIGNATURE_FIELD] = signature.model_dump(mode="json")
+    return record
+
+
+def render_error_line(message: str, *, line_number: int, payload_id: Any = None) -> str:
+    """Render a per-line failure as a single NDJSON line."""
+    record = render_error_record(message, line_number=line_number, payload_id=payload_id)
+    return json.dumps(record, ensure_ascii=False)
+
+
+def render_error_record(
+    message: str, *, line_number: int, payload_id: Any = None
+) -> dict[str, Any]:
+    """Build the output record for a per-line failure."""
+    record: dict[str, Any] = {}
+    if payload_id is not None:
+        record[PAYLOAD_ID_FIELD] = payload_id
+    record["error"] = message
+    record["line"] = line_number
+    return record
@@ -0,0 +1,64 @@
+"""
+Named policy packs.
+
+A pack is a bundle of ``EvaluationPolicy`` fields for a common deployment
+profile, so callers can pick a profile by name instead of spelling out each
+setting:
+
+- ``strict``: every assertive statement cites a ground (A8), tool results
+  nobody asked for are ignored, executable code fences are judged, and
+  unsupported answers or high-risk judgments block.
+- ``lenient``: weighted aggregation (a status decides only with half of the
+  statement weight), mixed text/refusal content evaluated part by part,
+  pronoun subjects resolved, and personalized advice admitted without user
+  context (A9 disabled).
+- ``rag``: retrieval pipelines; assertive content needs one citation per 200
+  words, only evidence since the last user message grounds the answer, and
+  unmatched tool results count as weak evidence.
+
+Packs are starting points: fields given next to a pack override it.
+"""
+
+from __future__ import annotations
+
+from typing import Any
+
+from .models.policy import EvaluationPolicy
+
+POLICY_PACKS: dict[str, dict[str, Any]] = {
+    "strict": {
+        "min_citations_per_assertive": 1,
+        "unmatched_tool_results": "ignore",
+        "code_blocks": "flag_executable",
+        "gate_by_status": {"unsupported": "block"},
+        "gate_by_risk_tier": {"high": "block"},
+    },
+    "lenient": {
+        "aggregation": "weighted",
+        "weighted_status_threshold": 0.5,
+        "mixed_refusal_parts": "evaluate_parts",
+        "resolve_coreferences": True,
+        "disabled_axioms": ["A9"],
+    },
+    "rag": {
+        "min_citations_per_200_words": 1.0,
+        "ground_window_turns": 1,
+        "unmatched_tool_results": "weak",
+    },
+}
+
+
+def policy_pack(name: str, **overrides: Any) -> EvaluationPolicy:
+    """
+    Return the policy of pack ``name`` with ``overrides`` fields replacing its own.
+
+    Raises:
+        ValueError: ``name`` is not a known pack, or an override is invalid
+    """
+    try:
+        fields = POLICY_PACKS[name]
+    except KeyError:
+        raise ValueError(
+            f"Unknown policy pack '{name}' (choose from {', '.join(POLICY_PACKS)})"
+        ) from None
+    return EvaluationPolicy.model_validate({**fields, **overrides})
@@ -0,0 +1,351 @@
+"""
+Protobuf encoding of judgments.
+
+Judgments can travel over protobuf-based event buses using the schema in
+``judgment.proto`` (``PROTO_SCHEMA_PATH``)::
+
+    data = judgment_to_protobuf(judgment)
+    assert judgment_from_protobuf(data) == judgment
+
+The conversion is lossless: every ``AdmissibilityJudgment`` field has a
+schema field, and decoding yields an equal model. Consumers in other
+languages generate their types from the ``.proto`` file; Python callers
+need no protobuf runtime, because this module implements the proto3 wire
+format for the schema directly. Unknown fields are skipped when decoding,
+so older readers accept messages from newer schema versions.
+"""
+
+from __future__ import annotations
+
+import struct
+from collections.abc import Iterator
+from dataclasses import dataclass
+from enum import Enum
+from pathlib import Path
+from typing import Any
+
+from pydantic import ValidationError
+
+from .models import AdmissibilityJudgment, AdmissibilityStatus, GateDecision, RiskTier
+
+PROTO_SCHEMA_PATH = Path(__file__).with_name("judgment.proto")
+PROTO_PACKAGE = "normcore.v1"
+
+_VARINT = 0
+_FIXED64 = 1
+_LENGTH_DELIMITED = 2
+_FIXED32 = 5
+
+
+@dataclass(frozen=True)
+class _Field:
+    number: int
+    name: str
+    # "string", "bool", "int64", "double", an Enum class, or a nested _Message
+    kind: Any
+    repeated: bool = False
+    # proto3 ``optional``: written whenever not None, decoded as None when absent
+    optional: bool = False
+
+
+@dataclass(frozen=True)
+class _Message:
+    name: str
+    fields: tuple[_Field, ...]
+
+
+_GROUND_REF = _Message(
+    "GroundRef",
+    (
+        _Field(1, "id", "string"),
+        _Field(2, "scope", "string"),
+        _Field(3, "source", "string"),
+        _Field(4, "status", "string"),
+        _Field(5, "confidence", "double"),
+        _Field(6, "strength", "string"),
+        _Field(7, "semantic_id", "string", optional=True),
+        _Field(8, "derived_from", "string", repeated=True),
+    ),
+)
+_UNCERTAINTY = _Message(
+    "StatementUncertainty",
+    (
+        _Field(1, "markers", "string", repeated=True),
+        _Field(2, "probability", "double", optional=True),
+    ),
+)
+_ATTRIBUTION = _Message(
+    "StatementAttribution",
+    (
+        _Field(1, "source", "string"),
+        _Field(2, "marker", "string"),
+    ),
+)
+_LICENSE_TRACE = _Message(
+    "LicenseTrace",
+    (
+        _Field(1, "rule", "string"),
+        _Field(2, "mode", "string"),
+        _Field(3, "ground_set_size", "int64"),
+        _Field(4, "factual_present", "bool"),
+        _Field(5, "factual_strength", "string", optional=True),
+        _Field(6, "factual_has_strong", "bool"),
+        _Field(7, "non_factual_scopes", "string", repeated=True),
+        _Field(8, "user_context", "bool"),
+        _Field(9, "supports_links_count", "int64"),
+        _Field(10, "permitted_modalities", "string", repeated=True),
+    ),
+)
+_STATEMENT_EVALUATION = _Message(
+    "StatementEvaluation",
+    (
+        _Field(1, "statement_id", "string"),
+        _Field(2, "statement", "string"),
+        _Field(3, "modality", "string"),
+        _Field(4, "reading", "string", optional=True),
+        _Field(5, "uncertainty", _UNCERTAINTY),
+        _Field(6, "attribution", _ATTRIBUTION),
+        _Field(7, "personalized", "bool"),
+        _Field(8, "license", "string", repeated=True),
+        _Field(9, "status", AdmissibilityStatus),
+        _Field(10, "violated_axiom", "string", optional=True),
+        _Field(11, "explanation", "string"),
+        _Field(12, "recommended_modality", "string", optional=True),
+        _Field(13, "grounding_trace", _GROUND_REF, repeated=True),
+        _Field(14, "weight", "double"),
+        _Field(15, "confidence", "double"),
+        _Field(16, "subject", "string", optional=True),
+        _Field(17, "predicate", "string", optional=True),
+        _Field(18, "license_trace", _LICENSE_TRACE),
+    ),
+)
+_STATUS_COUNT = _Message(
+    "StatusCount",
+    (
+        _Field(1, "status", AdmissibilityStatus),
+        _Field(2, "count", "int64"),
+    ),
+)
+_CITATION_MAP_ENTRY = _Message(
+    "CitationMapEntry",
+    (
+        _Field(1, "key", "string"),
+        _Field(2, "ground_ids", "string", repeated=True),
+    ),
+)
+_JUDGMENT = _Message(
+    "AdmissibilityJudgment",
+    (
+        _Field(1, "status", AdmissibilityStatus),
+        _Field(2, "licensed", "bool"),
+        _Field(3, "can_retry", "bool"),
+        _Field(4, "statement_evaluations", _STATEMENT_EVALUATION, repeated=True),
+        _Field(5, "feedback_hint", "string", optional=True),
+        _Field(6, "violated_axioms", "string", repeated=True),
+        _Field(7, "explanation", "string"),
+        _Field(8, "num_statements", "int64"),
+        _Field(9, "num_acceptable", "int64"),
+        _Field(10, "status_counts", _STATUS_COUNT, repeated=True),
+        _Field(11, "confidence", "double"),
+        _Field(12, "grounds_accepted", "int64"),
+        _Field(13, "grounds_cited", "int64"),
+        _Field(14, "accepted_ground_ids", "string", repeated=True),
+        _Field(15, "cited_ground_ids", "string", repeated=True),
+        _Field(16, "citation_map", _CITATION_MAP_ENTRY, repeated=True),
+        _Field(21, "out_of_window_ground_ids", "string", repeated=True),
+        _Field(17, "risk_tier", RiskTier),
+        _Field(18, "gate_decision", GateDecision),
+        _Field(19, "reasoning_items_skipped", "int64"),
+        _Field(20, "warnings", "string", repeated=True),
+    ),
+)
+
+
+def _enum_numbers(enum: type[Enum]) -> dict[str, int]:
+    # Schema enum numbers follow declaration order; 0 is UNSPECIFIED.
+    return {member.value: index for index, member in enumerate(enum, 1)}
+
+
+def _varint(value: int) -> bytes:
+    value &= (1 << 64) - 1
+    out = bytearray()
+    while True:
+        byte = value & 0x7F
+        value >>= 7
+        if value:
+            out.append(byte | 0x80)
+        else:
+            out.append(byte)
+            return bytes(out)
+
+
+def _key(number: int, wire_type: int) -> bytes:
+    return _varint(number << 3 | wire_type)
+
+
+def _encode_value(field: _Field, value: Any) -> bytes:
+    kind = field.kind
+    if isinstance(kind, _Message):
+        body = _encode_message(kind, value)
+        return _key(field.number, _LENGTH_DELIMITED) + _varint(len(body)) + body
+    if kind == "string":
+        data = value.encode("utf-8")
+        return _key(field.number, _LENGTH_DELIMITED) + _varint(len(data)) + data
+    if kind == "double":
+        return _key(field.number, _FIXED64) + struct.pack("<d", value)
+    if kind == "bool":
+        return _key(field.number, _VARINT) + _varint(int(value))
+    if kind == "int64":
+        return _key(field.number, _VARINT) + _varint(value)
+    return _key(field.number, _VARINT) + _varint(_enum_numbers(kind)[value])
+
+
+def _is_default(field: _Field, value: Any) -> bool:
+    if field.kind == "double":
+        # -0.0 has a non-zero bit pattern and is written, as in protobuf itself.
+        return struct.pack("<d", value) == bytes(8)
+    return not value
+
+
+def _encode_message(message: _Message, data: dict[str, Any]) -> bytes:
+    out = bytearray()
+    for field in message.fields:
+        value = data.get(field.name)
+        if field.repeated:
+            for item in value or ():
+                out += _encode_value(field, item)
+        elif value is None:
+            continue
+        elif field.optional or isinstance(field.kind, _Message) or not _is_default(field, value):
+            out += _encode_value(field, value)
+    return bytes(out)
+
+
+def _read_varint(data: bytes, offset: int) -> tuple[int, int]:
+    result = 0
+    shift = 0
+    while True:
+        if offset >= len(data):
+            raise ValueError("Truncated varint")
+        byte = data[offset]
+        offset += 1
+        result |= (byte & 0x7F) << shift
+        if not byte & 0x80:
+            return result, offset
+        shift += 7
+        if shift >= 70:
+            raise ValueError("Varint too long")
+
+
+def _fields(data: bytes) -> Iterator[tuple[int, int, Any]]:
+    offset = 0
+    while offset < len(data):
+        key, offset = _read_varint(data, offset)
+        number, wire_type = key >> 3, key & 0x7
+        if wire_type == _VARINT:
+            value, offset = _read_varint(data, offset)
+        elif wire_type in (_FIXED64, _FIXED32):
+            size = 8 if wire_type == _FIXED64 else 4
+            value = data[offset : offset + size]
+            offset += size
+        elif wire_type == _LENGTH_DELIMITED:
+            size, offset = _read_varint(data, offset)
+            value = data[offset : offset + size]
+            offset += size
+        else:
+            raise ValueError(f"Unsupported wire type {wire_type} for field {number}")
+        if offset > len(data):
+            raise ValueError(f"Truncated field {number}")
+        yield number, wire_type, value
+
+
+_EXPECTED_WIRE_TYPES = {"string": _LENGTH_DELIMITED, "double": _FIXED64}
+
+
+def _decode_value(field: _Field, wire_type: int, raw: Any) -> Any:
+    kind = field.kind
+    expected = (
+        _LENGTH_DELIMITED if isinstance(kind, _Message) else _EXPECTED_WIRE_TYPES.get(kind, _VARINT)
+    )
+    if wire_type != expected:
+        raise ValueError(f"Field {field.name} has wire type {wire_type}, expected {expected}")
+    if isinstance(kind, _Message):
+        return _decode_message(kind, raw)
+    if kind == "string":
+        return raw.decode("utf-8")
+    if kind == "double":
+        return struct.unpack("<d", raw)[0]
+    if kind == "bool":
+        return bool(raw)
+    if kind == "int64":
+        return raw - (1 << 64) if raw >= 1 << 63 else raw
+    if raw == 0:
+        return None
+    values = {number: value for value, number in _enum_numbers(kind).items()}
+    if raw not in values:
+        raise ValueError(f"Unknown {kind.__name__} value {raw} for field {field.name}")
+    return values[raw]
+
+
+def _decode_message(message: _Message, data: bytes) -> dict[str, Any]:
+    result: dict[str, Any] = {}
+    for field in message.fields:
+        if field.repeated:
+            result[field.name] = []
+        elif field.kind == "string" and not field.optional:
+            result[field.name] = ""
+        elif field.kind == "bool":
+            result[field.name] = False
+        elif field.kind == "int64":
+            result[field.name] = 0
+        elif field.kind == "double" and not field.optional:
+            result[field.name] = 0.0
+        # Absent enums (UNSPECIFIED), messages and optional fields are left out.
+    fields = {field.number: field for field in message.fields}
+    for number, wire_type, raw in _fields(data):
+        field = fields.get(number)
+        if field is None:
+            continue
+        value = _decode_value(field, wire_type, raw)
+        if value is None:
+            continue
+        if field.repeated:
+            result[field.name].append(value)
+        else:
+            result[field.name] = value
+    return result
+
+
+def judgment_to_protobuf(judgment: AdmissibilityJudgment) -> bytes:
+    """Serialize a judgment as a ``normcore.v1.AdmissibilityJudgment`` message."""
+    data = judgment.model_dump(mode="json")
+    for evaluation in data["statement_evaluations"]:
+        evaluation["license"] = sorted(evaluation["license"])
+    data["status_counts"] = [
+        {"status": status, "count": count} for status, count in data["status_counts"].items()
+    ]
+    data["citation_map"] = [
+        {"key": key, "ground_ids": ground_ids} for key, ground_ids in data["citation_map"].items()
+    ]
+    return _encode_message(_JUDGMENT, data)
+
+
+def judgment_from_protobuf(data: bytes) -> AdmissibilityJudgment:
+    """
+    Parse a ``normcore.v1.AdmissibilityJudgment`` message.
+
+    Raises:
+        ValueError: the data is not a valid judgment message
+    """
+    try:
+        fields = _decode_message(_JUDGMENT, bytes(data))
+    except (UnicodeDecodeError, struct.error) as exc:
+        raise ValueError(f"Invalid judgment message: {exc}") from exc
+    fields["status_counts"] = {
+        entry.get("status"): entry["count"] for entry in fields["status_counts"]
+    }
+    fields["citation_map"] = {entry["key"]: entry["ground_ids"] for entry in fields["citation_map"]}
+    try:
+        return AdmissibilityJudgment.model_validate(fields)
+    except ValidationError as exc:
+        raise ValueError(f"Invalid judgment message: {exc}") from exc
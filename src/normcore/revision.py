@@ -0,0 +1,80 @@
+"""
+Revision-loop convergence tracking.
+
+Orchestrators that ask an agent to revise a rejected response need to know
+whether the retry moved toward admissibility. ``compare_judgments`` reports
+the delta between the two judgments: status direction, resolved and new
+axiom violations, and statements that started or stopped failing.
+
+Severity follows the evaluator's lexicographic aggregation order, so
+"improved" means the retry would lose to the original in aggregation.
+"""
+
+from __future__ import annotations
+
+from .models import ADMISSIBLE_SEVERITY, STATUS_SEVERITY, AdmissibilityJudgment, RevisionDelta
+
+
+def _failing_statements(judgment: AdmissibilityJudgment) -> set[str]:
+    return {
+        evaluation.statement_id
+        for evaluation in judgment.statement_evaluations
+        if STATUS_SEVERITY[evaluation.status] > ADMISSIBLE_SEVERITY
+    }
+
+
+def compare_judgments(
+    original: AdmissibilityJudgment,
+    retry: AdmissibilityJudgment,
+) -> RevisionDelta:
+    """
+    Report the improvement delta from ``original`` to its ``retry``.
+
+    Args:
+        original: Judgment of the rejected response
+        retry: Judgment of the revised response
+
+    Returns:
+        RevisionDelta; ``retry_worthwhile`` is False once the retry converged, when
+        the evaluator does not recommend retrying, or when the revision made no
+        progress
+    """
+    before = STATUS_SEVERITY[original.status]
+    after = STATUS_SEVERITY[retry.status]
+    if after < before:
+        status_change = "improved"
+    elif after > before:
+        status_change = "regressed"
+    else:
+        status_change = "unchanged"
+
+    violations_before = set(original.violated_axioms)
+    violations_after = set(retry.violated_axioms)
+    failing_before = _failing_statements(original)
+    failing_after = _failing_statements(retry)
+
+    resolved_violations = sorted(violations_before - violations_after)
+    new_violations = sorted(violations_after - violations_before)
+    resolved_statements = sorted(failing_before - failing_after)
+    new_failing_statements = sorted(failing_after - failing_before)
+
+    converged = after <= ADMISSIBLE_SEVERITY
+    # A better status is progress even if it trades violations (A5 -> A4 after weakening a
+    # claim); at an unchanged status only a net-clean fix counts.
+    progressed = status_change == "improved" or (
+        status_change == "unchanged"
+        and bool(resolved_violations or resolved_statements)
+        and not (new_violations or new_failing_statements)
+    )
+
+    return RevisionDelta(
+        status_before=original.status,
+        status_after=retry.status,
+        status_change=status_change,
+        resolved_violations=resolved_violations,
+        new_violations=new_violations,
+        resolved_statements=resolved_statements,
+        new_failing_statements=new_failing_statements,
+        converged=converged,
+        retry_worthwhile=not converged and retry.can_retry and progressed,
+    )
@@ -0,0 +1,184 @@
+"""
+SARIF 2.1.0 output for CI pipelines.
+
+Code-review-style CI (GitHub code scanning, GitLab) shows SARIF results as
+annotations, so prompt and agent regression suites can surface normcore
+violations the same way as linter findings:
+
+- one ``rule`` per axiom that can be violated (``A4``, ``A5``, ``A8``, ...)
+- one ``result`` per statement with a ``violated_axiom``; ``violates_norm``
+  statements are errors, other failing statuses are warnings
+- with an artifact (the evaluated payload file), each result points at the
+  payload's line; the statement id is always a logical location
+- inputs that could not be evaluated become tool execution notifications
+"""
+
+from __future__ import annotations
+
+import hashlib
+from collections.abc import Iterable, Mapping
+from importlib.metadata import PackageNotFoundError, version
+from typing import Any
+
+from .models import AdmissibilityJudgment
+from .normative.axiom_checker import AXIOMS
+from .normative.models import EvaluationStatus
+
+SARIF_FORMAT = "sarif"
+SARIF_VERSION = "2.1.0"
+SARIF_SCHEMA = "https://json.schemastore.org/sarif-2.1.0.json"
+INFORMATION_URI = "https://github.com/olegische/normcore"
+FINGERPRINT_KEY = "normcoreStatement/v1"
+
+_ERROR_STATUSES = {EvaluationStatus.VIOLATES_NORM.value}
+_PASSING_STATUSES = {
+    EvaluationStatus.ACCEPTABLE.value,
+    EvaluationStatus.CONDITIONALLY_ACCEPTABLE.value,
+}
+# Axioms whose outcome is a failing status; A6 and A7 only ever admit statements.
+_RULES = [spec for spec in AXIOMS if spec.severity.value not in _PASSING_STATUSES]
+_RULE_INDEX = {spec.code: index for index, spec in enumerate(_RULES)}
+
+
+def _level(status: str) -> str:
+    return "error" if status in _ERROR_STATUSES else "warning"
+
+
+def _tool_version() -> str | None:
+    try:
+        return version("normcore")
+    except PackageNotFoundError:
+        return None
+
+
+class SarifLog:
+    """
+    Collect judgments into a single SARIF run.
+
+    Args:
+        artifact_uri: Payload file the judgments came from; results then carry
+            a physical location (the payload's line) in that file
+    """
+
+    def __init__(self, *, artifact_uri: str | None = None) -> None:
+        self.artifact_uri = artifact_uri
+        self.results: list[dict[str, Any]] = []
+        self.notifications: list[dict[str, Any]] = []
+
+    def _locations(
+        self, *, line: int | None, payload_id: Any, statement_id: str | None = None
+    ) -> list[dict[str, Any]]:
+        location: dict[str, Any] = {}
+        if self.artifact_uri is not None:
+            physical: dict[str, Any] = {"artifactLocation": {"uri": self.artifact_uri}}
+            if line is not None:
+                physical["region"] = {"startLine": line}
+            location["physicalLocation"] = physical
+        names = [str(name) for name in (payload_id, statement_id) if name is not None]
+        if names:
+            location["logicalLocations"] = [
+                {"name": names[-1], "fullyQualifiedName": "/".join(names)}
+            ]
+        return [location] if location else []
+
+    def add(
+        self,
+        judgment: AdmissibilityJudgment | Mapping[str, Any],
+        *,
+        line: int | None = None,
+        payload_id: Any = None,
+    ) -> None:
+        """Add one result per statement of ``judgment`` that violates an axiom."""
+        if isinstance(judgment, AdmissibilityJudgment):
+            judgment = judgment.model_dump(mode="json")
+        for evaluation in judgment.get("statement_evaluations") or []:
+            axiom = evaluation.get("violated_axiom")
+            if axiom is None:
+                continue
+            statement_id = evaluation["statement_id"]
+            statement = evaluation.get("statement", "")
+            explanation = evaluation.get("explanation") or f"Statement violates axiom {axiom}"
+            fingerprint = hashlib.sha256(
+                "\x1f".join([str(payload_id), statement_id, statement]).encode("utf-8")
+            ).hexdigest()
+            result: dict[str, Any] = {
+                "ruleId": axiom,
+                "level": _level(evaluation["status"]),
+                "message": {"text": f'{explanation} Statement: "{statement}"'},
+                "locations": self._locations(
+                    line=line, payload_id=payload_id, statement_id=statement_id
+                ),
+                "partialFingerprints": {FINGERPRINT_KEY: fingerprint},
+                "properties": {
+                    "status": evaluation["status"],
+                    "modality": evaluation.get("modality"),
+                    "judgmentStatus": judgment.get("status"),
+                },
+            }
+            if axiom in _RULE_INDEX:
+                result["ruleIndex"] = _RULE_INDEX[axiom]
+            self.results.append(result)
+
+    def add_error(self, message: str, *, line: int | None = None, payload_id: Any = None) -> None:
+        """Record an input that could not be evaluated."""
+        notification: dict[str, Any] = {"level": "error", "message": {"text": message}}
+        locations = self._locations(line=line, payload_id=payload_id)
+        if locations:
+            notification["locations"] = locations
+        self.notifications.append(notification)
+
+    def add_record(self, record: Mapping[str, Any], *, line: int | None = None) -> None:
+        """Add an output record: a judgment record or an error record."""
+        if "error" in record:
+            line = record.get("line", line)
+            self.add_error(record["error"], line=line, payload_id=record.get("id"))
+        else:
+            self.add(record, line=line, payload_id=record.get("id"))
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return the SARIF log as a JSON-ready dict."""
+        driver: dict[str, Any] = {"name": "normcore", "informationUri": INFORMATION_URI}
+        tool_version = _tool_version()
+        if tool_version is not None:
+            driver["version"] = tool_version
+        driver["rules"] = [
+            {
+                "id": spec.code,
+                "shortDescription": {"text": spec.description},
+                "defaultConfiguration": {"level": _level(spec.severity.value)},
+            }
+            for spec in _RULES
+        ]
+        run: dict[str, Any] = {
+            "tool": {"driver": driver},
+            "invocations": [
+                {
+                    "executionSuccessful": not self.notifications,
+                    "toolExecutionNotifications": self.notifications,
+                }
+            ],
+            "results": self.results,
+        }
+        if self.artifact_uri is not None:
+            run["artifacts"] = [{"location": {"uri": self.artifact_uri}}]
+        return {"$schema": SARIF_SCHEMA, "version": SARIF_VERSION, "runs": [run]}
+
+
+def to_sarif(
+    judgments: AdmissibilityJudgment | Iterable[AdmissibilityJudgment],
+    *,
+    artifact_uri: str | None = None,
+) -> dict[str, Any]:
+    """
+    Serialize judgments as a SARIF 2.1.0 log (a JSON-ready dict).
+
+    Args:
+        judgments: One judgment or several (one run holds all results)
+        artifact_uri: File the judgments were evaluated from, if any
+    """
+    if isinstance(judgments, AdmissibilityJudgment):
+        judgments = [judgments]
+    log = SarifLog(artifact_uri=artifact_uri)
+    for judgment in judgments:
+        log.add(judgment)
+    return log.to_dict()
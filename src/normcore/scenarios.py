@@ -0,0 +1,326 @@
+"""
+Declarative admissibility scenarios.
+
+A scenario describes a conversation turn by turn and the verdict it should
+get, without spelling out OpenAI message plumbing::
+
+    name = "grounded deploy advice"
+
+    [[turns]]
+    user = "Can we deploy?"
+
+    [[turns]]
+    tool = "get_ci_status"
+    id = "call_ci"
+    result = { pipeline_id = "ci-42", status = "green" }
+
+    [[turns]]
+    assistant = "You should deploy now [@call_ci]."
+
+    [expect]
+    status = "acceptable"
+
+Turns (exactly one kind key each):
+
+- ``user`` / ``system``: a message with that text
+- ``tool``: a tool call and its result. ``result`` is the tool output (text,
+  or any JSON value, which is serialized); optional ``arguments`` (object) and
+  ``id`` (default ``call_<n>``). Consecutive tool turns share one assistant
+  message, like parallel tool calls.
+- ``assistant``: assistant text; ``refusal``: an assistant refusal
+- the last turn is the evaluated assistant message
+
+Instead of ``turns``, a scenario may give ``conversation``: OpenAI Chat
+Completions messages as passed to ``evaluate()``, for cases captured from real
+traces.
+
+Optional top-level keys: ``description``, ``grounds`` (as for ``evaluate()``)
+and ``policy``. ``expect`` lists judgment fields to check (``status``,
+``violated_axioms``, ``gate_decision``, ...); ``expect.statements`` holds
+per-statement field checks in statement order. Fields not listed are not
+compared, so scenarios stay stable when unrelated judgment details change.
+
+Scenario files may be JSON, YAML or TOML. The same files serve tests
+(``run_scenario``), the CLI golden runner (``normcore golden``), the corpus
+runner (``run_corpus``, ``normcore run-corpus``) and documentation examples.
+The corpus runner evaluates every scenario under a base policy, so policy
+authors can check a policy file against a corpus of expected outcomes; a
+scenario's own ``policy`` fields override the base policy.
+"""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Iterable
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from .evaluator import evaluate
+from .inputs import TOML_SUFFIX, YAML_SUFFIXES, load_structured_file
+from .models import AdmissibilityJudgment, AdmissibilityStatus, StatementEvaluation
+from .models.policy import EvaluationPolicy
+
+TURN_KINDS = ("user", "system", "tool", "assistant", "refusal")
+CORPUS_SUFFIXES = frozenset({".json", TOML_SUFFIX, *YAML_SUFFIXES})
+_SCENARIO_KEYS = {"name", "description", "turns", "conversation", "grounds", "policy", "expect"}
+_TOOL_KEYS = {"tool", "id", "arguments", "result"}
+_JUDGMENT_FIELDS = set(AdmissibilityJudgment.model_fields)
+_STATEMENT_FIELDS = set(StatementEvaluation.model_fields)
+
+
+@dataclass(frozen=True)
+class Scenario:
+    """A compiled scenario: evaluate arguments plus the expected judgment fields."""
+
+    name: str
+    conversation: list[dict[str, Any]]
+    expect: dict[str, Any]
+    grounds: Any = None
+    policy: dict[str, Any] | None = None
+    description: str = ""
+    path: Path | None = None
+
+
+@dataclass
+class ScenarioResult:
+    """Outcome of running one scenario."""
+
+    scenario: Scenario
+    judgment: AdmissibilityJudgment | None = None
+    # "field" or "statements[i].field" -> (expected, actual)
+    mismatches: dict[str, tuple[Any, Any]] = field(default_factory=dict)
+    error: str | None = None
+
+    @property
+    def passed(self) -> bool:
+        """Whether the scenario evaluated and every expected field matched."""
+        return self.error is None and not self.mismatches
+
+
+def is_scenario(data: Any) -> bool:
+    """Whether decoded file content uses the scenario format (``turns`` or ``expect``)."""
+    return isinstance(data, dict) and ("turns" in data or "expect" in data)
+
+
+def _tool_call(turn: dict[str, Any], number: int) -> tuple[dict[str, Any], dict[str, Any]]:
+    unknown = sorted(set(turn) - _TOOL_KEYS)
+    if unknown:
+        raise ValueError(f"Unknown tool turn keys: {', '.join(unknown)}")
+    if "result" not in turn:
+        raise ValueError(f"Tool turn '{turn['tool']}' has no result")
+    call_id = str(turn.get("id") or f"call_{number}")
+    arguments = turn.get("arguments", {})
+    if not isinstance(arguments, dict):
+        raise ValueError(f"Tool turn '{turn['tool']}' arguments must be an object")
+    result = turn["result"]
+    call = {
+        "id": call_id,
+        "type": "function",
+        "function": {"name": str(turn["tool"]), "arguments": json.dumps(arguments)},
+    }
+    message = {
+        "role": "tool",
+        "tool_call_id": call_id,
+        "content": result if isinstance(result, str) else json.dumps(result),
+    }
+    return call, message
+
+
+def compile_turns(turns: Any) -> list[dict[str, Any]]:
+    """
+    Translate scenario turns into an OpenAI Chat Completions conversation.
+
+    Raises:
+        ValueError: a turn is malformed, or the last turn is not an assistant turn
+    """
+    if not isinstance(turns, list) or not turns:
+        raise ValueError("Scenario 'turns' must be a non-empty list")
+    conversation: list[dict[str, Any]] = []
+    pending_calls: list[dict[str, Any]] = []
+    pending_results: list[dict[str, Any]] = []
+    tool_calls = 0
+
+    def flush_tools() -> None:
+        if pending_calls:
+            conversation.append(
+                {"role": "assistant", "content": "", "tool_calls": list(pending_calls)}
+            )
+            conversation.extend(pending_results)
+        pending_calls.clear()
+        pending_results.clear()
+
+    for index, turn in enumerate(turns, 1):
+        kinds = [kind for kind in TURN_KINDS if isinstance(turn, dict) and kind in turn]
+        if len(kinds) != 1:
+            raise ValueError(f"Turn {index} must have exactly one of: {', '.join(TURN_KINDS)}")
+        (kind,) = kinds
+        if kind == "tool":
+            tool_calls += 1
+            call, message = _tool_call(turn, tool_calls)
+            pending_calls.append(call)
+            pending_results.append(message)
+            continue
+        if len(turn) != 1:
+            raise ValueError(f"Turn {index} ({kind}) takes no other keys")
+        flush_tools()
+        text = turn[kind]
+        if not isinstance(text, str):
+            raise ValueError(f"Turn {index} ({kind}) must be text")
+        if kind == "refusal":
+            conversation.append(
+                {"role": "assistant", "content": [{"type": "refusal", "refusal": text}]}
+            )
+        else:
+            conversation.append({"role": kind, "content": text})
+    flush_tools()
+    if conversation[-1]["role"] != "assistant" or "tool_calls" in conversation[-1]:
+        raise ValueError("The last scenario turn must be an assistant or refusal turn")
+    return conversation
+
+
+def _check_expect(expect: Any) -> dict[str, Any]:
+    if not isinstance(expect, dict) or not expect:
+        raise ValueError("Scenario 'expect' must be a non-empty object")
+    unknown = sorted(set(expect) - _JUDGMENT_FIELDS - {"statements"})
+    statements = expect.get("statements", [])
+    if not isinstance(statements, list) or not all(isinstance(s, dict) for s in statements):
+        raise ValueError("Scenario 'expect.statements' must be a list of objects")
+    for checks in statements:
+        unknown.extend(f"statements.{key}" for key in sorted(set(checks) - _STATEMENT_FIELDS))
+    if unknown:
+        raise ValueError(f"Unknown expected fields: {', '.join(unknown)}")
+    if "status" in expect:
+        AdmissibilityStatus(expect["status"])
+    return expect
+
+
+def scenario_from_dict(data: Any, *, name: str = "", path: Path | None = None) -> Scenario:
+    """
+    Build a scenario from decoded file content.
+
+    Raises:
+        ValueError: the content is not a valid scenario
+    """
+    if not isinstance(data, dict) or ("turns" in data) == ("conversation" in data):
+        raise ValueError("Scenario must be an object with either 'turns' or 'conversation'")
+    unknown = sorted(set(data) - _SCENARIO_KEYS)
+    if unknown:
+        raise ValueError(f"Unknown scenario keys: {', '.join(unknown)}")
+    policy = data.get("policy")
+    if policy is not None and not isinstance(policy, dict):
+        raise ValueError("Scenario 'policy' must be an object")
+    if "turns" in data:
+        conversation = compile_turns(data["turns"])
+    elif isinstance(data["conversation"], list) and data["conversation"]:
+        conversation = data["conversation"]
+    else:
+        raise ValueError("Scenario 'conversation' must be a non-empty list")
+    return Scenario(
+        name=str(data.get("name") or name),
+        conversation=conversation,
+        expect=_check_expect(data.get("expect")),
+        grounds=data.get("grounds"),
+        policy=policy,
+        description=str(data.get("description", "")),
+        path=path,
+    )
+
+
+def load_scenario(path: str | Path) -> Scenario:
+    """
+    Load a scenario file (JSON, YAML or TOML).
+
+    Raises:
+        OSError: the file cannot be read
+        ValueError: the content is not a valid scenario
+    """
+    path = Path(path)
+    return scenario_from_dict(load_structured_file(path), name=path.stem, path=path)
+
+
+def check_judgment(
+    expect: dict[str, Any], judgment: AdmissibilityJudgment
+) -> dict[str, tuple[Any, Any]]:
+    """Compare the expected fields with a judgment; returns the mismatching fields."""
+    actual = judgment.model_dump(mode="json")
+    mismatches: dict[str, tuple[Any, Any]] = {}
+    for key, expected in expect.items():
+        if key != "statements" and expected != actual[key]:
+            mismatches[key] = (expected, actual[key])
+    statements = actual["statement_evaluations"]
+    for index, checks in enumerate(expect.get("statements", [])):
+        evaluation = statements[index] if index < len(statements) else {}
+        for key, expected in checks.items():
+            value = evaluation.get(key)
+            if key == "license" and isinstance(value, list):
+                value, expected = sorted(value), sorted(expected)
+            if expected != value:
+                mismatches[f"statements[{index}].{key}"] = (expected, value)
+    if len(expect.get("statements", [])) not in (0, len(statements)):
+        mismatches["statements"] = (len(expect["statements"]), len(statements))
+    return mismatches
+
+
+def run_scenario(scenario: Scenario, policy: EvaluationPolicy | None = None) -> ScenarioResult:
+    """
+    Evaluate a scenario and check its expected fields.
+
+    ``policy`` is the base policy; the scenario's own ``policy`` fields
+    override it field by field.
+    """
+    try:
+        resolved: EvaluationPolicy | dict[str, Any] | None = scenario.policy
+        if policy is not None:
+            resolved = EvaluationPolicy.model_validate(
+                {**policy.model_dump(exclude_unset=True), **(scenario.policy or {})}
+            )
+        judgment = evaluate(
+            conversation=scenario.conversation, grounds=scenario.grounds, policy=resolved
+        )
+    except ValueError as exc:
+        return ScenarioResult(scenario=scenario, error=str(exc))
+    return ScenarioResult(
+        scenario=scenario, judgment=judgment, mismatches=check_judgment(scenario.expect, judgment)
+    )
+
+
+def run_scenarios(
+    paths: Iterable[str | Path], policy: EvaluationPolicy | None = None
+) -> list[ScenarioResult]:
+    """
+    Load and run scenario files under the base ``policy``; unreadable or
+    invalid files become error results.
+    """
+    results: list[ScenarioResult] = []
+    for path in paths:
+        path = Path(path)
+        try:
+            scenario = load_scenario(path)
+        except (OSError, ValueError) as exc:
+            placeholder = Scenario(name=path.stem, conversation=[], expect={}, path=path)
+            results.append(ScenarioResult(scenario=placeholder, error=str(exc)))
+            continue
+        results.append(run_scenario(scenario, policy))
+    return results
+
+
+def run_corpus(
+    directory: str | Path, policy: EvaluationPolicy | None = None
+) -> list[ScenarioResult]:
+    """
+    Run every scenario file (JSON, YAML or TOML) under ``directory``,
+    recursively and sorted by path, under the base ``policy``.
+
+    Raises:
+        NotADirectoryError: ``directory`` is not a directory
+    """
+    directory = Path(directory)
+    if not directory.is_dir():
+        raise NotADirectoryError(f"Not a directory: {directory}")
+    paths = sorted(
+        path
+        for path in directory.rglob("*")
+        if path.is_file() and path.suffix.lower() in CORPUS_SUFFIXES
+    )
+    return run_scenarios(paths, policy)
@@ -0,0 +1,464 @@
+"""
+Runtime controls for long-running CLI modes.
+
+Deployments behind a load balancer need bounded resource use and a clean
+exit on rollout:
+
+- ``EvaluationPool``: evaluates up to ``workers`` payloads concurrently; a
+  payload that runs longer than ``timeout`` seconds (measured from the moment
+  a worker picks it up) is reported as timed out. Python threads cannot be
+  interrupted, so the abandoned evaluation finishes in the background and its
+  result is discarded.
+- ``ProcessEvaluationPool``: evaluates on worker processes instead, for
+  CPU-bound batch work that threads cannot parallelize under the GIL.
+- ``read_payload_lines``: reads NDJSON lines, rejecting lines above a size
+  limit without buffering them whole; ``read_msgpack_payloads`` reads a
+  MessagePack stream under the same limit.
+- ``graceful_shutdown``: on SIGTERM/SIGINT stop accepting input, let in-flight
+  evaluations finish and flush their results, then exit normally.
+- ``serve_unix_socket``: accept connections on a Unix domain socket, handling
+  each on its own thread until shutdown.
+"""
+
+from __future__ import annotations
+
+import functools
+import queue
+import signal
+import socket
+import socketserver
+import stat
+import threading
+import time
+from collections.abc import Callable, Iterator
+from concurrent.futures import Future, ProcessPoolExecutor, ThreadPoolExecutor
+from concurrent.futures import TimeoutError as FutureTimeoutError
+from contextlib import contextmanager
+from dataclasses import dataclass
+from pathlib import Path
+from types import ModuleType
+from typing import Any, BinaryIO, TextIO
+
+from .encodings import import_msgpack
+
+DEFAULT_WORKERS = 1
+# Payloads sent to a worker process per task by ProcessEvaluationPool.
+DEFAULT_CHUNKSIZE = 8
+# Chunk size used to discard the rest of an oversized line.
+_DRAIN_CHUNK = 64 * 1024
+# How often blocked readers re-check the shutdown flag.
+_POLL_SECONDS = 0.1
+
+
+class EvaluationTimeoutError(TimeoutError):
+    """Raised when a payload exceeds the per-request timeout."""
+
+
+class EvaluationJob:
+    """Handle for one submitted evaluation."""
+
+    def __init__(self, timeout: float | None) -> None:
+        """Initialize a pending job with the pool's per-payload timeout."""
+        self._timeout = timeout
+        self._started = threading.Event()
+        self._started_at = 0.0
+        self._future: Future[Any] = Future()
+
+    def _run(self, fn: Callable[..., Any], args: tuple[Any, ...], kwargs: dict[str, Any]) -> Any:
+        self._started_at = time.monotonic()
+        self._started.set()
+        return fn(*args, **kwargs)
+
+    def result(self) -> Any:
+        """
+        Wait for the evaluation result.
+
+        Raises:
+            EvaluationTimeoutError: the evaluation ran longer than the pool timeout
+        """
+        if self._timeout is None:
+            return self._future.result()
+        self._started.wait()
+        remaining = self._started_at + self._timeout - time.monotonic()
+        try:
+            return self._future.result(timeout=max(remaining, 0.0))
+        except FutureTimeoutError:
+            if self._future.done():
+                return self._future.result()
+            raise EvaluationTimeoutError(f"Evaluation timed out after {self._timeout:g}s") from None
+
+    def done(self) -> bool:
+        """Whether the evaluation has finished."""
+        return self._future.done()
+
+
+class EvaluationPool:
+    """
+    Bounded worker pool for payload evaluation.
+
+    Use as a context manager; leaving it waits for running evaluations.
+    """
+
+    def __init__(self, workers: int = DEFAULT_WORKERS, *, timeout: float | None = None) -> None:
+        """Initialize a pool of ``workers`` threads with an optional per-payload timeout."""
+        if workers < 1:
+            raise ValueError("workers must be at least 1")
+        if timeout is not None and timeout <= 0:
+            raise ValueError("timeout must be positive")
+        self.workers = workers
+        self.timeout = timeout
+        self._executor = ThreadPoolExecutor(max_workers=workers, thread_name_prefix="normcore")
+
+    @property
+    def capacity(self) -> int:
+        """Submitted evaluations that keep every worker busy."""
+        return self.workers
+
+    def submit(self, fn: Callable[..., Any], /, *args: Any, **kwargs: Any) -> EvaluationJob:
+        """Schedule ``fn(*args, **kwargs)`` and return its job handle."""
+        job = EvaluationJob(self.timeout)
+        job._future = self._executor.submit(job._run, fn, args, kwargs)
+        return job
+
+    def close(self) -> None:
+        """Stop accepting work and wait for running evaluations."""
+        self._executor.shutdown(wait=True)
+
+    def __enter__(self) -> EvaluationPool:
+        """Return the pool."""
+        return self
+
+    def __exit__(self, *exc_info: object) -> None:
+        """Close the pool."""
+        self.close()
+
+
+def _run_chunk(
+    calls: list[tuple[Callable[..., Any], tuple[Any, ...], dict[str, Any]]],
+) -> list[tuple[Any, BaseException | None]]:
+    outcomes: list[tuple[Any, BaseException | None]] = []
+    for fn, args, kwargs in calls:
+        try:
+            outcomes.append((fn(*args, **kwargs), None))
+        except Exception as exc:
+            outcomes.append((None, exc))
+    return outcomes
+
+
+def _settle_chunk(
+    jobs: list[ChunkedJob], chunk: Future[list[tuple[Any, BaseException | None]]]
+) -> None:
+    error = chunk.exception()
+    if error is not None:
+        for job in jobs:
+            job._future.set_exception(error)
+        return
+    for job, (value, job_error) in zip(jobs, chunk.result()):
+        if job_error is not None:
+            job._future.set_exception(job_error)
+        else:
+            job._future.set_result(value)
+
+
+class ChunkedJob:
+    """Handle for one evaluation submitted to a ``ProcessEvaluationPool``."""
+
+    def __init__(self, pool: ProcessEvaluationPool) -> None:
+        """Initialize a job buffered in ``pool`` until its chunk is dispatched."""
+        self._pool = pool
+        self._dispatched = False
+        self._future: Future[Any] = Future()
+
+    def result(self) -> Any:
+        """Wait for the evaluation result, dispatching a partial chunk if needed."""
+        if not self._dispatched:
+            self._pool._flush()
+        return self._future.result()
+
+    def done(self) -> bool:
+        """Whether the evaluation has finished."""
+        return self._future.done()
+
+
+class ProcessEvaluationPool:
+    """
+    Worker-process pool for CPU-bound payload evaluation.
+
+    Submitted calls are buffered and sent to a worker ``chunksize`` at a time,
+    so pickling costs one round trip per chunk; asking for the result of a
+    buffered call dispatches its partial chunk. Results are per call, so
+    callers keep their own order. Functions, arguments, results and raised
+    errors must be picklable, and there is no per-payload timeout. Use as a
+    context manager; leaving it waits for dispatched evaluations.
+    """
+
+    def __init__(
+        self, workers: int = DEFAULT_WORKERS, *, chunksize: int = DEFAULT_CHUNKSIZE
+    ) -> None:
+        """Initialize a pool of ``workers`` processes fed ``chunksize`` calls per task."""
+        if workers < 1:
+            raise ValueError("workers must be at least 1")
+        if chunksize < 1:
+            raise ValueError("chunksize must be at least 1")
+        self.workers = workers
+        self.chunksize = chunksize
+        self._executor = ProcessPoolExecutor(max_workers=workers)
+        self._lock = threading.Lock()
+        self._calls: list[tuple[Callable[..., Any], tuple[Any, ...], dict[str, Any]]] = []
+        self._jobs: list[ChunkedJob] = []
+
+    @property
+    def capacity(self) -> int:
+        """Submitted evaluations that keep every worker busy (one chunk each)."""
+        return self.workers * self.chunksize
+
+    def submit(self, fn: Callable[..., Any], /, *args: Any, **kwargs: Any) -> ChunkedJob:
+        """Buffer ``fn(*args, **kwargs)`` and return its job handle."""
+        job = ChunkedJob(self)
+        with self._lock:
+            self._calls.append((fn, args, kwargs))
+            self._jobs.append(job)
+            full = len(self._calls) >= self.chunksize
+        if full:
+            self._flush()
+        return job
+
+    def _flush(self) -> None:
+        with self._lock:
+            if not self._calls:
+                return
+            chunk = self._executor.submit(_run_chunk, self._calls)
+            for job in self._jobs:
+                job._dispatched = True
+            chunk.add_done_callback(functools.partial(_settle_chunk, self._jobs))
+            self._calls, self._jobs = [], []
+
+    def close(self) -> None:
+        """Dispatch buffered calls, stop accepting work and wait for running evaluations."""
+        self._flush()
+        self._executor.shutdown(wait=True)
+
+    def __enter__(self) -> ProcessEvaluationPool:
+        """Return the pool."""
+        return self
+
+    def __exit__(self, *exc_info: object) -> None:
+        """Close the pool."""
+        self.close()
+
+
+@dataclass
+class PayloadLine:
+    """One input item (``oversized`` lines and ``error`` items carry no content)."""
+
+    line_number: int
+    raw: Any
+    oversized: bool = False
+    error: str | None = None
+
+
+def _read_line(stream: TextIO, max_bytes: int | None) -> tuple[str, bool]:
+    if max_bytes is None:
+        return stream.readline(), False
+    # Every character takes at least one UTF-8 byte, so max_bytes + 1 characters exceed the limit.
+    raw = stream.readline(max_bytes + 1)
+    if len(raw.encode("utf-8")) <= max_bytes:
+        return raw, False
+    tail = raw
+    while tail and not tail.endswith("\n"):
+        tail = stream.readline(_DRAIN_CHUNK)
+    return "", True
+
+
+def read_payload_lines(
+    stream: TextIO,
+    *,
+    max_bytes: int | None = None,
+    stop: threading.Event | None = None,
+) -> Iterator[PayloadLine]:
+    """
+    Yield non-blank input lines until EOF or until ``stop`` is set.
+
+    Lines longer than ``max_bytes`` (UTF-8, newline included) are yielded as
+    ``oversized`` and their content is discarded. With ``stop``, reading
+    happens on a daemon thread so a blocked read does not delay shutdown.
+    """
+    lines = _numbered_lines(stream, max_bytes)
+    yield from (lines if stop is None else _until_stopped(lines, stop))
+
+
+def read_msgpack_payloads(
+    stream: BinaryIO,
+    *,
+    max_bytes: int | None = None,
+    stop: threading.Event | None = None,
+) -> Iterator[PayloadLine]:
+    """
+    Yield decoded MessagePack objects until EOF or until ``stop`` is set.
+
+    ``raw`` holds the decoded object and ``line_number`` its 1-based position.
+    A concatenated stream has no delimiters to resynchronize on, so an object
+    larger than ``max_bytes``, malformed data or a truncated final object ends
+    reading with one ``error`` item.
+
+    Raises:
+        ValueError: msgpack is not installed
+    """
+    frames = _msgpack_frames(stream, max_bytes, import_msgpack())
+    yield from (frames if stop is None else _until_stopped(frames, stop))
+
+
+def _until_stopped(items: Iterator[PayloadLine], stop: threading.Event) -> Iterator[PayloadLine]:
+    # Read on a daemon thread so a blocked read does not delay shutdown.
+    buffered: queue.Queue[PayloadLine | None] = queue.Queue(maxsize=1)
+
+    def pump() -> None:
+        for item in items:
+            while not stop.is_set():
+                try:
+                    buffered.put(item, timeout=_POLL_SECONDS)
+                    break
+                except queue.Full:
+                    continue
+            if stop.is_set():
+                return
+        buffered.put(None)
+
+    threading.Thread(target=pump, name="normcore-reader", daemon=True).start()
+    while not stop.is_set():
+        try:
+            item = buffered.get(timeout=_POLL_SECONDS)
+        except queue.Empty:
+            continue
+        if item is None:
+            return
+        yield item
+
+
+def _numbered_lines(stream: TextIO, max_bytes: int | None) -> Iterator[PayloadLine]:
+    line_number = 0
+    while True:
+        raw, oversized = _read_line(stream, max_bytes)
+        if not raw and not oversized:
+            return
+        line_number += 1
+        if oversized or raw.strip():
+            yield PayloadLine(line_number=line_number, raw=raw, oversized=oversized)
+
+
+class _CountingReader:
+    """Binary stream wrapper counting the bytes handed to the decoder."""
+
+    def __init__(self, stream: BinaryIO) -> None:
+        """Wrap ``stream``."""
+        self._stream = stream
+        self.consumed = 0
+
+    def read(self, size: int = -1) -> bytes:
+        """Read from the wrapped stream."""
+        data = self._stream.read(size)
+        self.consumed += len(data)
+        return data
+
+
+def _msgpack_frames(
+    stream: BinaryIO, max_bytes: int | None, msgpack: ModuleType
+) -> Iterator[PayloadLine]:
+    reader = _CountingReader(stream)
+    unpacker = msgpack.Unpacker(reader, raw=False, max_buffer_size=max_bytes or 0)
+    index = 0
+    while True:
+        index += 1
+        try:
+            value = next(unpacker)
+        except StopIteration:
+            if reader.consumed > unpacker.tell():
+                yield PayloadLine(index, None, error="Truncated MessagePack payload")
+            return
+        except msgpack.exceptions.BufferFull:
+            message = f"Payload exceeds {max_bytes} bytes; remaining input skipped"
+            yield PayloadLine(index, None, error=message)
+            return
+        except ValueError as exc:
+            message = f"Invalid MessagePack payload: {exc}; remaining input skipped"
+            yield PayloadLine(index, None, error=message)
+            return
+        yield PayloadLine(index, value)
+
+
+@contextmanager
+def graceful_shutdown() -> Iterator[threading.Event]:
+    """
+    Set the yielded event on SIGTERM/SIGINT instead of terminating.
+
+    Handlers are installed only on the main thread and restored on exit.
+    """
+    stop = threading.Event()
+    if threading.current_thread() is not threading.main_thread():
+        yield stop
+        return
+
+    def request_stop(signum: int, frame: object) -> None:
+        stop.set()
+
+    previous = {sig: signal.signal(sig, request_stop) for sig in (signal.SIGTERM, signal.SIGINT)}
+    try:
+        yield stop
+    finally:
+        for sig, handler in previous.items():
+            signal.signal(sig, handler)
+
+
+def _remove_stale_socket(path: Path) -> None:
+    try:
+        mode = path.stat().st_mode
+    except FileNotFoundError:
+        return
+    if not stat.S_ISSOCK(mode):
+        raise OSError(f"{path} exists and is not a socket")
+    with socket.socket(socket.AF_UNIX, socket.SOCK_STREAM) as probe:
+        try:
+            probe.connect(str(path))
+        except OSError:
+            path.unlink()
+            return
+    raise OSError(f"{path} is in use by another process")
+
+
+def serve_unix_socket(
+    path: str | Path,
+    handle: Callable[[BinaryIO, BinaryIO], None],
+    *,
+    stop: threading.Event,
+) -> None:
+    """
+    Accept connections on a Unix domain socket until ``stop`` is set.
+
+    Each connection is handled on its own thread by ``handle(reader, writer)``
+    with the connection's binary read and write streams. A socket file left
+    behind by a dead process is replaced. On shutdown no new connections are
+    accepted, running handlers are waited for, and the socket file is removed.
+
+    Raises:
+        OSError: the path is taken by a live socket or another file, or cannot be bound
+    """
+    path = Path(path)
+    _remove_stale_socket(path)
+
+    class Handler(socketserver.StreamRequestHandler):
+        def handle(self) -> None:
+            handle(self.rfile, self.wfile)
+
+    server = socketserver.ThreadingUnixStreamServer(str(path), Handler)
+    server.block_on_close = True
+    server.daemon_threads = False
+    threading.Thread(target=server.serve_forever, name="normcore-socket", daemon=True).start()
+    try:
+        while not stop.wait(_POLL_SECONDS):
+            pass
+    finally:
+        stop.set()
+        server.shutdown()
+        # Waits for handler threads, which stop reading once ``stop`` is set.
+        server.server_close()
+        path.unlink(missing_ok=True)
@@ -0,0 +1,205 @@
+"""
+Incremental evaluation of long agent sessions.
+
+``evaluate()`` parses every tool result of the conversation on each call, so
+judging every turn of a long session re-reads the whole history each time.
+A ``SessionEvaluator`` takes the messages one at a time instead: each tool
+result is parsed into knowledge nodes once, when it arrives, and
+``evaluate()`` judges the latest assistant turn against the accumulated
+knowledge state. The judgment is the one ``evaluate(conversation=messages)``
+returns for the same messages, provided every tool result arrives after the
+tool call it answers.
+"""
+
+from __future__ import annotations
+
+import copy
+from collections.abc import Iterable
+from typing import Any
+
+from .evaluator import (
+    AdmissibilityEvaluator,
+    _is_reasoning_item,
+    _strip_reasoning_from_message,
+    _ToolKnowledge,
+)
+from .limits import InputLimitError, content_bytes
+from .logging import collect_warnings, report_warning
+from .models import AdmissibilityJudgment, EvaluationPolicy, LinkSet
+from .models.messages import ToolResultSpeechAct
+from .models.policy import coerce_policy
+from .normative.models import KnowledgeNode
+
+
+class SessionEvaluator:
+    """
+    Stateful evaluator for a conversation that grows one message at a time.
+
+    ``grounds`` apply to every judgment of the session. Policy limits
+    (``max_messages``, ``max_content_bytes``) are enforced as messages are
+    added: a message that would exceed them raises ``InputLimitError`` and is
+    not added.
+    """
+
+    def __init__(
+        self,
+        policy: EvaluationPolicy | dict[str, Any] | None = None,
+        *,
+        grounds: list[Any] | dict[str, Any] | None = None,
+        evaluator: AdmissibilityEvaluator | None = None,
+    ) -> None:
+        """
+        Start an empty session.
+
+        Args:
+            policy: Evaluation policy (defaults reproduce built-in behavior)
+            grounds: External grounds available to every turn
+            evaluator: Configured pipeline (see ``AdmissibilityEvaluator.builder()``)
+                used instead of one built from ``policy``
+        """
+        if evaluator is not None and policy is not None:
+            raise ValueError("SessionEvaluator takes policy or evaluator, not both")
+        self.evaluator = evaluator or AdmissibilityEvaluator(policy=coerce_policy(policy))
+        self.grounds = grounds
+        self._messages: list[Any] = []
+        self._content_bytes = 0
+        self._tool_calls: dict[str, dict] = {}
+        # (tool result, its knowledge nodes, its tool-call citation refs), in arrival order
+        self._observations: list[
+            tuple[ToolResultSpeechAct, list[KnowledgeNode], dict[str, list[str]]]
+        ] = []
+        self._warnings: list[str] = []
+
+    @property
+    def messages(self) -> list[Any]:
+        """Messages added so far, in order."""
+        return list(self._messages)
+
+    @property
+    def knowledge_nodes(self) -> list[KnowledgeNode]:
+        """Knowledge nodes parsed from the tool results added so far."""
+        return [node for _, nodes, _ in self._observations for node in nodes]
+
+    def add(self, message: Any) -> None:
+        """
+        Append one message; a tool result is parsed into knowledge nodes now.
+
+        Raises:
+            InputLimitError: the message would exceed a policy limit
+            ValueError: the message is malformed
+        """
+        policy = self.evaluator.policy
+        if policy.max_messages is not None and len(self._messages) >= policy.max_messages:
+            raise InputLimitError("max_messages", policy.max_messages, len(self._messages) + 1)
+        size = self._content_bytes + content_bytes(message)
+        if policy.max_content_bytes is not None and size > policy.max_content_bytes:
+            raise InputLimitError("max_content_bytes", policy.max_content_bytes, size)
+
+        position = len(self._messages)
+        observation = None
+        with collect_warnings() as warnings:
+            if not _is_reasoning_item(message):
+                stripped, _ = _strip_reasoning_from_message(message)
+                mapped = self.evaluator._map_message(self.evaluator._validate_message(stripped))
+                self.evaluator._register_tool_calls(mapped, self._tool_calls)
+                tool_result = self.evaluator._tool_result_at(mapped, position, self._tool_calls)
+                if tool_result is not None:
+                    nodes, refs = self.evaluator.knowledge_builder.build_with_references(
+                        [tool_result]
+                    )
+                    observation = (tool_result, nodes, refs)
+
+        self._messages.append(message)
+        self._content_bytes = size
+        if observation is not None:
+            self._observations.append(observation)
+        self._warnings.extend(warning for warning in warnings if warning not in self._warnings)
+
+    def extend(self, messages: Iterable[Any]) -> None:
+        """Append several messages in order (see ``add``)."""
+        for message in messages:
+            self.add(message)
+
+    def evaluate(
+        self,
+        *,
+        statement_ids: Iterable[str] | None = None,
+        span: tuple[int, int] | list[int] | None = None,
+        links: LinkSet | dict[str, Any] | list[Any] | None = None,
+        strict: bool = False,
+        trace: bool = False,
+        domain: str | None = None,
+    ) -> AdmissibilityJudgment:
+        """
+        Judge the latest assistant message against the accumulated knowledge.
+
+        Options behave as in ``normcore.evaluate()``; ``strict`` also rejects
+        recoverable issues found while messages were added.
+
+        Raises:
+            ValueError: the latest message is not an assistant message
+        """
+        agent_message = next(
+            (item for item in reversed(self._messages) if not _is_reasoning_item(item)),
+            None,
+        )
+        if not isinstance(agent_message, dict) or agent_message.get("role") != "assistant":
+            raise ValueError("The latest session message must be an assistant message")
+
+        return self._judge(
+            agent_message,
+            self._messages,
+            statement_ids=statement_ids,
+            span=span,
+            links=links,
+            strict=strict,
+            trace=trace,
+            domain=domain,
+        )
+
+    def _judge(
+        self,
+        agent_message: dict,
+        trajectory: list[Any],
+        *,
+        statement_ids: Iterable[str] | None = None,
+        span: tuple[int, int] | list[int] | None = None,
+        links: LinkSet | dict[str, Any] | list[Any] | None = None,
+        strict: bool = False,
+        trace: bool = False,
+        domain: str | None = None,
+    ) -> AdmissibilityJudgment:
+        """Judge ``agent_message`` (ending ``trajectory``) against the session knowledge."""
+        evaluator = self.evaluator
+        if trace and not evaluator.trace_licenses:
+            evaluator = copy.copy(evaluator)
+            evaluator.trace_licenses = True
+        return evaluator.evaluate_message(
+            agent_message,
+            trajectory,
+            grounds=self.grounds,
+            statement_ids=statement_ids,
+            span=span,
+            links=links,
+            strict=True if strict else None,
+            domain=domain,
+            tool_knowledge=self._tool_knowledge,
+        )
+
+    def _tool_knowledge(self) -> _ToolKnowledge:
+        """Knowledge state of the session, split by the policy's ground window."""
+        for warning in self._warnings:
+            report_warning(warning)
+        window_start = self.evaluator._ground_window_start(self._messages)
+        tool_results: list[ToolResultSpeechAct] = []
+        knowledge_nodes: list[KnowledgeNode] = []
+        tool_call_refs: dict[str, list[str]] = {}
+        stale_ground_ids: set[str] = set()
+        for tool_result, nodes, refs in self._observations:
+            tool_results.append(tool_result)
+            if tool_result.position is not None and tool_result.position < window_start:
+                stale_ground_ids.update(node.semantic_id or node.id for node in nodes)
+                continue
+            knowledge_nodes.extend(nodes)
+            tool_call_refs.update(refs)
+        return tool_results, knowledge_nodes, tool_call_refs, sorted(stale_ground_ids)
@@ -0,0 +1,160 @@
+"""
+Judgment signing for audit trails.
+
+Downstream systems that store verdicts can verify that a stored judgment
+came from a specific NormCore deployment and was not altered afterwards.
+
+A judgment is serialized canonically (``canonical_json``: RFC 8785 JSON,
+UTF-8) and signed with HMAC-SHA256 under a deployment key. The
+signature is detached: it travels next to the judgment (``signature`` field
+of an output record), never inside the signed bytes. The signed bytes also
+bind the payload ``id`` and the ``input_hash`` of the evaluated payload, so a
+stored verdict cannot be moved onto another input.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import hmac
+from collections.abc import Mapping
+from pathlib import Path
+from typing import Any
+
+from pydantic import BaseModel, Field, ValidationError
+
+from .canonical import canonical_bytes
+from .models import AdmissibilityJudgment
+
+SIGNATURE_ALGORITHM = "hmac-sha256"
+SIGNATURE_FIELD = "signature"
+
+# Record fields that are not part of the judgment (echoed payload id, the signature itself);
+# the id is signed separately.
+_UNSIGNED_RECORD_FIELDS = frozenset({"id", SIGNATURE_FIELD})
+
+
+class JudgmentSignature(BaseModel):
+    """
+    Detached signature over the canonical serialization of a judgment.
+    """
+
+    algorithm: str = Field(default=SIGNATURE_ALGORITHM, description="Signature algorithm.")
+    key_id: str | None = Field(
+        default=None,
+        description="Optional identifier of the signing key (deployment), for key rotation.",
+    )
+    input_hash: str | None = Field(
+        default=None,
+        description="input_digest() of the evaluated payload; part of the signed bytes.",
+    )
+    value: str = Field(description="Hex-encoded signature.")
+
+
+def input_digest(payload: Mapping[str, Any]) -> str:
+    """SHA-256 (hex) of the canonical evaluate payload, as in audit log ``input_hash``."""
+    return hashlib.sha256(canonical_bytes(payload)).hexdigest()
+
+
+def canonical_judgment_bytes(
+    judgment: AdmissibilityJudgment | Mapping[str, Any],
+    *,
+    payload_id: Any = None,
+    input_hash: str | None = None,
+) -> bytes:
+    """
+    Serialize a judgment canonically for signing.
+
+    Accepts a judgment model or its JSON record (as written by pipeline modes);
+    record-only fields (``id``, ``signature``) are excluded from the judgment.
+    The signed bytes are the canonical JSON of ``{"id", "input_hash", "judgment"}``.
+    """
+    if isinstance(judgment, AdmissibilityJudgment):
+        data: dict[str, Any] = judgment.model_dump(mode="json")
+    else:
+        data = {k: v for k, v in judgment.items() if k not in _UNSIGNED_RECORD_FIELDS}
+    return canonical_bytes({"id": payload_id, "input_hash": input_hash, "judgment": data})
+
+
+class JudgmentSigner:
+    """
+    Sign and verify judgments with a deployment HMAC key.
+    """
+
+    def __init__(self, key: bytes, *, key_id: str | None = None) -> None:
+        """Initialize signer with a non-empty secret key and optional key id."""
+        if not key:
+            raise ValueError("Signing key must not be empty")
+        self._key = key
+        self.key_id = key_id
+
+    @classmethod
+    def from_key_file(cls, path: str | Path, *, key_id: str | None = None) -> JudgmentSigner:
+        """Load the secret key from a file (surrounding whitespace is stripped)."""
+        return cls(Path(path).read_bytes().strip(), key_id=key_id)
+
+    def _digest(
+        self,
+        judgment: AdmissibilityJudgment | Mapping[str, Any],
+        payload_id: Any,
+        input_hash: str | None,
+    ) -> str:
+        data = canonical_judgment_bytes(judgment, payload_id=payload_id, input_hash=input_hash)
+        return hmac.new(self._key, data, hashlib.sha256).hexdigest()
+
+    def sign(
+        self,
+        judgment: AdmissibilityJudgment | Mapping[str, Any],
+        *,
+        payload_id: Any = None,
+        payload: Mapping[str, Any] | None = None,
+    ) -> JudgmentSignature:
+        """Return a detached signature for ``judgment`` of ``payload`` (echoed as payload_id)."""
+        input_hash = input_digest(payload) if payload is not None else None
+        return JudgmentSignature(
+            key_id=self.key_id,
+            input_hash=input_hash,
+            value=self._digest(judgment, payload_id, input_hash),
+        )
+
+    def verify(
+        self,
+        judgment: AdmissibilityJudgment | Mapping[str, Any],
+        signature: JudgmentSignature | Mapping[str, Any],
+        *,
+        payload_id: Any = None,
+        payload: Mapping[str, Any] | None = None,
+    ) -> bool:
+        """
+        Check a detached signature against ``judgment``.
+
+        Pass ``payload`` to also check that the judgment was produced from it.
+
+        Returns:
+            False if the signature is malformed, its algorithm or key id does not
+            match, or the judgment, payload id or input was altered
+        """
+        try:
+            signature = JudgmentSignature.model_validate(signature)
+        except ValidationError:
+            return False
+        if signature.algorithm != SIGNATURE_ALGORITHM:
+            return False
+        if signature.key_id != self.key_id:
+            return False
+        if payload is not None and input_digest(payload) != signature.input_hash:
+            return False
+        expected = self._digest(judgment, payload_id, signature.input_hash)
+        return hmac.compare_digest(signature.value, expected)
+
+    def verify_record(
+        self, record: Mapping[str, Any], *, payload: Mapping[str, Any] | None = None
+    ) -> bool:
+        """
+        Verify an output record carrying its signature in the ``signature`` field.
+
+        The record's ``id`` is checked as signed; pass ``payload`` to also check the input.
+        """
+        signature = record.get(SIGNATURE_FIELD)
+        if not isinstance(signature, Mapping):
+            return False
+        return self.verify(record, signature, payload_id=record.get("id"), payload=payload)
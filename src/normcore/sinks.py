@@ -0,0 +1,520 @@
+"""
+Output sinks for the pipeline modes.
+
+``--output PATH`` writes the output records to a file exactly as they would be
+written to stdout, atomically (see ``output_file``). ``--output SCHEME:PATH``
+sends them to a file-backed sink instead, so evaluation results can be queried
+without a JSONL post-processing step:
+
+- ``parquet:PATH``: Apache Parquet with one row per evaluated statement
+  (requires the optional ``parquet`` extra, ``pip install 'normcore[parquet]'``)
+- ``sqlite:PATH``: SQLite database with ``runs``, ``judgments``,
+  ``statements`` and ``grounds`` tables (standard library only)
+
+Parquet rows repeat the judgment-level columns (``status``, ``gate_decision``,
+...) next to the statement columns (``statement_*``), so DuckDB or Spark can
+filter and aggregate without joins. A judgment without statements yields one
+row with empty statement columns, and a rejected payload yields one row with
+``error`` set, so every output record is represented. ``record`` numbers
+output records in order (1-based) and ``id`` carries the echoed payload id as
+text.
+
+SQLite databases accumulate evaluation history: each opening of the sink
+adds a ``runs`` row, and the tables are created (with indices on ids,
+statuses, axioms and ground ids) only when missing. Judgments keep their
+full output record in ``record_json``; list-valued columns hold JSON arrays.
+Records are committed in batches and on close, so an interrupted run keeps
+everything up to the last commit.
+"""
+
+from __future__ import annotations
+
+import contextlib
+import json
+import os
+import sqlite3
+import tempfile
+import threading
+from collections.abc import Iterator, Mapping
+from datetime import datetime, timezone
+from pathlib import Path
+from types import ModuleType
+from typing import IO, Any, Protocol
+
+from .logging import logger
+from .payloads import PAYLOAD_ID_FIELD
+
+PARQUET_SCHEME = "parquet"
+SQLITE_SCHEME = "sqlite"
+OUTPUT_SCHEMES = (PARQUET_SCHEME, SQLITE_SCHEME)
+# Plain files, for --output values without a scheme.
+FILE_SCHEME = "file"
+PARTIAL_SUFFIX = ".partial"
+# Plain file names that almost certainly meant a sink.
+_SINK_SUFFIXES = {".parquet": PARQUET_SCHEME, ".sqlite": SQLITE_SCHEME, ".sqlite3": SQLITE_SCHEME}
+DEFAULT_ROW_GROUP_SIZE = 10_000
+DEFAULT_COMMIT_INTERVAL = 1_000
+SQLITE_SCHEMA_VERSION = 1
+
+# Column name and type, in file order.
+PARQUET_COLUMNS: tuple[tuple[str, str], ...] = (
+    ("record", "int64"),
+    ("id", "string"),
+    ("error", "string"),
+    ("status", "string"),
+    ("gate_decision", "string"),
+    ("risk_tier", "string"),
+    ("licensed", "bool"),
+    ("confidence", "double"),
+    ("violated_axioms", "list<string>"),
+    ("num_statements", "int64"),
+    ("statement_index", "int64"),
+    ("statement_id", "string"),
+    ("statement", "string"),
+    ("statement_modality", "string"),
+    ("statement_reading", "string"),
+    ("statement_status", "string"),
+    ("statement_violated_axiom", "string"),
+    ("statement_explanation", "string"),
+    ("statement_recommended_modality", "string"),
+    ("statement_license", "list<string>"),
+    ("statement_weight", "double"),
+    ("statement_confidence", "double"),
+    ("statement_personalized", "bool"),
+    ("statement_probability", "double"),
+    ("statement_attribution_source", "string"),
+    ("statement_ground_ids", "list<string>"),
+)
+
+
+class RecordSink(Protocol):
+    """Destination for output records (judgment and error records)."""
+
+    def write(self, record: Mapping[str, Any]) -> None:
+        """Store one output record."""
+
+    def close(self) -> None:
+        """Flush buffered records and release the destination."""
+
+
+class LockedSink:
+    """Serialize writes to a sink shared by concurrent streams."""
+
+    def __init__(self, sink: RecordSink) -> None:
+        """Wrap ``sink``."""
+        self._sink = sink
+        self._lock = threading.Lock()
+
+    def write(self, record: Mapping[str, Any]) -> None:
+        """Write one record while holding the lock."""
+        with self._lock:
+            self._sink.write(record)
+
+    def close(self) -> None:
+        """Close the wrapped sink."""
+        with self._lock:
+            self._sink.close()
+
+
+def parse_output(value: str) -> tuple[str, Path]:
+    """
+    Split an ``--output`` value into scheme and path.
+
+    A value without a ``SCHEME:`` prefix is a plain file (``FILE_SCHEME``).
+    Single-letter prefixes are Windows drive letters, not schemes.
+
+    Raises:
+        ValueError: the value has an unsupported scheme, no path, or is a
+            plain ``.parquet`` / ``.sqlite`` file name missing its scheme
+    """
+    scheme, separator, path = value.partition(":")
+    if separator and len(scheme) > 1 and scheme.isalnum():
+        if scheme not in OUTPUT_SCHEMES:
+            schemes = ", ".join(["PATH", *(f"{name}:PATH" for name in OUTPUT_SCHEMES)])
+            raise ValueError(f"Unsupported output {value!r} (expected {schemes})")
+        if not path:
+            raise ValueError(f"Missing path in output {value!r}")
+        return scheme, Path(path)
+    if not value:
+        raise ValueError("Missing path in output ''")
+    sink = _SINK_SUFFIXES.get(Path(value).suffix.lower())
+    if sink is not None:
+        raise ValueError(f"Unsupported output {value!r} (did you mean {sink}:{value}?)")
+    return FILE_SCHEME, Path(value)
+
+
+@contextlib.contextmanager
+def output_file(
+    path: str | Path, *, binary: bool = False, append: bool = False
+) -> Iterator[IO[Any]]:
+    """
+    Open ``path`` for output written as it would be to stdout.
+
+    Output goes to a temporary file next to ``path`` that replaces it only
+    when the block exits normally, so readers never see a half-written file
+    and a failed run leaves an earlier file untouched. An interrupted run
+    (Ctrl-C) keeps the output written so far as ``NAME.partial`` next to
+    ``path``; a killed process leaves it in the hidden ``.NAME.*.tmp`` file.
+    With ``append=True`` output is added to the end of ``path`` instead, so
+    every flushed record survives an interrupted run.
+
+    Raises:
+        OSError: the file cannot be created
+    """
+    path = Path(path)
+    encoding = None if binary else "utf-8"
+    if append:
+        with open(path, "ab" if binary else "a", encoding=encoding) as handle:
+            yield handle
+        return
+    if path.is_dir():
+        raise IsADirectoryError(f"Is a directory: {path}")
+    fd, temp_name = tempfile.mkstemp(dir=path.parent, prefix=f".{path.name}.", suffix=".tmp")
+    try:
+        with open(fd, "wb" if binary else "w", encoding=encoding) as handle:
+            os.chmod(temp_name, _file_mode(path))
+            yield handle
+            handle.flush()
+            os.fsync(handle.fileno())
+        os.replace(temp_name, path)
+    except KeyboardInterrupt:
+        partial = path.with_name(path.name + PARTIAL_SUFFIX)
+        with contextlib.suppress(OSError):
+            os.replace(temp_name, partial)
+            logger.warning(f"output: interrupted; partial output kept in {partial}")
+        raise
+    except BaseException:
+        with contextlib.suppress(OSError):
+            os.unlink(temp_name)
+        raise
+
+
+def _file_mode(path: Path) -> int:
+    # mkstemp creates owner-only files; keep the replaced file's mode, or use the umask default.
+    if path.exists():
+        return path.stat().st_mode & 0o777
+    umask = os.umask(0)
+    os.umask(umask)
+    return 0o666 & ~umask
+
+
+def open_sink(value: str) -> RecordSink:
+    """
+    Open the sink named by an ``--output SCHEME:PATH`` value.
+
+    Raises:
+        ValueError: the value is invalid, names a plain file, or the sink's
+            dependency is missing
+        OSError: the destination cannot be created
+    """
+    scheme, path = parse_output(value)
+    if scheme == FILE_SCHEME:
+        raise ValueError(f"Output {value!r} is a plain file, not a sink")
+    if scheme == SQLITE_SCHEME:
+        return SqliteSink(path)
+    return ParquetSink(path)
+
+
+def _import_pyarrow() -> tuple[ModuleType, ModuleType]:
+    try:
+        import pyarrow
+        import pyarrow.parquet
+    except ImportError:
+        raise ValueError(
+            "Parquet output requires the optional pyarrow dependency "
+            "(pip install 'normcore[parquet]')"
+        ) from None
+    return pyarrow, pyarrow.parquet
+
+
+def _text(value: Any) -> str | None:
+    if value is None or isinstance(value, str):
+        return value
+    return json.dumps(value, ensure_ascii=False)
+
+
+def statement_rows(record: Mapping[str, Any], *, record_number: int) -> list[dict[str, Any]]:
+    """Flatten one output record into Parquet rows (see ``PARQUET_COLUMNS``)."""
+    base: dict[str, Any] = dict.fromkeys(name for name, _ in PARQUET_COLUMNS)
+    base["record"] = record_number
+    base["id"] = _text(record.get(PAYLOAD_ID_FIELD))
+    if "error" in record:
+        base["error"] = str(record["error"])
+        return [base]
+    base.update(
+        status=record["status"],
+        gate_decision=record["gate_decision"],
+        risk_tier=record["risk_tier"],
+        licensed=record["licensed"],
+        confidence=record["confidence"],
+        violated_axioms=record["violated_axioms"],
+        num_statements=record["num_statements"],
+    )
+    rows = []
+    for index, evaluation in enumerate(record["statement_evaluations"]):
+        uncertainty = evaluation.get("uncertainty") or {}
+        attribution = evaluation.get("attribution") or {}
+        rows.append(
+            {
+                **base,
+                "statement_index": index,
+                "statement_id": evaluation["statement_id"],
+                "statement": evaluation["statement"],
+                "statement_modality": evaluation["modality"],
+                "statement_reading": evaluation.get("reading"),
+                "statement_status": evaluation["status"],
+                "statement_violated_axiom": evaluation.get("violated_axiom"),
+                "statement_explanation": evaluation.get("explanation"),
+                "statement_recommended_modality": evaluation.get("recommended_modality"),
+                "statement_license": sorted(evaluation["license"]),
+                "statement_weight": evaluation.get("weight"),
+                "statement_confidence": evaluation.get("confidence"),
+                "statement_personalized": evaluation.get("personalized"),
+                "statement_probability": uncertainty.get("probability"),
+                "statement_attribution_source": attribution.get("source"),
+                "statement_ground_ids": [
+                    ground["id"] for ground in evaluation.get("grounding_trace", ())
+                ],
+            }
+        )
+    return rows or [base]
+
+
+class ParquetSink:
+    """
+    Write output records as flattened Parquet rows.
+
+    Rows are buffered and written as a row group once ``row_group_size`` rows
+    are pending, so memory stays bounded on long runs. The file is complete
+    only after ``close()``.
+    """
+
+    def __init__(self, path: str | Path, *, row_group_size: int = DEFAULT_ROW_GROUP_SIZE) -> None:
+        """Create (or truncate) the Parquet file at ``path``."""
+        if row_group_size < 1:
+            raise ValueError("row_group_size must be at least 1")
+        pyarrow, parquet = _import_pyarrow()
+        types = {
+            "string": pyarrow.string(),
+            "int64": pyarrow.int64(),
+            "double": pyarrow.float64(),
+            "bool": pyarrow.bool_(),
+            "list<string>": pyarrow.list_(pyarrow.string()),
+        }
+        self._pyarrow = pyarrow
+        self._schema = pyarrow.schema(
+            [pyarrow.field(name, types[kind]) for name, kind in PARQUET_COLUMNS]
+        )
+        self._writer = parquet.ParquetWriter(str(path), self._schema)
+        self._row_group_size = row_group_size
+        self._rows: list[dict[str, Any]] = []
+        self._records = 0
+
+    def write(self, record: Mapping[str, Any]) -> None:
+        """Buffer the rows of one output record, writing a row group when full."""
+        self._records += 1
+        self._rows.extend(statement_rows(record, record_number=self._records))
+        if len(self._rows) >= self._row_group_size:
+            self._flush()
+
+    def _flush(self) -> None:
+        if self._rows:
+            table = self._pyarrow.Table.from_pylist(self._rows, schema=self._schema)
+            self._writer.write_table(table)
+            self._rows = []
+
+    def close(self) -> None:
+        """Write the remaining rows and the file footer."""
+        self._flush()
+        self._writer.close()
+
+
+_SQLITE_SCHEMA = """
+CREATE TABLE IF NOT EXISTS runs (
+    run_id INTEGER PRIMARY KEY,
+    started_at TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS judgments (
+    judgment_id INTEGER PRIMARY KEY,
+    run_id INTEGER NOT NULL REFERENCES runs (run_id),
+    record INTEGER NOT NULL,
+    payload_id TEXT,
+    error TEXT,
+    status TEXT,
+    gate_decision TEXT,
+    risk_tier TEXT,
+    licensed INTEGER,
+    can_retry INTEGER,
+    confidence REAL,
+    num_statements INTEGER,
+    num_acceptable INTEGER,
+    grounds_accepted INTEGER,
+    grounds_cited INTEGER,
+    violated_axioms TEXT,
+    explanation TEXT,
+    feedback_hint TEXT,
+    warnings TEXT,
+    record_json TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS statements (
+    judgment_id INTEGER NOT NULL REFERENCES judgments (judgment_id),
+    statement_index INTEGER NOT NULL,
+    statement_id TEXT NOT NULL,
+    statement TEXT NOT NULL,
+    modality TEXT NOT NULL,
+    reading TEXT,
+    status TEXT NOT NULL,
+    violated_axiom TEXT,
+    explanation TEXT,
+    recommended_modality TEXT,
+    license TEXT NOT NULL,
+    weight REAL,
+    confidence REAL,
+    personalized INTEGER,
+    PRIMARY KEY (judgment_id, statement_index)
+);
+CREATE TABLE IF NOT EXISTS grounds (
+    judgment_id INTEGER NOT NULL,
+    statement_index INTEGER NOT NULL,
+    trace_index INTEGER NOT NULL,
+    ground_id TEXT NOT NULL,
+    semantic_id TEXT,
+    scope TEXT,
+    source TEXT,
+    status TEXT,
+    strength TEXT,
+    confidence REAL,
+    PRIMARY KEY (judgment_id, statement_index, trace_index),
+    FOREIGN KEY (judgment_id, statement_index) REFERENCES statements
+);
+CREATE INDEX IF NOT EXISTS judgments_run ON judgments (run_id);
+CREATE INDEX IF NOT EXISTS judgments_payload_id ON judgments (payload_id);
+CREATE INDEX IF NOT EXISTS judgments_status ON judgments (status);
+CREATE INDEX IF NOT EXISTS statements_status ON statements (status);
+CREATE INDEX IF NOT EXISTS statements_violated_axiom ON statements (violated_axiom);
+CREATE INDEX IF NOT EXISTS grounds_ground_id ON grounds (ground_id);
+CREATE INDEX IF NOT EXISTS grounds_semantic_id ON grounds (semantic_id);
+"""
+
+
+def _json_column(value: Any) -> str | None:
+    return None if value is None else json.dumps(value, ensure_ascii=False)
+
+
+class SqliteSink:
+    """
+    Write output records into relational SQLite tables.
+
+    Records are committed every ``commit_interval`` records and on
+    ``close()``. The connection may be used from a thread other than the
+    one that opened it (the stream writer thread), one thread at a time.
+    """
+
+    def __init__(self, path: str | Path, *, commit_interval: int = DEFAULT_COMMIT_INTERVAL) -> None:
+        """Open (or create) the database at ``path`` and start a new run."""
+        if commit_interval < 1:
+            raise ValueError("commit_interval must be at least 1")
+        try:
+            self._connection = sqlite3.connect(str(path), check_same_thread=False)
+            version = self._connection.execute("PRAGMA user_version").fetchone()[0]
+            if version > SQLITE_SCHEMA_VERSION:
+                self._connection.close()
+                raise ValueError(
+                    f"{path} uses results schema version {version}; this NormCore "
+                    f"writes version {SQLITE_SCHEMA_VERSION}"
+                )
+            self._connection.executescript(_SQLITE_SCHEMA)
+            self._connection.execute(f"PRAGMA user_version = {SQLITE_SCHEMA_VERSION}")
+            started_at = datetime.now(timezone.utc).isoformat()
+            cursor = self._connection.execute(
+                "INSERT INTO runs (started_at) VALUES (?)", (started_at,)
+            )
+            self._connection.commit()
+        except sqlite3.Error as exc:
+            raise OSError(f"Cannot open SQLite database {path}: {exc}") from exc
+        self.run_id = cursor.lastrowid
+        self._commit_interval = commit_interval
+        self._records = 0
+
+    def write(self, record: Mapping[str, Any]) -> None:
+        """Insert one output record with its statements and grounding trace."""
+        self._records += 1
+        cursor = self._connection.execute(
+            "INSERT INTO judgments (run_id, record, payload_id, error, status, gate_decision, "
+            "risk_tier, licensed, can_retry, confidence, num_statements, num_acceptable, "
+            "grounds_accepted, grounds_cited, violated_axioms, explanation, feedback_hint, "
+            "warnings, record_json) "
+            "VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            (
+                self.run_id,
+                self._records,
+                _text(record.get(PAYLOAD_ID_FIELD)),
+                record.get("error"),
+                record.get("status"),
+                record.get("gate_decision"),
+                record.get("risk_tier"),
+                record.get("licensed"),
+                record.get("can_retry"),
+                record.get("confidence"),
+                record.get("num_statements"),
+                record.get("num_acceptable"),
+                record.get("grounds_accepted"),
+                record.get("grounds_cited"),
+                _json_column(record.get("violated_axioms")),
+                record.get("explanation"),
+                record.get("feedback_hint"),
+                _json_column(record.get("warnings")),
+                json.dumps(record, ensure_ascii=False),
+            ),
+        )
+        judgment_id = cursor.lastrowid
+        for index, evaluation in enumerate(record.get("statement_evaluations", ())):
+            self._connection.execute(
+                "INSERT INTO statements (judgment_id, statement_index, statement_id, statement, "
+                "modality, reading, status, violated_axiom, explanation, recommended_modality, "
+                "license, weight, confidence, personalized) "
+                "VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                (
+                    judgment_id,
+                    index,
+                    evaluation["statement_id"],
+                    evaluation["statement"],
+                    evaluation["modality"],
+                    evaluation.get("reading"),
+                    evaluation["status"],
+                    evaluation.get("violated_axiom"),
+                    evaluation.get("explanation"),
+                    evaluation.get("recommended_modality"),
+                    _json_column(sorted(evaluation["license"])),
+                    evaluation.get("weight"),
+                    evaluation.get("confidence"),
+                    evaluation.get("personalized"),
+                ),
+            )
+            self._connection.executemany(
+                "INSERT INTO grounds (judgment_id, statement_index, trace_index, ground_id, "
+                "semantic_id, scope, source, status, strength, confidence) "
+                "VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                [
+                    (
+                        judgment_id,
+                        index,
+                        trace_index,
+                        ground["id"],
+                        ground.get("semantic_id"),
+                        ground.get("scope"),
+                        ground.get("source"),
+                        ground.get("status"),
+                        ground.get("strength"),
+                        ground.get("confidence"),
+                    )
+                    for trace_index, ground in enumerate(evaluation.get("grounding_trace", ()))
+                ],
+            )
+        if self._records % self._commit_interval == 0:
+            self._connection.commit()
+
+    def close(self) -> None:
+        """Commit pending records and close the database."""
+        self._connection.commit()
+        self._connection.close()
@@ -0,0 +1,360 @@
+"""
+Structural validation of evaluate payloads.
+
+``evaluate()`` stops at the first malformed input with a terse message.
+``validate_payload`` checks the whole payload instead (fields, message roles
+and content shapes, tool call linkage, grounds, links and knowledge nodes)
+and reports every problem with its location, without running evaluation::
+
+    report = validate_payload({"conversation": [...]})
+    for issue in report.issues:
+        print(issue.path, issue.message)
+
+A payload that validates can still produce warnings during evaluation (for
+example grounds that no statement cites); only input shape is checked here.
+"""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Mapping
+from typing import Any
+
+from pydantic import ValidationError
+
+from .citations import Ground, parse_openai_citations, parse_sectioned_grounds
+from .evaluator import _REASONING_ITEM_TYPES, _is_reasoning_item
+from .logging import collect_warnings
+from .models import LinkSet, PayloadIssue, PayloadValidationReport
+from .normative.knowledge_builder import KnowledgeStateBuilder
+from .payloads import PAYLOAD_AGENT_FIELD, PAYLOAD_FIELDS, PAYLOAD_ID_FIELD
+
+# Content part types accepted per message role (function messages take strings only).
+_CONTENT_PART_TYPES: dict[str, frozenset[str]] = {
+    "system": frozenset({"text"}),
+    "developer": frozenset({"text"}),
+    "user": frozenset({"text", "image_url", "input_audio", "file"}),
+    "assistant": frozenset({"text", "refusal", *_REASONING_ITEM_TYPES}),
+    "tool": frozenset({"text"}),
+}
+# Field each content part type must carry, and its expected JSON type.
+_CONTENT_PART_FIELDS: dict[str, tuple[str, type]] = {
+    "text": ("text", str),
+    "refusal": ("refusal", str),
+    "image_url": ("image_url", dict),
+    "input_audio": ("input_audio", dict),
+    "file": ("file", dict),
+}
+_MESSAGE_ROLES = ("system", "developer", "user", "assistant", "tool", "function")
+
+
+class _Issues:
+    """Accumulates issues in payload order."""
+
+    def __init__(self) -> None:
+        self.items: list[PayloadIssue] = []
+
+    def add(self, path: str, message: str) -> None:
+        self.items.append(PayloadIssue(path=path, message=message))
+
+    def add_validation_error(self, path: str, exc: ValidationError) -> None:
+        for error in exc.errors():
+            self.add(_join(path, *error["loc"]), error["msg"])
+
+
+def _join(path: str, *parts: Any) -> str:
+    for part in parts:
+        if isinstance(part, int):
+            path = f"{path}[{part}]"
+        else:
+            path = f"{path}.{part}" if path else str(part)
+    return path
+
+
+def _type_name(value: Any) -> str:
+    if value is None:
+        return "null"
+    if isinstance(value, bool):
+        return "boolean"
+    if isinstance(value, (int, float)):
+        return "number"
+    if isinstance(value, str):
+        return "string"
+    if isinstance(value, list):
+        return "array"
+    if isinstance(value, dict):
+        return "object"
+    return type(value).__name__
+
+
+def validate_payload(payload: Any) -> PayloadValidationReport:
+    """
+    Check an evaluate payload for structural validity without evaluating it.
+
+    Accepts the payload object of ``stream`` / ``POST /evaluate`` (``evaluate()``
+    keyword arguments plus the optional ``id`` and ``agent`` labels) and
+    returns every problem found rather than stopping at the first.
+    """
+    issues = _Issues()
+    if not isinstance(payload, Mapping):
+        issues.add("", f"Payload must be an object, got {_type_name(payload)}")
+        return PayloadValidationReport(valid=False, issues=issues.items)
+
+    known = {*PAYLOAD_FIELDS, PAYLOAD_ID_FIELD, PAYLOAD_AGENT_FIELD}
+    for field in payload:
+        if field not in known:
+            issues.add(str(field), "Unknown payload field")
+
+    agent_output = payload.get("agent_output")
+    conversation = payload.get("conversation")
+    if agent_output is not None and not isinstance(agent_output, str):
+        issues.add("agent_output", f"Expected a string, got {_type_name(agent_output)}")
+    if agent_output is None and conversation is None:
+        issues.add("", "Payload requires agent_output or conversation")
+    text = agent_output if isinstance(agent_output, str) else None
+    if conversation is not None:
+        text = _check_conversation(conversation, agent_output, issues)
+
+    _check_selection(payload, text, issues)
+    domain = payload.get("domain")
+    if domain is not None and not isinstance(domain, str):
+        issues.add("domain", f"Expected a string, got {_type_name(domain)}")
+    if payload.get("grounds") is not None:
+        _check_grounds(payload["grounds"], issues)
+    if payload.get("links") is not None:
+        _check_links(payload["links"], issues)
+    if payload.get("knowledge_nodes") is not None:
+        _check_knowledge_nodes(payload["knowledge_nodes"], issues)
+    return PayloadValidationReport(valid=not issues.items, issues=issues.items)
+
+
+def _check_conversation(conversation: Any, agent_output: Any, issues: _Issues) -> str | None:
+    """Check messages and tool call linkage; return the final assistant text, if any."""
+    if not isinstance(conversation, list) or not conversation:
+        issues.add("conversation", "Expected a non-empty array of messages")
+        return None
+
+    call_ids: set[str] = set()
+    answered: set[str] = set()
+    last: tuple[str, Any] | None = None
+    for index, message in enumerate(conversation):
+        path = f"conversation[{index}]"
+        if _is_reasoning_item(message):
+            continue
+        last = (path, message)
+        if not isinstance(message, dict):
+            issues.add(path, f"Expected a message object, got {_type_name(message)}")
+            continue
+        role = message.get("role")
+        if role not in _MESSAGE_ROLES:
+            issues.add(
+                f"{path}.role",
+                f"Unknown role {role!r} (expected one of {', '.join(_MESSAGE_ROLES)})",
+            )
+            continue
+        _check_content(message, role, path, issues)
+        if "name" in message and not isinstance(message["name"], str):
+            issues.add(f"{path}.name", f"Expected a string, got {_type_name(message['name'])}")
+        if role == "function" and "name" not in message:
+            issues.add(f"{path}.name", "Function messages require a name")
+        if role == "assistant" and "tool_calls" in message:
+            _check_tool_calls(message["tool_calls"], f"{path}.tool_calls", call_ids, issues)
+        if role == "tool":
+            call_id = message.get("tool_call_id")
+            if not isinstance(call_id, str) or not call_id:
+                issues.add(f"{path}.tool_call_id", "Tool messages require a tool_call_id string")
+            elif call_id not in call_ids:
+                issues.add(
+                    f"{path}.tool_call_id",
+                    f"'{call_id}' matches no tool call of a preceding assistant message",
+                )
+            elif call_id in answered:
+                issues.add(f"{path}.tool_call_id", f"Tool call '{call_id}' already has a result")
+            else:
+                answered.add(call_id)
+
+    if last is None:
+        issues.add("conversation", "Conversation holds only reasoning items")
+        return None
+    path, message = last
+    if not isinstance(message, dict) or message.get("role") != "assistant":
+        issues.add(path, "Last conversation item must be an assistant message")
+        return None
+    content = message.get("content")
+    if agent_output is not None:
+        if not isinstance(content, str):
+            issues.add(
+                f"{path}.content",
+                "Must be a string when agent_output is provided",
+            )
+        elif content != agent_output:
+            issues.add("agent_output", f"Does not match the assistant content of {path}")
+    if isinstance(content, str):
+        return content
+    return None
+
+
+def _check_content(message: dict[str, Any], role: str, path: str, issues: _Issues) -> None:
+    path = f"{path}.content"
+    if "content" not in message:
+        if role != "assistant":
+            issues.add(path, f"{role.capitalize()} messages require content")
+        return
+    content = message["content"]
+    if content is None and role in ("assistant", "function"):
+        return
+    if isinstance(content, str):
+        return
+    allowed = _CONTENT_PART_TYPES.get(role)
+    if allowed is None or not isinstance(content, list):
+        expected = "a string" if allowed is None else "a string or an array of content parts"
+        issues.add(path, f"Expected {expected}, got {_type_name(content)}")
+        return
+    for index, part in enumerate(content):
+        part_path = f"{path}[{index}]"
+        if not isinstance(part, dict):
+            issues.add(part_path, f"Expected a content part object, got {_type_name(part)}")
+            continue
+        part_type = part.get("type")
+        if part_type not in allowed:
+            issues.add(
+                f"{part_path}.type",
+                f"Content part type {part_type!r} is not allowed in {role} messages "
+                f"(expected one of {', '.join(sorted(allowed))})",
+            )
+            continue
+        if part_type not in _CONTENT_PART_FIELDS:
+            continue
+        field, expected_type = _CONTENT_PART_FIELDS[part_type]
+        if not isinstance(part.get(field), expected_type):
+            issues.add(
+                f"{part_path}.{field}",
+                f"Expected {'a string' if expected_type is str else 'an object'}, "
+                f"got {_type_name(part.get(field))}",
+            )
+
+
+def _check_tool_calls(tool_calls: Any, path: str, call_ids: set[str], issues: _Issues) -> None:
+    if not isinstance(tool_calls, list):
+        issues.add(path, f"Expected an array, got {_type_name(tool_calls)}")
+        return
+    for index, tool_call in enumerate(tool_calls):
+        call_path = f"{path}[{index}]"
+        if not isinstance(tool_call, dict):
+            issues.add(call_path, f"Expected a tool call object, got {_type_name(tool_call)}")
+            continue
+        call_id = tool_call.get("id")
+        if not isinstance(call_id, str) or not call_id:
+            issues.add(f"{call_path}.id", "Tool calls require an id string")
+        elif call_id in call_ids:
+            issues.add(f"{call_path}.id", f"Duplicate tool call id '{call_id}'")
+        else:
+            call_ids.add(call_id)
+
+        call_type = tool_call.get("type")
+        if call_type not in ("function", "custom"):
+            issues.add(
+                f"{call_path}.type",
+                f"Unknown tool call type {call_type!r} (expected function or custom)",
+            )
+            continue
+        body = tool_call.get(call_type)
+        if not isinstance(body, dict):
+            issues.add(f"{call_path}.{call_type}", f"Expected an object, got {_type_name(body)}")
+            continue
+        value_field = "arguments" if call_type == "function" else "input"
+        for field in ("name", value_field):
+            if not isinstance(body.get(field), str):
+                issues.add(
+                    f"{call_path}.{call_type}.{field}",
+                    f"Expected a string, got {_type_name(body.get(field))}",
+                )
+        arguments = body.get("arguments")
+        if call_type == "function" and isinstance(arguments, str) and arguments.strip():
+            try:
+                parsed = json.loads(arguments)
+            except json.JSONDecodeError:
+                parsed = None
+            if not isinstance(parsed, dict):
+                issues.add(
+                    f"{call_path}.function.arguments", "Arguments must encode a JSON object"
+                )
+
+
+def _check_selection(payload: Mapping[str, Any], text: str | None, issues: _Issues) -> None:
+    statement_ids = payload.get("statement_ids")
+    if statement_ids is not None and (
+        not isinstance(statement_ids, list)
+        or not all(isinstance(statement_id, str) for statement_id in statement_ids)
+    ):
+        issues.add("statement_ids", "Expected an array of strings")
+
+    span = payload.get("span")
+    if span is None:
+        return
+    if (
+        not isinstance(span, list)
+        or len(span) != 2
+        or not all(isinstance(bound, int) and not isinstance(bound, bool) for bound in span)
+    ):
+        issues.add("span", "Expected a pair of integer offsets [start, end]")
+    elif text is not None and not 0 <= span[0] <= span[1] <= len(text):
+        issues.add("span", f"{span} is outside assistant text (length {len(text)})")
+
+
+def _check_grounds(grounds: Any, issues: _Issues) -> None:
+    if isinstance(grounds, dict):
+        # Sectioned grounds skip malformed items with a warning; report them instead.
+        with collect_warnings() as warnings:
+            parse_sectioned_grounds(grounds)
+        for warning in warnings:
+            issues.add("grounds", warning)
+        return
+    if not isinstance(grounds, list):
+        issues.add("grounds", f"Expected an array or a sectioned object, got {_type_name(grounds)}")
+        return
+    for index, item in enumerate(grounds):
+        path = f"grounds[{index}]"
+        if isinstance(item, dict) and "type" in item:
+            # OpenAI annotation shape
+            try:
+                parse_openai_citations([item])
+            except ValidationError as exc:
+                issues.add(path, f"Invalid OpenAI annotation: {exc.errors()[0]['msg']}")
+            continue
+        try:
+            Ground.model_validate(item)
+        except ValidationError as exc:
+            issues.add_validation_error(path, exc)
+
+
+def _check_links(links: Any, issues: _Issues) -> None:
+    if isinstance(links, list):
+        try:
+            parse_openai_citations(links)
+        except ValidationError as exc:
+            issues.add_validation_error("links", exc)
+        if not links:
+            issues.add("links", "Expected a non-empty array of OpenAI annotations")
+        return
+    if not isinstance(links, dict):
+        issues.add("links", f"Expected a LinkSet object, got {_type_name(links)}")
+        return
+    try:
+        LinkSet.model_validate(links)
+    except ValidationError as exc:
+        issues.add_validation_error("links", exc)
+
+
+def _check_knowledge_nodes(nodes: Any, issues: _Issues) -> None:
+    if not isinstance(nodes, list):
+        issues.add("knowledge_nodes", f"Expected an array, got {_type_name(nodes)}")
+        return
+    builder = KnowledgeStateBuilder()
+    for index, node in enumerate(nodes):
+        try:
+            builder.from_precomputed([node])
+        except ValueError as exc:
+            # Messages name the index within the one-node batch; the path carries it.
+            message = str(exc).split(": ", 1)[-1]
+            issues.add(f"knowledge_nodes[{index}]", message)
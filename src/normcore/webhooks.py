@@ -0,0 +1,155 @@
+"""
+Violation webhooks for long-running CLI modes.
+
+Alerting and incident tooling can subscribe to judgments instead of polling
+pipeline output: every judgment whose status is ``violates_norm`` or
+``unsupported`` is POSTed as JSON to each configured URL.
+
+Delivery is best-effort and off the evaluation path: ``notify`` queues the
+judgment and a background thread POSTs it, so a slow endpoint never delays a
+response. When the backlog is full the alert is dropped and logged. A failing
+endpoint is logged and never interrupts evaluation; the judgment is still
+written to the primary output.
+"""
+
+from __future__ import annotations
+
+import queue
+import threading
+from collections.abc import Iterable
+from typing import Any
+from urllib.error import URLError
+from urllib.request import Request, urlopen
+
+from .logging import logger
+from .models import AdmissibilityJudgment, AdmissibilityStatus
+from .payloads import render_result_line
+from .signing import JudgmentSigner
+
+WEBHOOK_STATUSES = frozenset({AdmissibilityStatus.VIOLATES_NORM, AdmissibilityStatus.UNSUPPORTED})
+DEFAULT_WEBHOOK_TIMEOUT = 5.0
+DEFAULT_WEBHOOK_BACKLOG = 1000
+
+# (judgment, payload id, evaluated payload) of one queued alert
+_Alert = tuple[AdmissibilityJudgment, Any, "dict[str, Any] | None"]
+
+
+class WebhookNotifier:
+    """
+    POST alerting judgments to configured webhook URLs from a background thread.
+
+    The request body is the same JSON record written by pipeline modes
+    (echoed payload ``id`` first, then the judgment fields, then the detached
+    ``signature`` when a signer is configured). Use as a context manager, or
+    call ``close()``, to deliver the queued alerts before exiting.
+    """
+
+    def __init__(
+        self,
+        urls: Iterable[str],
+        *,
+        timeout: float = DEFAULT_WEBHOOK_TIMEOUT,
+        signer: JudgmentSigner | None = None,
+        max_backlog: int = DEFAULT_WEBHOOK_BACKLOG,
+    ) -> None:
+        """
+        Initialize notifier with target URLs and a per-request timeout.
+
+        Args:
+            urls: Webhook endpoints; empty entries are ignored
+            timeout: Timeout of each POST
+            signer: Signs webhook bodies like pipeline output
+            max_backlog: Queued alerts beyond which new ones are dropped
+        """
+        if max_backlog < 1:
+            raise ValueError("max_backlog must be at least 1")
+        self.urls = [url for url in urls if url]
+        self.timeout = timeout
+        self.signer = signer
+        self._alerts: queue.Queue[_Alert | None] = queue.Queue(maxsize=max_backlog)
+        self._worker: threading.Thread | None = None
+        self._lock = threading.Lock()
+
+    def notify(
+        self,
+        judgment: AdmissibilityJudgment,
+        *,
+        payload_id: Any = None,
+        payload: dict[str, Any] | None = None,
+    ) -> bool:
+        """
+        Queue ``judgment`` for delivery if its status warrants an alert.
+
+        Returns:
+            Whether the alert was queued (False when not alerting or the backlog is full).
+        """
+        if not self.urls or judgment.status not in WEBHOOK_STATUSES:
+            return False
+        self._start()
+        try:
+            self._alerts.put_nowait((judgment, payload_id, payload))
+        except queue.Full:
+            logger.warning(f"webhook: backlog full, dropped alert for payload {payload_id!r}")
+            return False
+        return True
+
+    def deliver(
+        self,
+        judgment: AdmissibilityJudgment,
+        *,
+        payload_id: Any = None,
+        payload: dict[str, Any] | None = None,
+    ) -> int:
+        """
+        POST ``judgment`` to every URL now, on the calling thread.
+
+        Returns:
+            Number of endpoints that accepted the delivery.
+        """
+        body = render_result_line(
+            judgment, payload_id=payload_id, signer=self.signer, payload=payload
+        ).encode("utf-8")
+        delivered = 0
+        for url in self.urls:
+            request = Request(
+                url,
+                data=body,
+                headers={"Content-Type": "application/json"},
+                method="POST",
+            )
+            try:
+                with urlopen(request, timeout=self.timeout) as response:
+                    logger.debug(f"webhook: delivered to {url} (HTTP {response.status})")
+                delivered += 1
+            except (URLError, OSError, ValueError) as exc:
+                logger.warning(f"webhook: delivery to {url} failed: {exc}")
+        return delivered
+
+    def close(self) -> None:
+        """Deliver the queued alerts and stop the background thread."""
+        with self._lock:
+            worker, self._worker = self._worker, None
+        if worker is not None:
+            self._alerts.put(None)
+            worker.join()
+
+    def __enter__(self) -> WebhookNotifier:
+        """Return the notifier."""
+        return self
+
+    def __exit__(self, *exc_info: object) -> None:
+        """Close the notifier."""
+        self.close()
+
+    def _start(self) -> None:
+        with self._lock:
+            if self._worker is None:
+                self._worker = threading.Thread(
+                    target=self._run, name="normcore-webhooks", daemon=True
+                )
+                self._worker.start()
+
+    def _run(self) -> None:
+        while (alert := self._alerts.get()) is not None:
+            judgment, payload_id, payload = alert
+            self.deliver(judgment, payload_id=payload_id, payload=payload)
@@ -33,6 +33,22 @@ def test_build_links_from_grounds_only_for_cited_keys():
     assert link_set.links[0].statement_id == "final_response"
 
 
+def test_build_links_from_grounds_scopes_keys_to_citing_statements():
+    grounds = [
+        Ground(citation_key="toolCall1", ground_id="issue_AGENT-8"),
+        Ground(citation_key="DocX", ground_id="file_123"),
+    ]
+    link_set = build_links_from_grounds(
+        text="Hi [@DocX]! Close it [@toolCall1]. Then ship.",
+        grounds=grounds,
+        statement_id="final_response",
+        statement_texts={"s1": "Close it [@toolCall1].", "s2": "Then ship."},
+    )
+    assert [(link.statement_id, link.ground_id) for link in link_set.links] == [
+        ("s1", "issue_AGENT-8"),
+        ("final_response", "file_123"),
+    ]
+
 def test_grounds_from_tool_call_refs_expands_multiple_grounds():
     grounds = grounds_from_tool_call_refs(
         {
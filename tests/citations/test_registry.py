@@ -0,0 +1,69 @@
+import json
+
+import pytest
+
+from normcore.citations import load_ground_registry, merge_grounds, parse_ground_manifest
+from normcore.models.links import CreatorType, LinkRole
+
+
+def _write(directory, name, payload):
+    (directory / name).write_text(json.dumps(payload), encoding="utf-8")
+
+
+def test_registry_merges_manifests_by_ground_id_in_file_name_order(tmp_path):
+    _write(tmp_path, "10-kb.json", [{"citation_key": "runbook", "ground_id": "kb_42"}])
+    _write(
+        tmp_path,
+        "20-policies.json",
+        {
+            "documents": [
+                {"citation_key": "runbook", "ground_id": "kb_42", "evidence_content": "v2"},
+                "policy_7",
+            ],
+            "user_facts": ["prefers_email"],
+        },
+    )
+    (tmp_path / "notes.txt").write_text("not a manifest", encoding="utf-8")
+
+    grounds = load_ground_registry(tmp_path)
+
+    assert [ground.ground_id for ground in grounds] == ["kb_42", "policy_7", "prefers_email"]
+    assert grounds[0].evidence_content == "v2"
+    assert grounds[2].role == LinkRole.CONTEXTUALIZES
+    assert grounds[2].creator == CreatorType.HUMAN
+
+
+@pytest.mark.parametrize(
+    "payload,message",
+    [
+        ([{"citation_key": 3}], "kb.json: [0].citation_key: Input should be a valid string"),
+        ({"manuals": []}, "kb.json: unknown grounds sections: manuals"),
+        ({"documents": "kb_1"}, "kb.json: section 'documents' must be an array"),
+        ([7], "kb.json: [0]: expected a ground object, got int"),
+        (
+            [{"type": "url_citation"}],
+            "kb.json: [0]: invalid OpenAI annotation: url_citation.end_index: Field required",
+        ),
+        ("kb_1", "kb.json: expected a grounds array or a sectioned object"),
+    ],
+)
+def test_invalid_manifest_rejects_the_registry(tmp_path, payload, message):
+    _write(tmp_path, "kb.json", payload)
+    with pytest.raises(ValueError) as exc_info:
+        load_ground_registry(tmp_path)
+    assert str(exc_info.value) == message
+
+
+def test_registry_requires_a_directory(tmp_path):
+    with pytest.raises(OSError):
+        load_ground_registry(tmp_path / "missing")
+
+
+def test_merge_grounds_keeps_first_position():
+    first = parse_ground_manifest(["a", "b"])
+    override = parse_ground_manifest([{"citation_key": "a2", "ground_id": "a"}])
+    merged = merge_grounds(first, override)
+    assert [(ground.ground_id, ground.citation_key) for ground in merged] == [
+        ("a", "a2"),
+        ("b", "b"),
+    ]
@@ -0,0 +1,44 @@
+import json
+
+import pytest
+
+from normcore.cli import main as cli_main
+
+
+@pytest.mark.parametrize(
+    "argv,message",
+    [
+        (
+            ["evaluate", "--converation", "[]"],
+            "normcore evaluate: error: unrecognized arguments: --converation [] "
+            "(did you mean --conversation?)",
+        ),
+        (
+            ["evaluate", "--agent-out", "Hi"],
+            "unrecognized arguments: --agent-out Hi (did you mean --agent-output?)",
+        ),
+        (["evaluate", "--agent-output", "Hi", "extra"], "unrecognized arguments: extra"),
+        (["--verbse", "axioms"], "normcore: error: unrecognized arguments: --verbse"),
+        (
+            ["evaluate", "--agent-output", "--conversation", "[]"],
+            "argument --agent-output: expected one argument",
+        ),
+    ],
+)
+def test_unknown_or_malformed_arguments_are_usage_errors(capsys, argv, message):
+    with pytest.raises(SystemExit) as exc:
+        cli_main(argv)
+    assert exc.value.code == 2
+    assert message in capsys.readouterr().err
+
+
+def test_subcommand_help(capsys):
+    with pytest.raises(SystemExit) as exc:
+        cli_main(["batch", "--help"])
+    assert exc.value.code == 0
+    assert capsys.readouterr().out.startswith("usage: normcore batch")
+
+
+def test_option_values_may_start_with_a_dash(capsys):
+    assert cli_main(["evaluate", "--agent-output=-- Hello!"]) == 0
+    assert json.loads(capsys.readouterr().out)["status"] == "no_normative_content"
@@ -0,0 +1,63 @@
+import io
+import json
+import sys
+
+from normcore import EvaluationPolicy, evaluate
+from normcore.audit import GENESIS_HASH, AuditLog, verify_audit_log
+from normcore.cli import main as cli_main
+
+
+def test_audit_log_chains_records_across_reopen(tmp_path):
+    path = tmp_path / "audit.jsonl"
+    judgment = evaluate(agent_output="We should deploy now.")
+
+    log = AuditLog(path)
+    first = log.append({"agent_output": "We should deploy now."}, judgment)
+    log.close()
+    log = AuditLog(path)
+    second = log.append({"agent_output": "We should deploy now."}, judgment)
+    log.close()
+
+    assert first["prev_hash"] == GENESIS_HASH
+    assert second["prev_hash"] == first["hash"]
+    assert (first["seq"], second["seq"]) == (0, 1)
+    assert first["input_hash"] == second["input_hash"]
+    assert first["policy_fingerprint"] == EvaluationPolicy().fingerprint()
+    assert first["judgment"]["status"] == "violates_norm"
+    assert verify_audit_log(path.read_text().splitlines()) is None
+
+
+def test_verify_audit_log_reports_first_tampered_record(tmp_path):
+    path = tmp_path / "audit.jsonl"
+    log = AuditLog(path)
+    for text in ("Hello!", "We should deploy now.", "The deployment is blocked."):
+        log.append({"agent_output": text}, evaluate(agent_output=text))
+    log.close()
+    lines = path.read_text().splitlines()
+
+    tampered = json.loads(lines[1])
+    tampered["judgment"]["status"] = "acceptable"
+    assert verify_audit_log([lines[0], json.dumps(tampered), lines[2]]) == 2
+    assert verify_audit_log([lines[0], lines[2]]) == 2
+
+
+def test_policy_fingerprint_tracks_settings():
+    assert EvaluationPolicy().fingerprint() == EvaluationPolicy().fingerprint()
+    assert EvaluationPolicy(risk_floor="high").fingerprint() != EvaluationPolicy().fingerprint()
+
+
+def test_stream_appends_audit_records(tmp_path, monkeypatch, capsys):
+    path = tmp_path / "audit.jsonl"
+    lines = [
+        json.dumps({"id": "a", "agent_output": "We should deploy now."}),
+        "{not json}",
+        json.dumps({"id": "b", "agent_output": "Hello!"}),
+    ]
+    monkeypatch.setattr(sys, "stdin", io.StringIO("".join(f"{line}\n" for line in lines)))
+
+    assert cli_main(["stream", "--audit-log", str(path)]) == 0
+    capsys.readouterr()
+
+    records = [json.loads(line) for line in path.read_text().splitlines()]
+    assert [r["judgment"]["status"] for r in records] == ["violates_norm", "no_normative_content"]
+    assert verify_audit_log(path.read_text().splitlines()) is None
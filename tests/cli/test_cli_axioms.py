@@ -0,0 +1,70 @@
+import json
+
+import pytest
+
+from normcore.cli import main as cli_main
+from normcore.normative import RuleAxiom, register_axiom, unregister_axiom
+from normcore.normative.axiom_checker import AXIOMS
+from normcore.normative.models import EvaluationStatus
+
+PRICING = RuleAxiom(
+    "ORG1",
+    "Pricing claims require a pricing ground",
+    EvaluationStatus.VIOLATES_NORM,
+    lambda statement, context: None,
+)
+
+
+def test_cli_axioms_lists_every_axiom_in_evaluation_order(capsys):
+    assert cli_main(["axioms", "--json"]) == 0
+    rows = json.loads(capsys.readouterr().out)
+    assert [row["code"] for row in rows] == ["I1", "A6", "A4", "A5", "A9", "A7", "A8"]
+    assert rows[3] == {
+        "code": "A5",
+        "description": "Categorical claims and promises require a matching license",
+        "severity": "violates_norm",
+        "enabled": True,
+    }
+
+
+def test_cli_axioms_policy_axiom_is_enabled_only_when_policy_requires_it(capsys):
+    cli_main(["axioms", "--json"])
+    default = {row["code"]: row["enabled"] for row in json.loads(capsys.readouterr().out)}
+    cli_main(["axioms", "--json", "--policy", '{"min_citations_per_200_words": 1.5}'])
+    required = {row["code"]: row["enabled"] for row in json.loads(capsys.readouterr().out)}
+
+    assert default["A8"] is False
+    assert required["A8"] is True
+    assert all(enabled for code, enabled in default.items() if code != "A8")
+
+
+def test_cli_axioms_lists_registered_axioms_and_disabled_ones(capsys):
+    register_axiom(PRICING)
+    try:
+        cli_main(["axioms", "--json", "--policy", '{"disabled_axioms": ["A9"]}'])
+    finally:
+        unregister_axiom("ORG1")
+    rows = {row["code"]: row for row in json.loads(capsys.readouterr().out)}
+
+    assert rows["ORG1"] == {
+        "code": "ORG1",
+        "description": "Pricing claims require a pricing ground",
+        "severity": "violates_norm",
+        "enabled": True,
+    }
+    assert rows["A9"]["enabled"] is False
+
+
+def test_cli_axioms_prints_table_by_default(capsys):
+    assert cli_main(["axioms"]) == 0
+    lines = capsys.readouterr().out.splitlines()
+    assert lines[0].split() == ["CODE", "SEVERITY", "ENABLED", "DESCRIPTION"]
+    assert len(lines) == len(AXIOMS) + 1
+    assert lines[-1].split()[:3] == ["A8", "insufficient_citations", "no"]
+
+
+def test_cli_axioms_rejects_invalid_policy(capsys):
+    with pytest.raises(SystemExit) as exc:
+        cli_main(["axioms", "--policy", '{"unknown_setting": 1}'])
+    assert exc.value.code == 2
+    assert "Invalid --policy" in capsys.readouterr().err
@@ -0,0 +1,102 @@
+import io
+import json
+import sys
+
+import pytest
+
+import normcore.cli
+from normcore.cli import main as cli_main
+
+
+def _write_payloads(path, lines):
+    path.write_text("".join(f"{line}\n" for line in lines), encoding="utf-8")
+
+
+def test_batch_evaluates_a_jsonl_file_in_input_order(tmp_path, capsys):
+    payloads = tmp_path / "dump.jsonl"
+    _write_payloads(
+        payloads,
+        [
+            json.dumps({"id": f"case-{index}", "agent_output": text})
+            for index, text in enumerate(
+                ["We should deploy now.", "Hello!", "If tests pass, we should deploy."] * 4
+            )
+        ]
+        + ["not json"],
+    )
+
+    assert cli_main(["batch", str(payloads), "--workers", "4"]) == 0
+
+    records = [json.loads(line) for line in capsys.readouterr().out.splitlines()]
+    assert [record.get("id") for record in records[:-1]] == [f"case-{i}" for i in range(12)]
+    assert [record["status"] for record in records[:3]] == [
+        "violates_norm",
+        "no_normative_content",
+        "conditionally_acceptable",
+    ]
+    assert records[-1]["line"] == 13 and "Invalid JSON" in records[-1]["error"]
+
+
+def test_batch_reads_stdin_when_no_path_is_given(monkeypatch, capsys):
+    line = json.dumps({"agent_output": "We should deploy now."})
+    monkeypatch.setattr(sys, "stdin", io.StringIO(line + "\n"))
+
+    assert cli_main(["batch"]) == 0
+    (record,) = [json.loads(line) for line in capsys.readouterr().out.splitlines()]
+    assert record["status"] == "violates_norm"
+
+
+def test_batch_audits_each_judgment(tmp_path, capsys):
+    payloads = tmp_path / "dump.jsonl"
+    _write_payloads(payloads, [json.dumps({"agent_output": "We should deploy now."})] * 2)
+    audit = tmp_path / "audit.jsonl"
+
+    assert cli_main(["batch", str(payloads), "--audit-log", str(audit)]) == 0
+    assert len(capsys.readouterr().out.splitlines()) == 2
+    assert len(audit.read_text(encoding="utf-8").splitlines()) == 2
+
+
+@pytest.mark.parametrize("jobs,workers", [("3", 3), ("0", 6)])
+def test_batch_jobs_sizes_the_process_pool(jobs, workers, tmp_path, monkeypatch, capsys):
+    payloads = tmp_path / "dump.jsonl"
+    _write_payloads(payloads, [json.dumps({"agent_output": "Hello!"})] * 4)
+    calls = []
+
+    def run_stream(*args, **kwargs):
+        calls.append((kwargs["workers"], kwargs["processes"]))
+        return 0
+
+    monkeypatch.setattr(normcore.cli.os, "cpu_count", lambda: 6)
+    monkeypatch.setattr(normcore.cli, "_run_stream", run_stream)
+
+    assert cli_main(["batch", str(payloads), "--jobs", jobs]) == 0
+    assert calls == [(workers, True)]
+
+
+def test_batch_jobs_keeps_input_order(tmp_path, capsys):
+    payloads = tmp_path / "dump.jsonl"
+    lines = [json.dumps({"id": index, "agent_output": "Hello!"}) for index in range(20)]
+    lines[5] = json.dumps({"id": 5, "conversation": []})
+    _write_payloads(payloads, lines)
+
+    assert cli_main(["batch", str(payloads), "--jobs", "2"]) == 0
+
+    records = [json.loads(line) for line in capsys.readouterr().out.splitlines()]
+    assert [record["id"] for record in records] == list(range(20))
+    assert "non-empty" in records[5]["error"]
+    assert all("status" in record for index, record in enumerate(records) if index != 5)
+
+
+@pytest.mark.parametrize(
+    "argv,message",
+    [
+        (["batch", "missing.jsonl"], "Failed to read missing.jsonl"),
+        (["batch", "--workers", "0"], "--workers must be at least 1"),
+        (["batch", "--jobs", "-1"], "--jobs must be at least 0"),
+        (["batch", "--jobs", "2", "--workers", "2"], "not allowed with argument"),
+    ],
+)
+def test_batch_argument_errors(argv, message, capsys):
+    with pytest.raises(SystemExit):
+        cli_main(argv)
+    assert message in capsys.readouterr().err
@@ -0,0 +1,60 @@
+import io
+import json
+import math
+
+import pytest
+
+from normcore import evaluate
+from normcore.canonical import canonical_bytes, canonical_json
+from normcore.cli import _run_stream
+from normcore.cli import main as cli_main
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (1.0, "1"),
+        (-0.0, "0"),
+        (0.1, "0.1"),
+        (2.5e-7, "2.5e-7"),
+        (1e-6, "0.000001"),
+        (1e20, "100000000000000000000"),
+        (1e21, "1e+21"),
+        (-1.5e300, "-1.5e+300"),
+        (123, "123"),
+        (True, "true"),
+        (None, "null"),
+    ],
+)
+def test_canonical_numbers_and_literals(value, expected):
+    assert canonical_json(value) == expected
+
+
+def test_canonical_objects_sort_keys_and_drop_whitespace():
+    value = {"b": [1, {"y": 2.50, "x": "é\n"}], "a": None, "\U0001f600": 1, "｡": 2}
+    assert canonical_json(value) == '{"a":null,"b":[1,{"x":"é\\n","y":2.5}],"😀":1,"｡":2}'
+    assert canonical_bytes({"b": 1, "a": 2}) == canonical_bytes({"a": 2, "b": 1.0})
+
+
+def test_canonical_rejects_values_without_json_form():
+    with pytest.raises(ValueError):
+        canonical_json({"score": math.nan})
+    with pytest.raises(TypeError):
+        canonical_json({1: "x"})
+
+
+def test_canonical_judgment_roundtrips_to_same_data():
+    judgment = evaluate(agent_output="We should deploy now.")
+    assert json.loads(canonical_json(judgment)) == json.loads(judgment.model_dump_json())
+
+
+def test_cli_evaluate_and_stream_write_canonical_json(capsys):
+    assert cli_main(["evaluate", "--agent-output", "We should deploy now.", "--canonical"]) == 0
+    line = capsys.readouterr().out.rstrip("\n")
+    assert line == canonical_json(json.loads(line))
+    assert '"confidence":1,' in line
+
+    output = io.StringIO()
+    payload = json.dumps({"id": "x", "agent_output": "We should deploy now."})
+    _run_stream(io.StringIO(payload + "\n"), output, canonical=True)
+    assert output.getvalue() == canonical_json({"id": "x", **json.loads(line)}) + "\n"
@@ -0,0 +1,26 @@
+import json
+
+from normcore.cli import main as cli_main
+
+DELETE = json.dumps(
+    {
+        "id": "call_2",
+        "type": "function",
+        "function": {"name": "delete_branch", "arguments": '{"name": "feature-x"}'},
+    }
+)
+
+
+def test_check_tool_call_exits_1_for_ungrounded_state_change(capsys):
+    assert cli_main(["check-tool-call", "--tool-call", DELETE]) == 1
+
+    record = json.loads(capsys.readouterr().out)
+    assert record["gate_decision"] == "block"
+    assert record["statement_evaluations"][0]["statement_id"] == "tool_call:call_2"
+
+
+def test_check_tool_call_exits_0_when_tool_effects_mark_it_read_only(capsys):
+    policy = json.dumps({"tool_effects": {"delete_branch": "read_only"}})
+
+    assert cli_main(["check-tool-call", "--tool-call", DELETE, "--policy", policy]) == 0
+    assert json.loads(capsys.readouterr().out)["status"] == "no_normative_content"
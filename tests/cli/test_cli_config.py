@@ -0,0 +1,117 @@
+import io
+import json
+import sys
+
+import pytest
+
+from normcore.cli import main as cli_main
+from normcore.config import NormcoreConfig, load_config
+
+CONFIG = """
+format = "compact"
+fail_on = ["violates_norm"]
+
+[policy]
+disabled_axioms = ["A7"]
+
+[policy.modality_keywords]
+refusal = ["above my pay grade"]
+"""
+
+REFUSAL_TEXT = "Choosing a vendor is above my pay grade, so you should ask procurement."
+
+
+@pytest.fixture
+def config_path(tmp_path):
+    path = tmp_path / "normcore.toml"
+    path.write_text(CONFIG, encoding="utf-8")
+    return path
+
+
+def test_load_config(config_path):
+    config = load_config(config_path)
+    assert config.format == "compact"
+    assert config.fail_on == ["violates_norm"]
+    assert config.policy.disabled_axioms == ["A7"]
+
+
+def test_config_supplies_format_fail_on_and_policy(config_path, capsys):
+    argv = ["--config", str(config_path), "evaluate", "--agent-output", "We should deploy now."]
+    assert cli_main(argv) == 1
+    (line,) = capsys.readouterr().out.splitlines()
+    assert json.loads(line)["status"] == "violates_norm"
+
+    argv = ["--config", str(config_path), "evaluate", "--agent-output", REFUSAL_TEXT]
+    assert cli_main(argv) == 0
+    assert json.loads(capsys.readouterr().out)["status"] == "acceptable"
+
+
+def test_command_line_options_win_over_config(config_path, capsys):
+    argv = [
+        "--config",
+        str(config_path),
+        "evaluate",
+        "--agent-output",
+        REFUSAL_TEXT,
+        "--format",
+        "json",
+        "--fail-on",
+        "acceptable",
+        "--policy",
+        '{"modality_keywords": {}}',
+    ]
+    assert cli_main(argv) == 0
+    out = capsys.readouterr().out
+    assert out.startswith("{\n")
+    assert json.loads(out)["status"] == "violates_norm"
+
+
+def test_config_policy_applies_to_pipeline_modes(config_path, tmp_path, capsys):
+    payloads = tmp_path / "turns.jsonl"
+    payloads.write_text(json.dumps({"agent_output": REFUSAL_TEXT}) + "\n", encoding="utf-8")
+
+    assert cli_main(["--config", str(config_path), "batch", str(payloads)]) == 0
+    assert json.loads(capsys.readouterr().out)["status"] == "acceptable"
+
+
+def test_config_in_working_directory_is_discovered(config_path, monkeypatch, capsys):
+    monkeypatch.chdir(config_path.parent)
+    assert cli_main(["axioms", "--json"]) == 0
+    rows = {row["code"]: row["enabled"] for row in json.loads(capsys.readouterr().out)}
+    assert rows["A7"] is False and rows["A5"] is True
+
+
+def test_config_format_is_ignored_where_output_cannot_take_it(tmp_path, monkeypatch, capsys):
+    path = tmp_path / "normcore.toml"
+    path.write_text('format = "sarif"', encoding="utf-8")
+    monkeypatch.setattr(sys, "stdin", io.StringIO(json.dumps({"agent_output": "Hi"}) + "\n"))
+
+    assert cli_main(["--config", str(path), "stream"]) == 0
+    assert "status" in json.loads(capsys.readouterr().out)
+
+
+@pytest.mark.parametrize(
+    "content,message",
+    [
+        ('format = "yaml"', "Unknown format 'yaml'"),
+        ("[policy]\ndisabled_axioms = ['A6']", "Axioms cannot be disabled: A6"),
+        ("colour = 'red'", "colour"),
+        ("format = ", "Invalid TOML"),
+    ],
+)
+def test_invalid_config_is_a_usage_error(tmp_path, capsys, content, message):
+    path = tmp_path / "bad.toml"
+    path.write_text(content, encoding="utf-8")
+    with pytest.raises(SystemExit):
+        cli_main(["--config", str(path), "axioms"])
+    err = capsys.readouterr().err
+    assert "Failed to load --config" in err and message in err
+
+
+def test_merged_policy_overrides_field_by_field():
+    config = NormcoreConfig.model_validate(
+        {"policy": {"disabled_axioms": ["A7"], "risk_floor": "medium"}}
+    )
+    policy = config.merged_policy({"risk_floor": "high"})
+    assert policy.disabled_axioms == ["A7"]
+    assert policy.risk_floor == "high"
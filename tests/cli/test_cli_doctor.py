@@ -0,0 +1,31 @@
+from normcore.cli import main as cli_main
+from normcore.doctor import DOCTOR_SCENARIOS, run_doctor
+from normcore.models import EvaluationPolicy
+
+
+def test_doctor_scenarios_pass_under_default_policy():
+    results = run_doctor()
+    assert [r.scenario.name for r in results] == [
+        "grounded_acceptable",
+        "ungrounded_assertive",
+        "refusal",
+        "conditional",
+    ]
+    assert all(r.passed for r in results)
+
+
+def test_doctor_reports_verdicts_changed_by_policy():
+    results = run_doctor(EvaluationPolicy(min_citations_per_assertive=2))
+    failed = [(r.scenario.name, r.status.value) for r in results if not r.passed]
+    assert failed == [("grounded_acceptable", "insufficient_citations")]
+
+
+def test_cli_doctor_prints_pass_fail_and_exit_code(capsys):
+    assert cli_main(["doctor"]) == 0
+    out = capsys.readouterr().out
+    assert out.endswith(f"{len(DOCTOR_SCENARIOS)} passed, 0 failed\n")
+    assert "PASS refusal" in out
+
+    assert cli_main(["doctor", "--policy", '{"min_citations_per_assertive": 2}']) == 1
+    out = capsys.readouterr().out
+    assert "FAIL grounded_acceptable: expected acceptable, got insufficient_citations" in out
@@ -0,0 +1,97 @@
+import json
+
+import pytest
+
+from normcore.cli import main as cli_main
+
+DEPLOY = ["evaluate", "--agent-output", "We should deploy now."]
+
+
+@pytest.fixture(autouse=True)
+def clean_environment(monkeypatch):
+    for name in ("NORMCORE_CONFIG", "NORMCORE_POLICY", "NORMCORE_FAIL_ON"):
+        monkeypatch.delenv(name, raising=False)
+
+
+def test_environment_supplies_config_policy_and_fail_on(tmp_path, monkeypatch, capsys):
+    config = tmp_path / "container.toml"
+    config.write_text('format = "compact"\nfail_on = ["acceptable"]\n', encoding="utf-8")
+    monkeypatch.setenv("NORMCORE_CONFIG", str(config))
+    monkeypatch.setenv("NORMCORE_FAIL_ON", "violates_norm")
+
+    assert cli_main(DEPLOY) == 1
+    (line,) = capsys.readouterr().out.splitlines()
+    assert json.loads(line)["status"] == "violates_norm"
+
+    monkeypatch.setenv("NORMCORE_POLICY", '{"disabled_axioms": ["A5"]}')
+    assert cli_main(["axioms", "--json"]) == 0
+    rows = {row["code"]: row["enabled"] for row in json.loads(capsys.readouterr().out)}
+    assert rows["A5"] is False
+
+
+def test_command_line_options_win_over_environment(monkeypatch, capsys):
+    monkeypatch.setenv("NORMCORE_FAIL_ON", "violates_norm")
+    monkeypatch.setenv("NORMCORE_POLICY", "lenient")
+
+    assert cli_main([*DEPLOY, "--fail-on", "acceptable"]) == 0
+    capsys.readouterr()
+
+    assert cli_main(["axioms", "--json", "--policy", "strict"]) == 0
+    rows = {row["code"]: row["enabled"] for row in json.loads(capsys.readouterr().out)}
+    assert rows["A9"] is True
+
+
+@pytest.mark.parametrize(
+    "name,value,message",
+    [
+        ("NORMCORE_FAIL_ON", "broken", "Invalid NORMCORE_FAIL_ON: unknown status 'broken'"),
+        ("NORMCORE_POLICY", '{"aggregation": 1}', "Invalid NORMCORE_POLICY:"),
+        ("NORMCORE_CONFIG", "missing.toml", "Failed to load NORMCORE_CONFIG:"),
+    ],
+)
+def test_invalid_environment_values_are_usage_errors(monkeypatch, capsys, name, value, message):
+    monkeypatch.setenv(name, value)
+    with pytest.raises(SystemExit):
+        cli_main(DEPLOY)
+    assert message in capsys.readouterr().err
+
+
+def test_empty_variables_are_ignored(monkeypatch, capsys):
+    monkeypatch.setenv("NORMCORE_FAIL_ON", "")
+    monkeypatch.setenv("NORMCORE_POLICY", "")
+    assert cli_main(DEPLOY) == 0
+
+
+UNREQUESTED_TOOL_RESULT = {
+    "id": "ci",
+    "conversation": [
+        {"role": "user", "content": "Why is CI red?"},
+        {"role": "tool", "tool_call_id": "call_ci", "content": '{"runner": "offline"}'},
+        {"role": "assistant", "content": "You should restart the runner [@call_ci]."},
+    ],
+}
+
+
+def test_environment_policy_applies_to_pipeline_commands(tmp_path, monkeypatch, capsys):
+    payloads = tmp_path / "traces.jsonl"
+    payloads.write_text(json.dumps(UNREQUESTED_TOOL_RESULT) + "\n", encoding="utf-8")
+    monkeypatch.setenv("NORMCORE_POLICY", "strict")
+    monkeypatch.setenv("NORMCORE_FAIL_ON", "violates_norm,unsupported")
+
+    assert cli_main(["batch", str(payloads)]) == 1
+    assert json.loads(capsys.readouterr().out)["status"] == "violates_norm"
+
+    dataset = tmp_path / "dataset.jsonl"
+    labeled = {**UNREQUESTED_TOOL_RESULT, "expected_status": "violates_norm"}
+    dataset.write_text(json.dumps(labeled) + "\n", encoding="utf-8")
+    assert cli_main(["eval", "--dataset", str(dataset)]) == 0
+    assert json.loads(capsys.readouterr().out)["correct"] == 1
+
+
+def test_environment_policy_is_skipped_by_import_without_evaluate(tmp_path, monkeypatch, capsys):
+    transcript = tmp_path / "run.json"
+    transcript.write_text(json.dumps({"messages": UNREQUESTED_TOOL_RESULT["conversation"]}))
+    monkeypatch.setenv("NORMCORE_POLICY", "strict")
+
+    assert cli_main(["import", "--from", "agent-transcript", str(transcript)]) == 0
+    assert "conversation" in json.loads(capsys.readouterr().out)
@@ -0,0 +1,90 @@
+import json
+
+from normcore.cli import main as cli_main
+from normcore.labeled_eval import evaluate_dataset
+
+
+STRICT_CONFIG = """
+[policy]
+min_citations_per_assertive = 1
+unmatched_tool_results = "ignore"
+code_blocks = "flag_executable"
+gate_by_status = { unsupported = "block" }
+gate_by_risk_tier = { high = "block" }
+"""
+
+UNREQUESTED_TOOL_RESULT = [
+    {"role": "user", "content": "Why is CI red?"},
+    {"role": "tool", "tool_call_id": "call_ci", "content": '{"runner": "offline"}'},
+    {"role": "assistant", "content": "You should restart the runner [@call_ci]."},
+]
+
+
+def _dataset_lines():
+    return [
+        json.dumps(
+            {"id": "a", "agent_output": "We should deploy now.", "expected_status": "violates_norm"}
+        ),
+        json.dumps(
+            {"id": "b", "agent_output": "Hello!", "expected_status": "no_normative_content"}
+        ),
+        json.dumps(
+            {
+                "id": "c",
+                "agent_output": "If tests pass, we should deploy.",
+                "expected_status": "violates_norm",
+            }
+        ),
+        json.dumps({"id": "d", "agent_output": "Hello!"}),
+        json.dumps({"agent_output": "Hello!", "expected_status": "great"}),
+    ]
+
+
+def test_evaluate_dataset_scores_predictions():
+    report = evaluate_dataset(_dataset_lines())
+
+    assert report.cases == 3
+    assert report.correct == 2
+    assert report.accuracy == 0.6667
+    assert report.confusion_matrix == {
+        "violates_norm": {"violates_norm": 1, "conditionally_acceptable": 1},
+        "no_normative_content": {"no_normative_content": 1},
+    }
+    violates = report.per_status["violates_norm"]
+    assert (violates.support, violates.predicted, violates.true_positives) == (2, 1, 1)
+    assert (violates.precision, violates.recall) == (1.0, 0.5)
+    conditional = report.per_status["conditionally_acceptable"]
+    assert (conditional.precision, conditional.recall) == (0.0, None)
+
+    assert [(m.id, m.actual.value) for m in report.mismatches] == [
+        ("c", "conditionally_acceptable")
+    ]
+    assert [(e.line, e.id) for e in report.errors] == [(4, "d"), (5, None)]
+    assert "Missing" in report.errors[0].error
+    assert "Unknown" in report.errors[1].error
+
+
+def test_cli_eval_prints_report(tmp_path, capsys):
+    dataset = tmp_path / "labeled.jsonl"
+    dataset.write_text("\n".join(_dataset_lines()) + "\n", encoding="utf-8")
+
+    assert cli_main(["eval", "--dataset", str(dataset)]) == 0
+
+    report = json.loads(capsys.readouterr().out)
+    assert report["cases"] == 3
+    assert report["per_status"]["no_normative_content"]["recall"] == 1.0
+
+
+def test_cli_eval_scores_under_config_policy(tmp_path, capsys):
+    dataset = tmp_path / "dataset.jsonl"
+    dataset.write_text(
+        json.dumps({"conversation": UNREQUESTED_TOOL_RESULT, "expected_status": "violates_norm"})
+        + "\n",
+        encoding="utf-8",
+    )
+    config = tmp_path / "normcore.toml"
+    config.write_text(STRICT_CONFIG, encoding="utf-8")
+
+    assert evaluate_dataset(dataset.read_text().splitlines()).correct == 0
+    assert cli_main(["--config", str(config), "eval", "--dataset", str(dataset)]) == 0
+    assert json.loads(capsys.readouterr().out)["correct"] == 1
@@ -0,0 +1,38 @@
+import io
+import json
+import sys
+
+from normcore.cli import main as cli_main
+
+
+def test_extract_prints_kept_statements(capsys):
+    text = (
+        "Hello! You should deploy now. Let me know if you need anything.\n\n"
+        "| Option | Verdict |\n| --- | --- |\n| A | Recommended |\n"
+    )
+    assert cli_main(["extract", "--text", text]) == 0
+
+    statements = json.loads(capsys.readouterr().out)["statements"]
+    assert statements == [
+        {
+            "id": "final_response",
+            "subject": "agent",
+            "predicate": "participation",
+            "text": "You should deploy now.",
+        },
+        {"id": "table:1:1:2", "subject": "A", "predicate": "Verdict", "text": "Recommended"},
+    ]
+
+
+def test_extract_protocol_only_text_keeps_nothing(monkeypatch, capsys):
+    monkeypatch.setattr(sys, "stdin", io.StringIO("Hello! How can I help?"))
+    assert cli_main(["extract"]) == 0
+    assert json.loads(capsys.readouterr().out) == {"statements": []}
+
+
+def test_extract_applies_policy_extraction_settings(capsys):
+    text = "Run this:\n\n```sh\nrm -rf /data\n```\n"
+    policy = json.dumps({"code_blocks": "flag_executable"})
+    assert cli_main(["extract", "--text", text, "--policy", policy]) == 0
+    ids = [statement["id"] for statement in json.loads(capsys.readouterr().out)["statements"]]
+    assert "code:1:1" in ids
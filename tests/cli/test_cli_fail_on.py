@@ -0,0 +1,59 @@
+import io
+import json
+import sys
+
+import pytest
+
+from normcore.cli import main as cli_main
+
+
+@pytest.mark.parametrize(
+    "fail_on,expected",
+    [
+        ("violates_norm", 1),
+        ("violates_norm,unsupported", 1),
+        ("unsupported", 0),
+    ],
+)
+def test_evaluate_exit_code_follows_fail_on(fail_on, expected, capsys):
+    argv = ["evaluate", "--agent-output", "We should deploy now.", "--fail-on", fail_on]
+    assert cli_main(argv) == expected
+    assert json.loads(capsys.readouterr().out)["status"] == "violates_norm"
+
+
+def test_evaluate_exits_zero_without_fail_on(capsys):
+    assert cli_main(["evaluate", "--agent-output", "We should deploy now."]) == 0
+
+
+def test_batch_fails_when_any_judgment_matches(tmp_path, capsys):
+    payloads = tmp_path / "suite.jsonl"
+    payloads.write_text(
+        json.dumps({"agent_output": "Hello!"})
+        + "\n"
+        + json.dumps({"agent_output": "We should deploy now."})
+        + "\n",
+        encoding="utf-8",
+    )
+
+    assert cli_main(["batch", str(payloads), "--fail-on", "violates_norm"]) == 1
+    assert len(capsys.readouterr().out.splitlines()) == 2
+    assert cli_main(["batch", str(payloads), "--fail-on", "ill_formed"]) == 0
+
+
+def test_stream_fail_on_ignores_error_records(monkeypatch, capsys):
+    monkeypatch.setattr(sys, "stdin", io.StringIO("not json\n"))
+    assert cli_main(["stream", "--fail-on", "violates_norm"]) == 0
+
+
+@pytest.mark.parametrize(
+    "argv,message",
+    [
+        (["evaluate", "--agent-output", "x", "--fail-on", "bad"], "unknown status 'bad'"),
+        (["evaluate", "--agent-output", "x", "--fail-on", ","], "expected at least one status"),
+        (["stream", "--fail-on", "unsupported", "--unix-socket", "x.sock"], "not --unix-socket"),
+    ],
+)
+def test_fail_on_argument_errors(argv, message, capsys):
+    with pytest.raises(SystemExit):
+        cli_main(argv)
+    assert message in capsys.readouterr().err
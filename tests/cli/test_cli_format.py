@@ -0,0 +1,137 @@
+import io
+import json
+import sys
+
+import pytest
+
+from normcore.cli import _use_color
+from normcore.cli import main as cli_main
+from normcore.formats import render_record, render_summary, render_table
+
+
+def test_evaluate_compact_format_prints_single_line_json(capsys):
+    argv = ["evaluate", "--agent-output", "We should deploy now.", "--format", "compact"]
+    assert cli_main(argv) == 0
+    (line,) = capsys.readouterr().out.splitlines()
+    record = json.loads(line)
+    assert line == json.dumps(record, ensure_ascii=False, separators=(",", ":"))
+    assert record["status"] == "violates_norm"
+
+
+def test_evaluate_table_format_summarizes_the_judgment(capsys):
+    argv = ["evaluate", "--agent-output", "We should deploy now.", "--format", "table"]
+    assert cli_main(argv) == 0
+    lines = capsys.readouterr().out.splitlines()
+    assert lines[0].startswith("violates_norm (gate: ")
+    assert lines[1] == "violated axioms: A5"
+    assert lines[2].split() == ["STATEMENT", "STATUS", "MODALITY", "AXIOM", "TEXT"]
+    assert lines[3].split()[:4] == ["final_response", "violates_norm", "assertive", "A5"]
+
+
+def test_batch_jsonl_and_table_formats(tmp_path, capsys):
+    payloads = tmp_path / "dump.jsonl"
+    payloads.write_text(
+        json.dumps({"id": "case-1", "agent_output": "Hello!"}) + "\nnot json\n", encoding="utf-8"
+    )
+
+    assert cli_main(["batch", str(payloads), "--format", "jsonl"]) == 0
+    assert len(capsys.readouterr().out.splitlines()) == 2
+
+    assert cli_main(["batch", str(payloads), "--format", "table"]) == 0
+    blocks = capsys.readouterr().out.strip().split("\n\n")
+    assert blocks[0].splitlines()[:2] == [
+        "[case-1] no_normative_content (gate: allow, risk: low, confidence: 1.00)",
+        "violated axioms: -",
+    ]
+    assert blocks[1].startswith("error (line 2): Invalid JSON")
+
+
+def test_stream_table_format(monkeypatch, capsys):
+    line = json.dumps({"agent_output": "We should deploy now."})
+    monkeypatch.setattr(sys, "stdin", io.StringIO(line + "\n"))
+
+    assert cli_main(["stream", "--format", "table"]) == 0
+    assert capsys.readouterr().out.startswith("violates_norm (gate: ")
+
+
+def test_table_truncates_long_statement_text():
+    record = {
+        "status": "acceptable",
+        "statement_evaluations": [{"statement_id": "s1", "statement": "word " * 40}],
+    }
+    row = render_table(record).splitlines()[-1]
+    assert row.endswith("...") and len(row.split(None, 4)[-1]) == 60
+
+
+def test_render_record_rejects_unknown_formats():
+    with pytest.raises(ValueError):
+        render_record({}, "yaml")
+
+
+@pytest.mark.parametrize(
+    "argv,message",
+    [
+        (
+            ["evaluate", "--agent-output", "x", "--format", "table", "--canonical"],
+            "--canonical cannot be combined with --format table",
+        ),
+        (["batch", "--format", "table", "--output", "sqlite:x.db"], "not --output"),
+        (["stream", "--format", "compact", "--encoding", "msgpack"], "requires --encoding json"),
+        (["batch", "--summary", "--format", "json"], "not allowed with argument --summary"),
+    ],
+)
+def test_format_argument_errors(argv, message, capsys):
+    with pytest.raises(SystemExit):
+        cli_main(argv)
+    assert message in capsys.readouterr().err
+
+
+def test_evaluate_summary_puts_the_verdict_first(capsys):
+    argv = ["evaluate", "--agent-output", "We should deploy now.", "--summary"]
+    assert cli_main(argv) == 0
+    lines = capsys.readouterr().out.splitlines()
+    assert lines[0].startswith("VIOLATES_NORM  gate: ")
+    assert lines[1] == "1 statement: 1 violates_norm"
+    assert lines[2] == "violations:"
+    assert lines[3] == '  A5 final_response (violates_norm): "We should deploy now."'
+    assert "\033[" not in "\n".join(lines)
+
+
+def test_summary_lists_resolved_and_unresolved_citations():
+    record = {
+        "status": "acceptable",
+        "num_statements": 1,
+        "status_counts": {"acceptable": 1},
+        "statement_evaluations": [
+            {"statement_id": "s1", "status": "acceptable", "statement": "Up [@ci] [@gone]."}
+        ],
+        "citation_map": {"ci": ["tool_ci"]},
+    }
+    assert render_summary(record).splitlines()[2:] == [
+        "citations:",
+        "  resolved    [@ci] -> tool_ci",
+        "  unresolved  [@gone]",
+    ]
+    assert render_summary(record, color=True).startswith("\033[1;32mACCEPTABLE\033[0m")
+
+
+def test_summary_colors_only_terminals():
+    class Terminal(io.StringIO):
+        def isatty(self):
+            return True
+
+    assert _use_color(Terminal(), {}) is True
+    assert _use_color(Terminal(), {"NO_COLOR": "1"}) is False
+    assert _use_color(io.StringIO(), {}) is False
+
+
+def test_batch_summary_blocks(tmp_path, capsys):
+    payloads = tmp_path / "dump.jsonl"
+    payloads.write_text(
+        json.dumps({"id": "case-1", "agent_output": "Hello!"}) + "\nnot json\n", encoding="utf-8"
+    )
+
+    assert cli_main(["batch", str(payloads), "--summary"]) == 0
+    blocks = capsys.readouterr().out.strip().split("\n\n")
+    assert blocks[0].splitlines()[0].startswith("[case-1] NO_NORMATIVE_CONTENT  gate: allow")
+    assert blocks[1].startswith("error (line 2): Invalid JSON")
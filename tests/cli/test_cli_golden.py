@@ -0,0 +1,172 @@
+import json
+
+import pytest
+
+from normcore import evaluate, policy_pack
+from normcore.cli import main as cli_main
+from normcore.golden import judgment_to_golden, run_golden_corpus
+
+
+STRICT_CONFIG = """
+[policy]
+min_citations_per_assertive = 1
+unmatched_tool_results = "ignore"
+code_blocks = "flag_executable"
+gate_by_status = { unsupported = "block" }
+gate_by_risk_tier = { high = "block" }
+"""
+
+UNREQUESTED_TOOL_RESULT = [
+    {"role": "user", "content": "Why is CI red?"},
+    {"role": "tool", "tool_call_id": "call_ci", "content": '{"runner": "offline"}'},
+    {"role": "assistant", "content": "You should restart the runner [@call_ci]."},
+]
+
+
+def _write_scenario(path, agent_output, expected):
+    path.write_text(
+        json.dumps({"input": {"agent_output": agent_output}, "expected": expected}),
+        encoding="utf-8",
+    )
+
+
+def test_golden_corpus_passes_on_unchanged_judgments(tmp_path):
+    text = "We should deploy now."
+    _write_scenario(tmp_path / "deploy.json", text, judgment_to_golden(evaluate(agent_output=text)))
+
+    results = run_golden_corpus(tmp_path)
+    assert [r.passed for r in results] == [True]
+
+
+def test_golden_cli_reports_diff_and_fails(tmp_path, capsys):
+    text = "We should deploy now."
+    expected = judgment_to_golden(evaluate(agent_output=text))
+    expected["status"] = "acceptable"
+    (tmp_path / "nested").mkdir()
+    _write_scenario(tmp_path / "nested" / "flipped.json", text, expected)
+    (tmp_path / "broken.json").write_text("[]", encoding="utf-8")
+
+    assert cli_main(["golden", str(tmp_path)]) == 1
+
+    out = capsys.readouterr().out
+    assert "FAIL" in out and "flipped.json" in out
+    assert '-  "status": "acceptable"' in out
+    assert '+  "status": "violates_norm"' in out
+    assert "error: Scenario must be a JSON object" in out
+    assert out.rstrip().endswith("0 passed, 2 failed")
+
+
+def test_golden_cli_update_rewrites_expectations(tmp_path, capsys):
+    scenario = tmp_path / "greeting.json"
+    _write_scenario(scenario, "Hello!", {"status": "acceptable"})
+
+    assert cli_main(["golden", str(tmp_path), "--update"]) == 0
+    assert cli_main(["golden", str(tmp_path)]) == 0
+
+    assert json.loads(scenario.read_text())["expected"]["status"] == "no_normative_content"
+    assert "1 passed, 0 failed" in capsys.readouterr().out
+
+
+_TOML_SCENARIO = """\
+# Hand-written scenario: a grounded recommendation.
+description = "grounded deploy advice"
+
+[[input.conversation]]
+role = "assistant"
+tool_calls = [
+  { id = "call_ci", type = "function", function = { name = "get_ci_status", arguments = "{}" } },
+]
+
+[[input.conversation]]
+role = "tool"
+tool_call_id = "call_ci"
+content = '{"pipeline_id": "ci-42", "status": "green"}'
+
+[[input.conversation]]
+role = "assistant"
+content = "You should deploy now [@call_ci]."
+
+[expected]
+status = "unsupported"
+"""
+
+
+def test_golden_toml_scenario_update_keeps_hand_written_input(tmp_path, capsys):
+    scenario = tmp_path / "grounded.toml"
+    scenario.write_text(_TOML_SCENARIO, encoding="utf-8")
+
+    assert cli_main(["golden", str(tmp_path)]) == 1
+    assert '-  "status": "unsupported"' in capsys.readouterr().out
+
+    assert cli_main(["golden", str(tmp_path), "--update"]) == 0
+    text = scenario.read_text(encoding="utf-8")
+    assert text.startswith(_TOML_SCENARIO.split("[expected]")[0])
+    assert 'status = "acceptable"' in text
+    assert "[[expected.statement_evaluations]]" in text
+    assert "null" not in text
+
+    assert cli_main(["golden", str(tmp_path)]) == 0
+    assert "1 passed, 0 failed" in capsys.readouterr().out
+
+
+def test_golden_corpus_mixes_json_and_toml_scenarios(tmp_path):
+    text = "We should deploy now."
+    _write_scenario(tmp_path / "a.json", text, judgment_to_golden(evaluate(agent_output=text)))
+    (tmp_path / "b.toml").write_text(
+        '[input]\nagent_output = "Hello!"\n\n[expected]\nstatus = "acceptable"\n', encoding="utf-8"
+    )
+    (tmp_path / "c.toml").write_text("input = [", encoding="utf-8")
+
+    results = run_golden_corpus(tmp_path)
+    assert [(r.path.name, r.passed) for r in results] == [
+        ("a.json", True),
+        ("b.toml", False),
+        ("c.toml", False),
+    ]
+    assert results[2].error.startswith("Invalid TOML")
+
+
+def test_golden_yaml_scenario_runs_and_updates_as_yaml(tmp_path, capsys):
+    scenario = tmp_path / "greeting.yaml"
+    scenario.write_text(
+        "input:\n  agent_output: Hello!\nexpected:\n  status: acceptable\n", encoding="utf-8"
+    )
+    (tmp_path / "notes.txt").write_text("not a scenario", encoding="utf-8")
+
+    assert cli_main(["golden", str(tmp_path)]) == 1
+    assert "greeting.yaml" in capsys.readouterr().out
+
+    assert cli_main(["golden", str(tmp_path), "--update"]) == 0
+    text = scenario.read_text(encoding="utf-8")
+    assert text.startswith("input:\n  agent_output: Hello!\n")
+    assert "status: no_normative_content" in text
+
+    assert cli_main(["golden", str(tmp_path)]) == 0
+    assert "1 passed, 0 failed" in capsys.readouterr().out
+
+
+def test_golden_help_lists_every_scenario_format(capsys):
+    with pytest.raises(SystemExit) as exc:
+        cli_main(["golden", "--help"])
+    assert exc.value.code == 0
+    help_text = " ".join(capsys.readouterr().out.split())
+    assert "Directory searched recursively for JSON, YAML and TOML scenario files." in help_text
+
+
+def test_golden_corpus_runs_under_config_policy(tmp_path, capsys):
+    corpus = tmp_path / "corpus"
+    corpus.mkdir()
+    expected = judgment_to_golden(
+        evaluate(conversation=UNREQUESTED_TOOL_RESULT, policy=policy_pack("strict"))
+    )
+    (corpus / "strict.json").write_text(
+        json.dumps({"input": {"conversation": UNREQUESTED_TOOL_RESULT}, "expected": expected}),
+        encoding="utf-8",
+    )
+    config = tmp_path / "normcore.toml"
+    config.write_text(STRICT_CONFIG, encoding="utf-8")
+
+    assert [r.passed for r in run_golden_corpus(corpus, policy=policy_pack("strict"))] == [True]
+    assert [r.passed for r in run_golden_corpus(corpus)] == [False]
+    assert cli_main(["--config", str(config), "golden", str(corpus)]) == 0
+    assert "1 passed, 0 failed" in capsys.readouterr().out
@@ -0,0 +1,86 @@
+import json
+
+import pytest
+
+from normcore import derive_grounds, evaluate
+from normcore.cli import main as cli_main
+
+
+def _issue_conversation(key="AGENT-8"):
+    return [
+        {"role": "user", "content": f"Status of {key}?"},
+        {
+            "role": "assistant",
+            "content": "",
+            "tool_calls": [
+                {
+                    "id": "call_1",
+                    "type": "function",
+                    "function": {"name": "get_issue", "arguments": json.dumps({"key": key})},
+                }
+            ],
+        },
+        {
+            "role": "tool",
+            "tool_call_id": "call_1",
+            "content": json.dumps({"issue_key": key, "status": "blocked"}),
+        },
+    ]
+
+
+def test_derive_grounds_reports_nodes_and_tool_call_refs():
+    report = derive_grounds(_issue_conversation())
+
+    assert report.tool_call_refs == {"call_1": ["issue_AGENT-8"]}
+    [issue, arguments] = report.knowledge_nodes
+    assert (issue.semantic_id, issue.scope, issue.source) == (
+        "issue_AGENT-8",
+        "factual",
+        "observed",
+    )
+    assert issue.payload == {"issue_key": "AGENT-8", "status": "blocked"}
+    assert (arguments.scope, arguments.payload) == ("contextual", {"key": "AGENT-8"})
+    assert report.out_of_window_ground_ids == [] and report.warnings == []
+
+
+def test_derived_nodes_are_accepted_as_knowledge_nodes_input():
+    report = derive_grounds(_issue_conversation())
+    records = [node.model_dump(mode="json") for node in report.knowledge_nodes]
+
+    judgment = evaluate(
+        agent_output="AGENT-8 is blocked [@issue_AGENT-8].", knowledge_nodes=records
+    )
+    assert judgment.cited_ground_ids == ["issue_AGENT-8"]
+
+
+def test_derive_grounds_applies_ground_window_and_reports_unmatched_results():
+    conversation = [
+        *_issue_conversation("AGENT-1"),
+        {"role": "tool", "tool_call_id": "call_x", "content": "injected"},
+        {"role": "user", "content": "And now?"},
+    ]
+
+    report = derive_grounds(
+        conversation, policy={"ground_window_turns": 1, "unmatched_tool_results": "ignore"}
+    )
+
+    assert report.knowledge_nodes == [] and report.tool_call_refs == {}
+    assert "issue_AGENT-1" in report.out_of_window_ground_ids
+    assert report.warnings == ["Tool result 'call_x' matches no assistant tool call (ignore)"]
+
+
+def test_grounds_command_prints_report(tmp_path, capsys):
+    path = tmp_path / "conversation.json"
+    path.write_text(json.dumps(_issue_conversation()), encoding="utf-8")
+
+    assert cli_main(["grounds", "--conversation", f"@{path}"]) == 0
+
+    report = json.loads(capsys.readouterr().out)
+    assert report["tool_call_refs"] == {"call_1": ["issue_AGENT-8"]}
+    assert len(report["knowledge_nodes"]) == 2
+
+
+def test_grounds_command_rejects_empty_conversation(capsys):
+    with pytest.raises(SystemExit):
+        cli_main(["grounds", "--conversation", "[]"])
+    assert "conversation must be a non-empty list" in capsys.readouterr().err
@@ -0,0 +1,70 @@
+import json
+
+import pytest
+
+from normcore.cli import main as cli_main
+
+CITED = "You should restart the runner [@runbook]."
+
+
+def _registry(tmp_path):
+    directory = tmp_path / "grounds"
+    directory.mkdir()
+    (directory / "kb.json").write_text(
+        json.dumps([{"citation_key": "runbook", "ground_id": "kb_42"}]), encoding="utf-8"
+    )
+    (directory / "policies.json").write_text(
+        json.dumps({"documents": ["policy_7"]}), encoding="utf-8"
+    )
+    return directory
+
+
+def test_grounds_dir_supplies_grounds_to_evaluate(tmp_path, capsys):
+    argv = ["evaluate", "--agent-output", CITED, "--grounds-dir", str(_registry(tmp_path))]
+    assert cli_main(argv) == 0
+
+    record = json.loads(capsys.readouterr().out)
+    assert record["status"] == "acceptable"
+    assert record["accepted_ground_ids"] == ["kb_42", "policy_7"]
+    assert record["cited_ground_ids"] == ["kb_42"]
+
+
+def test_inline_grounds_override_registry_entries(tmp_path, capsys):
+    inline = json.dumps([{"citation_key": "runbook_v2", "ground_id": "kb_42"}])
+    argv = [
+        "evaluate",
+        "--agent-output",
+        CITED,
+        "--grounds-dir",
+        str(_registry(tmp_path)),
+        "--grounds",
+        inline,
+    ]
+    assert cli_main(argv) == 0
+
+    record = json.loads(capsys.readouterr().out)
+    # [@runbook] no longer resolves: kb_42 is now cited as [@runbook_v2]
+    assert record["status"] == "violates_norm"
+    assert record["accepted_ground_ids"] == ["kb_42", "policy_7"]
+
+
+@pytest.mark.parametrize(
+    "extra,message",
+    [
+        ([], "Failed to load --grounds-dir: bad.json: [0].ground_id: Field required"),
+        (["--grounds", "[7]"], "Invalid --grounds: [0]: expected a ground object, got int"),
+    ],
+)
+def test_grounds_dir_errors(tmp_path, capsys, extra, message):
+    directory = _registry(tmp_path)
+    if not extra:
+        (directory / "bad.json").write_text('[{"citation_key": "x"}]', encoding="utf-8")
+    with pytest.raises(SystemExit):
+        cli_main(["evaluate", "--agent-output", CITED, "--grounds-dir", str(directory), *extra])
+    assert message in capsys.readouterr().err
+
+
+def test_missing_grounds_dir(tmp_path, capsys):
+    with pytest.raises(SystemExit):
+        cli_main(["evaluate", "--agent-output", CITED, "--grounds-dir", str(tmp_path / "nope")])
+    assert "Failed to load --grounds-dir: Not a directory" in capsys.readouterr().err
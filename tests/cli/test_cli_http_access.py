@@ -0,0 +1,76 @@
+import sys
+
+import pytest
+
+from normcore.cli import main as cli_main
+from normcore.http_access import HttpAccessPolicy, load_api_keys
+from normcore.metrics import EvaluationMetrics, metrics_handler
+
+
+@pytest.fixture
+def get_metrics(http_exchange):
+    def start(access):
+        handler = metrics_handler(EvaluationMetrics(), access=access)
+
+        def get(method="GET", **headers):
+            status, response_headers, _ = http_exchange(handler, method, "/metrics", **headers)
+            return status, response_headers
+
+        return get
+
+    return start
+
+
+def test_api_key_required_as_bearer_token_or_header(get_metrics):
+    get = get_metrics(HttpAccessPolicy(api_keys=("k1", "k2")))
+
+    status, headers = get()
+    assert status == 401
+    assert headers["WWW-Authenticate"].startswith("Bearer")
+    assert get(Authorization="Bearer wrong")[0] == 401
+    assert get(Authorization="Bearer k2")[0] == 200
+    assert get(**{"X-API-Key": "k1"})[0] == 200
+
+
+def test_cors_headers_only_for_allowed_origins(get_metrics):
+    get = get_metrics(
+        HttpAccessPolicy(api_keys=("k1",), cors_origins=("https://dash.example.com",))
+    )
+
+    status, headers = get(method="OPTIONS", Origin="https://dash.example.com")
+    assert status == 204
+    assert headers["Access-Control-Allow-Origin"] == "https://dash.example.com"
+    assert "X-API-Key" in headers["Access-Control-Allow-Headers"]
+
+    status, headers = get(Origin="https://evil.example.com", Authorization="Bearer k1")
+    assert status == 200
+    assert "Access-Control-Allow-Origin" not in headers
+
+
+def test_open_by_default_without_cors(get_metrics):
+    status, headers = get_metrics(None)(Origin="https://dash.example.com")
+    assert status == 200
+    assert "Access-Control-Allow-Origin" not in headers
+
+
+def test_wildcard_origin():
+    access = HttpAccessPolicy(cors_origins=("*",))
+    assert access.cors_headers("https://any.example.com")["Access-Control-Allow-Origin"] == "*"
+    assert access.cors_headers(None) == {}
+
+
+def test_load_api_keys_skips_blank_lines_and_comments(tmp_path):
+    path = tmp_path / "keys"
+    path.write_text("# prod\nk1\n\n  k2  \n", encoding="utf-8")
+    assert load_api_keys(path) == ["k1", "k2"]
+    path.write_text("# none\n", encoding="utf-8")
+    with pytest.raises(ValueError, match="No API keys"):
+        load_api_keys(path)
+
+
+def test_stream_http_access_options_require_metrics_port(monkeypatch, capsys):
+    monkeypatch.setattr(sys, "stdin", None)
+    with pytest.raises(SystemExit) as exc:
+        cli_main(["stream", "--cors-origin", "*"])
+    assert exc.value.code == 2
+    assert "require --metrics-port" in capsys.readouterr().err
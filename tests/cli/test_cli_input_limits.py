@@ -0,0 +1,44 @@
+import json
+
+import pytest
+
+from normcore.cli import main as cli_main
+
+TOOL_DUMP = "x" * 5000
+
+
+CONVERSATION = [
+    {"role": "user", "content": "Summarize the log."},
+    {"role": "tool", "tool_call_id": "call_log", "content": TOOL_DUMP},
+    {"role": "assistant", "content": "The build failed."},
+]
+
+
+def test_evaluate_rejects_oversized_input(capsys):
+    argv = ["evaluate", "--conversation", json.dumps(CONVERSATION)]
+    with pytest.raises(SystemExit):
+        cli_main([*argv, "--max-content-bytes", "1024"])
+    assert "Input exceeds max_content_bytes: 5035 > 1024" in capsys.readouterr().err
+
+    with pytest.raises(SystemExit):
+        cli_main([*argv, "--max-messages", "2"])
+    assert "Input exceeds max_messages: 3 > 2" in capsys.readouterr().err
+
+
+def test_batch_reports_oversized_payloads_and_keeps_going(tmp_path, capsys):
+    path = tmp_path / "payloads.jsonl"
+    big = json.dumps({"id": "big", "conversation": CONVERSATION})
+    small = json.dumps({"id": "small", "agent_output": "The build failed."})
+    path.write_text(f"{big}\n{small}\n", encoding="utf-8")
+
+    assert cli_main(["batch", str(path), "--max-content-bytes", "1024"]) == 0
+
+    big, ok = (json.loads(line) for line in capsys.readouterr().out.splitlines())
+    assert big == {"id": "big", "error": "Input exceeds max_content_bytes: 5035 > 1024", "line": 1}
+    assert ok["id"] == "small" and "status" in ok
+
+
+def test_limits_must_be_positive(capsys):
+    with pytest.raises(SystemExit):
+        cli_main(["evaluate", "--agent-output", "Hi", "--max-messages", "0"])
+    assert "--max-messages must be positive" in capsys.readouterr().err
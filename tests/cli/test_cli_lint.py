@@ -0,0 +1,62 @@
+import io
+import json
+import sys
+
+from normcore import check_structure
+from normcore.cli import main as cli_main
+from normcore.normative.models import Modality, Statement
+from normcore.normative.structure_checker import StructureChecker
+
+
+def test_check_structure_reports_extracted_statements_without_grounding():
+    report = check_structure("We should deploy now.")
+    assert report.status == "well_formed"
+    assert report.well_formed
+    [statement] = report.statements
+    assert (statement.statement_id, statement.modality, statement.issues) == (
+        "final_response",
+        "assertive",
+        [],
+    )
+
+
+def test_check_structure_protocol_only_text_has_no_normative_content():
+    report = check_structure("Hello! How can I help?")
+    assert report.status == "no_normative_content"
+    assert report.well_formed
+
+
+def test_structure_checker_reports_unformable_statement():
+    statement = Statement(id="s1", subject="", predicate="", raw_text=" ")
+    assert StructureChecker().check(statement) == [
+        "empty_text",
+        "missing_subject",
+        "missing_predicate",
+        "undetermined_modality",
+    ]
+    formed = Statement(
+        id="s2",
+        subject="agent",
+        predicate="participation",
+        raw_text="We should deploy now.",
+        modality=Modality.ASSERTIVE,
+    )
+    assert StructureChecker().check(formed) == []
+
+
+def test_lint_reads_stdin_and_prints_report(monkeypatch, capsys):
+    monkeypatch.setattr(sys, "stdin", io.StringIO("If tests pass, we should deploy."))
+    assert cli_main(["lint"]) == 0
+    report = json.loads(capsys.readouterr().out)
+    assert report["status"] == "well_formed"
+    assert report["statements"][0]["modality"] == "conditional"
+
+
+def test_lint_extracts_under_config_policy(tmp_path, capsys):
+    config = tmp_path / "normcore.toml"
+    config.write_text('[policy]\nstatement_granularity = "sentence"\n', encoding="utf-8")
+    argv = ["--config", str(config), "lint", "--agent-output", "We should deploy. Tests pass."]
+
+    assert cli_main(argv) == 0
+    report = json.loads(capsys.readouterr().out)
+    assert [s["statement_id"] for s in report["statements"]] == ["s1", "s2"]
@@ -0,0 +1,34 @@
+import io
+import json
+import sys
+
+import pytest
+
+from normcore.cli import main as cli_main
+
+
+def test_evaluate_locale_flag(capsys):
+    argv = ["evaluate", "--agent-output", "We should deploy now.", "--locale", "fr"]
+    assert cli_main(argv) == 0
+
+    record = json.loads(capsys.readouterr().out)
+    assert record["status"] == "violates_norm"
+    assert record["explanation"] == "Axiomes enfreints : ['A5']"
+
+
+def test_stream_locale_flag_overrides_config(tmp_path, monkeypatch, capsys):
+    config = tmp_path / "normcore.toml"
+    config.write_text('[policy]\nlocale = "de"\n', encoding="utf-8")
+    line = json.dumps({"agent_output": "We should deploy now."})
+    monkeypatch.setattr(sys, "stdin", io.StringIO(line + "\n" + line + "\n"))
+
+    assert cli_main(["--config", str(config), "stream", "--locale", "es"]) == 0
+
+    records = [json.loads(line) for line in capsys.readouterr().out.splitlines()]
+    assert [record["explanation"] for record in records] == ["Axiomas violados: ['A5']"] * 2
+
+
+def test_unsupported_locale_is_a_usage_error(capsys):
+    with pytest.raises(SystemExit):
+        cli_main(["batch", "--locale", "pt"])
+    assert "invalid choice: 'pt'" in capsys.readouterr().err
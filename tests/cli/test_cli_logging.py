@@ -0,0 +1,56 @@
+import json
+
+import pytest
+
+from normcore.cli import main as cli_main
+from normcore.logging import configure_logging
+
+ARGV = ["evaluate", "--agent-output", "We should deploy now."]
+
+
+@pytest.fixture(autouse=True)
+def reset_logging(monkeypatch):
+    monkeypatch.delenv("NORMCORE_LOG_LEVEL", raising=False)
+    monkeypatch.delenv("NORMCORE_LOG_FORMAT", raising=False)
+    yield
+    configure_logging(None)
+
+
+def _json_events(err):
+    return [json.loads(line) for line in err.splitlines()]
+
+
+def test_json_log_format_emits_stage_timings_and_statement_decisions(capsys):
+    assert cli_main(["-vv", "--log-format", "json", *ARGV]) == 0
+    events = _json_events(capsys.readouterr().err)
+
+    assert {event["level"] for event in events} <= {"DEBUG", "INFO", "WARNING"}
+    stages = {event["stage"]: event for event in events if "stage" in event}
+    assert {"knowledge", "links", "extract", "aggregate"} <= set(stages)
+    assert all(event["duration_ms"] >= 0 for event in stages.values())
+    (statement,) = [e for e in events if e["message"].endswith("statement evaluated")]
+    assert statement["statement_id"] == "final_response"
+    assert statement["license_rule"] == "normative"
+    assert statement["license"] == ["refusal"]
+    assert statement["violated_axiom"] == "A5"
+    (extraction,) = [e for e in events if e["message"].endswith("extraction decided")]
+    assert extraction["statement_ids"] == ["final_response"]
+
+
+def test_verbosity_selects_level_and_text_format_appends_fields(capsys):
+    assert cli_main(["-v", *ARGV]) == 0
+    err = capsys.readouterr().err
+    assert "judgment ready | status=violates_norm statements=1 warnings=0 duration_ms=" in err
+    assert "stage extract finished" not in err
+
+
+def test_log_format_falls_back_to_environment(monkeypatch, capsys):
+    monkeypatch.setenv("NORMCORE_LOG_FORMAT", "json")
+    assert cli_main(["--log-level", "INFO", *ARGV]) == 0
+    events = _json_events(capsys.readouterr().err)
+    assert events[-1]["message"] == "AdmissibilityEvaluator: judgment ready"
+
+
+def test_no_diagnostics_without_log_level(capsys):
+    assert cli_main(ARGV) == 0
+    assert capsys.readouterr().err == ""
@@ -0,0 +1,52 @@
+import io
+import json
+
+from normcore import evaluate
+from normcore.cli import _run_stream
+from normcore.metrics import EvaluationMetrics, metrics_handler
+
+
+def test_metrics_render_counters_and_histograms():
+    metrics = EvaluationMetrics()
+    violation = evaluate(agent_output="We should deploy now.")
+    metrics.observe(violation, latency_seconds=0.002, agent="a1")
+    metrics.observe(evaluate(agent_output="Hello!"), latency_seconds=0.2)
+    metrics.observe_payload_error()
+
+    text = metrics.render()
+    assert 'normcore_judgments_total{agent="a1",status="violates_norm"} 1' in text
+    assert 'normcore_judgments_total{agent="unknown",status="no_normative_content"} 1' in text
+    assert 'normcore_violated_axioms_total{agent="a1",axiom="A5"} 1' in text
+    assert 'normcore_evaluation_seconds_bucket{agent="a1",le="0.005"} 1' in text
+    assert 'normcore_evaluation_seconds_bucket{agent="unknown",le="0.1"} 0' in text
+    assert 'normcore_evaluation_seconds_count{agent="unknown"} 1' in text
+    assert 'normcore_grounds_cited_bucket{agent="a1",le="0"} 1' in text
+    assert "normcore_payload_errors_total 1" in text
+
+
+def test_stream_records_metrics_per_agent():
+    lines = [
+        json.dumps({"agent": "planner", "agent_output": "We should deploy now."}),
+        json.dumps({"agent": "planner", "bogus": 1}),
+    ]
+    metrics = EvaluationMetrics()
+    output = io.StringIO()
+    _run_stream(io.StringIO("\n".join(lines) + "\n"), output, metrics=metrics)
+
+    text = metrics.render()
+    assert 'normcore_judgments_total{agent="planner",status="violates_norm"} 1' in text
+    assert "normcore_payload_errors_total 1" in text
+    assert len(output.getvalue().splitlines()) == 2
+
+
+def test_metrics_handler_serves_metrics_path(http_exchange):
+    metrics = EvaluationMetrics()
+    metrics.observe(evaluate(agent_output="Hello!"), latency_seconds=0.01)
+    handler = metrics_handler(metrics)
+
+    status, headers, body = http_exchange(handler, "GET", "/metrics")
+
+    assert status == 200
+    assert headers["Content-Type"].startswith("text/plain")
+    assert "normcore_judgments_total" in body.decode("utf-8")
+    assert http_exchange(handler, "GET", "/other")[0] == 404
@@ -0,0 +1,168 @@
+import io
+import sys
+
+import pytest
+
+from normcore import cli
+from normcore.cli import main as cli_main
+from normcore.encodings import content_type_encoding
+from normcore.http_server import EvaluationService, evaluation_handler
+from normcore.serving import EvaluationPool
+
+
+@pytest.fixture
+def msgpack():
+    return pytest.importorskip("msgpack")
+
+
+def _records(msgpack, data):
+    return list(msgpack.Unpacker(io.BytesIO(data), raw=False))
+
+
+def test_stream_reads_and_writes_msgpack(msgpack):
+    data = b"".join(
+        msgpack.packb(item)
+        for item in (
+            {"id": 1, "agent_output": "We should deploy now."},
+            ["not", "a", "map"],
+            {"id": 3, "agent_output": "If tests pass, we should deploy."},
+        )
+    )
+    output = io.BytesIO()
+    cli._run_stream(
+        io.BytesIO(data),
+        output,
+        parse_line=cli.payload_from_object,
+        encoding="msgpack",
+    )
+    first, error, third = _records(msgpack, output.getvalue())
+    assert (first["id"], first["status"]) == (1, "violates_norm")
+    assert error == {"error": "Payload must be a map", "line": 2}
+    assert (third["id"], third["status"]) == (3, "conditionally_acceptable")
+
+
+@pytest.mark.parametrize(
+    "tail,max_bytes,error",
+    [
+        (b"\xc1", None, "Invalid MessagePack payload"),
+        (b"\xdf\x00\x00", None, "Truncated MessagePack payload"),
+        (None, 64, "Payload exceeds 64 bytes; remaining input skipped"),
+    ],
+)
+def test_stream_stops_at_unrecoverable_msgpack_input(msgpack, tail, max_bytes, error):
+    if tail is None:
+        tail = msgpack.packb({"agent_output": "x" * 200})
+    data = msgpack.packb({"id": 1, "agent_output": "Hello!"}) + tail
+    data += msgpack.packb({"id": 3, "agent_output": "Hello!"})
+    output = io.BytesIO()
+    cli._run_stream(
+        io.BytesIO(data),
+        output,
+        parse_line=cli.payload_from_object,
+        max_payload_bytes=max_bytes,
+        encoding="msgpack",
+    )
+    first, *rest = _records(msgpack, output.getvalue())
+    assert first["id"] == 1
+    assert len(rest) == 1
+    assert rest[0]["line"] == 2
+    assert rest[0]["error"].startswith(error)
+
+
+def test_cli_stream_uses_binary_stdio(msgpack, monkeypatch):
+    stdin = io.TextIOWrapper(io.BytesIO(msgpack.packb({"id": "a", "agent_output": "Hello!"})))
+    stdout = io.TextIOWrapper(io.BytesIO())
+    monkeypatch.setattr(sys, "stdin", stdin)
+    monkeypatch.setattr(sys, "stdout", stdout)
+
+    assert cli_main(["stream", "--encoding", "msgpack"]) == 0
+    (record,) = _records(msgpack, stdout.buffer.getvalue())
+    assert (record["id"], record["status"]) == ("a", "no_normative_content")
+
+
+@pytest.mark.parametrize("concurrency", [[], ["--jobs", "1"]])
+def test_cli_batch_reads_and_writes_msgpack(msgpack, tmp_path, monkeypatch, concurrency):
+    payloads = tmp_path / "payloads.msgpack"
+    payloads.write_bytes(
+        msgpack.packb({"id": "a", "agent_output": "We should deploy now."})
+        + msgpack.packb({"id": "b", "agent_output": "Hello!"})
+    )
+    stdout = io.TextIOWrapper(io.BytesIO())
+    monkeypatch.setattr(sys, "stdout", stdout)
+
+    assert cli_main(["batch", str(payloads), "--encoding", "msgpack", *concurrency]) == 0
+    records = _records(msgpack, stdout.buffer.getvalue())
+    assert [(record["id"], record["status"]) for record in records] == [
+        ("a", "violates_norm"),
+        ("b", "no_normative_content"),
+    ]
+
+
+@pytest.mark.parametrize(
+    "content_type,encoding",
+    [
+        ("application/msgpack", "msgpack"),
+        ("Application/MsgPack; charset=binary", "msgpack"),
+        ("application/json", "json"),
+        (None, "json"),
+    ],
+)
+def test_http_content_type_selects_encoding(content_type, encoding):
+    assert content_type_encoding(content_type) == encoding
+
+
+def test_evaluation_service_handles_msgpack_bodies(msgpack):
+    with EvaluationPool(1) as pool:
+        service = EvaluationService(pool)
+        body = msgpack.packb({"id": "turn-1", "agent_output": "We should deploy now."})
+        record = service.handle(body, "msgpack")
+
+        assert (record["id"], record["status"]) == ("turn-1", "violates_norm")
+        assert msgpack.unpackb(service.render(record, "msgpack"), raw=False) == record
+        for invalid, message in [
+            (body + b"\xc1", "Invalid MessagePack payload"),
+            (msgpack.packb(["not", "a", "map"]), "Payload must be a map"),
+        ]:
+            with pytest.raises(Exception, match=message) as exc:
+                service.handle(invalid, "msgpack")
+            assert exc.value.status == 400
+
+
+def test_evaluate_endpoint_answers_in_the_request_encoding(msgpack, http_exchange):
+    body = msgpack.packb({"id": "turn-1", "agent_output": "We should deploy now."})
+    with EvaluationPool(1) as pool:
+        handler = evaluation_handler(EvaluationService(pool))
+        status, headers, response = http_exchange(
+            handler, "POST", "/evaluate", body, **{"Content-Type": "application/msgpack"}
+        )
+
+    assert status == 200
+    assert headers["Content-Type"] == "application/msgpack"
+    assert msgpack.unpackb(response, raw=False)["status"] == "violates_norm"
+
+
+def test_import_writes_msgpack_records(msgpack):
+    output = io.BytesIO()
+    payloads = [{"id": "p1", "agent_output": "We should deploy now."}, {"id": "p2", "bogus": 1}]
+
+    cli._run_import(payloads, output, evaluate_payloads=True, encoding="msgpack")
+    judgment, error = _records(msgpack, output.getvalue())
+    assert (judgment["id"], judgment["status"]) == ("p1", "violates_norm")
+    assert error == {"id": "p2", "error": "Unknown payload fields: bogus", "line": 2}
+
+
+def test_cli_msgpack_rejects_json_only_options(msgpack, capsys):
+    with pytest.raises(SystemExit):
+        cli_main(["stream", "--encoding", "msgpack", "--canonical"])
+    assert "--canonical requires --encoding json" in capsys.readouterr().err
+
+    with pytest.raises(SystemExit):
+        cli_main(["stream", "--encoding", "msgpack", "--input-format", "openai-batch"])
+    assert "--encoding msgpack requires --input-format payload" in capsys.readouterr().err
+
+
+def test_cli_msgpack_without_dependency_points_at_extra(monkeypatch, capsys):
+    monkeypatch.setitem(sys.modules, "msgpack", None)
+    with pytest.raises(SystemExit):
+        cli_main(["stream", "--encoding", "msgpack"])
+    assert "pip install 'normcore[msgpack]'" in capsys.readouterr().err
@@ -0,0 +1,122 @@
+import json
+import os
+
+import pytest
+
+import normcore.cli
+from normcore.cli import main as cli_main
+from normcore.sinks import FILE_SCHEME, output_file, parse_output
+
+
+def _batch_file(tmp_path, texts):
+    path = tmp_path / "payloads.jsonl"
+    path.write_text(
+        "".join(
+            json.dumps({"id": index, "agent_output": text}) + "\n"
+            for index, text in enumerate(texts)
+        ),
+        encoding="utf-8",
+    )
+    return path
+
+
+def test_evaluate_writes_judgment_to_output_file(tmp_path, capsys):
+    path = tmp_path / "judgment.json"
+    path.write_text("stale", encoding="utf-8")
+    path.chmod(0o640)
+
+    argv = ["evaluate", "--agent-output", "We should deploy now.", "--output", str(path)]
+    assert cli_main(argv) == 0
+
+    assert capsys.readouterr().out == ""
+    assert json.loads(path.read_text(encoding="utf-8"))["status"] == "violates_norm"
+    assert path.stat().st_mode & 0o777 == 0o640
+    assert os.listdir(tmp_path) == ["judgment.json"]
+
+
+def test_output_file_is_replaced_only_on_success(tmp_path):
+    path = tmp_path / "judgments.jsonl"
+    path.write_text("previous run\n", encoding="utf-8")
+
+    with pytest.raises(RuntimeError):
+        with output_file(path) as handle:
+            handle.write('{"status": "acceptable"}\n')
+            assert path.read_text(encoding="utf-8") == "previous run\n"
+            raise RuntimeError("killed")
+
+    assert path.read_text(encoding="utf-8") == "previous run\n"
+    assert os.listdir(tmp_path) == ["judgments.jsonl"]
+
+
+def test_interrupted_batch_keeps_partial_output(tmp_path, monkeypatch):
+    payloads = _batch_file(tmp_path, ["We should deploy now.", "Hello!", "Stop here."])
+    path = tmp_path / "judgments.jsonl"
+    path.write_text("previous run\n", encoding="utf-8")
+    evaluate_payload = normcore.cli.evaluate_payload
+
+    def interrupt_on_last(payload, **kwargs):
+        if payload["agent_output"] == "Stop here.":
+            raise KeyboardInterrupt
+        return evaluate_payload(payload, **kwargs)
+
+    monkeypatch.setattr(normcore.cli, "evaluate_payload", interrupt_on_last)
+    with pytest.raises(KeyboardInterrupt):
+        cli_main(["batch", str(payloads), "--output", str(path), "--workers", "1"])
+
+    assert path.read_text(encoding="utf-8") == "previous run\n"
+    partial = tmp_path / "judgments.jsonl.partial"
+    records = [json.loads(line) for line in partial.read_text(encoding="utf-8").splitlines()]
+    assert [record["id"] for record in records] == [0, 1]
+    assert sorted(os.listdir(tmp_path)) == [
+        "judgments.jsonl",
+        "judgments.jsonl.partial",
+        "payloads.jsonl",
+    ]
+
+
+def test_batch_output_and_append(tmp_path, capsys):
+    payloads = _batch_file(tmp_path, ["We should deploy now.", "Hello!"])
+    path = tmp_path / "out" / "judgments.jsonl"
+    path.parent.mkdir()
+
+    assert cli_main(["batch", str(payloads), "--output", str(path)]) == 0
+    assert cli_main(["batch", str(payloads), "--output", str(path), "--append"]) == 0
+
+    assert capsys.readouterr().out == ""
+    records = [json.loads(line) for line in path.read_text(encoding="utf-8").splitlines()]
+    assert [record["id"] for record in records] == [0, 1, 0, 1]
+
+    assert cli_main(["batch", str(payloads), "--output", str(path)]) == 0
+    assert len(path.read_text(encoding="utf-8").splitlines()) == 2
+
+
+@pytest.mark.parametrize(
+    "extra,message",
+    [
+        (["--append"], "--append requires --output PATH"),
+        (["--append", "--output", "sqlite:{dir}/runs.db"], "--append requires --output PATH"),
+        (["--append", "--output", "{dir}/log.sarif", "--format", "sarif"], "--append cannot"),
+        (["--output", "{dir}"], "Failed to open --output: Is a directory"),
+        (["--output", "{dir}/judgments.parquet"], "did you mean parquet:"),
+    ],
+)
+def test_batch_output_errors(tmp_path, capsys, extra, message):
+    payloads = _batch_file(tmp_path, ["Hello!"])
+    argv = ["batch", str(payloads), *(arg.format(dir=tmp_path) for arg in extra)]
+    with pytest.raises(SystemExit):
+        cli_main(argv)
+    assert message in capsys.readouterr().err
+
+
+def test_stream_output_file_rejects_unix_socket(tmp_path, capsys):
+    argv = ["stream", "--unix-socket", str(tmp_path / "s"), "--output", str(tmp_path / "o")]
+    with pytest.raises(SystemExit):
+        cli_main(argv)
+    assert "--output PATH applies to stdin/stdout streaming" in capsys.readouterr().err
+
+
+@pytest.mark.parametrize(
+    "value", ["judgments.jsonl", "out/judgments.json", "C:\\runs\\judgments.jsonl"]
+)
+def test_plain_paths_are_file_outputs(value):
+    assert parse_output(value)[0] == FILE_SCHEME
@@ -0,0 +1,117 @@
+import io
+import json
+import sys
+
+import pytest
+
+from normcore import evaluate
+from normcore.cli import main as cli_main
+from normcore.payloads import render_result_record
+from normcore.sinks import PARQUET_COLUMNS, ParquetSink, parse_output
+
+
+@pytest.fixture
+def parquet():
+    pytest.importorskip("pyarrow")
+    return pytest.importorskip("pyarrow.parquet")
+
+
+def test_stream_writes_rows_to_parquet_instead_of_stdout(parquet, tmp_path, monkeypatch, capsys):
+    path = tmp_path / "judgments.parquet"
+    lines = [
+        json.dumps({"id": "deploy", "agent_output": "We should deploy now."}),
+        json.dumps({"id": 7, "agent_output": "Hello!"}),
+        "not json",
+    ]
+    monkeypatch.setattr(sys, "stdin", io.StringIO("\n".join(lines) + "\n"))
+
+    assert cli_main(["stream", "--output", f"parquet:{path}"]) == 0
+    assert capsys.readouterr().out == ""
+
+    table = parquet.read_table(path)
+    assert table.schema.names == [name for name, _ in PARQUET_COLUMNS]
+    deploy, greeting, error = table.to_pylist()
+    assert (deploy["record"], deploy["id"], deploy["status"]) == (1, "deploy", "violates_norm")
+    assert deploy["statement_index"] == 0
+    assert deploy["statement"] == "We should deploy now."
+    assert deploy["statement_status"] == "violates_norm"
+    assert (greeting["record"], greeting["id"]) == (2, "7")
+    assert greeting["status"] == "no_normative_content"
+    assert greeting["statement_id"] is None
+    assert error["record"] == 3
+    assert error["error"].startswith("Invalid JSON payload")
+    assert error["status"] is None
+
+
+def _two_statement_record():
+    judgment = evaluate(agent_output="We should deploy now.")
+    (evaluation,) = judgment.statement_evaluations
+    second = evaluation.model_copy(update={"statement_id": "s2", "statement": "Then notify."})
+    judgment = judgment.model_copy(update={"statement_evaluations": [evaluation, second]})
+    return render_result_record(judgment, payload_id="a")
+
+
+def test_parquet_sink_writes_one_row_per_statement_in_bounded_row_groups(parquet, tmp_path):
+    path = tmp_path / "judgments.parquet"
+
+    sink = ParquetSink(path, row_group_size=2)
+    for _ in range(2):
+        sink.write(_two_statement_record())
+    sink.close()
+
+    assert parquet.ParquetFile(path).num_row_groups == 2
+    rows = parquet.read_table(path).to_pylist()
+    assert [(row["record"], row["statement_index"], row["statement_id"]) for row in rows] == [
+        (1, 0, "final_response"),
+        (1, 1, "s2"),
+        (2, 0, "final_response"),
+        (2, 1, "s2"),
+    ]
+    assert rows[0]["statement_license"] == sorted(rows[0]["statement_license"])
+
+
+def test_import_evaluate_writes_parquet(parquet, tmp_path, capsys):
+    export = tmp_path / "samples.jsonl"
+    export.write_text(
+        json.dumps({"input": "Deploy?", "completion": "We should deploy now."}) + "\n",
+        encoding="utf-8",
+    )
+    path = tmp_path / "judgments.parquet"
+    argv = ["import", "--from", "openai-evals", str(export), "--evaluate"]
+
+    assert cli_main([*argv, "--output", f"parquet:{path}"]) == 0
+    assert capsys.readouterr().out == ""
+    (row,) = parquet.read_table(path).to_pylist()
+    assert (row["id"], row["status"]) == ("sample.0", "violates_norm")
+
+    with pytest.raises(SystemExit):
+        cli_main([*argv[:-1], "--output", f"parquet:{path}"])
+    assert "--output requires --evaluate" in capsys.readouterr().err
+
+
+@pytest.mark.parametrize(
+    "value,message",
+    [
+        ("judgments.parquet", "Unsupported output"),
+        ("csv:judgments.csv", "Unsupported output"),
+        ("parquet:", "Missing path"),
+    ],
+)
+def test_parse_output_rejects_unknown_sinks(value, message):
+    with pytest.raises(ValueError, match=message):
+        parse_output(value)
+
+
+def test_cli_output_validation(tmp_path, capsys, monkeypatch):
+    with pytest.raises(SystemExit):
+        cli_main(["stream", "--output", str(tmp_path / "out.parquet")])
+    assert "Unsupported output" in capsys.readouterr().err
+
+    with pytest.raises(SystemExit):
+        cli_main(["stream", "--canonical", "--output", f"parquet:{tmp_path / 'out.parquet'}"])
+    assert "--canonical applies to stdout output" in capsys.readouterr().err
+
+    monkeypatch.setitem(sys.modules, "pyarrow", None)
+    with pytest.raises(SystemExit):
+        cli_main(["stream", "--output", f"parquet:{tmp_path / 'out.parquet'}"])
+    assert "pip install 'normcore[parquet]'" in capsys.readouterr().err
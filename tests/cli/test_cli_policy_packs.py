@@ -0,0 +1,116 @@
+import io
+import json
+import sys
+
+import pytest
+
+import normcore.cli
+
+from normcore import POLICY_PACKS, evaluate, policy_pack
+from normcore.cli import main as cli_main
+
+
+@pytest.mark.parametrize("name", sorted(POLICY_PACKS))
+def test_every_pack_is_a_valid_policy(name):
+    assert policy_pack(name).model_dump(exclude_unset=True) == POLICY_PACKS[name]
+
+
+def test_pack_overrides_and_unknown_names():
+    assert policy_pack("strict", min_citations_per_assertive=2).min_citations_per_assertive == 2
+    with pytest.raises(ValueError, match="Unknown policy pack 'paranoid'"):
+        policy_pack("paranoid")
+
+
+def test_strict_pack_ignores_unrequested_tool_results():
+    conversation = [
+        {"role": "user", "content": "Why is CI red?"},
+        {"role": "tool", "tool_call_id": "call_ci", "content": '{"runner": "offline"}'},
+        {"role": "assistant", "content": "You should restart the runner [@call_ci]."},
+    ]
+
+    assert evaluate(conversation=conversation).status == "acceptable"
+    strict = evaluate(conversation=conversation, policy=policy_pack("strict"))
+    assert strict.status == "violates_norm"
+    assert strict.gate_decision == "block"
+
+
+def test_commands_select_pack_by_name(capsys):
+    argv = ["evaluate", "--agent-output", "We should deploy now.", "--policy", "lenient"]
+    assert cli_main(["axioms", "--json", "--policy", "lenient"]) == 0
+    rows = {row["code"]: row["enabled"] for row in json.loads(capsys.readouterr().out)}
+    assert rows["A9"] is False
+
+    assert cli_main(argv) == 0
+    assert json.loads(capsys.readouterr().out)["status"] == "violates_norm"
+
+
+def test_policy_path_without_prefix(tmp_path, capsys):
+    path = tmp_path / "policy.json"
+    path.write_text(json.dumps({"disabled_axioms": ["A5"]}), encoding="utf-8")
+
+    assert cli_main(["axioms", "--json", "--policy", str(path)]) == 0
+
+    rows = {row["code"]: row["enabled"] for row in json.loads(capsys.readouterr().out)}
+    assert rows["A5"] is False
+
+
+def test_pack_overrides_config_policy(tmp_path, capsys):
+    config = tmp_path / "normcore.toml"
+    config.write_text("[policy]\naggregation = \"lexicographic\"\nrisk_floor = \"medium\"\n")
+
+    argv = ["--config", str(config), "evaluate", "--agent-output", "Hello!", "--policy", "lenient"]
+    assert cli_main(argv) == 0
+
+    record = json.loads(capsys.readouterr().out)
+    assert record["risk_tier"] == "medium"
+
+
+def test_unknown_pack_is_a_usage_error(capsys):
+    with pytest.raises(SystemExit):
+        cli_main(["evaluate", "--agent-output", "Hi", "--policy", "paranoid"])
+    assert "Unknown policy pack 'paranoid' (choose from strict, lenient, rag" in (
+        capsys.readouterr().err
+    )
+
+
+def test_evaluate_domain_option_selects_pack_risk_floor(capsys):
+    policy = '{"domain_risk_floors": {"finance": "medium"}}'
+    argv = ["evaluate", "--agent-output", "Hello!", "--policy", policy, "--domain", "finance"]
+    assert cli_main(argv) == 0
+
+    assert json.loads(capsys.readouterr().out)["risk_tier"] == "medium"
+
+
+_UNREQUESTED_TOOL_RESULT = {
+    "id": "ci",
+    "conversation": [
+        {"role": "user", "content": "Why is CI red?"},
+        {"role": "tool", "tool_call_id": "call_ci", "content": '{"runner": "offline"}'},
+        {"role": "assistant", "content": "You should restart the runner [@call_ci]."},
+    ],
+}
+
+
+def test_pipeline_commands_select_pack_by_name(tmp_path, monkeypatch, capsys):
+    payloads = tmp_path / "payloads.jsonl"
+    payloads.write_text(json.dumps(_UNREQUESTED_TOOL_RESULT) + "\n", encoding="utf-8")
+
+    assert cli_main(["batch", str(payloads)]) == 0
+    assert json.loads(capsys.readouterr().out)["status"] == "acceptable"
+
+    assert cli_main(["batch", str(payloads), "--policy", "strict"]) == 0
+    assert json.loads(capsys.readouterr().out)["status"] == "violates_norm"
+
+    monkeypatch.setattr(sys, "stdin", io.StringIO(payloads.read_text(encoding="utf-8")))
+    assert cli_main(["stream", "--policy", "strict"]) == 0
+    assert json.loads(capsys.readouterr().out)["status"] == "violates_norm"
+
+
+def test_serve_evaluates_under_selected_pack(monkeypatch):
+    services = []
+    monkeypatch.setattr(
+        normcore.cli, "_run_serve", lambda service, *args, **kwargs: services.append(service) or 0
+    )
+
+    assert cli_main(["serve", "--policy", "strict"]) == 0
+    assert services[0].policy == policy_pack("strict")
@@ -0,0 +1,83 @@
+import json
+
+import pytest
+
+from normcore import EvaluationPolicy
+from normcore.cli import main as cli_main
+from normcore.scenarios import run_corpus
+
+# Cites a tool result nobody asked for: admitted by default, ignored by the strict pack.
+UNREQUESTED_RESULT = {
+    "name": "unrequested tool result",
+    "conversation": [
+        {"role": "user", "content": "Why is CI red?"},
+        {"role": "tool", "tool_call_id": "call_ci", "content": '{"runner": "offline"}'},
+        {"role": "assistant", "content": "You should restart the runner [@call_ci]."},
+    ],
+    "expect": {"status": "acceptable", "violated_axioms": []},
+}
+
+UNGROUNDED = """
+[[turns]]
+user = "Can we deploy?"
+
+[[turns]]
+assistant = "We should deploy now."
+
+[expect]
+status = "violates_norm"
+violated_axioms = ["A5"]
+"""
+
+
+@pytest.fixture
+def corpus(tmp_path):
+    (tmp_path / "unrequested.json").write_text(json.dumps(UNREQUESTED_RESULT), encoding="utf-8")
+    (tmp_path / "deploy").mkdir()
+    (tmp_path / "deploy" / "ungrounded.toml").write_text(UNGROUNDED, encoding="utf-8")
+    (tmp_path / "notes.txt").write_text("not a scenario", encoding="utf-8")
+    return tmp_path
+
+
+def test_run_corpus_passes_under_default_policy(corpus, capsys):
+    assert cli_main(["run-corpus", str(corpus)]) == 0
+
+    assert capsys.readouterr().out.splitlines() == [
+        f"PASS {corpus / 'deploy' / 'ungrounded.toml'}",
+        f"PASS {corpus / 'unrequested.json'}",
+        "2 passed, 0 failed",
+    ]
+
+
+def test_run_corpus_reports_mismatches_under_a_policy(corpus, capsys):
+    assert cli_main(["run-corpus", str(corpus), "--policy", "strict"]) == 1
+
+    out = capsys.readouterr().out
+    assert f"FAIL {corpus / 'unrequested.json'}" in out
+    assert "  status: expected 'acceptable', got 'violates_norm'" in out
+    assert out.endswith("1 passed, 1 failed\n")
+
+
+def test_scenario_policy_overrides_base_policy(corpus):
+    scenario = {**UNREQUESTED_RESULT, "policy": {"unmatched_tool_results": "trust"}}
+    (corpus / "unrequested.json").write_text(json.dumps(scenario), encoding="utf-8")
+    strict = EvaluationPolicy(unmatched_tool_results="ignore")
+
+    assert all(result.passed for result in run_corpus(corpus, strict))
+
+
+def test_run_corpus_json_report_and_errors(corpus, capsys):
+    (corpus / "broken.json").write_text('{"expect": {"status": "acceptable"}}', encoding="utf-8")
+
+    assert cli_main(["run-corpus", str(corpus), "--json"]) == 1
+
+    report = json.loads(capsys.readouterr().out)
+    assert (report["passed"], report["failed"]) == (2, 1)
+    broken = report["scenarios"][0]
+    assert broken["name"] == "broken" and broken["passed"] is False
+    assert "either 'turns' or 'conversation'" in broken["error"]
+    assert report["scenarios"][2]["status"] == "acceptable"
+
+    with pytest.raises(SystemExit):
+        cli_main(["run-corpus", str(corpus / "missing")])
+    assert "Corpus directory not found" in capsys.readouterr().err
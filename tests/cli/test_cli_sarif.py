@@ -0,0 +1,75 @@
+import json
+
+import pytest
+
+from normcore import evaluate
+from normcore.cli import main as cli_main
+from normcore.sarif import FINGERPRINT_KEY, SARIF_VERSION, to_sarif
+
+
+def test_sarif_reports_one_result_per_violating_statement():
+    judgments = [
+        evaluate(agent_output="We should deploy now."),
+        evaluate(agent_output="If tests pass, we should deploy."),
+    ]
+
+    log = to_sarif(judgments)
+
+    assert log["version"] == SARIF_VERSION
+    (run,) = log["runs"]
+    rules = run["tool"]["driver"]["rules"]
+    assert {"A4", "A5", "A8"} <= {rule["id"] for rule in rules}
+    assert not {"A6", "A7"} & {rule["id"] for rule in rules}
+    (result,) = run["results"]
+    assert result["ruleId"] == "A5" and result["level"] == "error"
+    assert rules[result["ruleIndex"]]["id"] == "A5"
+    assert "We should deploy now." in result["message"]["text"]
+    assert result["locations"] == [
+        {"logicalLocations": [{"name": "final_response", "fullyQualifiedName": "final_response"}]}
+    ]
+    assert result["partialFingerprints"][FINGERPRINT_KEY]
+    assert run["invocations"][0]["executionSuccessful"] is True
+
+
+def test_evaluate_sarif_format(capsys):
+    argv = ["evaluate", "--agent-output", "We should deploy now.", "--format", "sarif"]
+    assert cli_main(argv) == 0
+    (run,) = json.loads(capsys.readouterr().out)["runs"]
+    assert [result["ruleId"] for result in run["results"]] == ["A5"]
+
+
+def test_batch_sarif_points_results_at_payload_lines(tmp_path, capsys):
+    payloads = tmp_path / "suite.jsonl"
+    payloads.write_text(
+        "\n".join(
+            [
+                json.dumps({"id": "greeting", "agent_output": "Hello!"}),
+                json.dumps({"id": "deploy", "agent_output": "We should deploy now."}),
+                "not json",
+            ]
+        )
+        + "\n",
+        encoding="utf-8",
+    )
+
+    assert cli_main(["batch", str(payloads), "--format", "sarif"]) == 0
+
+    (run,) = json.loads(capsys.readouterr().out)["runs"]
+    assert run["artifacts"] == [{"location": {"uri": str(payloads)}}]
+    (result,) = run["results"]
+    (location,) = result["locations"]
+    assert location["physicalLocation"] == {
+        "artifactLocation": {"uri": str(payloads)},
+        "region": {"startLine": 2},
+    }
+    assert location["logicalLocations"][0]["fullyQualifiedName"] == "deploy/final_response"
+    (invocation,) = run["invocations"]
+    assert invocation["executionSuccessful"] is False
+    (notification,) = invocation["toolExecutionNotifications"]
+    assert notification["locations"][0]["physicalLocation"]["region"] == {"startLine": 3}
+
+
+def test_stream_does_not_offer_sarif(capsys):
+    with pytest.raises(SystemExit):
+        cli_main(["stream", "--format", "sarif"])
+    assert "invalid choice" in capsys.readouterr().err
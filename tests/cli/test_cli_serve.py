@@ -0,0 +1,128 @@
+import json
+
+import pytest
+
+from normcore import EvaluationPolicy
+from normcore.cli import main as cli_main
+from normcore.http_access import HttpAccessPolicy
+from normcore.http_server import EvaluationService, evaluation_handler
+from normcore.metrics import EvaluationMetrics
+from normcore.serving import EvaluationPool
+
+
+@pytest.fixture
+def serve(http_exchange):
+    pools = []
+
+    def start(access=None, **options):
+        pool = EvaluationPool(1, timeout=options.pop("timeout", None))
+        pools.append(pool)
+        handler = evaluation_handler(EvaluationService(pool, **options), access=access)
+
+        def request(path, body=None, **headers):
+            status, _, response = http_exchange(
+                handler, "GET" if body is None else "POST", path, body, **headers
+            )
+            return status, response
+
+        return request
+
+    yield start
+    for pool in pools:
+        pool.close()
+
+
+def test_evaluate_endpoint_returns_judgment_record(serve):
+    request = serve()
+    payload = {"id": "turn-1", "agent_output": "We should deploy now."}
+
+    status, body = request("/evaluate", json.dumps(payload))
+
+    assert status == 200
+    record = json.loads(body)
+    assert record["id"] == "turn-1"
+    assert record["status"] == "violates_norm"
+    assert record["violated_axioms"] == ["A5"]
+
+
+def test_healthz_is_open_while_evaluate_requires_key(serve):
+    request = serve(access=HttpAccessPolicy(api_keys=("k1",)))
+    payload = json.dumps({"agent_output": "Hello!"})
+
+    assert request("/healthz") == (200, b'{"status": "ok"}')
+    assert request("/evaluate", payload)[0] == 401
+    assert request("/evaluate", payload, Authorization="Bearer k1")[0] == 200
+
+
+@pytest.mark.parametrize(
+    "body,status,message",
+    [
+        ("not json", 400, "Invalid JSON payload"),
+        ("[]", 400, "Payload must be a JSON object"),
+        ('{"agent_output": "Hi", "extra": 1}', 400, "Unknown payload fields: extra"),
+        (json.dumps({"agent_output": "x" * 200}), 413, "Payload exceeds 100 bytes"),
+        (json.dumps({"agent_output": "x" * 20}), 413, "Input exceeds max_content_bytes: 20 > 10"),
+    ],
+)
+def test_rejected_requests_get_error_records(serve, body, status, message):
+    metrics = EvaluationMetrics()
+    policy = EvaluationPolicy(max_content_bytes=10)
+    request = serve(max_payload_bytes=100, metrics=metrics, policy=policy)
+
+    code, response = request("/evaluate", body)
+
+    assert code == status
+    assert message in json.loads(response)["error"]
+    assert "normcore_payload_errors_total 1" in request("/metrics")[1].decode()
+
+
+def test_negative_content_length_is_rejected(serve):
+    request = serve()
+
+    status, body = request("/evaluate", "", **{"Content-Length": "-1"})
+
+    assert status == 400
+    assert json.loads(body)["error"] == "Content-Length must not be negative"
+
+
+class _BrokenAuditLog:
+    def append(self, payload, judgment):
+        raise OSError("disk full")
+
+
+def test_unexpected_failures_get_a_500_error_record(serve):
+    request = serve(audit_log=_BrokenAuditLog())
+
+    status, body = request("/evaluate", json.dumps({"agent_output": "Hello!"}))
+
+    assert status == 500
+    assert json.loads(body) == {"error": "Internal error while evaluating the payload"}
+    assert request("/healthz")[0] == 200
+
+
+def test_unknown_endpoints_and_methods(serve):
+    request = serve()
+    assert request("/metrics")[0] == 404
+    assert request("/evaluate")[0] == 405
+    assert request("/judge", "{}")[0] == 404
+
+
+def test_canonical_response_body(serve):
+    request = serve(canonical=True)
+    _, body = request("/evaluate", json.dumps({"agent_output": "Hello!"}))
+    record = json.loads(body)
+    assert body.decode() == json.dumps(record, sort_keys=True, separators=(",", ":"))
+
+
+@pytest.mark.parametrize(
+    "argv,message",
+    [
+        (["serve", "--workers", "0"], "--workers must be at least 1"),
+        (["serve", "--timeout", "0"], "--timeout must be positive"),
+        (["serve", "--signing-key-id", "prod"], "--signing-key-id requires --signing-key-file"),
+    ],
+)
+def test_serve_argument_errors(argv, message, capsys):
+    with pytest.raises(SystemExit):
+        cli_main(argv)
+    assert message in capsys.readouterr().err
@@ -0,0 +1,97 @@
+import io
+import json
+import sys
+
+import pytest
+
+from normcore import evaluate
+from normcore.cli import main as cli_main
+from normcore.signing import JudgmentSigner, canonical_judgment_bytes, input_digest
+
+
+def test_signature_verifies_and_detects_tampering():
+    signer = JudgmentSigner(b"secret", key_id="prod")
+    judgment = evaluate(agent_output="We should deploy now.")
+    signature = signer.sign(judgment)
+
+    assert signature.algorithm == "hmac-sha256"
+    assert signer.verify(judgment, signature)
+    assert not signer.verify(judgment.model_copy(update={"status": "acceptable"}), signature)
+    assert not JudgmentSigner(b"other", key_id="prod").verify(judgment, signature)
+    assert not JudgmentSigner(b"secret", key_id="staging").verify(judgment, signature)
+    assert not signer.verify(judgment, {"value": 1, "algorithm": []})
+
+
+def test_signature_binds_payload_id_and_input():
+    signer = JudgmentSigner(b"secret")
+    payload = {"id": "case-1", "agent_output": "The deployment is blocked."}
+    judgment = evaluate(agent_output=payload["agent_output"])
+    signature = signer.sign(judgment, payload_id="case-1", payload=payload)
+
+    assert signer.verify(judgment, signature, payload_id="case-1", payload=payload)
+    assert not signer.verify(judgment, signature, payload_id="case-2")
+    other = {"id": "case-1", "agent_output": "The deployment is not blocked."}
+    assert not signer.verify(judgment, signature, payload_id="case-1", payload=other)
+    forged = {**signature.model_dump(), "input_hash": input_digest(other)}
+    assert not signer.verify(judgment, forged, payload_id="case-1")
+
+
+def test_canonical_bytes_ignore_record_only_fields():
+    judgment = evaluate(agent_output="Hello!")
+    record = {"id": "x", **judgment.model_dump(mode="json"), "signature": {"value": "00"}}
+    assert canonical_judgment_bytes(record) == canonical_judgment_bytes(judgment)
+
+
+def test_empty_key_is_rejected():
+    with pytest.raises(ValueError):
+        JudgmentSigner(b"")
+
+
+def test_stream_signs_each_record(tmp_path, monkeypatch, capsys):
+    key_file = tmp_path / "key"
+    key_file.write_text("secret\n")
+    lines = [json.dumps({"id": "a", "agent_output": "We should deploy now."})]
+    monkeypatch.setattr(sys, "stdin", io.StringIO("".join(f"{line}\n" for line in lines)))
+
+    assert cli_main(["stream", "--signing-key-file", str(key_file), "--signing-key-id", "k1"]) == 0
+    record = json.loads(capsys.readouterr().out)
+
+    assert record["signature"]["key_id"] == "k1"
+    signer = JudgmentSigner(b"secret", key_id="k1")
+    assert signer.verify_record(record)
+    assert signer.verify_record(record, payload=json.loads(lines[0]))
+    assert not signer.verify_record({**record, "id": "b"})
+    record["status"] = "acceptable"
+    assert not signer.verify_record(record)
+
+
+def test_canonical_stream_records_verify(tmp_path, monkeypatch, capsys):
+    key_file = tmp_path / "key"
+    key_file.write_text("secret\n")
+    lines = [json.dumps({"id": "a", "agent_output": "We should deploy now."})]
+    monkeypatch.setattr(sys, "stdin", io.StringIO("".join(f"{line}\n" for line in lines)))
+
+    assert cli_main(["stream", "--signing-key-file", str(key_file), "--canonical"]) == 0
+    output = capsys.readouterr().out
+    record = json.loads(output)
+
+    assert '"confidence":1,' in output
+    assert JudgmentSigner(b"secret").verify_record(record)
+
+
+def test_evaluate_signs_printed_judgment(tmp_path, capsys):
+    key_file = tmp_path / "key"
+    key_file.write_bytes(b"secret")
+
+    argv = ["evaluate", "--agent-output", "Hello!", "--signing-key-file", str(key_file)]
+    assert cli_main(argv) == 0
+    record = json.loads(capsys.readouterr().out)
+    signer = JudgmentSigner(b"secret")
+    assert signer.verify_record(record, payload={"agent_output": "Hello!"})
+    assert not signer.verify_record(record, payload={"agent_output": "Hi!"})
+
+
+def test_signing_key_id_requires_key_file(capsys):
+    with pytest.raises(SystemExit):
+        cli_main(["evaluate", "--agent-output", "Hello!", "--signing-key-id", "k1"])
+    assert "--signing-key-id requires --signing-key-file" in capsys.readouterr().err
@@ -0,0 +1,95 @@
+import io
+import json
+import sqlite3
+import sys
+
+import pytest
+
+from normcore import evaluate
+from normcore.cli import main as cli_main
+from normcore.doctor import DOCTOR_SCENARIOS
+from normcore.payloads import render_result_record
+from normcore.sinks import SQLITE_SCHEMA_VERSION, SqliteSink
+
+
+def _stream_into(database, monkeypatch, capsys):
+    grounded = {"id": "grounded", **DOCTOR_SCENARIOS[0].payload}
+    lines = [json.dumps(grounded), json.dumps({"id": 2, "agent_output": "Hello!"}), "not json"]
+    monkeypatch.setattr(sys, "stdin", io.StringIO("\n".join(lines) + "\n"))
+    assert cli_main(["stream", "--output", f"sqlite:{database}"]) == 0
+    assert capsys.readouterr().out == ""
+
+
+def test_stream_writes_relational_tables(tmp_path, monkeypatch, capsys):
+    database = tmp_path / "results.db"
+    _stream_into(database, monkeypatch, capsys)
+
+    with sqlite3.connect(database) as connection:
+        judgments = connection.execute(
+            "SELECT run_id, record, payload_id, status, error IS NOT NULL, record_json "
+            "FROM judgments ORDER BY judgment_id"
+        ).fetchall()
+        assert [row[:5] for row in judgments] == [
+            (1, 1, "grounded", "acceptable", 0),
+            (1, 2, "2", "no_normative_content", 0),
+            (1, 3, None, None, 1),
+        ]
+        assert json.loads(judgments[0][5])["id"] == "grounded"
+
+        statements = connection.execute(
+            "SELECT j.payload_id, s.statement, s.status, s.license "
+            "FROM statements s JOIN judgments j USING (judgment_id)"
+        ).fetchall()
+        assert statements == [
+            (
+                "grounded",
+                "You should deploy now [@call_ci].",
+                "acceptable",
+                json.dumps(sorted(json.loads(statements[0][3]))),
+            )
+        ]
+
+        grounds = connection.execute(
+            "SELECT statement_index, trace_index, semantic_id, status FROM grounds"
+        ).fetchall()
+        assert grounds and all(row[:2] == (0, index) for index, row in enumerate(grounds))
+
+        indices = {row[0] for row in connection.execute("SELECT name FROM sqlite_master")}
+        assert {"judgments_status", "statements_violated_axiom", "grounds_ground_id"} <= indices
+        assert connection.execute("PRAGMA user_version").fetchone() == (SQLITE_SCHEMA_VERSION,)
+
+
+def test_runs_accumulate_in_the_same_database(tmp_path, monkeypatch, capsys):
+    database = tmp_path / "results.db"
+    _stream_into(database, monkeypatch, capsys)
+    _stream_into(database, monkeypatch, capsys)
+
+    with sqlite3.connect(database) as connection:
+        assert connection.execute("SELECT count(*) FROM runs").fetchone() == (2,)
+        counts = connection.execute(
+            "SELECT run_id, count(*) FROM judgments GROUP BY run_id"
+        ).fetchall()
+        assert counts == [(1, 3), (2, 3)]
+
+
+def test_sqlite_sink_commits_in_batches(tmp_path):
+    database = tmp_path / "results.db"
+    record = render_result_record(evaluate(agent_output="We should deploy now."))
+
+    sink = SqliteSink(database, commit_interval=2)
+    for _ in range(3):
+        sink.write(record)
+    with sqlite3.connect(database) as reader:
+        assert reader.execute("SELECT count(*) FROM judgments").fetchone() == (2,)
+    sink.close()
+    with sqlite3.connect(database) as reader:
+        assert reader.execute("SELECT count(*) FROM judgments").fetchone() == (3,)
+
+
+def test_sqlite_sink_rejects_newer_schema(tmp_path):
+    database = tmp_path / "results.db"
+    with sqlite3.connect(database) as connection:
+        connection.execute(f"PRAGMA user_version = {SQLITE_SCHEMA_VERSION + 1}")
+
+    with pytest.raises(ValueError, match="schema version"):
+        SqliteSink(database)
@@ -0,0 +1,69 @@
+import io
+import json
+import sys
+
+from normcore.cli import main as cli_main
+
+
+def _run_stream(monkeypatch, capsys, lines):
+    monkeypatch.setattr(sys, "stdin", io.StringIO("".join(f"{line}\n" for line in lines)))
+    assert cli_main(["stream"]) == 0
+    return [json.loads(line) for line in capsys.readouterr().out.splitlines()]
+
+
+def test_stream_writes_one_judgment_per_payload_line(monkeypatch, capsys):
+    records = _run_stream(
+        monkeypatch,
+        capsys,
+        [
+            json.dumps({"agent_output": "We should deploy now."}),
+            "",
+            json.dumps(
+                {
+                    "id": "case-2",
+                    "conversation": [
+                        {"role": "assistant", "content": "If tests pass, we should deploy."}
+                    ],
+                }
+            ),
+        ],
+    )
+    assert [r["status"] for r in records] == ["violates_norm", "conditionally_acceptable"]
+    assert "id" not in records[0]
+    assert records[1]["id"] == "case-2"
+
+
+def test_stream_reports_bad_lines_and_keeps_going(monkeypatch, capsys):
+    records = _run_stream(
+        monkeypatch,
+        capsys,
+        [
+            "{not json}",
+            json.dumps({"id": 7, "conversation": []}),
+            json.dumps({"agent_output": "x", "extra": 1}),
+            json.dumps({"agent_output": "The deployment is blocked."}),
+        ],
+    )
+    assert records[0]["line"] == 1 and "Invalid JSON" in records[0]["error"]
+    assert records[1]["id"] == 7 and "non-empty" in records[1]["error"]
+    assert "Unknown payload fields: extra" in records[2]["error"]
+    assert records[3]["status"] == "unsupported"
+
+
+def test_stream_rejects_non_finite_json_numbers(monkeypatch, capsys):
+    records = _run_stream(monkeypatch, capsys, ['{"agent_output": "x", "span": [NaN, 1]}'])
+    assert "NaN is not valid JSON" in records[0]["error"]
+
+
+def test_stream_payload_domain_selects_policy_risk_floor(tmp_path, monkeypatch, capsys):
+    lines = [
+        json.dumps({"agent_output": "Hello!", "domain": "medical"}),
+        json.dumps({"agent_output": "Hello!"}),
+    ]
+    monkeypatch.setattr(sys, "stdin", io.StringIO("".join(f"{line}\n" for line in lines)))
+    config = tmp_path / "normcore.toml"
+    config.write_text('[policy.domain_risk_floors]\nmedical = "high"\n')
+    assert cli_main(["--config", str(config), "stream"]) == 0
+
+    records = [json.loads(line) for line in capsys.readouterr().out.splitlines()]
+    assert [record["risk_tier"] for record in records] == ["high", "low"]
@@ -0,0 +1,138 @@
+import io
+import json
+import threading
+import time
+
+import pytest
+
+from normcore import cli, evaluate
+from normcore.limits import InputLimitError
+from normcore.serving import (
+    EvaluationPool,
+    EvaluationTimeoutError,
+    ProcessEvaluationPool,
+    read_payload_lines,
+)
+
+
+def _lines(*payloads):
+    return io.StringIO("".join(json.dumps(p) + "\n" for p in payloads))
+
+
+def _slow_evaluate(real):
+    def evaluate_payload(payload, **options):
+        time.sleep(payload.pop("sleep", 0))
+        return real(payload, **options)
+
+    return evaluate_payload
+
+
+def test_workers_evaluate_concurrently_and_keep_input_order(monkeypatch):
+    monkeypatch.setattr(cli, "evaluate_payload", _slow_evaluate(cli.evaluate_payload))
+    output = io.StringIO()
+    cli._run_stream(
+        _lines(
+            {"id": 1, "agent_output": "We should deploy now.", "sleep": 0.2},
+            {"id": 2, "agent_output": "If tests pass, we should deploy.", "sleep": 0.2},
+            {"id": 3, "agent_output": "The deployment is blocked."},
+        ),
+        output,
+        workers=3,
+    )
+    records = [json.loads(line) for line in output.getvalue().splitlines()]
+    assert [(r["id"], r["status"]) for r in records] == [
+        (1, "violates_norm"),
+        (2, "conditionally_acceptable"),
+        (3, "unsupported"),
+    ]
+
+
+def test_timeout_reports_error_line_and_continues(monkeypatch):
+    monkeypatch.setattr(cli, "evaluate_payload", _slow_evaluate(cli.evaluate_payload))
+    output = io.StringIO()
+    cli._run_stream(
+        _lines(
+            {"id": "slow", "agent_output": "We should deploy now.", "sleep": 0.5},
+            {"id": "fast", "agent_output": "We should deploy now."},
+        ),
+        output,
+        workers=2,
+        timeout=0.1,
+    )
+    slow, fast = [json.loads(line) for line in output.getvalue().splitlines()]
+    assert slow == {"id": "slow", "line": 1, "error": "Evaluation timed out after 0.1s"}
+    assert fast["status"] == "violates_norm"
+
+
+def test_oversized_lines_are_rejected_without_parsing():
+    big = {"agent_output": "We should deploy now. " * 50}
+    output = io.StringIO()
+    cli._run_stream(
+        _lines(big, {"agent_output": "We should deploy now."}), output, max_payload_bytes=200
+    )
+    first, second = [json.loads(line) for line in output.getvalue().splitlines()]
+    assert first == {"line": 1, "error": "Payload exceeds 200 bytes"}
+    assert second["status"] == "violates_norm"
+
+
+def test_stop_finishes_in_flight_payloads_and_stops_reading():
+    class BlockingInput:
+        def __init__(self):
+            self.lines = [json.dumps({"agent_output": "We should deploy now."}) + "\n"]
+            self.release = threading.Event()
+
+        def readline(self, *args):
+            if self.lines:
+                return self.lines.pop()
+            self.release.wait(5)
+            return json.dumps({"agent_output": "never evaluated"}) + "\n"
+
+    stream = BlockingInput()
+    stop = threading.Event()
+    output = io.StringIO()
+    runner = threading.Thread(target=cli._run_stream, args=(stream, output), kwargs={"stop": stop})
+    runner.start()
+    deadline = time.monotonic() + 5
+    while not output.getvalue() and time.monotonic() < deadline:
+        time.sleep(0.01)
+    stop.set()
+    runner.join(5)
+    stream.release.set()
+
+    assert not runner.is_alive()
+    [record] = [json.loads(line) for line in output.getvalue().splitlines()]
+    assert record["status"] == "violates_norm"
+
+
+def test_evaluation_pool_timeout_counts_from_start_of_evaluation():
+    with EvaluationPool(1, timeout=0.2) as pool:
+        first = pool.submit(time.sleep, 0.15)
+        second = pool.submit(time.sleep, 0.15)
+        assert first.result() is None
+        assert second.result() is None
+        hung = pool.submit(time.sleep, 0.4)
+        with pytest.raises(EvaluationTimeoutError):
+            hung.result()
+
+
+def test_process_pool_dispatches_chunks_and_keeps_per_call_results():
+    conversation = [{"role": "user", "content": "Hi"}, {"role": "assistant", "content": "Hello!"}]
+    with ProcessEvaluationPool(2, chunksize=3) as pool:
+        assert pool.capacity == 6
+        jobs = [pool.submit(int, value) for value in ("0", "1", "2", "x")]
+        limited = pool.submit(evaluate, conversation=conversation, policy={"max_messages": 1})
+        assert jobs[0]._dispatched and not limited._dispatched
+        assert [job.result() for job in jobs[:3]] == [0, 1, 2]
+        with pytest.raises(ValueError):
+            jobs[3].result()
+        with pytest.raises(InputLimitError) as excinfo:
+            limited.result()
+    assert (excinfo.value.limit, excinfo.value.actual) == ("max_messages", 2)
+
+
+def test_read_payload_lines_counts_blank_lines_and_skips_them():
+    lines = list(read_payload_lines(io.StringIO('{"a": 1}\n\n{"b": 2}\n')))
+    assert [(line.line_number, line.raw.strip()) for line in lines] == [
+        (1, '{"a": 1}'),
+        (3, '{"b": 2}'),
+    ]
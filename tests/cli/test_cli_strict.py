@@ -0,0 +1,55 @@
+import io
+import json
+import sys
+
+import pytest
+
+from normcore import evaluate
+from normcore.cli import main as cli_main
+
+_BAD_GROUNDS = [{"ground_id": "file_doc"}]  # citation_key missing
+
+
+def test_strict_evaluation_rejects_recoverable_input_issues():
+    lenient = evaluate(agent_output="We should deploy now.", grounds=_BAD_GROUNDS)
+    assert lenient.warnings
+
+    with pytest.raises(ValueError, match="strict mode: Invalid grounds ignored"):
+        evaluate(agent_output="We should deploy now.", grounds=_BAD_GROUNDS, strict=True)
+
+
+def test_strict_evaluation_accepts_clean_input():
+    judgment = evaluate(
+        agent_output="We should deploy now [@doc].",
+        grounds=[{"citation_key": "doc", "ground_id": "file_doc"}],
+        strict=True,
+    )
+    assert judgment.status.value == "acceptable"
+
+
+def test_stream_strict_turns_issues_into_error_lines(monkeypatch, capsys):
+    lines = [
+        json.dumps({"id": "a", "agent_output": "We should deploy now.", "grounds": _BAD_GROUNDS}),
+        json.dumps({"id": "b", "agent_output": "Hello!"}),
+    ]
+    monkeypatch.setattr(sys, "stdin", io.StringIO("".join(f"{line}\n" for line in lines)))
+
+    assert cli_main(["stream", "--strict"]) == 0
+    records = [json.loads(line) for line in capsys.readouterr().out.splitlines()]
+    assert records[0]["id"] == "a"
+    assert records[0]["error"].startswith("strict mode: Invalid grounds ignored")
+    assert records[1]["status"] == "no_normative_content"
+
+
+def test_evaluate_strict_flag_reports_usage_error(capsys):
+    argv = [
+        "evaluate",
+        "--agent-output",
+        "We should deploy now.",
+        "--grounds",
+        json.dumps(_BAD_GROUNDS),
+        "--strict",
+    ]
+    with pytest.raises(SystemExit):
+        cli_main(argv)
+    assert "strict mode" in capsys.readouterr().err
@@ -0,0 +1,111 @@
+import builtins
+import json
+
+import pytest
+
+from normcore.cli import main as cli_main
+from normcore.inputs import load_structured
+
+pytest.importorskip("yaml")
+
+_CONVERSATION_YAML = """\
+# Hand-authored scenario
+- role: assistant
+  tool_calls:
+    - id: call_ci
+      type: function
+      function:
+        name: get_ci_status
+        arguments: '{}'
+- role: tool
+  tool_call_id: call_ci
+  content: '{"pipeline_id": "ci-42", "status": "green"}'
+- role: assistant
+  content: You should deploy now [@call_ci].
+"""
+
+
+def _judgment(capsys, argv):
+    assert cli_main(argv) == 0
+    return json.loads(capsys.readouterr().out)
+
+
+def test_evaluate_reads_conversation_and_policy_from_yaml(tmp_path, capsys):
+    (tmp_path / "conversation.yaml").write_text(_CONVERSATION_YAML, encoding="utf-8")
+    (tmp_path / "policy.yml").write_text("risk_floor: high\n", encoding="utf-8")
+
+    judgment = _judgment(
+        capsys,
+        [
+            "evaluate",
+            "--conversation",
+            f"@{tmp_path / 'conversation.yaml'}",
+            "--policy",
+            f"@{tmp_path / 'policy.yml'}",
+        ],
+    )
+    assert judgment["status"] == "acceptable"
+    assert judgment["risk_tier"] == "high"
+
+
+def test_evaluate_reads_grounds_from_yaml_and_json_files(tmp_path, capsys):
+    (tmp_path / "grounds.yaml").write_text(
+        "documents:\n  - citation_key: runbook\n    ground_id: runbook\n", encoding="utf-8"
+    )
+    (tmp_path / "grounds.json").write_text(json.dumps({"documents": ["runbook"]}), encoding="utf-8")
+    argv = ["evaluate", "--agent-output", "You should deploy now [@runbook].", "--grounds"]
+
+    from_yaml = _judgment(capsys, [*argv, f"@{tmp_path / 'grounds.yaml'}"])
+    from_json = _judgment(capsys, [*argv, f"@{tmp_path / 'grounds.json'}"])
+    assert from_yaml["status"] == from_json["status"] == "acceptable"
+
+
+def test_structured_input_errors_are_usage_errors(tmp_path, capsys):
+    (tmp_path / "broken.yaml").write_text("- role: [unclosed\n", encoding="utf-8")
+    for value in (f"@{tmp_path / 'broken.yaml'}", f"@{tmp_path / 'missing.json'}", "[oops"):
+        with pytest.raises(SystemExit) as exc:
+            cli_main(["evaluate", "--conversation", value])
+        assert exc.value.code == 2
+        assert "Failed to parse --conversation" in capsys.readouterr().err
+
+
+def test_yaml_without_pyyaml_explains_the_extra(tmp_path, monkeypatch):
+    path = tmp_path / "policy.yaml"
+    path.write_text("risk_floor: high\n", encoding="utf-8")
+    real_import = builtins.__import__
+
+    def without_yaml(name, *args, **kwargs):
+        if name == "yaml":
+            raise ImportError(name)
+        return real_import(name, *args, **kwargs)
+
+    monkeypatch.setattr(builtins, "__import__", without_yaml)
+    with pytest.raises(ValueError, match=r"normcore\[yaml\]"):
+        load_structured(f"@{path}")
+
+
+def test_evaluate_reads_toml_conversation_grounds_and_policy(tmp_path, capsys):
+    (tmp_path / "conversation.toml").write_text(
+        '[[conversation]]\nrole = "assistant"\ncontent = "You should deploy now [@runbook]."\n',
+        encoding="utf-8",
+    )
+    (tmp_path / "grounds.toml").write_text(
+        '[[documents]]\ncitation_key = "runbook"\nground_id = "kb-17"\n', encoding="utf-8"
+    )
+    (tmp_path / "policy.toml").write_text('risk_floor = "medium"\n', encoding="utf-8")
+
+    judgment = _judgment(
+        capsys,
+        [
+            "evaluate",
+            "--conversation",
+            f"@{tmp_path / 'conversation.toml'}",
+            "--grounds",
+            f"@{tmp_path / 'grounds.toml'}",
+            "--policy",
+            f"@{tmp_path / 'policy.toml'}",
+        ],
+    )
+    assert judgment["status"] == "acceptable"
+    assert judgment["cited_ground_ids"] == ["kb-17"]
+    assert judgment["risk_tier"] == "medium"
@@ -0,0 +1,77 @@
+import io
+import json
+import threading
+
+import pytest
+
+from normcore import cli
+from normcore.cli import main as cli_main
+
+
+@pytest.fixture
+def worker(monkeypatch):
+    """
+    Run ``stream --unix-socket`` with connections simulated in memory: each input
+    is one connection's bytes; returns each connection's records, in input order.
+    """
+
+    def run(*inputs, concurrent=False):
+        outputs = [io.BytesIO() for _ in inputs]
+
+        def serve_unix_socket(path, handle, *, stop):
+            connections = [
+                threading.Thread(target=handle, args=(io.BytesIO(data), output))
+                for data, output in zip(inputs, outputs)
+            ]
+            for connection in connections:
+                connection.start()
+                if not concurrent:
+                    connection.join()
+            for connection in connections:
+                connection.join(timeout=10)
+
+        monkeypatch.setattr(cli, "serve_unix_socket", serve_unix_socket)
+        assert cli_main(["stream", "--unix-socket", "normcore.sock"]) == 0
+        return [
+            [json.loads(line) for line in output.getvalue().decode("utf-8").splitlines()]
+            for output in outputs
+        ]
+
+    return run
+
+
+def _lines(*lines):
+    return "".join(line + "\n" for line in lines).encode("utf-8")
+
+
+def test_each_connection_is_answered_on_its_own_stream(worker):
+    first, second = worker(
+        _lines(json.dumps({"id": "a", "agent_output": "We should deploy now."})),
+        _lines(json.dumps({"id": "b", "agent_output": "Hello!"}), "not json"),
+    )
+
+    assert [(record["id"], record["status"]) for record in first] == [("a", "violates_norm")]
+    assert second[0]["status"] == "no_normative_content"
+    assert second[1]["error"].startswith("Invalid JSON payload")
+
+
+def test_concurrent_connections_are_served_independently(worker):
+    inputs = [
+        _lines(*[json.dumps({"id": name, "agent_output": "We should deploy now."})] * 3)
+        for name in ("x", "y", "z")
+    ]
+
+    answers = worker(*inputs, concurrent=True)
+
+    for name, records in zip(("x", "y", "z"), answers):
+        assert [record["id"] for record in records] == [name] * 3
+
+
+def test_refuses_a_path_that_is_not_a_socket(tmp_path, capsys):
+    path = tmp_path / "taken"
+    path.write_text("keep me", encoding="utf-8")
+
+    with pytest.raises(SystemExit):
+        cli_main(["stream", "--unix-socket", str(path)])
+    assert "Cannot listen on --unix-socket" in capsys.readouterr().err
+    assert path.read_text(encoding="utf-8") == "keep me"
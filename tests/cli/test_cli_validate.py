@@ -0,0 +1,165 @@
+import io
+import json
+import sys
+
+import pytest
+
+from normcore import validate_payload
+from normcore.cli import main as cli_main
+
+
+def _tool_call(call_id, arguments='{"service": "api"}'):
+    return {
+        "id": call_id,
+        "type": "function",
+        "function": {"name": "get_status", "arguments": arguments},
+    }
+
+
+def _issues(payload):
+    return [(issue.path, issue.message) for issue in validate_payload(payload).issues]
+
+
+def test_valid_conversation_has_no_issues():
+    payload = {
+        "id": "turn-1",
+        "conversation": [
+            {"role": "user", "content": [{"type": "text", "text": "Can we deploy?"}]},
+            {"role": "assistant", "content": None, "tool_calls": [_tool_call("call_1")]},
+            {"role": "tool", "tool_call_id": "call_1", "content": '{"status": "green"}'},
+            {"type": "reasoning", "summary": []},
+            {"role": "assistant", "content": "We should deploy now [@call_1]."},
+        ],
+        "span": [0, 10],
+    }
+    report = validate_payload(payload)
+    assert report.valid
+    assert report.issues == []
+
+
+def test_reports_every_problem_with_its_location():
+    payload = {
+        "extra": True,
+        "conversation": [
+            {"role": "bot", "content": "Hi"},
+            {"role": "user", "content": [{"type": "refusal", "refusal": "no"}]},
+            {
+                "role": "assistant",
+                "tool_calls": [_tool_call("call_1", "{bad"), _tool_call("call_1")],
+            },
+            {"role": "tool", "tool_call_id": "call_9", "content": "{}"},
+            {"role": "assistant", "content": "Deploy now."},
+        ],
+        "agent_output": "Deploy later.",
+    }
+
+    assert _issues(payload) == [
+        ("extra", "Unknown payload field"),
+        (
+            "conversation[0].role",
+            "Unknown role 'bot' (expected one of system, developer, user, assistant, tool, "
+            "function)",
+        ),
+        (
+            "conversation[1].content[0].type",
+            "Content part type 'refusal' is not allowed in user messages "
+            "(expected one of file, image_url, input_audio, text)",
+        ),
+        ("conversation[2].tool_calls[0].function.arguments", "Arguments must encode a JSON object"),
+        ("conversation[2].tool_calls[1].id", "Duplicate tool call id 'call_1'"),
+        (
+            "conversation[3].tool_call_id",
+            "'call_9' matches no tool call of a preceding assistant message",
+        ),
+        ("agent_output", "Does not match the assistant content of conversation[4]"),
+    ]
+
+
+def test_tool_result_before_its_call_and_repeated_results():
+    payload = {
+        "conversation": [
+            {"role": "tool", "tool_call_id": "call_1", "content": "{}"},
+            {"role": "assistant", "content": None, "tool_calls": [_tool_call("call_1")]},
+            {"role": "tool", "tool_call_id": "call_1", "content": "{}"},
+            {"role": "tool", "tool_call_id": "call_1", "content": "{}"},
+            {"role": "user", "content": "Well?"},
+        ]
+    }
+
+    assert _issues(payload) == [
+        (
+            "conversation[0].tool_call_id",
+            "'call_1' matches no tool call of a preceding assistant message",
+        ),
+        ("conversation[3].tool_call_id", "Tool call 'call_1' already has a result"),
+        ("conversation[4]", "Last conversation item must be an assistant message"),
+    ]
+
+
+def test_grounds_links_and_knowledge_nodes_are_checked_per_item():
+    payload = {
+        "agent_output": "We should deploy now.",
+        "grounds": [{"citation_key": "deploy", "ground_id": "g1"}, {"citation_key": 3}],
+        "links": {"links": [{"statement_id": "final_response", "ground_id": "g1"}]},
+        "knowledge_nodes": [{"id": "n1", "bogus": 1}, 5],
+        "statement_ids": ["final_response", 2],
+    }
+
+    issues = _issues(payload)
+
+    assert ("statement_ids", "Expected an array of strings") in issues
+    assert [path for path, _ in issues if path.startswith("grounds")] == [
+        "grounds[1].citation_key",
+        "grounds[1].ground_id",
+    ]
+    assert ("links.links[0].role", "Field required") in issues
+    assert ("knowledge_nodes[0]", "unknown fields: bogus") in issues
+    assert ("knowledge_nodes[1]", "expected an object, got int") in issues
+
+
+@pytest.mark.parametrize(
+    "payload,issue",
+    [
+        ([], ("", "Payload must be an object, got array")),
+        ({"id": "x"}, ("", "Payload requires agent_output or conversation")),
+        ({"conversation": []}, ("conversation", "Expected a non-empty array of messages")),
+        ({"agent_output": 42}, ("agent_output", "Expected a string, got number")),
+        (
+            {"agent_output": "Hi", "span": [1]},
+            ("span", "Expected a pair of integer offsets [start, end]"),
+        ),
+        ({"agent_output": "Hi", "domain": 1}, ("domain", "Expected a string, got number")),
+    ],
+)
+def test_payload_level_issues(payload, issue):
+    assert _issues(payload) == [issue]
+
+
+def test_validate_command_prints_report_and_fails_on_issues(capsys):
+    payload = {"conversation": [{"role": "tool", "tool_call_id": "call_1", "content": "{}"}]}
+
+    assert cli_main(["validate", "--payload", json.dumps(payload)]) == 1
+
+    report = json.loads(capsys.readouterr().out)
+    assert report["valid"] is False
+    assert [issue["path"] for issue in report["issues"]] == [
+        "conversation[0].tool_call_id",
+        "conversation[0]",
+    ]
+
+
+def test_validate_command_reads_stdin(monkeypatch, capsys):
+    monkeypatch.setattr(sys, "stdin", io.StringIO('{"agent_output": "Hello!"}'))
+
+    assert cli_main(["validate"]) == 0
+
+    assert json.loads(capsys.readouterr().out) == {"valid": True, "issues": []}
+
+
+def test_validate_command_rejects_unparsable_input(monkeypatch, capsys):
+    monkeypatch.setattr(sys, "stdin", io.StringIO("not json"))
+
+    with pytest.raises(SystemExit):
+        cli_main(["validate"])
+
+    assert "Failed to parse payload from stdin" in capsys.readouterr().err
@@ -0,0 +1,125 @@
+import io
+import json
+import sys
+import threading
+import time
+from urllib.error import URLError
+
+from normcore import evaluate, webhooks
+from normcore.cli import main as cli_main
+from normcore.http_server import EvaluationService
+from normcore.serving import EvaluationPool
+from normcore.signing import JudgmentSigner
+from normcore.webhooks import WebhookNotifier
+
+
+class _Response:
+    status = 204
+
+    def __enter__(self):
+        return self
+
+    def __exit__(self, *exc):
+        return False
+
+
+def _capture_posts(monkeypatch, fail_urls=()):
+    posts = []
+
+    def fake_urlopen(request, timeout):
+        if request.full_url in fail_urls:
+            raise URLError("connection refused")
+        posts.append((request.full_url, request.get_method(), json.loads(request.data), timeout))
+        return _Response()
+
+    monkeypatch.setattr(webhooks, "urlopen", fake_urlopen)
+    return posts
+
+
+def test_notifier_posts_only_alerting_statuses(monkeypatch):
+    posts = _capture_posts(monkeypatch)
+    with WebhookNotifier(["http://hook.test/a"], timeout=2.0) as notifier:
+        assert not notifier.notify(evaluate(agent_output="Hello!"))
+        assert notifier.notify(evaluate(agent_output="We should deploy now."), payload_id="t1")
+
+    assert len(posts) == 1
+    url, method, body, timeout = posts[0]
+    assert (url, method, timeout) == ("http://hook.test/a", "POST", 2.0)
+    assert body["id"] == "t1"
+    assert body["status"] == "violates_norm"
+
+
+def test_notifier_failure_does_not_raise(monkeypatch):
+    posts = _capture_posts(monkeypatch, fail_urls={"http://hook.test/down"})
+    notifier = WebhookNotifier(["http://hook.test/down", "http://hook.test/up"])
+
+    assert notifier.deliver(evaluate(agent_output="We should deploy now.")) == 1
+    assert [p[0] for p in posts] == ["http://hook.test/up"]
+
+
+def test_notify_returns_before_delivery_and_bounds_the_backlog(monkeypatch):
+    posts = _capture_posts(monkeypatch)
+    release = threading.Event()
+    deliver = WebhookNotifier.deliver
+
+    def blocked_deliver(self, judgment, **kwargs):
+        release.wait()
+        return deliver(self, judgment, **kwargs)
+
+    monkeypatch.setattr(WebhookNotifier, "deliver", blocked_deliver)
+    judgment = evaluate(agent_output="We should deploy now.")
+    notifier = WebhookNotifier(["http://hook.test/a"], max_backlog=1)
+
+    # The worker holds the first alert; the second fills the backlog; the third is dropped
+    assert notifier.notify(judgment, payload_id=1)
+    while notifier._alerts.qsize():
+        time.sleep(0.01)
+    assert notifier.notify(judgment, payload_id=2)
+    assert not notifier.notify(judgment, payload_id=3)
+    assert posts == []
+
+    release.set()
+    notifier.close()
+    assert [body["id"] for _, _, body, _ in posts] == [1, 2]
+
+
+def test_webhook_bodies_are_signed_when_a_signer_is_configured(monkeypatch):
+    posts = _capture_posts(monkeypatch)
+    signer = JudgmentSigner(b"k" * 32, key_id="hooks")
+    payload = {"id": "t1", "agent_output": "We should deploy now."}
+
+    judgment = evaluate(agent_output=payload["agent_output"])
+    with WebhookNotifier(["http://hook.test/a"], signer=signer) as notifier:
+        notifier.notify(judgment, payload_id="t1", payload=payload)
+
+    [(_, _, body, _)] = posts
+    assert body["signature"]["key_id"] == "hooks"
+    assert signer.verify_record(body, payload=payload)
+
+
+def test_stream_webhook_flag_posts_violations(monkeypatch, capsys):
+    posts = _capture_posts(monkeypatch)
+    lines = [
+        json.dumps({"id": "ok", "agent_output": "If tests pass, we should deploy."}),
+        json.dumps({"id": "bad", "agent_output": "We should deploy now."}),
+    ]
+    monkeypatch.setattr(sys, "stdin", io.StringIO("".join(f"{line}\n" for line in lines)))
+
+    assert cli_main(["stream", "--webhook", "http://hook.test/a"]) == 0
+
+    assert len(capsys.readouterr().out.splitlines()) == 2
+    assert [body["id"] for _, _, body, _ in posts] == ["bad"]
+
+
+def test_http_response_does_not_wait_for_webhook_delivery(monkeypatch):
+    release = threading.Event()
+    monkeypatch.setattr(WebhookNotifier, "deliver", lambda self, judgment, **kwargs: release.wait())
+    notifier = WebhookNotifier(["http://hook.test/slow"])
+
+    with EvaluationPool(1) as pool:
+        service = EvaluationService(pool, notifier=notifier)
+        record = service.handle(json.dumps({"agent_output": "We should deploy now."}).encode())
+
+    assert record["status"] == "violates_norm"
+    release.set()
+    notifier.close()
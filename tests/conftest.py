@@ -1,8 +1,79 @@
+import io
 import sys
+from http.client import HTTPResponse
 from pathlib import Path
 
+import pytest
+
 # Ensure src/ is on sys.path for test imports.
 PROJECT_ROOT = Path(__file__).resolve().parents[1]
 SRC_PATH = PROJECT_ROOT / "src"
 if str(SRC_PATH) not in sys.path:
     sys.path.insert(0, str(SRC_PATH))
+
+
+def _tool_call(call_id: str, name: str) -> dict:
+    return {"id": call_id, "type": "function", "function": {"name": name, "arguments": "{}"}}
+
+
+@pytest.fixture
+def ci_conversation():
+    """
+    Build a CI conversation: the user's ``question``, a ``get_ci_status`` call
+    (id ``call_ci``) answered with ``result``, optionally a ``get_runner_logs``
+    call (id ``call_logs``) answered with ``runner_logs``, then the assistant's
+    ``final`` text.
+    """
+
+    def build(
+        final: str,
+        result: str = '{"runner": "offline"}',
+        *,
+        question: str = "Why is CI red?",
+        runner_logs: str | None = None,
+    ) -> list[dict]:
+        calls = [_tool_call("call_ci", "get_ci_status")]
+        results = [{"role": "tool", "tool_call_id": "call_ci", "content": result}]
+        if runner_logs is not None:
+            calls.append(_tool_call("call_logs", "get_runner_logs"))
+            results.append({"role": "tool", "tool_call_id": "call_logs", "content": runner_logs})
+        return [
+            {"role": "user", "content": question},
+            {"role": "assistant", "content": None, "tool_calls": calls},
+            *results,
+            {"role": "assistant", "content": final},
+        ]
+
+    return build
+
+
+class _ResponseSocket:
+    def __init__(self, data: bytes) -> None:
+        self._data = data
+
+    def makefile(self, mode: str) -> io.BytesIO:
+        return io.BytesIO(self._data)
+
+
+@pytest.fixture
+def http_exchange():
+    """
+    Send one request to an ``http.server`` handler class in memory, without a
+    socket, and return ``(status, headers, body)`` of its response.
+    """
+
+    def exchange(handler_class, method, path, body=None, **headers):
+        data = body.encode("utf-8") if isinstance(body, str) else body or b""
+        if body is not None:
+            headers.setdefault("Content-Length", str(len(data)))
+        head = "".join(f"{name}: {value}\r\n" for name, value in headers.items())
+        handler = handler_class.__new__(handler_class)
+        handler.rfile = io.BytesIO(f"{method} {path} HTTP/1.1\r\n{head}\r\n".encode() + data)
+        handler.wfile = io.BytesIO()
+        handler.client_address = ("127.0.0.1", 0)
+        handler.handle_one_request()
+        response = HTTPResponse(_ResponseSocket(handler.wfile.getvalue()), method=method)
+        response.begin()
+        return response.status, response.headers, response.read()
+
+    return exchange
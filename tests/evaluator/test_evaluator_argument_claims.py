@@ -0,0 +1,81 @@
+import json
+
+import pytest
+from pydantic import ValidationError
+
+from normcore import EvaluationPolicy, evaluate
+from normcore.evaluator import AdmissibilityEvaluator
+
+
+def _email_turn(body: str) -> list[dict]:
+    return [
+        {"role": "user", "content": "Tell the team what happened."},
+        {
+            "role": "assistant",
+            "content": None,
+            "tool_calls": [
+                {
+                    "id": "call_mail",
+                    "type": "function",
+                    "function": {
+                        "name": "send_email",
+                        "arguments": json.dumps({"to": "team@example.com", "message": {"body": body}}),
+                    },
+                }
+            ],
+        },
+    ]
+
+
+def test_final_tool_call_arguments_are_not_evaluated_by_default():
+    judgment = evaluate(conversation=_email_turn("You must roll back the release now."))
+    assert judgment.status.value == "underdetermined"
+    assert judgment.statement_evaluations == []
+
+
+def test_configured_argument_fields_are_evaluated_as_claims():
+    judgment = evaluate(
+        conversation=_email_turn("You must roll back the release now."),
+        policy={"tool_claim_fields": {"send_email": ["message.body", "subject"]}},
+    )
+    assert judgment.status.value == "violates_norm"
+    assert [e.statement_id for e in judgment.statement_evaluations] == [
+        "tool_call:call_mail:message.body"
+    ]
+
+
+def test_argument_claims_can_cite_grounds():
+    conversation = [
+        {
+            "role": "assistant",
+            "content": None,
+            "tool_calls": [
+                {
+                    "id": "call_status",
+                    "type": "function",
+                    "function": {"name": "get_incident", "arguments": "{}"},
+                }
+            ],
+        },
+        {"role": "tool", "tool_call_id": "call_status", "content": '{"incident_id": "INC-1"}'},
+        *_email_turn("You should roll back the release [@call_status]."),
+    ]
+    judgment = evaluate(
+        conversation=conversation,
+        policy=EvaluationPolicy(tool_claim_fields={"send_email": ["message.body"]}),
+    )
+    assert judgment.status.value == "acceptable"
+    assert judgment.grounds_cited == 1
+
+
+def test_policy_rejects_unknown_settings():
+    with pytest.raises(ValidationError):
+        evaluate(agent_output="We should deploy.", policy={"no_such_setting": True})
+
+
+def test_policy_protocol_phrases_reach_extractor():
+    evaluator = AdmissibilityEvaluator(
+        policy=EvaluationPolicy(protocol_suffix_phrases=["Kind regards"])
+    )
+    statements = evaluator.extractor.extract("You should restart it. Kind regards, Acme")
+    assert statements[0].raw_text == "You should restart it."
@@ -0,0 +1,94 @@
+import pytest
+
+from normcore import JudgmentCache, evaluate, policy_pack
+from normcore.evaluator import DEFAULT_TASK_GOAL, AdmissibilityEvaluator
+from normcore.normative import Modality
+from normcore.normative.axiom_checker import AxiomChecker
+from normcore.normative.modality_detector import ModalityDetector
+
+
+class GoalRecordingChecker(AxiomChecker):
+    def __init__(self):
+        super().__init__()
+        self.goals = []
+
+    def check(self, statement, license, ground_set, task_goal, **context):
+        self.goals.append(task_goal)
+        return super().check(statement, license, ground_set, task_goal, **context)
+
+
+def test_default_builder_matches_evaluate():
+    evaluator = AdmissibilityEvaluator.builder().build()
+    text = "We should deploy now."
+
+    built = evaluate(agent_output=text, evaluator=evaluator)
+
+    assert built == evaluate(agent_output=text)
+    assert evaluator.task_goal == DEFAULT_TASK_GOAL
+
+
+def test_builder_swaps_stages_and_sets_task_goal():
+    checker = GoalRecordingChecker()
+    evaluator = (
+        AdmissibilityEvaluator.builder()
+        .modality_detector(ModalityDetector(keywords={Modality.CONDITIONAL: ["pending review"]}))
+        .axiom_checker(checker)
+        .task_goal("ship the release")
+        .build()
+    )
+
+    text = "We should deploy tonight, pending review."
+    judgment = evaluate(agent_output=text, evaluator=evaluator)
+
+    assert evaluate(agent_output=text).status == "violates_norm"
+    assert judgment.status == "conditionally_acceptable"
+    assert judgment.statement_evaluations[0].modality == "conditional"
+    assert checker.goals == ["ship the release"]
+    assert evaluator.axiom_checker is checker
+
+
+def test_unset_stages_follow_the_builder_policy():
+    evaluator = AdmissibilityEvaluator.builder().policy(policy_pack("strict")).build()
+
+    assert evaluator.policy == policy_pack("strict")
+    assert evaluator.extractor.flag_executable_code is True
+
+
+def test_builder_strict_and_trace_are_evaluator_defaults():
+    evaluator = AdmissibilityEvaluator.builder().strict().trace().build()
+
+    with pytest.raises(ValueError, match="Invalid grounds"):
+        evaluate(
+            agent_output="We should deploy now.",
+            grounds=[{"ground_id": "file_doc"}],
+            evaluator=evaluator,
+        )
+    judgment = evaluate(agent_output="We should deploy now.", evaluator=evaluator)
+    assert judgment.statement_evaluations[0].license_trace is not None
+
+
+def test_evaluate_trace_does_not_change_a_shared_evaluator():
+    evaluator = AdmissibilityEvaluator.builder().build()
+
+    traced = evaluate(agent_output="We should deploy now.", evaluator=evaluator, trace=True)
+
+    assert traced.statement_evaluations[0].license_trace is not None
+    assert evaluator.trace_licenses is False
+
+
+@pytest.mark.parametrize(
+    "kwargs,message",
+    [
+        ({"policy": {"risk_floor": "high"}}, "policy or evaluator, not both"),
+        ({"cache": JudgmentCache()}, "cache cannot be combined"),
+    ],
+)
+def test_evaluator_conflicts(kwargs, message):
+    evaluator = AdmissibilityEvaluator.builder().build()
+    with pytest.raises(ValueError, match=message):
+        evaluate(agent_output="Hello!", evaluator=evaluator, **kwargs)
+
+
+def test_task_goal_must_be_text():
+    with pytest.raises(ValueError, match="task_goal must be a non-empty string"):
+        AdmissibilityEvaluator.builder().task_goal(" ")
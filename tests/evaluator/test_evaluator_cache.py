@@ -0,0 +1,72 @@
+import pytest
+
+from normcore import JudgmentCache, evaluate
+from normcore.cache import evaluation_key
+from normcore.evaluator import AdmissibilityEvaluator
+from normcore.models import EvaluationPolicy
+
+_CONVERSATION = [{"role": "assistant", "content": "We should deploy now."}]
+
+
+def test_identical_evaluation_is_served_from_cache(monkeypatch):
+    cache = JudgmentCache()
+    first = evaluate(conversation=_CONVERSATION, cache=cache)
+
+    def fail(*args, **kwargs):
+        raise AssertionError("evaluator should not run on a cache hit")
+
+    monkeypatch.setattr(AdmissibilityEvaluator, "evaluate", fail)
+    second = evaluate(conversation=_CONVERSATION, cache=cache)
+
+    assert second == first
+    assert second is not first
+    assert (cache.hits, cache.misses, len(cache)) == (1, 1, 1)
+
+
+def test_policy_and_input_changes_miss():
+    cache = JudgmentCache()
+    evaluate(conversation=_CONVERSATION, cache=cache)
+    evaluate(conversation=_CONVERSATION, cache=cache, policy={"risk_floor": "high"})
+    evaluate(agent_output="We should deploy now.", cache=cache, span=(0, 9))
+    assert (cache.hits, cache.misses) == (0, 3)
+
+    evaluate(conversation=_CONVERSATION, cache=cache, policy=EvaluationPolicy(risk_floor="high"))
+    assert cache.hits == 1
+
+
+def test_cached_judgment_is_not_shared_with_callers():
+    cache = JudgmentCache()
+    evaluate(conversation=_CONVERSATION, cache=cache).warnings.append("edited")
+    assert evaluate(conversation=_CONVERSATION, cache=cache).warnings == []
+
+
+def test_least_recently_used_entry_is_evicted():
+    cache = JudgmentCache(maxsize=2)
+    for text in ("We should deploy.", "We should wait.", "We should deploy."):
+        evaluate(agent_output=text, cache=cache)
+    evaluate(agent_output="We should roll back.", cache=cache)
+
+    assert len(cache) == 2
+    hits = cache.hits
+    evaluate(agent_output="We should deploy.", cache=cache)
+    assert cache.hits == hits + 1
+    evaluate(agent_output="We should wait.", cache=cache)
+    assert cache.hits == hits + 1
+
+
+def test_one_shot_knowledge_nodes_are_evaluated_after_hashing():
+    cache = JudgmentCache()
+    nodes = ({"id": "ci", "source": "observed", "status": "confirmed"} for _ in range(1))
+    judgment = evaluate(
+        agent_output="You should deploy now [@ci].", knowledge_nodes=nodes, cache=cache
+    )
+    assert judgment.status == "acceptable"
+
+
+def test_evaluation_key_is_independent_of_mapping_order():
+    policy = EvaluationPolicy()
+    assert evaluation_key({"a": 1, "b": {"x": 1, "y": 2}}, policy) == evaluation_key(
+        {"b": {"y": 2, "x": 1}, "a": 1}, policy
+    )
+    with pytest.raises(ValueError, match="maxsize"):
+        JudgmentCache(maxsize=0)
@@ -0,0 +1,50 @@
+from normcore import evaluate
+
+ONE_CITATION = "You should restart the runner [@call_ci]."
+TWO_CITATIONS = "You should restart the runner [@call_ci] [@call_logs]."
+RUNNER_LOGS = '{"last_line": "OOM"}'
+
+
+def test_citation_count_is_not_enforced_by_default(ci_conversation):
+    judgment = evaluate(conversation=ci_conversation(ONE_CITATION, runner_logs=RUNNER_LOGS))
+    assert judgment.status == "acceptable"
+
+
+def test_under_cited_assertive_statement_fails_attribution_policy(ci_conversation):
+    judgment = evaluate(
+        conversation=ci_conversation(ONE_CITATION, runner_logs=RUNNER_LOGS),
+        policy={"min_citations_per_assertive": 2},
+    )
+
+    assert judgment.status == "insufficient_citations"
+    assert judgment.violated_axioms == ["A8"]
+    assert judgment.can_retry is True
+    assert judgment.risk_tier == "medium"
+
+
+def test_sufficiently_cited_statement_meets_attribution_policy(ci_conversation):
+    judgment = evaluate(
+        conversation=ci_conversation(TWO_CITATIONS, runner_logs=RUNNER_LOGS),
+        policy={"min_citations_per_assertive": 2},
+    )
+    assert judgment.status == "acceptable"
+
+
+def test_density_requirement_scales_with_statement_length(ci_conversation):
+    # 6 words at 40 citations per 200 words -> 2 citations required
+    judgment = evaluate(
+        conversation=ci_conversation(ONE_CITATION, runner_logs=RUNNER_LOGS),
+        policy={"min_citations_per_200_words": 40},
+    )
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.status == "insufficient_citations"
+    assert evaluation.explanation.endswith("policy requires 2")
+
+
+def test_attribution_policy_never_masks_a_violation():
+    judgment = evaluate(
+        agent_output="You should restart the runner.",
+        policy={"min_citations_per_assertive": 1},
+    )
+    assert judgment.status == "violates_norm"
+    assert judgment.violated_axioms == ["A5"]
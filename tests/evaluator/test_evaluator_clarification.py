@@ -0,0 +1,15 @@
+from normcore import evaluate
+
+
+def test_clarification_request_is_acceptable_with_own_modality():
+    judgment = evaluate(agent_output="Which environment are you deploying to?")
+
+    assert judgment.status == "acceptable"
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.modality == "clarification"
+    assert evaluation.violated_axiom is None
+
+
+def test_help_prompt_is_still_protocol_only():
+    judgment = evaluate(agent_output="Hello! How can I help you today?")
+    assert judgment.status == "no_normative_content"
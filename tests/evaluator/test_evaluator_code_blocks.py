@@ -0,0 +1,21 @@
+from normcore import evaluate
+
+ANSWER = "You should free up disk space first.\n\n```bash\n# wipe the volume\nrm -rf /data\n```\n"
+
+
+def test_code_block_commands_are_not_evaluated_by_default():
+    judgment = evaluate(agent_output=ANSWER)
+    assert [e.statement_id for e in judgment.statement_evaluations] == ["final_response"]
+
+
+def test_flagged_command_requires_grounding():
+    judgment = evaluate(
+        agent_output=ANSWER,
+        policy={"code_blocks": "flag_executable"},
+        statement_ids=["code:1:1"],
+    )
+
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.statement == "rm -rf /data"
+    assert evaluation.modality == "assertive"
+    assert evaluation.status == "violates_norm"
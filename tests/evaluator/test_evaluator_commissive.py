@@ -0,0 +1,42 @@
+from normcore import evaluate
+
+PROMISE = "I will file the ticket and email you by Friday."
+
+
+def _tool_conversation(final: str) -> list[dict]:
+    return [
+        {"role": "user", "content": "The deploy failed, please follow up."},
+        {
+            "role": "assistant",
+            "content": None,
+            "tool_calls": [
+                {
+                    "id": "call_logs",
+                    "type": "function",
+                    "function": {"name": "read_logs", "arguments": "{}"},
+                }
+            ],
+        },
+        {"role": "tool", "tool_call_id": "call_logs", "content": '{"status": "failed"}'},
+        {"role": "assistant", "content": final},
+    ]
+
+
+def test_promise_without_tool_capability_violates_norm():
+    judgment = evaluate(agent_output=PROMISE)
+
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.modality == "commissive"
+    assert evaluation.license == {"refusal"}
+    assert evaluation.status == "violates_norm"
+    assert evaluation.violated_axiom == "A5"
+    assert evaluation.recommended_modality == "refusal"
+
+
+def test_promise_with_tool_calls_present_is_acceptable():
+    judgment = evaluate(conversation=_tool_conversation(PROMISE))
+
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.modality == "commissive"
+    assert evaluation.license == {"commissive", "refusal"}
+    assert judgment.status == "acceptable"
@@ -0,0 +1,58 @@
+from normcore import evaluate
+
+
+def _node(node_id: str, confidence: float = 1.0) -> dict:
+    return {"id": node_id, "source": "observed", "status": "confirmed", "confidence": confidence}
+
+
+def _link(ground_id: str) -> dict:
+    return {
+        "statement_id": "final_response",
+        "ground_id": ground_id,
+        "role": "supports",
+        "provenance": {"creator": "human", "evidence_type": "explicit"},
+    }
+
+
+def test_grounded_indicator_backed_verdict_is_fully_confident():
+    judgment = evaluate(agent_output="You should deploy now [@ci].", knowledge_nodes=[_node("ci")])
+    assert judgment.confidence == 1.0
+    assert judgment.statement_evaluations[0].confidence == 1.0
+
+
+def test_node_confidence_propagates():
+    judgment = evaluate(
+        agent_output="You should deploy now [@ci].", knowledge_nodes=[_node("ci", 0.5)]
+    )
+    assert judgment.status == "acceptable"
+    assert judgment.confidence == 0.5
+
+
+def test_default_policy_modality_lowers_confidence():
+    judgment = evaluate(agent_output="The cluster is old. You should upgrade it.")
+    assert judgment.statement_evaluations[0].modality == "assertive"
+    assert judgment.confidence == 0.6
+
+
+def test_unresolved_links_lower_confidence():
+    judgment = evaluate(
+        agent_output="You should deploy now.",
+        knowledge_nodes=[_node("ci")],
+        links={"links": [_link("ci"), _link("missing")]},
+    )
+    assert judgment.confidence == 0.5
+
+
+def test_no_statements_is_fully_confident():
+    assert evaluate(agent_output="Hello! How can I help?").confidence == 1.0
+
+
+def test_policy_strong_confidence_threshold_weakens_evidence():
+    kwargs = {
+        "agent_output": "You should deploy now [@ci].",
+        "knowledge_nodes": [_node("ci", 0.6)],
+    }
+    assert evaluate(**kwargs).status == "acceptable"
+    judgment = evaluate(**kwargs, policy={"strong_confidence_threshold": 0.8})
+    assert judgment.status == "violates_norm"
+    assert judgment.statement_evaluations[0].grounding_trace[0].strength == "weak"
@@ -1,3 +1,4 @@
+from normcore import AdmissibilityStatus, evaluate
 from normcore.evaluator import AdmissibilityEvaluator
 from normcore.normative.models import (
     EvaluationStatus,
@@ -82,3 +83,21 @@ def test_to_judgment_maps_status_and_statement_data():
     assert judgment.statement_evaluations[0].statement_id == "s1"
     assert judgment.grounds_accepted == 3
     assert judgment.grounds_cited == 2
+    assert judgment.status_counts == {AdmissibilityStatus.ACCEPTABLE: 1}
+
+
+def test_judgment_status_counts_histogram():
+    judgment = evaluate(
+        agent_output=(
+            "| Option | Verdict |\n|---|---|\n| A | You should deploy now |\n"
+            "| B | You should wait for review |\n\n"
+            "If the integration tests pass, we should deploy on Friday."
+        )
+    )
+    assert judgment.status_counts == {
+        AdmissibilityStatus.CONDITIONALLY_ACCEPTABLE: 1,
+        AdmissibilityStatus.VIOLATES_NORM: 2,
+    }
+    assert sum(judgment.status_counts.values()) == judgment.num_statements
+    dumped = judgment.model_dump(mode="json")["status_counts"]
+    assert dumped == {"conditionally_acceptable": 1, "violates_norm": 2}
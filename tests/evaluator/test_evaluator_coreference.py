@@ -0,0 +1,77 @@
+import json
+
+import pytest
+
+from normcore import evaluate
+from normcore.normative.coreference import CoreferenceResolver
+from normcore.normative.models import Statement
+
+
+def _conversation(user_text: str, answer: str, tool_calls=None):
+    assistant = {"role": "assistant", "content": answer}
+    if tool_calls:
+        assistant["tool_calls"] = tool_calls
+    return [{"role": "user", "content": user_text}, assistant]
+
+
+def test_pronoun_subject_resolves_to_entity_in_last_user_message():
+    conversation = _conversation("Is AGENT-7 done? And AGENT-8?", "It should be deployed first.")
+    resolved = evaluate(conversation=conversation, policy={"resolve_coreferences": True})
+    default = evaluate(conversation=conversation)
+
+    assert resolved.statement_evaluations[0].subject == "AGENT-8"
+    assert default.statement_evaluations[0].subject == "agent"
+    assert resolved.status == default.status
+
+
+def test_tool_claims_resolve_against_the_response_text_first():
+    tool_calls = [
+        {
+            "id": "call_1",
+            "type": "function",
+            "function": {
+                "name": "post_comment",
+                "arguments": json.dumps({"body": "It must be rolled back."}),
+            },
+        }
+    ]
+    conversation = _conversation(
+        "What about AGENT-8?", "I looked at `payments-api` for you.", tool_calls
+    )
+    judgment = evaluate(
+        conversation=conversation,
+        policy={"resolve_coreferences": True, "tool_claim_fields": {"post_comment": ["body"]}},
+    )
+    subjects = {e.statement_id: e.subject for e in judgment.statement_evaluations}
+    assert subjects["tool_call:call_1:body"] == "payments-api"
+
+
+@pytest.mark.parametrize(
+    "text,pronoun",
+    [
+        ("It should be deployed first.", "It"),
+        ("They must wait for review.", "They"),
+        ("This should ship today.", "This"),
+        ("That's the wrong branch.", "That"),
+        ("This release should ship today.", None),
+        ("It seems the build is green.", None),
+        ("It is important to wait.", None),
+        ("Italy is a good region.", None),
+    ],
+)
+def test_only_leading_pronoun_subjects_are_resolved(text, pronoun):
+    assert CoreferenceResolver().pronoun(text) == pronoun
+
+
+def test_antecedent_is_the_last_formal_mention_of_the_nearest_context():
+    resolver = CoreferenceResolver()
+    statement = Statement(id="s", subject="agent", predicate="p", raw_text="It must go.")
+
+    assert not resolver.resolve(statement, ["The build failed.", "Nothing named here."])
+    assert statement.subject == "agent"
+
+    assert resolver.resolve(statement, ["No ids.", "Compare issue_id: 42 with `svc-a` now."])
+    assert statement.subject == "svc-a"
+    assert resolver.last_entity("Fix `AGENT-8` and see https://example.com/x") == (
+        "https://example.com/x"
+    )
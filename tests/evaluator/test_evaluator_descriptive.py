@@ -0,0 +1,29 @@
+from normcore import evaluate
+
+
+def test_grounded_observation_carries_descriptive_license(ci_conversation):
+    conversation = ci_conversation("The build is blocked.", '{"build": "blocked"}')
+    judgment = evaluate(conversation=conversation)
+
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.modality == "descriptive"
+    assert evaluation.license == {"descriptive", "conditional", "refusal"}
+    assert judgment.status == "acceptable"
+
+
+def test_ungrounded_observation_is_refusal_only():
+    judgment = evaluate(agent_output="The build is blocked.")
+
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.license == {"refusal"}
+    assert evaluation.status == "unsupported"
+    assert evaluation.recommended_modality == "refusal"
+
+
+def test_observation_on_weak_ground_is_conditionally_acceptable():
+    grounds = [{"citation_key": "digest", "ground_id": "digest", "derived_from": ["missing"]}]
+    judgment = evaluate(agent_output="The build is blocked [@digest].", grounds=grounds)
+
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.license == {"conditional", "refusal"}
+    assert judgment.status == "conditionally_acceptable"
@@ -0,0 +1,51 @@
+import pytest
+from pydantic import ValidationError
+
+from normcore import AdmissibilityStatus, EvaluationPolicy, GateDecision, RiskTier, evaluate
+from normcore.evaluator import _derive_gate_decision
+
+
+def test_default_gate_per_status():
+    expected = {
+        AdmissibilityStatus.ACCEPTABLE: GateDecision.ALLOW,
+        AdmissibilityStatus.NO_NORMATIVE_CONTENT: GateDecision.ALLOW,
+        AdmissibilityStatus.CONDITIONALLY_ACCEPTABLE: GateDecision.ALLOW,
+        AdmissibilityStatus.UNDERDETERMINED: GateDecision.ALLOW,
+        AdmissibilityStatus.INSUFFICIENT_CITATIONS: GateDecision.REVISE,
+        AdmissibilityStatus.UNSUPPORTED: GateDecision.REVISE,
+        AdmissibilityStatus.ILL_FORMED: GateDecision.REVISE,
+        AdmissibilityStatus.VIOLATES_NORM: GateDecision.BLOCK,
+    }
+    for status in AdmissibilityStatus:
+        gate = _derive_gate_decision(status, RiskTier.LOW, EvaluationPolicy())
+        assert gate == expected[status]
+
+
+def test_judgment_carries_gate_decision():
+    assert evaluate(agent_output="We should deploy now.").gate_decision == GateDecision.BLOCK
+    assert evaluate(agent_output="The deployment is blocked.").gate_decision == GateDecision.REVISE
+    judgment = evaluate(agent_output="Hello!")
+    assert judgment.model_dump(mode="json")["gate_decision"] == "allow"
+
+
+def test_policy_status_override_replaces_default():
+    policy = {"gate_by_status": {"violates_norm": "revise", "conditionally_acceptable": "revise"}}
+    assert evaluate(agent_output="We should deploy now.", policy=policy).gate_decision == "revise"
+    judgment = evaluate(agent_output="If tests pass, we should deploy.", policy=policy)
+    assert judgment.gate_decision == GateDecision.REVISE
+
+
+def test_risk_tier_gate_only_tightens():
+    policy = {"risk_floor": "high", "gate_by_risk_tier": {"high": "block", "low": "allow"}}
+    assert evaluate(agent_output="Hello!", policy=policy).gate_decision == GateDecision.BLOCK
+
+    lenient = EvaluationPolicy(gate_by_risk_tier={"high": "allow"})
+    gate = _derive_gate_decision(AdmissibilityStatus.VIOLATES_NORM, RiskTier.HIGH, lenient)
+    assert gate == GateDecision.BLOCK
+
+
+def test_unknown_gate_keys_are_rejected():
+    with pytest.raises(ValidationError):
+        EvaluationPolicy(gate_by_status={"great": "allow"})
+    with pytest.raises(ValidationError):
+        EvaluationPolicy(gate_by_risk_tier={"high": "escalate"})
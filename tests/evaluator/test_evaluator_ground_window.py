@@ -0,0 +1,55 @@
+import json
+
+import pytest
+
+from normcore import evaluate
+
+
+def _call(call_id: str, name: str):
+    return {
+        "role": "assistant",
+        "content": "",
+        "tool_calls": [
+            {"id": call_id, "type": "function", "function": {"name": name, "arguments": "{}"}}
+        ],
+    }
+
+
+def _result(call_id: str, payload: dict):
+    return {"role": "tool", "tool_call_id": call_id, "content": json.dumps(payload)}
+
+
+CONVERSATION = [
+    {"role": "user", "content": "Is the pipeline green?"},
+    _call("call_old", "get_ci_status"),
+    _result("call_old", {"pipeline_id": "ci-41", "status": "green"}),
+    {"role": "assistant", "content": "Pipeline ci-41 is green."},
+    {"role": "user", "content": "Can we deploy now?"},
+    _call("call_new", "get_release"),
+    _result("call_new", {"release_id": "r-7", "state": "ready"}),
+    {"role": "assistant", "content": "You should deploy now [@call_old]."},
+]
+
+
+def test_default_window_admits_every_observation():
+    judgment = evaluate(conversation=CONVERSATION)
+    assert judgment.status == "acceptable"
+    assert judgment.out_of_window_ground_ids == []
+
+
+def test_results_before_the_window_cannot_license():
+    judgment = evaluate(conversation=CONVERSATION, policy={"ground_window_turns": 1})
+
+    assert judgment.out_of_window_ground_ids == ["pipeline_ci-41"]
+    assert "pipeline_ci-41" not in judgment.accepted_ground_ids
+    assert "release_r-7" in judgment.accepted_ground_ids
+    trace = judgment.statement_evaluations[0].grounding_trace
+    assert "pipeline_ci-41" not in {ground.semantic_id for ground in trace}
+    assert judgment.status != "acceptable"
+
+
+@pytest.mark.parametrize("turns", [2, 5])
+def test_wider_windows_reach_earlier_turns(turns):
+    judgment = evaluate(conversation=CONVERSATION, policy={"ground_window_turns": turns})
+    assert judgment.status == "acceptable"
+    assert judgment.out_of_window_ground_ids == []
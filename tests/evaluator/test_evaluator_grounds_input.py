@@ -1,6 +1,8 @@
 from openai.types.responses.response_output_text import AnnotationFileCitation
 
+from normcore import evaluate
 from normcore.citations import Ground, coerce_grounds_input
+from normcore.models import CreatorType, EvidenceType, LinkRole
 from normcore.normative.knowledge_builder import KnowledgeStateBuilder
 from normcore.normative.models import KnowledgeNode, Scope, Source, Status
 
@@ -93,3 +95,85 @@ def test_materialize_external_ground_nodes_skips_existing_ids():
     out = builder.materialize_external_grounds(initial, grounds)
 
     assert len(out) == 1
+
+
+def test_coerce_grounds_accepts_sectioned_payload_with_section_defaults():
+    grounds = coerce_grounds_input(
+        grounds={
+            "documents": [
+                {"citation_key": "spec", "ground_id": "file_spec"},
+                {"type": "file_citation", "file_id": "file_x", "filename": "x.md", "index": 0},
+            ],
+            "tool_calls": ["callCI"],
+            "urls": [{"citation_key": "guide", "ground_id": "https://example.com/guide"}],
+            "user_facts": [{"citation_key": "pref", "ground_id": "fact_pref", "creator": "human"}],
+            "bogus": ["ignored"],
+        }
+    )
+    by_key = {g.citation_key: g for g in grounds}
+    assert set(by_key) == {"spec", "file_x", "callCI", "guide", "pref"}
+    assert by_key["spec"].creator == CreatorType.UPSTREAM_PIPELINE
+    assert by_key["callCI"].ground_id == "callCI"
+    assert by_key["callCI"].creator == CreatorType.TOOL_OBSERVER
+    assert by_key["pref"].role == LinkRole.CONTEXTUALIZES
+    assert by_key["pref"].evidence_type == EvidenceType.EXPLICIT
+
+
+def test_sectioned_item_fields_override_section_defaults():
+    grounds = coerce_grounds_input(
+        grounds={"documents": [{"citation_key": "d", "ground_id": "g", "creator": "human"}]}
+    )
+    assert grounds[0].creator == CreatorType.HUMAN
+
+
+def test_user_facts_are_not_materialized_and_do_not_license():
+    builder = KnowledgeStateBuilder()
+    grounds = coerce_grounds_input(grounds={"user_facts": ["pref"], "documents": ["doc"]})
+    nodes = builder.materialize_external_grounds([], grounds)
+    assert [node.id for node in nodes] == ["doc"]
+
+    judgment = evaluate(
+        agent_output="We should deploy now [@pref].", grounds={"user_facts": ["pref"]}
+    )
+    assert judgment.status.value == "violates_norm"
+    judgment = evaluate(agent_output="We should deploy now [@doc].", grounds={"documents": ["doc"]})
+    assert judgment.status.value == "acceptable"
+
+
+def test_derived_ground_chain_is_exposed_in_grounding_trace():
+    grounds = {
+        "documents": [
+            "doc",
+            {"citation_key": "summary", "ground_id": "summary", "derived_from": ["doc"]},
+        ]
+    }
+    judgment = evaluate(agent_output="We should deploy now [@summary].", grounds=grounds)
+    assert judgment.status.value == "acceptable"
+    trace = judgment.statement_evaluations[0].grounding_trace
+    assert {g.id: g.derived_from for g in trace} == {"doc": [], "summary": ["doc"]}
+
+
+def test_broken_derivation_chain_does_not_license_assertion():
+    grounds = {
+        "documents": [{"citation_key": "summary", "ground_id": "summary", "derived_from": ["gone"]}]
+    }
+    judgment = evaluate(agent_output="We should deploy now [@summary].", grounds=grounds)
+    assert judgment.status.value == "violates_norm"
+    assert judgment.statement_evaluations[0].grounding_trace[0].strength == "weak"
+
+
+def test_judgment_lists_accepted_and_cited_ground_ids():
+    grounds = [
+        {"citation_key": "doc", "ground_id": "file_doc"},
+        {"citation_key": "runbook", "ground_id": "file_runbook"},
+        {"citation_key": "runbook", "ground_id": "file_runbook_v2"},
+    ]
+    judgment = evaluate(agent_output="We should deploy now [@runbook].", grounds=grounds)
+    assert judgment.accepted_ground_ids == ["file_doc", "file_runbook", "file_runbook_v2"]
+    assert judgment.cited_ground_ids == ["file_runbook", "file_runbook_v2"]
+    assert judgment.citation_map == {
+        "doc": ["file_doc"],
+        "runbook": ["file_runbook", "file_runbook_v2"],
+    }
+    assert judgment.grounds_accepted == 3
+    assert judgment.grounds_cited == 2
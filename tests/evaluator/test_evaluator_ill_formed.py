@@ -0,0 +1,77 @@
+from normcore import check_structure, evaluate
+from normcore.normative.models import Modality, Statement
+from normcore.normative.structure_checker import StructureChecker
+
+
+def _issues(text: str, statement_id: str = "final_response") -> list[str]:
+    statement = Statement(
+        id=statement_id,
+        subject="agent",
+        predicate="participation",
+        raw_text=text,
+        modality=Modality.ASSERTIVE,
+    )
+    return StructureChecker().check(statement)
+
+
+def test_text_level_issues():
+    assert _issues("We should deploy the") == ["fragment"]
+    assert _issues("Should.") == ["fragment"]
+    assert _issues("You should restart the router â€™ now.") == ["encoding_noise"]
+    assert _issues("You should restart � the router.") == ["encoding_noise"]
+    assert _issues("xkcdqw brtzpl should mnbvcx.") == ["gibberish"]
+    assert _issues("xkcd vbnm should qwpo zzzz") == ["gibberish"]
+    assert _issues("asdf qwer zxcv!!!") == ["gibberish"]
+    assert _issues("You should ###### $$$$ @@@@ %%%% now.") == ["gibberish"]
+    assert _issues('You should set {"retries": [1, 2') == ["unparseable_structure"]
+
+
+def test_legible_prose_is_well_formed():
+    for text in (
+        "We should deploy now.",
+        "If tests pass, we should deploy (after the freeze).",
+        "1) You should rotate the key 2) then restart.",
+        "You should set the timeout to 30000 ms [@call_cfg].",
+        "You should send it over HTTPS, not SMTP.",
+    ):
+        assert _issues(text) == [], text
+
+
+def test_code_and_table_statements_are_exempt_from_text_checks():
+    assert _issues("rm -rf /tmp/build/*", statement_id="code:1:1") == []
+    assert _issues("Recommended", statement_id="table:1:1:2") == []
+
+
+def test_garbage_output_is_ill_formed():
+    judgment = evaluate(agent_output="You should restart the rou�� (and then")
+
+    assert judgment.status == "ill_formed"
+    assert judgment.violated_axioms == ["I1"]
+    assert judgment.can_retry
+    [evaluation] = judgment.statement_evaluations
+    assert "encoding_noise" in evaluation.explanation
+    assert check_structure("You should restart the rou�� (and then").status == "ill_formed"
+
+
+def test_gibberish_is_ill_formed_before_modality_applies():
+    # One mash carries a modal, the other no normative indicator at all
+    for text in ("xkcd vbnm should qwpo zzzz", "asdf qwer zxcv!!!"):
+        judgment = evaluate(agent_output=text)
+
+        assert judgment.status == "ill_formed", text
+        assert judgment.violated_axioms == ["I1"]
+        assert check_structure(text).status == "ill_formed"
+
+
+def test_protocol_speech_and_code_are_not_garbage():
+    assert evaluate(agent_output="Thanks! How can I help?").status == "no_normative_content"
+    assert evaluate(agent_output='```json\n{"a": 1}\n```').status == "no_normative_content"
+
+
+def test_norm_violation_outranks_ill_formed_statement():
+    text = "| Option | Verdict |\n|---|---|\n| A | You should deploy now |\n\nYou should ship the"
+    judgment = evaluate(agent_output=text)
+
+    statuses = {e.statement_id: e.status.value for e in judgment.statement_evaluations}
+    assert statuses == {"final_response": "ill_formed", "table:1:1:2": "violates_norm"}
+    assert judgment.status == "violates_norm"
@@ -0,0 +1,52 @@
+import pytest
+
+from normcore import EvaluationPolicy, InputLimitError, evaluate
+from normcore.limits import content_bytes
+
+CONVERSATION = [
+    {"role": "user", "content": "Why is CI red?"},
+    {
+        "role": "assistant",
+        "content": None,
+        "tool_calls": [
+            {
+                "id": "call_ci",
+                "type": "function",
+                "function": {"name": "ci_status", "arguments": '{"job": "build"}'},
+            }
+        ],
+    },
+    {"role": "tool", "tool_call_id": "call_ci", "content": '{"runner": "offline"}'},
+    {"role": "assistant", "content": "The runner is offline [@call_ci]."},
+]
+
+
+def test_content_bytes_counts_text_parts_and_tool_arguments():
+    assert content_bytes({"role": "user", "content": "héllo"}) == 6
+    assert content_bytes(CONVERSATION[1]) == len('{"job": "build"}')
+    parts = {"role": "user", "content": [{"type": "text", "text": "hi"}]}
+    assert content_bytes(parts) == len('[{"type": "text", "text": "hi"}]')
+
+
+def test_limits_are_off_by_default_and_allow_input_at_the_limit():
+    unlimited = evaluate(conversation=CONVERSATION)
+    total = sum(content_bytes(message) for message in CONVERSATION)
+    policy = EvaluationPolicy(max_messages=4, max_content_bytes=total)
+    assert evaluate(conversation=CONVERSATION, policy=policy).status == unlimited.status
+
+
+@pytest.mark.parametrize(
+    "policy,limit",
+    [({"max_messages": 3}, "max_messages"), ({"max_content_bytes": 64}, "max_content_bytes")],
+)
+def test_input_over_a_limit_raises_input_limit_error(policy, limit):
+    with pytest.raises(InputLimitError, match=f"Input exceeds {limit}") as exc:
+        evaluate(conversation=CONVERSATION, policy=policy)
+    assert isinstance(exc.value, ValueError)
+    assert exc.value.limit == limit
+    assert exc.value.actual > exc.value.maximum
+
+
+def test_limits_must_be_positive():
+    with pytest.raises(ValueError):
+        EvaluationPolicy(max_content_bytes=0)
@@ -0,0 +1,80 @@
+import json
+
+import pytest
+
+from normcore import evaluate
+from normcore.cli import main as cli_main
+from normcore.normative.models import KnowledgeNode, Scope, Source, Status
+
+_NODES = [
+    {"id": "tool_get_ci_1", "semantic_id": "ci_main", "source": "observed", "status": "confirmed"}
+]
+_CONVERSATION = [
+    {"role": "user", "content": "Can we deploy?"},
+    {
+        "role": "assistant",
+        "content": "",
+        "tool_calls": [
+            {
+                "id": "call_ci",
+                "type": "function",
+                "function": {"name": "get_ci", "arguments": "{}"},
+            }
+        ],
+    },
+    {"role": "tool", "tool_call_id": "call_ci", "content": '{"status": "green"}'},
+    {"role": "assistant", "content": "We should deploy now [@call_ci]."},
+]
+
+
+def test_precomputed_nodes_license_by_their_own_citation_key():
+    judgment = evaluate(agent_output="We should deploy now [@ci_main].", knowledge_nodes=_NODES)
+    assert judgment.status.value == "acceptable"
+    assert judgment.grounds_cited == 1
+
+
+def test_precomputed_nodes_replace_tool_result_parsing():
+    assert evaluate(conversation=_CONVERSATION).status.value == "acceptable"
+
+    judgment = evaluate(conversation=_CONVERSATION, knowledge_nodes=[])
+    assert judgment.status.value == "violates_norm"
+
+
+def test_precomputed_nodes_merge_external_grounds():
+    node = KnowledgeNode(
+        id="ci_main",
+        source=Source.OBSERVED,
+        status=Status.CONFIRMED,
+        confidence=1.0,
+        scope=Scope.FACTUAL,
+    )
+    grounds = [{"citation_key": "runbook", "ground_id": "file_runbook"}]
+    judgment = evaluate(
+        agent_output="We should deploy now [@ci_main] per the runbook [@runbook].",
+        knowledge_nodes=[node],
+        grounds=grounds,
+    )
+    assert judgment.status.value == "acceptable"
+    assert judgment.grounds_cited == 2
+
+
+def test_invalid_knowledge_node_record_is_rejected():
+    with pytest.raises(ValueError, match="index 0"):
+        evaluate(agent_output="We should deploy now.", knowledge_nodes=[{"id": "x"}])
+    with pytest.raises(ValueError, match="unknown fields: tool"):
+        evaluate(
+            agent_output="We should deploy now.",
+            knowledge_nodes=[{**_NODES[0], "tool": "get_ci"}],
+        )
+
+
+def test_cli_knowledge_nodes_flag(capsys):
+    argv = [
+        "evaluate",
+        "--agent-output",
+        "We should deploy now [@ci_main].",
+        "--knowledge-nodes",
+        json.dumps(_NODES),
+    ]
+    assert cli_main(argv) == 0
+    assert json.loads(capsys.readouterr().out)["status"] == "acceptable"
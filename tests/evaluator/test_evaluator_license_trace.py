@@ -0,0 +1,51 @@
+import json
+
+from normcore import evaluate
+from normcore.cli import main as cli_main
+
+CI_GREEN = '{"pipeline_id": "ci-42", "status": "green"}'
+
+
+def test_trace_is_absent_by_default():
+    judgment = evaluate(agent_output="We should deploy now.")
+    assert judgment.statement_evaluations[0].license_trace is None
+
+
+def test_trace_explains_cited_normative_license(ci_conversation):
+    conversation = ci_conversation(
+        "You should deploy now [@call_ci].", CI_GREEN, question="Can we deploy?"
+    )
+    judgment = evaluate(conversation=conversation, trace=True)
+
+    [evaluation] = judgment.statement_evaluations
+    trace = evaluation.license_trace
+    assert (trace.rule, trace.mode) == ("normative", "links")
+    assert trace.factual_present and trace.factual_has_strong
+    assert trace.factual_strength == "strong"
+    assert trace.supports_links_count == 1
+    assert trace.permitted_modalities == sorted(evaluation.license)
+    assert trace.ground_set_size == len(evaluation.grounding_trace)
+
+
+def test_trace_explains_missing_grounding():
+    judgment = evaluate(agent_output="We should deploy now.", trace=True)
+
+    trace = judgment.statement_evaluations[0].license_trace
+    assert trace.ground_set_size == 0 and not trace.factual_present
+    assert trace.permitted_modalities == ["refusal"]
+
+
+def test_trace_names_descriptive_and_epistemic_rules(ci_conversation):
+    conversation = ci_conversation("The build is blocked.", CI_GREEN, question="Can we deploy?")
+    observation = evaluate(conversation=conversation, trace=True)
+    inference = evaluate(agent_output="The build must have failed.", trace=True)
+
+    assert observation.statement_evaluations[0].license_trace.rule == "descriptive"
+    epistemic = inference.statement_evaluations[0].license_trace
+    assert epistemic.rule == "epistemic" and epistemic.permitted_modalities == []
+
+
+def test_evaluate_command_trace_flag(capsys):
+    assert cli_main(["evaluate", "--agent-output", "We should deploy now.", "--trace"]) == 0
+    [evaluation] = json.loads(capsys.readouterr().out)["statement_evaluations"]
+    assert evaluation["license_trace"]["rule"] == "normative"
@@ -0,0 +1,51 @@
+import json
+
+from normcore import evaluate
+from normcore.cli import main as cli_main
+from normcore.models import LinkSet
+
+_GROUNDS = [{"citation_key": "doc", "ground_id": "file_1"}]
+_LINKS = {
+    "links": [
+        {
+            "statement_id": "final_response",
+            "ground_id": "file_1",
+            "role": "supports",
+            "provenance": {"creator": "upstream_pipeline", "evidence_type": "observation"},
+        }
+    ]
+}
+
+
+def test_precomputed_links_license_without_citation_keys():
+    judgment = evaluate(agent_output="We should deploy now.", grounds=_GROUNDS, links=_LINKS)
+    assert judgment.status.value == "acceptable"
+    assert judgment.grounds_cited == 1
+
+
+def test_precomputed_links_bypass_citation_extraction():
+    text = "We should deploy now [@doc]."
+    assert evaluate(agent_output=text, grounds=_GROUNDS).status.value == "acceptable"
+
+    judgment = evaluate(agent_output=text, grounds=_GROUNDS, links=LinkSet())
+    assert judgment.status.value == "violates_norm"
+    assert judgment.grounds_cited == 0
+
+
+def test_links_to_unknown_grounds_do_not_license():
+    judgment = evaluate(agent_output="We should deploy now.", links=_LINKS)
+    assert judgment.status.value == "violates_norm"
+
+
+def test_cli_links_flag(capsys):
+    argv = [
+        "evaluate",
+        "--agent-output",
+        "We should deploy now.",
+        "--grounds",
+        json.dumps(_GROUNDS),
+        "--links",
+        json.dumps(_LINKS),
+    ]
+    assert cli_main(argv) == 0
+    assert json.loads(capsys.readouterr().out)["status"] == "acceptable"
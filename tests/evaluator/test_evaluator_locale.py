@@ -0,0 +1,61 @@
+import pytest
+
+from normcore import EvaluationPolicy, evaluate
+from normcore.i18n import _CATALOGS, LOCALES, translate
+
+
+def test_feedback_hint_and_explanations_follow_policy_locale():
+    judgment = evaluate(agent_output="We should deploy now.", policy={"locale": "de"})
+
+    assert judgment.status == "violates_norm"
+    assert judgment.violated_axioms == ["A5"]
+    assert judgment.feedback_hint.startswith("Deine Antwort verletzt normative Axiome: A5.")
+    assert judgment.explanation == "Verletzte Axiome: ['A5']"
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.explanation == (
+        "Behauptung ohne ausreichende Begründung (Kategorizitätsverbot)"
+    )
+
+
+def test_default_locale_keeps_english_messages():
+    default = evaluate(agent_output="We should deploy now.")
+    english = evaluate(agent_output="We should deploy now.", policy={"locale": "en"})
+
+    assert default.feedback_hint == english.feedback_hint
+    assert default.explanation == "Violated axioms: ['A5']"
+
+
+def test_axiom_hints_are_translated_separately():
+    judgment = evaluate(agent_output="This hotel is better for you.", policy={"locale": "es"})
+
+    assert judgment.violated_axioms == ["A9"]
+    assert judgment.feedback_hint == (
+        "Tus afirmaciones carecen del fundamento necesario. Pide más contexto o usa una "
+        "formulación condicional. Presentaste un consejo como adecuado para la persona sin "
+        "conocer sus preferencias o restricciones. Pregúntale por ellas o da un consejo general."
+    )
+
+
+def test_nested_messages_translate_inner_message():
+    discounted = "All statements are normatively acceptable (2 low-weight statement(s) discounted)"
+    assert translate(discounted, "de") == (
+        "Alle Aussagen sind normativ zulässig (2 gering gewichtete Aussage(n) nicht "
+        "berücksichtigt)"
+    )
+    assert translate(discounted, "fr") == (
+        "Tous les énoncés sont normativement acceptables (2 énoncé(s) de faible poids non pris "
+        "en compte)"
+    )
+
+
+def test_unknown_messages_and_locales():
+    assert translate("Something new", "de") == "Something new"
+    with pytest.raises(ValueError, match="Unsupported locale 'pt'"):
+        translate("No content to validate", "pt")
+    with pytest.raises(ValueError, match="Unsupported locale 'pt'"):
+        EvaluationPolicy(locale="pt")
+
+
+def test_catalogs_cover_the_same_messages():
+    assert LOCALES == ("en", "de", "es", "fr")
+    assert _CATALOGS["de"].keys() == _CATALOGS["es"].keys() == _CATALOGS["fr"].keys()
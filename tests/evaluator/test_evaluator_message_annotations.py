@@ -0,0 +1,64 @@
+from normcore import evaluate
+from normcore.citations import citations_from_assistant_message
+
+_TEXT = "We should deploy now. The docs say so."
+
+
+def _chat_message(start, end):
+    return {
+        "role": "assistant",
+        "content": _TEXT,
+        "annotations": [
+            {
+                "type": "url_citation",
+                "url_citation": {
+                    "url": "https://docs.example.com/deploy",
+                    "title": "Deploy guide",
+                    "start_index": start,
+                    "end_index": end,
+                },
+            }
+        ],
+    }
+
+
+def test_chat_completions_annotations_become_grounds_and_links():
+    judgment = evaluate(conversation=[_chat_message(0, 21)])
+    assert judgment.status.value == "acceptable"
+    assert judgment.grounds_accepted == 1
+    assert judgment.grounds_cited == 1
+
+
+def test_without_annotations_same_text_is_unlicensed():
+    judgment = evaluate(agent_output=_TEXT)
+    assert judgment.status.value == "violates_norm"
+
+
+def test_responses_style_part_annotations_are_offset_into_joined_text():
+    message = {
+        "role": "assistant",
+        "content": [
+            {"type": "text", "text": "Intro. "},
+            {
+                "type": "text",
+                "text": "We should deploy now.",
+                "annotations": [
+                    {"type": "file_citation", "file_id": "file_9", "filename": "a.md", "index": 3}
+                ],
+            },
+        ],
+    }
+    citations = citations_from_assistant_message(message)
+    assert [(c.file_id, c.index) for c in citations] == [("file_9", 10)]
+    assert evaluate(conversation=[message]).status.value == "acceptable"
+
+
+def test_invalid_annotations_are_ignored():
+    message = {"role": "assistant", "content": _TEXT, "annotations": [{"type": "bogus"}]}
+    assert citations_from_assistant_message(message) == []
+
+
+def test_annotations_outside_span_are_not_used():
+    start = _TEXT.index("The docs")
+    judgment = evaluate(conversation=[_chat_message(start, len(_TEXT))], span=(0, start))
+    assert judgment.grounds_cited == 0
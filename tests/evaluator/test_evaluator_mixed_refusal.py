@@ -0,0 +1,53 @@
+import pytest
+
+from normcore import evaluate
+
+_MIXED = [
+    {"role": "user", "content": "Deploy and wipe the database."},
+    {
+        "role": "assistant",
+        "content": [
+            {"type": "text", "text": "If the tests pass, we should deploy on Friday."},
+            {"type": "refusal", "refusal": "I can't wipe the production database."},
+        ],
+    },
+]
+_POLICY = {"mixed_refusal_parts": "evaluate_parts"}
+
+
+def test_mixed_text_and_refusal_parts_are_rejected_by_default():
+    with pytest.raises(ValueError, match="cannot mix text and refusal parts"):
+        evaluate(conversation=_MIXED)
+
+
+def test_evaluate_parts_judges_text_and_refusal_separately():
+    judgment = evaluate(conversation=_MIXED, policy=_POLICY)
+
+    evaluations = {e.statement_id: e for e in judgment.statement_evaluations}
+    assert list(evaluations) == ["final_response", "refusal"]
+    assert evaluations["final_response"].modality == "conditional"
+    assert evaluations["refusal"].modality == "refusal"
+    assert evaluations["refusal"].statement == "I can't wipe the production database."
+    assert evaluations["refusal"].status.value == "acceptable"
+    assert judgment.status.value == "conditionally_acceptable"
+
+
+def test_evaluate_parts_refusal_only_content():
+    conversation = [
+        {"role": "user", "content": "Wipe the database."},
+        {
+            "role": "assistant",
+            "content": [
+                {"type": "text", "text": "Hello!"},
+                {"type": "refusal", "refusal": "I can't do that."},
+            ],
+        },
+    ]
+    judgment = evaluate(conversation=conversation, policy=_POLICY)
+    assert [e.statement_id for e in judgment.statement_evaluations] == ["refusal"]
+    assert judgment.status.value == "acceptable"
+
+
+def test_statement_ids_selection_can_skip_refusal_part():
+    judgment = evaluate(conversation=_MIXED, policy=_POLICY, statement_ids=["final_response"])
+    assert [e.statement_id for e in judgment.statement_evaluations] == ["final_response"]
@@ -0,0 +1,29 @@
+from normcore import evaluate
+
+
+def test_ungrounded_inference_is_unsupported_not_violation():
+    judgment = evaluate(agent_output="The build must have failed.")
+
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.reading == "epistemic"
+    assert evaluation.status == "unsupported"
+    assert evaluation.violated_axiom == "A4"
+
+
+def test_ungrounded_obligation_violates_norm():
+    judgment = evaluate(agent_output="You must rotate the key.")
+
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.reading == "deontic"
+    assert evaluation.status == "violates_norm"
+    assert evaluation.violated_axiom == "A5"
+
+
+def test_inference_grounded_in_tool_observation_is_acceptable(ci_conversation):
+    conversation = ci_conversation("The build must have failed.", '{"build": "red"}')
+    judgment = evaluate(conversation=conversation)
+
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.reading == "epistemic"
+    assert evaluation.license == set()
+    assert judgment.status == "acceptable"
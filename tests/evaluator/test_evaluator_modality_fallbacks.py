@@ -0,0 +1,36 @@
+import pytest
+from pydantic import ValidationError
+
+from normcore import evaluate
+from normcore.models import EvaluationPolicy
+
+
+def test_failing_statement_reports_recommended_modality():
+    judgment = evaluate(agent_output="You should restart the router.")
+
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.modality == "assertive"
+    assert evaluation.recommended_modality == "refusal"
+
+
+def test_passing_statement_has_no_recommended_modality():
+    judgment = evaluate(agent_output="If the router is down, you should restart it.")
+
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.recommended_modality is None
+
+
+def test_policy_fallbacks_replace_default_chain():
+    judgment = evaluate(
+        agent_output="You should restart the router.",
+        policy={"modality_fallbacks": {"assertive": ["conditional"]}},
+    )
+
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.status == "violates_norm"
+    assert evaluation.recommended_modality is None
+
+
+def test_policy_rejects_unknown_modalities():
+    with pytest.raises(ValidationError):
+        EvaluationPolicy(modality_fallbacks={"assertive": ["hedged"]})
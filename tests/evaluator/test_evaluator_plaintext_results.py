@@ -0,0 +1,72 @@
+from normcore import evaluate
+
+
+def _conversation(answer: str, tool_output: str):
+    return [
+        {"role": "user", "content": "Is the Safari login bug tracked?"},
+        {
+            "role": "assistant",
+            "content": "",
+            "tool_calls": [
+                {
+                    "id": "call_s",
+                    "type": "function",
+                    "function": {"name": "search", "arguments": '{"q": "safari login"}'},
+                }
+            ],
+        },
+        {"role": "tool", "tool_call_id": "call_s", "content": tool_output},
+        {"role": "assistant", "content": answer},
+    ]
+
+
+SNIPPET = "Login fails on Safari (PROJ-42), opened 2 days ago."
+
+
+def test_plaintext_tracker_id_becomes_the_ground_id():
+    judgment = evaluate(
+        conversation=_conversation("You should prioritize the fix [@call_s].", SNIPPET)
+    )
+    assert judgment.status == "acceptable"
+    assert judgment.cited_ground_ids == ["PROJ-42"]
+
+
+def test_precomputed_link_resolves_plaintext_semantic_id():
+    link = {
+        "statement_id": "final_response",
+        "ground_id": "PROJ-42",
+        "role": "supports",
+        "provenance": {"creator": "upstream_pipeline", "evidence_type": "observation"},
+    }
+    judgment = evaluate(
+        conversation=_conversation("You should prioritize the fix.", SNIPPET),
+        links={"links": [link]},
+    )
+    assert judgment.status == "acceptable"
+
+
+def test_url_annotation_resolves_to_plaintext_observation():
+    url = "https://jira.example.com/browse/PROJ-42"
+    text = "You should prioritize the fix."
+    agent_message = {
+        "role": "assistant",
+        "content": text,
+        "annotations": [
+            {
+                "type": "url_citation",
+                "url_citation": {
+                    "url": url,
+                    "title": "PROJ-42",
+                    "start_index": 0,
+                    "end_index": len(text),
+                },
+            }
+        ],
+    }
+    conversation = _conversation(text, f"Result: {url}")
+    conversation[-1] = agent_message
+    judgment = evaluate(conversation=conversation)
+    trace = judgment.statement_evaluations[0].grounding_trace
+    assert [(g.semantic_id, g.source) for g in trace if g.scope == "factual"] == [
+        (url, "observed")
+    ]
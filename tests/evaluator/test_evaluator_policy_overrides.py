@@ -0,0 +1,110 @@
+import pytest
+
+from normcore import evaluate
+from normcore.evaluator import AdmissibilityEvaluator
+from normcore.models.policy import EvaluationPolicy
+from normcore.normative.axiom_checker import AxiomChecker
+
+
+def _conversation(tool_name: str):
+    return [
+        {"role": "user", "content": "Can we deploy?"},
+        {
+            "role": "assistant",
+            "content": "",
+            "tool_calls": [
+                {
+                    "id": "call_1",
+                    "type": "function",
+                    "function": {"name": tool_name, "arguments": "{}"},
+                }
+            ],
+        },
+        {"role": "tool", "tool_call_id": "call_1", "content": '{"status": "green"}'},
+        {"role": "assistant", "content": "We should deploy now [@call_1]."},
+    ]
+
+
+def test_non_epistemic_tools_cannot_ground_statements():
+    assert evaluate(conversation=_conversation("crm_notes")).status == "acceptable"
+
+    judgment = evaluate(
+        conversation=_conversation("crm_notes"), policy={"non_epistemic_tools": ["CRM_Notes"]}
+    )
+
+    assert judgment.status == "violates_norm"
+    assert judgment.grounds_accepted == 0
+
+
+def test_modality_keywords_extend_detection():
+    text = "Choosing a vendor is above my pay grade, so you should ask procurement."
+    assert evaluate(agent_output=text).status == "violates_norm"
+
+    judgment = evaluate(
+        agent_output=text, policy={"modality_keywords": {"refusal": ["above my pay grade"]}}
+    )
+
+    assert judgment.status == "acceptable"
+    assert judgment.statement_evaluations[0].modality == "refusal"
+
+
+def test_modality_keywords_match_whole_words_only():
+    judgment = evaluate(
+        agent_output="We should deploy now.",
+        policy={"modality_keywords": {"refusal": ["ploy"]}},
+    )
+    assert judgment.statement_evaluations[0].modality == "assertive"
+
+
+def test_disabled_axiom_falls_through_to_remaining_axioms():
+    text = "We should deploy now."
+    assert evaluate(agent_output=text).violated_axioms == ["A5"]
+
+    judgment = evaluate(agent_output=text, policy={"disabled_axioms": ["A5"]})
+    [evaluation] = judgment.statement_evaluations
+    assert (evaluation.status, evaluation.violated_axiom) == ("unsupported", "A4")
+
+    judgment = evaluate(agent_output=text, policy={"disabled_axioms": ["A5", "A4"]})
+    assert judgment.status == "underdetermined"
+    assert judgment.violated_axioms == []
+
+
+def test_disabled_axiom_is_unregistered_from_a_copy_of_the_checker():
+    checker = AxiomChecker()
+    evaluator = AdmissibilityEvaluator(
+        EvaluationPolicy(disabled_axioms=["A9"]), axiom_checker=checker
+    )
+
+    assert "A9" not in evaluator.axiom_checker.registry
+    assert "A9" in checker.registry
+
+
+def test_disabled_attribution_axiom_skips_citation_check():
+    policy = {"min_citations_per_assertive": 2}
+    conversation = _conversation("get_ci")
+    assert evaluate(conversation=conversation, policy=policy).status == "insufficient_citations"
+
+    judgment = evaluate(
+        conversation=conversation, policy={**policy, "disabled_axioms": ["A8"]}
+    )
+    assert judgment.status == "acceptable"
+
+
+@pytest.mark.parametrize(
+    "fields,message",
+    [
+        ({"disabled_axioms": ["A6"]}, "Axioms cannot be disabled: A6"),
+        ({"modality_keywords": {"guess": ["x"]}}, "Unknown modalities in modality_keywords"),
+    ],
+)
+def test_policy_override_validation(fields, message):
+    with pytest.raises(ValueError, match=message):
+        EvaluationPolicy.model_validate(fields)
+
+
+def test_axiom_enabled_reflects_disabled_axioms_and_policy_axioms():
+    policy = EvaluationPolicy(disabled_axioms=["A7"], min_citations_per_assertive=1)
+    assert not policy.axiom_enabled("A7")
+    assert policy.axiom_enabled("A5")
+    assert policy.axiom_enabled("A8", policy_axiom=True)
+    assert not EvaluationPolicy().axiom_enabled("A8", policy_axiom=True)
@@ -0,0 +1,47 @@
+from normcore import evaluate
+from normcore.evaluator import _strip_reasoning_items
+
+
+def test_strip_reasoning_items_drops_items_parts_and_fields():
+    messages = [
+        {"role": "user", "content": "Deploy?"},
+        {"type": "reasoning", "summary": [{"type": "summary_text", "text": "We must..."}]},
+        {
+            "role": "assistant",
+            "reasoning_content": "Maybe we should deploy",
+            "content": [
+                {"type": "thinking", "thinking": "You must deploy now"},
+                {"type": "text", "text": "If tests pass, we should deploy."},
+            ],
+        },
+    ]
+    stripped, removed = _strip_reasoning_items(messages)
+    assert removed == 3
+    assert len(stripped) == 2
+    assert stripped[1]["content"] == [{"type": "text", "text": "If tests pass, we should deploy."}]
+    assert "reasoning_content" not in stripped[1]
+    assert messages[2]["content"][0]["type"] == "thinking"  # input is not mutated
+
+
+def test_evaluate_ignores_reasoning_and_records_presence():
+    judgment = evaluate(
+        conversation=[
+            {"role": "user", "content": "Deploy?"},
+            {
+                "role": "assistant",
+                "content": [
+                    {"type": "reasoning", "text": "We must deploy immediately, no doubt."},
+                    {"type": "text", "text": "If tests pass, we should deploy."},
+                ],
+            },
+            {"type": "reasoning", "summary": []},
+        ]
+    )
+    assert judgment.status.value == "conditionally_acceptable"
+    assert judgment.reasoning_items_skipped == 2
+    assert "must deploy immediately" not in judgment.statement_evaluations[0].statement
+
+
+def test_evaluate_without_reasoning_reports_zero():
+    judgment = evaluate(agent_output="We should deploy now.")
+    assert judgment.reasoning_items_skipped == 0
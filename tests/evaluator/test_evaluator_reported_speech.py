@@ -0,0 +1,43 @@
+from normcore import evaluate
+
+_CONVERSATION = [
+    {"role": "user", "content": "What does the vendor advisory say?"},
+    {
+        "role": "assistant",
+        "content": "",
+        "tool_calls": [
+            {
+                "id": "call_advisory",
+                "type": "function",
+                "function": {"name": "get_advisory", "arguments": "{}"},
+            }
+        ],
+    },
+    {"role": "tool", "tool_call_id": "call_advisory", "content": '{"advisory_id": "ADV-7"}'},
+    {"role": "assistant", "content": "The vendor claims you should upgrade immediately."},
+]
+
+
+def test_relayed_claim_is_not_the_agents_own_assertion():
+    own = evaluate(agent_output="You should upgrade immediately.")
+    relayed = evaluate(agent_output="The vendor claims you should upgrade immediately.")
+
+    assert own.status == "violates_norm"
+    assert relayed.status == "unsupported"
+    [evaluation] = relayed.statement_evaluations
+    assert evaluation.modality == "assertive"
+    assert evaluation.violated_axiom == "A4"
+    assert evaluation.attribution.source == "The vendor"
+    assert evaluation.attribution.marker == "claims"
+
+
+def test_grounded_report_is_acceptable():
+    judgment = evaluate(conversation=_CONVERSATION)
+    assert judgment.status == "acceptable"
+    [evaluation] = judgment.statement_evaluations
+    assert "attributed to 'The vendor'" in evaluation.explanation
+
+
+def test_own_claim_has_no_attribution():
+    [evaluation] = evaluate(agent_output="You should upgrade immediately.").statement_evaluations
+    assert evaluation.attribution is None
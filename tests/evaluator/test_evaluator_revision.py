@@ -0,0 +1,42 @@
+from normcore import compare_judgments, evaluate
+
+
+def test_retry_that_converges_resolves_violation():
+    delta = compare_judgments(
+        evaluate(agent_output="We should deploy now."),
+        evaluate(agent_output="If tests pass, we should deploy."),
+    )
+    assert delta.status_change == "improved"
+    assert delta.resolved_violations == ["A5"]
+    assert delta.resolved_statements == ["final_response"]
+    assert delta.converged
+    assert not delta.retry_worthwhile
+
+
+def test_weakened_claim_is_progress_despite_trading_axioms():
+    delta = compare_judgments(
+        evaluate(agent_output="We should deploy now."),
+        evaluate(agent_output="The deployment is blocked."),
+    )
+    assert (delta.status_before.value, delta.status_after.value) == (
+        "violates_norm",
+        "unsupported",
+    )
+    assert (delta.resolved_violations, delta.new_violations) == (["A5"], ["A4"])
+    assert not delta.converged
+    assert delta.retry_worthwhile
+
+
+def test_regression_and_stalled_retry_are_not_worthwhile():
+    conditional = evaluate(agent_output="If tests pass, we should deploy.")
+    violation = evaluate(agent_output="We should deploy now.")
+
+    regressed = compare_judgments(conditional, violation)
+    assert regressed.status_change == "regressed"
+    assert regressed.new_violations == ["A5"]
+    assert regressed.new_failing_statements == ["final_response"]
+    assert not regressed.retry_worthwhile
+
+    stalled = compare_judgments(violation, evaluate(agent_output="We must deploy today."))
+    assert stalled.status_change == "unchanged"
+    assert not stalled.retry_worthwhile
@@ -0,0 +1,110 @@
+import pytest
+
+from normcore import AdmissibilityStatus, RiskTier, evaluate
+from normcore.evaluator import AdmissibilityEvaluator, _derive_risk_tier
+from normcore.models import StatementEvaluation
+from normcore.models.policy import EvaluationPolicy
+from normcore.normative import AxiomCheckResult, AxiomRegistry, EvaluationStatus, RuleAxiom
+
+
+def _evaluation(status, *, modality="assertive", predicate=None, violated_axiom=None):
+    return StatementEvaluation(
+        statement_id="s1",
+        statement="text",
+        modality=modality,
+        license=[],
+        status=status,
+        predicate=predicate,
+        violated_axiom=violated_axiom,
+    )
+
+
+def test_status_sets_base_risk_tier():
+    expected = {
+        AdmissibilityStatus.ACCEPTABLE: RiskTier.LOW,
+        AdmissibilityStatus.NO_NORMATIVE_CONTENT: RiskTier.LOW,
+        AdmissibilityStatus.CONDITIONALLY_ACCEPTABLE: RiskTier.MEDIUM,
+        AdmissibilityStatus.UNDERDETERMINED: RiskTier.MEDIUM,
+        AdmissibilityStatus.INSUFFICIENT_CITATIONS: RiskTier.MEDIUM,
+        AdmissibilityStatus.UNSUPPORTED: RiskTier.HIGH,
+        AdmissibilityStatus.ILL_FORMED: RiskTier.HIGH,
+        AdmissibilityStatus.VIOLATES_NORM: RiskTier.HIGH,
+    }
+    for status in AdmissibilityStatus:
+        assert _derive_risk_tier(status, RiskTier.LOW) == expected[status]
+
+
+def test_risk_floor_raises_but_never_lowers_tier():
+    assert _derive_risk_tier(AdmissibilityStatus.ACCEPTABLE, RiskTier.MEDIUM) == RiskTier.MEDIUM
+    assert _derive_risk_tier(AdmissibilityStatus.VIOLATES_NORM, RiskTier.MEDIUM) == RiskTier.HIGH
+
+
+def test_judgment_carries_risk_tier():
+    assert evaluate(agent_output="You should restart the router.").risk_tier == RiskTier.HIGH
+    assert evaluate(agent_output="Hello!").risk_tier == RiskTier.LOW
+
+
+def test_policy_risk_floor_applies_to_judgment():
+    judgment = evaluate(agent_output="Hello!", policy={"risk_floor": "medium"})
+    assert judgment.risk_tier == RiskTier.MEDIUM
+    assert judgment.model_dump(mode="json")["risk_tier"] == "medium"
+
+
+@pytest.mark.parametrize(
+    "modality,predicate,status,tier",
+    [
+        ("commissive", None, AdmissibilityStatus.CONDITIONALLY_ACCEPTABLE, RiskTier.HIGH),
+        ("assertive", "execution", AdmissibilityStatus.UNDERDETERMINED, RiskTier.HIGH),
+        ("assertive", "action", AdmissibilityStatus.INSUFFICIENT_CITATIONS, RiskTier.HIGH),
+        ("assertive", None, AdmissibilityStatus.UNDERDETERMINED, RiskTier.MEDIUM),
+        ("commissive", None, AdmissibilityStatus.ACCEPTABLE, RiskTier.LOW),
+    ],
+)
+def test_commitments_and_actions_rank_one_tier_higher(modality, predicate, status, tier):
+    evaluation = _evaluation(status, modality=modality, predicate=predicate)
+    assert _derive_risk_tier(AdmissibilityStatus.ACCEPTABLE, RiskTier.LOW, [evaluation]) == tier
+
+
+def test_violated_axiom_severity_raises_tier():
+    evaluation = _evaluation(AdmissibilityStatus.UNDERDETERMINED, violated_axiom="ORG1")
+    severities = {"ORG1": RiskTier.HIGH}
+
+    assert _derive_risk_tier(evaluation.status, RiskTier.LOW, [evaluation]) == RiskTier.MEDIUM
+    assert (
+        _derive_risk_tier(evaluation.status, RiskTier.LOW, [evaluation], severities)
+        == RiskTier.HIGH
+    )
+
+
+def test_judgment_tier_uses_registered_axiom_severity():
+    # Reports underdetermined, but declares violates_norm as its severity
+    axiom = RuleAxiom(
+        "ORG1",
+        "Deployment advice needs a change ticket",
+        EvaluationStatus.VIOLATES_NORM,
+        lambda statement, context: AxiomCheckResult(
+            status=EvaluationStatus.UNDERDETERMINED,
+            violated_axiom="ORG1",
+            explanation="No change ticket",
+        ),
+    )
+    registry = AxiomRegistry()
+    registry.register(axiom, before="I1")
+    evaluator = AdmissibilityEvaluator.builder().axiom_registry(registry).build()
+
+    judgment = evaluate(agent_output="We should deploy now.", evaluator=evaluator)
+    assert judgment.status == "underdetermined"
+    assert judgment.risk_tier == RiskTier.HIGH
+
+
+def test_domain_risk_floor_applies_to_declared_domain_only():
+    policy = {"risk_floor": "medium", "domain_risk_floors": {"medical": "high"}}
+
+    assert evaluate(agent_output="Hello!", policy=policy, domain="medical").risk_tier == "high"
+    assert evaluate(agent_output="Hello!", policy=policy, domain="retail").risk_tier == "medium"
+    assert evaluate(agent_output="Hello!", policy=policy).risk_tier == "medium"
+
+
+def test_domain_risk_floor_never_lowers_policy_floor():
+    policy = EvaluationPolicy(risk_floor="high", domain_risk_floors={"support": "low"})
+    assert policy.domain_risk_floor("support") == RiskTier.HIGH
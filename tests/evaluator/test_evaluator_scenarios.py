@@ -0,0 +1,97 @@
+import json
+from pathlib import Path
+
+import pytest
+
+from normcore import load_scenario, run_scenario
+from normcore.cli import main as cli_main
+from normcore.scenarios import compile_turns, run_scenarios, scenario_from_dict
+
+SCENARIO_DIR = Path(__file__).parents[1] / "scenarios"
+
+
+@pytest.mark.parametrize("path", sorted(SCENARIO_DIR.iterdir()), ids=lambda path: path.name)
+def test_shared_scenarios_pass(path):
+    result = run_scenario(load_scenario(path))
+    assert result.error is None
+    assert result.mismatches == {}
+
+
+def test_tool_turns_compile_to_one_assistant_call_message():
+    conversation = compile_turns(
+        [
+            {"user": "Status?"},
+            {"tool": "get_ci", "result": {"status": "green"}},
+            {"tool": "get_release", "id": "rel", "arguments": {"n": 1}, "result": "ready"},
+            {"assistant": "All good."},
+        ]
+    )
+    assert [message["role"] for message in conversation] == [
+        "user",
+        "assistant",
+        "tool",
+        "tool",
+        "assistant",
+    ]
+    calls = conversation[1]["tool_calls"]
+    assert [call["id"] for call in calls] == ["call_1", "rel"]
+    assert calls[1]["function"] == {"name": "get_release", "arguments": '{"n": 1}'}
+    assert conversation[2]["content"] == '{"status": "green"}'
+    assert conversation[3] == {"role": "tool", "tool_call_id": "rel", "content": "ready"}
+
+
+def test_mismatches_name_the_differing_fields():
+    scenario = scenario_from_dict(
+        {
+            "turns": [{"assistant": "We should deploy now."}],
+            "expect": {"status": "acceptable", "statements": [{"modality": "assertive"}]},
+        },
+        name="flipped",
+    )
+    result = run_scenario(scenario)
+    assert not result.passed
+    assert result.mismatches == {"status": ("acceptable", "violates_norm")}
+
+
+@pytest.mark.parametrize(
+    "data,message",
+    [
+        ({"turns": [], "expect": {"status": "acceptable"}}, "non-empty list"),
+        ({"turns": [{"user": "Hi", "assistant": "Hi"}], "expect": {}}, "exactly one of"),
+        ({"turns": [{"user": "Deploy?"}], "expect": {"status": "acceptable"}}, "last scenario"),
+        ({"turns": [{"tool": "get_ci"}, {"assistant": "Ok."}], "expect": {}}, "has no result"),
+        ({"turns": [{"assistant": "Ok."}], "expect": {"verdict": "ok"}}, "Unknown expected"),
+        ({"turns": [{"assistant": "Ok."}], "expect": {"status": "fine"}}, "not a valid"),
+        ({"turns": [{"assistant": "Ok."}], "expected": {}}, "Unknown scenario keys"),
+    ],
+)
+def test_invalid_scenarios_are_rejected(data, message):
+    with pytest.raises(ValueError, match=message):
+        scenario_from_dict(data)
+
+
+def test_run_scenarios_reports_unloadable_files(tmp_path):
+    broken = tmp_path / "broken.json"
+    broken.write_text(json.dumps({"turns": [{"user": "Hi"}], "expect": {}}), encoding="utf-8")
+    (result,) = run_scenarios([broken])
+    assert result.scenario.name == "broken"
+    assert result.error == "The last scenario turn must be an assistant or refusal turn"
+
+
+def test_golden_runner_checks_declarative_scenarios(tmp_path, capsys):
+    for path in SCENARIO_DIR.iterdir():
+        (tmp_path / path.name).write_text(path.read_text(encoding="utf-8"), encoding="utf-8")
+    flipped = tmp_path / "flipped.json"
+    flipped.write_text(
+        json.dumps(
+            {"turns": [{"assistant": "We should deploy now."}], "expect": {"status": "acceptable"}}
+        ),
+        encoding="utf-8",
+    )
+
+    assert cli_main(["golden", str(tmp_path), "--update"]) == 1
+    out = capsys.readouterr().out
+    assert '-  "status": "acceptable"' in out
+    assert '+  "status": "violates_norm"' in out
+    assert out.rstrip().endswith("4 passed, 1 failed")
+    assert json.loads(flipped.read_text(encoding="utf-8"))["expect"] == {"status": "acceptable"}
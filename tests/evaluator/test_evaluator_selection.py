@@ -0,0 +1,79 @@
+import json
+
+import pytest
+
+from normcore import evaluate
+from normcore.cli import main as cli_main
+
+_TEXT = "Hello there. If the build is green, we should deploy."
+
+
+def test_span_restricts_evaluated_text():
+    start = _TEXT.index("If")
+    judgment = evaluate(agent_output=_TEXT, span=(start, len(_TEXT)))
+    assert judgment.statement_evaluations[0].statement == _TEXT[start:]
+
+
+def test_span_outside_text_is_rejected():
+    with pytest.raises(ValueError, match="outside assistant text"):
+        evaluate(agent_output=_TEXT, span=[0, len(_TEXT) + 1])
+
+
+def test_statement_ids_skip_unselected_statements():
+    conversation = [
+        {
+            "role": "assistant",
+            "content": "We should deploy now.",
+            "tool_calls": [
+                {
+                    "id": "c1",
+                    "type": "function",
+                    "function": {
+                        "name": "notify",
+                        "arguments": json.dumps({"text": "If tests pass, we should deploy."}),
+                    },
+                }
+            ],
+        }
+    ]
+    policy = {"tool_claim_fields": {"notify": ["text"]}}
+
+    full = evaluate(conversation=conversation, policy=policy)
+    assert full.status.value == "violates_norm"
+
+    selected = evaluate(
+        conversation=conversation, policy=policy, statement_ids=["tool_call:c1:text"]
+    )
+    assert selected.status.value == "conditionally_acceptable"
+    assert [e.statement_id for e in selected.statement_evaluations] == ["tool_call:c1:text"]
+
+
+def test_statement_ids_without_match_is_underdetermined():
+    judgment = evaluate(agent_output="We should deploy now.", statement_ids=["s9"])
+    assert judgment.status.value == "underdetermined"
+    assert judgment.statement_evaluations == []
+
+
+def test_cli_span_and_statement_id_flags(capsys):
+    start = _TEXT.index("If")
+    assert (
+        cli_main(
+            [
+                "evaluate",
+                "--agent-output",
+                _TEXT,
+                "--span",
+                f"{start}:{len(_TEXT)}",
+                "--statement-id",
+                "final_response",
+            ]
+        )
+        == 0
+    )
+    payload = json.loads(capsys.readouterr().out)
+    assert payload["status"] == "conditionally_acceptable"
+
+
+def test_cli_rejects_malformed_span():
+    with pytest.raises(SystemExit):
+        cli_main(["evaluate", "--agent-output", _TEXT, "--span", "5"])
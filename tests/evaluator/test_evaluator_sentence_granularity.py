@@ -0,0 +1,54 @@
+from normcore import evaluate
+
+ANSWER = "The deploy is safe. You should roll back if the health check fails."
+
+
+def test_response_granularity_is_the_default():
+    judgment = evaluate(agent_output=ANSWER)
+    assert [e.statement_id for e in judgment.statement_evaluations] == ["final_response"]
+
+
+def test_sentence_granularity_names_the_failing_sentence():
+    judgment = evaluate(agent_output=ANSWER, policy={"statement_granularity": "sentence"})
+
+    assert [(e.statement_id, e.statement, e.status) for e in judgment.statement_evaluations] == [
+        ("s1", "The deploy is safe.", "violates_norm"),
+        ("s2", "You should roll back if the health check fails.", "conditionally_acceptable"),
+    ]
+    assert judgment.status == "violates_norm"
+
+
+def test_sentence_statements_weigh_as_final_response():
+    judgment = evaluate(
+        agent_output=ANSWER + "\n\n```bash\nkubectl rollout undo deploy/api\n```\n",
+        policy={
+            "statement_granularity": "sentence",
+            "code_blocks": "flag_executable",
+            "aggregation": "weighted",
+            "statement_class_weights": {"final_response": 0.5},
+        },
+    )
+
+    weights = {e.statement_id: e.weight for e in judgment.statement_evaluations}
+    assert weights == {"s1": 0.25, "s2": 0.25, "code:1:1": 0.5}
+
+
+CITED_ONCE = "You should restart the runner [@call_ci]. You should also wipe the database."
+
+
+def test_one_citation_licenses_the_whole_response_by_default(ci_conversation):
+    judgment = evaluate(conversation=ci_conversation(CITED_ONCE))
+    assert judgment.status == "acceptable"
+
+
+def test_sentence_citations_license_only_their_sentence(ci_conversation):
+    judgment = evaluate(
+        conversation=ci_conversation(CITED_ONCE),
+        policy={"statement_granularity": "sentence"},
+    )
+
+    assert [(e.statement_id, e.status) for e in judgment.statement_evaluations] == [
+        ("s1", "acceptable"),
+        ("s2", "violates_norm"),
+    ]
+    assert judgment.cited_ground_ids
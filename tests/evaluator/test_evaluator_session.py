@@ -0,0 +1,96 @@
+import json
+
+import pytest
+
+from normcore import InputLimitError, SessionEvaluator, derive_grounds, evaluate
+
+
+def _tool_turn(call_id: str, name: str, result: dict) -> list[dict]:
+    return [
+        {
+            "role": "assistant",
+            "content": None,
+            "tool_calls": [
+                {"id": call_id, "type": "function", "function": {"name": name, "arguments": "{}"}}
+            ],
+        },
+        {"role": "tool", "tool_call_id": call_id, "content": json.dumps(result)},
+    ]
+
+
+SESSION = [
+    {"role": "user", "content": "Why is CI red?"},
+    *_tool_turn("call_ci", "get_ci_status", {"runner": "offline"}),
+    {"role": "assistant", "content": "You should restart the runner [@call_ci]."},
+    {"role": "user", "content": "And the database?"},
+    {"role": "assistant", "content": "You should restore the database [@call_db]."},
+    *_tool_turn("call_db", "get_db_status", {"db": "corrupt"}),
+    {"role": "assistant", "content": "You should restore the database [@call_db]."},
+]
+
+
+def _dump(judgment):
+    return judgment.model_dump(mode="json")
+
+
+def test_session_judgments_match_evaluating_each_prefix():
+    session = SessionEvaluator()
+    for index, message in enumerate(SESSION):
+        session.add(message)
+        if message["role"] == "assistant" and message["content"]:
+            expected = evaluate(conversation=SESSION[: index + 1])
+            assert _dump(session.evaluate()) == _dump(expected)
+
+
+def test_session_applies_ground_window_to_accumulated_results():
+    policy = {"ground_window_turns": 1}
+    session = SessionEvaluator(policy)
+    session.extend(SESSION[:6])
+
+    judgment = session.evaluate()
+    assert _dump(judgment) == _dump(evaluate(conversation=SESSION[:6], policy=policy))
+    assert judgment.out_of_window_ground_ids
+
+
+def test_session_keeps_parsed_knowledge_nodes():
+    session = SessionEvaluator()
+    session.extend(SESSION[:3])
+
+    assert [node.id for node in session.knowledge_nodes] == [
+        node.id for node in derive_grounds(SESSION[:3]).knowledge_nodes
+    ]
+    assert session.messages == SESSION[:3]
+
+
+def test_unmatched_tool_result_warning_is_reported_on_each_judgment():
+    session = SessionEvaluator()
+    session.extend(
+        [
+            {"role": "user", "content": "Status?"},
+            {"role": "tool", "tool_call_id": "call_x", "content": '{"status": "green"}'},
+            {"role": "assistant", "content": "You should deploy now."},
+        ]
+    )
+
+    assert session.evaluate().warnings == [
+        "Tool result 'call_x' matches no assistant tool call (trust)"
+    ]
+    with pytest.raises(ValueError, match="strict mode"):
+        session.evaluate(strict=True)
+
+
+def test_session_enforces_message_limit_when_adding():
+    session = SessionEvaluator({"max_messages": 2})
+    session.extend(SESSION[:2])
+
+    with pytest.raises(InputLimitError):
+        session.add(SESSION[2])
+    assert len(session.messages) == 2
+
+
+def test_session_evaluates_only_assistant_turns():
+    session = SessionEvaluator()
+    session.add(SESSION[0])
+
+    with pytest.raises(ValueError, match="latest session message must be an assistant"):
+        session.evaluate()
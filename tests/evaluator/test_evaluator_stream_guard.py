@@ -0,0 +1,92 @@
+import json
+
+import pytest
+
+from normcore import GateDecision, StreamGuard, evaluate
+
+CONVERSATION = [
+    {"role": "user", "content": "Why is CI red?"},
+    {
+        "role": "assistant",
+        "content": None,
+        "tool_calls": [
+            {
+                "id": "call_ci",
+                "type": "function",
+                "function": {"name": "get_ci_status", "arguments": "{}"},
+            }
+        ],
+    },
+    {"role": "tool", "tool_call_id": "call_ci", "content": json.dumps({"runner": "offline"})},
+]
+
+RESPONSE = (
+    "The runner is offline [@call_ci]. You must delete the database now. "
+    "You should restart the runner [@call_ci]."
+)
+
+
+def _stream(guard: StreamGuard, text: str, size: int = 5) -> list[tuple[str, list]]:
+    fed = []
+    for start in range(0, len(text), size):
+        fed.append((guard.text, guard.feed(text[start : start + size])))
+    return fed
+
+
+def test_stream_guard_flags_ungrounded_sentence_before_response_completes():
+    guard = StreamGuard(CONVERSATION)
+    events = [event for _, chunk_events in _stream(guard, RESPONSE) for event in chunk_events]
+
+    assert [(event.statement_id, event.statement) for event in events] == [
+        ("s2", "You must delete the database now.")
+    ]
+    assert events[0].gate_decision == GateDecision.BLOCK
+    assert events[0].violated_axiom
+    assert events[0].chars_received < len(RESPONSE)
+    assert guard.blocked
+
+
+def test_stream_guard_does_not_judge_the_sentence_still_streaming():
+    guard = StreamGuard(CONVERSATION)
+
+    assert guard.feed("You must delete the database") == []
+    assert guard.feed(" now. ") == []
+    assert [event.statement_id for event in guard.feed("The runner")] == ["s1"]
+
+
+def test_stream_guard_finish_matches_sentence_level_evaluation():
+    guard = StreamGuard(CONVERSATION)
+    _stream(guard, RESPONSE)
+
+    expected = evaluate(
+        conversation=[*CONVERSATION, {"role": "assistant", "content": RESPONSE}],
+        policy={"statement_granularity": "sentence"},
+    )
+    assert guard.finish().model_dump(mode="json") == expected.model_dump(mode="json")
+    assert [event.statement_id for event in guard.events] == ["s2"]
+
+
+def test_stream_guard_finish_reports_last_sentence():
+    guard = StreamGuard(CONVERSATION)
+    guard.feed("The runner is offline [@call_ci]. You must delete the database now.")
+
+    assert guard.events == []
+    guard.finish()
+    assert [event.statement_id for event in guard.events] == ["s2"]
+
+
+def test_stream_guard_policy_gates_apply_per_sentence():
+    guard = StreamGuard(CONVERSATION, policy={"gate_by_status": {"violates_norm": "revise"}})
+    _stream(guard, RESPONSE)
+
+    assert [event.gate_decision for event in guard.events] == [GateDecision.REVISE]
+    assert not guard.blocked
+
+
+def test_stream_guard_rejects_chunks_after_finish():
+    guard = StreamGuard(CONVERSATION)
+    guard.feed(RESPONSE)
+    guard.finish()
+
+    with pytest.raises(ValueError, match="already finished"):
+        guard.feed(" More.")
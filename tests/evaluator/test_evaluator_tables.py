@@ -0,0 +1,25 @@
+from normcore import evaluate
+
+
+def test_ungrounded_table_recommendation_is_evaluated():
+    judgment = evaluate(
+        agent_output="| Option | Verdict |\n| --- | --- |\n| Postgres | Recommended |\n"
+    )
+
+    assert judgment.status == "violates_norm"
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.statement_id == "table:1:1:2"
+    assert evaluation.subject == "Postgres"
+    assert evaluation.predicate == "Verdict"
+
+
+def test_table_cells_can_be_selected_by_statement_id():
+    judgment = evaluate(
+        agent_output=(
+            "You should pick Postgres.\n\n"
+            "| Option | Verdict |\n| --- | --- |\n| Postgres | Recommended |\n"
+        ),
+        statement_ids=["table:1:1:2"],
+    )
+
+    assert [e.statement_id for e in judgment.statement_evaluations] == ["table:1:1:2"]
@@ -0,0 +1,34 @@
+from normcore import evaluate
+
+
+def _conversation(answer: str):
+    return [
+        {"role": "user", "content": "What is the weather in New York?"},
+        {
+            "role": "assistant",
+            "content": "",
+            "tool_calls": [
+                {
+                    "id": "call_w",
+                    "type": "function",
+                    "function": {"name": "get_weather", "arguments": '{"city": "New York"}'},
+                }
+            ],
+        },
+        {"role": "tool", "tool_call_id": "call_w", "content": '{"temp_c": 21}'},
+        {"role": "assistant", "content": answer},
+    ]
+
+
+def test_tool_call_arguments_appear_as_contextual_grounds():
+    judgment = evaluate(conversation=_conversation("You should wear a light jacket [@call_w]."))
+    trace = judgment.statement_evaluations[0].grounding_trace
+    contextual = [ground for ground in trace if ground.scope == "contextual"]
+    assert len(contextual) == 1
+    assert contextual[0].id.startswith("args_get_weather_")
+    assert judgment.status == "acceptable"
+
+
+def test_contextual_arguments_are_not_cited_grounds():
+    judgment = evaluate(conversation=_conversation("You should wear a light jacket [@call_w]."))
+    assert all(not ground_id.startswith("args_") for ground_id in judgment.cited_ground_ids)
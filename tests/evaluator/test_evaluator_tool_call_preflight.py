@@ -0,0 +1,73 @@
+import json
+
+import pytest
+
+from normcore import EvaluationPolicy, check_tool_call
+
+
+def _call(name: str, call_id: str = "call_2", **arguments) -> dict:
+    return {
+        "id": call_id,
+        "type": "function",
+        "function": {"name": name, "arguments": json.dumps(arguments)},
+    }
+
+
+OBSERVED = [
+    {"role": "user", "content": "Clean up the stale branch."},
+    {"role": "assistant", "content": None, "tool_calls": [_call("list_branches", "call_1")]},
+    {"role": "tool", "tool_call_id": "call_1", "content": '{"stale": ["feature-x"]}'},
+]
+
+
+def test_state_changing_call_without_grounds_is_blocked():
+    judgment = check_tool_call(_call("delete_branch", branch="feature-x"), OBSERVED[:1])
+
+    [action] = judgment.statement_evaluations
+    assert action.statement_id == "tool_call:call_2"
+    assert action.statement == 'delete_branch({"branch": "feature-x"})'
+    assert (action.status, action.violated_axiom) == ("violates_norm", "A5")
+    assert judgment.gate_decision == "block"
+
+
+def test_state_changing_call_grounded_by_observed_results_is_allowed():
+    judgment = check_tool_call(
+        _call("delete_branch", branch="feature-x"),
+        OBSERVED,
+        policy={"min_citations_per_assertive": 1},
+    )
+    assert judgment.status == "acceptable"
+    assert judgment.gate_decision == "allow"
+
+
+def test_read_only_call_needs_no_license():
+    judgment = check_tool_call(_call("get_branch", branch="feature-x"))
+
+    assert judgment.status == "no_normative_content"
+    assert judgment.statement_evaluations == []
+
+
+def test_tool_effects_override_name_verbs():
+    policy = {"tool_effects": {"get_branch": "state_changing", "delete_branch": "read_only"}}
+
+    assert check_tool_call(_call("get_branch"), policy=policy).status == "violates_norm"
+    assert check_tool_call(_call("delete_branch"), policy=policy).status == "no_normative_content"
+
+
+@pytest.mark.parametrize(
+    "name,effect",
+    [
+        ("get_issue", "read_only"),
+        ("search", "read_only"),
+        ("deleteBranch", "state_changing"),
+        ("DROP_TABLE", "state_changing"),
+        ("send-email", "state_changing"),
+    ],
+)
+def test_unlisted_tools_are_classified_by_name_verbs(name, effect):
+    assert EvaluationPolicy().tool_effect(name) == effect
+
+
+def test_tool_call_must_be_an_object():
+    with pytest.raises(ValueError, match="tool_call must be an object"):
+        check_tool_call("delete_branch")
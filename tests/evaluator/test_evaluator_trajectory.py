@@ -0,0 +1,85 @@
+import pytest
+
+from normcore import evaluate, evaluate_trajectory
+
+
+def _tool_turn(call_id: str, name: str, result: str) -> list[dict]:
+    return [
+        {
+            "role": "assistant",
+            "content": None,
+            "tool_calls": [
+                {"id": call_id, "type": "function", "function": {"name": name, "arguments": "{}"}}
+            ],
+        },
+        {"role": "tool", "tool_call_id": call_id, "content": result},
+    ]
+
+
+CONVERSATION = [
+    {"role": "user", "content": "Why is CI red?"},
+    *_tool_turn("call_ci", "get_ci_status", '{"runner": "offline"}'),
+    {"role": "assistant", "content": "You should restart the runner [@call_ci]."},
+    {"role": "user", "content": "And the database?"},
+    {"role": "assistant", "content": "You should restore the database [@call_db]."},
+    *_tool_turn("call_db", "get_db_status", '{"db": "corrupt"}'),
+    {"role": "assistant", "content": "You should restore the database [@call_db]."},
+]
+
+
+def test_every_assistant_turn_is_judged_with_its_own_knowledge():
+    report = evaluate_trajectory(CONVERSATION)
+
+    assert report.message_indices == [3, 5, 8]
+    assert [judgment.status for judgment in report.judgments] == [
+        "acceptable",
+        "violates_norm",
+        "acceptable",
+    ]
+    # The final turn alone hides the break: its citation was observed by then
+    assert evaluate(conversation=CONVERSATION).status == "acceptable"
+
+
+def test_aggregate_reports_worst_status_and_first_failing_turn():
+    report = evaluate_trajectory(CONVERSATION)
+
+    assert report.status == "violates_norm"
+    assert report.gate_decision == "block"
+    assert report.first_failing_index == 5
+
+
+def test_admissible_trajectory_has_no_failing_turn():
+    report = evaluate_trajectory(CONVERSATION[:4])
+
+    assert report.status == "acceptable"
+    assert report.gate_decision == "allow"
+    assert report.first_failing_index is None
+
+
+def test_underdetermined_turn_is_not_the_first_failing_turn():
+    conversation = [
+        {"role": "user", "content": "Can we deploy?"},
+        {"role": "assistant", "content": "We should deploy now."},
+        {"role": "user", "content": "Which hotel?"},
+        {"role": "assistant", "content": "This hotel is better for you."},
+    ]
+    # Without A4 and A5 no axiom decides the first turn
+    report = evaluate_trajectory(conversation, policy={"disabled_axioms": ["A4", "A5"]})
+
+    assert [judgment.status for judgment in report.judgments] == [
+        "underdetermined",
+        "unsupported",
+    ]
+    assert report.first_failing_index == 3
+
+
+def test_trajectory_without_assistant_content_has_no_judgments():
+    report = evaluate_trajectory(CONVERSATION[:3])
+
+    assert report.judgments == []
+    assert report.status == "no_normative_content"
+
+
+def test_trajectory_requires_a_conversation():
+    with pytest.raises(ValueError, match="non-empty list"):
+        evaluate_trajectory([])
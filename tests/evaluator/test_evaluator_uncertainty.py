@@ -0,0 +1,20 @@
+from normcore import evaluate
+
+
+def test_hedged_claim_is_not_punished_like_flat_assertion():
+    flat = evaluate(agent_output="You should roll back, the cache is the cause.")
+    hedged = evaluate(agent_output="You should roll back, the cache is most likely the cause.")
+
+    assert flat.status == "violates_norm"
+    assert hedged.status == "unsupported"
+    [evaluation] = hedged.statement_evaluations
+    assert evaluation.modality == "assertive"
+    assert evaluation.violated_axiom == "A4"
+    assert evaluation.uncertainty.markers == ["most likely"]
+    assert evaluation.uncertainty.probability == 0.7
+
+
+def test_flat_statement_has_no_uncertainty():
+    judgment = evaluate(agent_output="You should roll back the release.")
+    [evaluation] = judgment.statement_evaluations
+    assert evaluation.uncertainty is None
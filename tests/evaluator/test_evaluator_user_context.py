@@ -0,0 +1,62 @@
+from normcore import evaluate
+
+
+def _conversation(user_text: str, answer: str):
+    return [
+        {"role": "user", "content": user_text},
+        {
+            "role": "assistant",
+            "content": "",
+            "tool_calls": [
+                {
+                    "id": "call_h",
+                    "type": "function",
+                    "function": {"name": "search_hotels", "arguments": "{}"},
+                }
+            ],
+        },
+        {"role": "tool", "tool_call_id": "call_h", "content": '{"hotel_id": "H1"}'},
+        {"role": "assistant", "content": answer},
+    ]
+
+
+ANSWER = "Hotel H1 is the better choice for you [@call_h]."
+
+
+def test_user_preference_becomes_contextual_ground():
+    judgment = evaluate(
+        conversation=_conversation("I prefer quiet neighborhoods. Which hotel?", ANSWER)
+    )
+    evaluation = judgment.statement_evaluations[0]
+    user_context = [g for g in evaluation.grounding_trace if g.source == "explicit"]
+    assert [g.scope for g in user_context] == ["contextual"]
+    assert evaluation.personalized
+    assert "conditional" in evaluation.license
+    assert judgment.status == "conditionally_acceptable"
+
+
+def test_personalization_without_user_context_violates_a9():
+    judgment = evaluate(conversation=_conversation("Which hotel should I book?", ANSWER))
+    evaluation = judgment.statement_evaluations[0]
+    assert evaluation.license == {"refusal"}
+    assert evaluation.status == "unsupported"
+    assert evaluation.violated_axiom == "A9"
+    assert judgment.status == "unsupported"
+    assert "Ask the user" in judgment.feedback_hint
+
+
+def test_user_facts_grounds_count_as_user_context():
+    judgment = evaluate(
+        conversation=_conversation("Which hotel should I book?", ANSWER),
+        grounds={"user_facts": ["pref_quiet"]},
+    )
+    evaluation = judgment.statement_evaluations[0]
+    assert "conditional" in evaluation.license
+
+
+def test_questions_are_not_user_context():
+    judgment = evaluate(
+        conversation=_conversation("I have a question: which hotel is cheapest?", ANSWER)
+    )
+    trace = judgment.statement_evaluations[0].grounding_trace
+    assert all(g.source != "explicit" for g in trace)
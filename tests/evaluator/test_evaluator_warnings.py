@@ -0,0 +1,66 @@
+from normcore import evaluate
+from normcore.logging import collect_warnings, report_warning
+
+
+def _conversation(arguments: str):
+    return [
+        {"role": "user", "content": "Email the team."},
+        {
+            "role": "assistant",
+            "content": "We should deploy now.",
+            "tool_calls": [
+                {
+                    "id": "call_mail",
+                    "type": "function",
+                    "function": {"name": "send_email", "arguments": arguments},
+                }
+            ],
+        },
+    ]
+
+
+def test_clean_input_has_no_warnings():
+    judgment = evaluate(
+        agent_output="We should deploy now [@doc].",
+        grounds=[{"citation_key": "doc", "ground_id": "file_doc"}],
+    )
+    assert judgment.warnings == []
+
+
+def test_grounds_missing_citation_key_are_reported():
+    judgment = evaluate(agent_output="We should deploy now.", grounds=[{"ground_id": "file_doc"}])
+    assert judgment.status.value == "violates_norm"
+    assert len(judgment.warnings) == 1
+    assert judgment.warnings[0].startswith("Invalid grounds ignored")
+
+
+def test_invalid_sectioned_grounds_and_links_are_reported():
+    judgment = evaluate(
+        agent_output="We should deploy now.",
+        grounds={"documents": "doc", "notes": []},
+        links={"links": [{"ground_id": "g"}]},
+    )
+    assert [w.split(":")[0] for w in judgment.warnings] == [
+        "Unknown grounds sections ignored",
+        "Grounds section 'documents' must be a list; ignored",
+        "Invalid links dict ignored",
+    ]
+
+
+def test_unparsable_tool_arguments_are_reported_once():
+    judgment = evaluate(
+        conversation=_conversation("{not json"),
+        policy={"tool_claim_fields": {"send_email": ["body"]}},
+    )
+    assert judgment.warnings == ["Unparsable arguments of tool call 'call_mail' ignored"]
+    assert evaluate(conversation=_conversation('{"body": "hi"}')).warnings == []
+
+
+def test_collector_is_scoped_to_one_evaluation():
+    report_warning("outside any evaluation")
+    with collect_warnings() as outer:
+        report_warning("outer")
+        judgment = evaluate(agent_output="We should deploy now.", grounds=[{"ground_id": "g"}])
+        report_warning("outer")
+    assert outer == ["outer"]
+    assert judgment.warnings and "outer" not in judgment.warnings
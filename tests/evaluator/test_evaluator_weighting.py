@@ -0,0 +1,50 @@
+import pytest
+from pydantic import ValidationError
+
+from normcore import EvaluationPolicy, evaluate
+
+_TEXT = (
+    "| Option | Verdict |\n|---|---|\n| A | You should deploy now |\n\n"
+    "If the integration tests pass, we should deploy after the freeze window closes on Friday."
+)
+
+
+def test_lexicographic_aggregation_lets_any_statement_decide():
+    judgment = evaluate(agent_output=_TEXT)
+    assert judgment.status == "violates_norm"
+    assert [e.weight for e in judgment.statement_evaluations] == [0.5, 0.5]
+
+
+def test_weighted_aggregation_discounts_short_aside():
+    policy = {"aggregation": "weighted", "statement_weighting": "length"}
+    judgment = evaluate(agent_output=_TEXT, policy=policy)
+
+    statuses = {e.statement_id: e.status.value for e in judgment.statement_evaluations}
+    assert statuses == {"final_response": "conditionally_acceptable", "table:1:1:2": "violates_norm"}
+    assert judgment.status == "conditionally_acceptable"
+    assert "1 low-weight statement(s) discounted" in judgment.explanation
+    assert judgment.violated_axioms == ["A5"]
+
+
+def test_class_weights_can_restore_the_violation():
+    policy = {
+        "aggregation": "weighted",
+        "statement_weighting": "length",
+        "statement_class_weights": {"table": 5.0},
+    }
+    assert evaluate(agent_output=_TEXT, policy=policy).status == "violates_norm"
+
+
+def test_position_weighting_favors_earlier_statements():
+    policy = {"aggregation": "weighted", "statement_weighting": "position"}
+    judgment = evaluate(agent_output=_TEXT, policy=policy)
+    weights = {e.statement_id: e.weight for e in judgment.statement_evaluations}
+    assert weights == {"final_response": 0.6667, "table:1:1:2": 0.3333}
+    assert judgment.status == "violates_norm"
+
+
+def test_invalid_weighting_policy_is_rejected():
+    with pytest.raises(ValidationError):
+        EvaluationPolicy(statement_class_weights={"footnote": 1.0})
+    with pytest.raises(ValidationError):
+        EvaluationPolicy(weighted_status_threshold=0)
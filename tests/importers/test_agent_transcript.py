@@ -0,0 +1,85 @@
+import json
+
+from normcore.cli import main as cli_main
+from normcore.importers import conversation_from_agent_transcript, payload_from_agent_transcript
+
+_MESSAGES = [
+    {"role": "user", "content": "Does the parser handle empty input?"},
+    {
+        "role": "assistant",
+        "content": [
+            {"type": "thinking", "thinking": "Let me look."},
+            {"type": "text", "text": "Checking the parser."},
+            {
+                "type": "tool_use",
+                "id": "toolu_1",
+                "name": "read_file",
+                "input": {"path": "src/parser.rs"},
+            },
+        ],
+    },
+    {
+        "role": "user",
+        "content": [
+            {
+                "type": "tool_result",
+                "tool_use_id": "toolu_1",
+                "content": [{"type": "text", "text": "fn parse(s: &str) { s.is_empty(); }"}],
+            }
+        ],
+    },
+    {"role": "assistant", "content": [{"type": "text", "text": "You should add a test."}]},
+]
+
+
+def test_tool_use_blocks_become_tool_calls_and_tool_messages():
+    trajectory = conversation_from_agent_transcript(_MESSAGES)
+    assert [m["role"] for m in trajectory] == ["user", "assistant", "tool", "assistant"]
+    assistant = trajectory[1]
+    assert assistant["content"] == "Checking the parser."
+    call = assistant["tool_calls"][0]
+    assert call["id"] == "toolu_1"
+    assert call["function"]["name"] == "read_file"
+    assert json.loads(call["function"]["arguments"]) == {"path": "src/parser.rs"}
+    assert trajectory[2] == {
+        "role": "tool",
+        "tool_call_id": "toolu_1",
+        "content": "fn parse(s: &str) { s.is_empty(); }",
+    }
+
+
+def test_split_assistant_entries_are_merged():
+    entries = [
+        {"role": "user", "content": "Run it"},
+        {"role": "assistant", "content": [{"type": "text", "text": "Running."}]},
+        {
+            "role": "assistant",
+            "content": [{"type": "tool_use", "id": "t1", "name": "bash", "input": {}}],
+        },
+    ]
+    trajectory = conversation_from_agent_transcript(entries)
+    assert len(trajectory) == 2
+    assert trajectory[1]["content"] == "Running."
+    assert trajectory[1]["tool_calls"][0]["id"] == "t1"
+
+
+def test_jsonl_envelopes_and_session_id(tmp_path):
+    path = tmp_path / "session.jsonl"
+    lines = [{"type": "summary", "summary": "ignored"}] + [
+        {"type": m["role"], "sessionId": "sess-9", "message": m} for m in _MESSAGES
+    ]
+    path.write_text("\n".join(json.dumps(line) for line in lines), encoding="utf-8")
+
+    payload = payload_from_agent_transcript(path)
+    assert payload["id"] == "sess-9"
+    assert len(payload["conversation"]) == 4
+
+
+def test_cli_import_agent_transcript(tmp_path, capsys):
+    path = tmp_path / "run-42.json"
+    path.write_text(json.dumps({"messages": _MESSAGES}), encoding="utf-8")
+
+    assert cli_main(["import", "--from", "agent-transcript", str(path), "--evaluate"]) == 0
+    judgment = json.loads(capsys.readouterr().out)
+    assert judgment["id"] == "run-42"
+    assert judgment["statement_evaluations"][0]["statement"] == "You should add a test."
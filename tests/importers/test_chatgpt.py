@@ -0,0 +1,94 @@
+import json
+
+from normcore.cli import main as cli_main
+from normcore.importers import conversation_from_chatgpt, payloads_from_chatgpt_export
+
+
+def _node(node_id, parent, children, role=None, text=None, **message_fields):
+    message = None
+    if role is not None:
+        content = message_fields.pop("content", {"content_type": "text", "parts": [text]})
+        message = {
+            "id": node_id,
+            "author": {"role": role},
+            "content": content,
+            "recipient": message_fields.pop("recipient", "all"),
+            "metadata": message_fields.pop("metadata", {}),
+        }
+    return node_id, {"id": node_id, "parent": parent, "children": children, "message": message}
+
+
+def _export():
+    nodes = dict(
+        [
+            _node("root", None, ["sys"]),
+            _node(
+                "sys",
+                "root",
+                ["u1"],
+                role="system",
+                text="",
+                metadata={"is_visually_hidden_from_conversation": True},
+            ),
+            _node("u1", "sys", ["a_old", "think"], role="user", text="Is the deploy blocked?"),
+            _node("a_old", "u1", [], role="assistant", text="Discarded regeneration."),
+            _node(
+                "think",
+                "u1",
+                ["call"],
+                role="assistant",
+                content={"content_type": "thoughts", "thoughts": []},
+            ),
+            _node(
+                "call",
+                "think",
+                ["tool"],
+                role="assistant",
+                recipient="ci_status",
+                content={"content_type": "code", "text": "pipeline main"},
+            ),
+            _node(
+                "tool",
+                "call",
+                ["a1"],
+                role="tool",
+                content={"content_type": "execution_output", "text": "status: failing"},
+            ),
+            _node("a1", "tool", [], role="assistant", text="We should deploy now."),
+        ]
+    )
+    return [
+        {"conversation_id": "conv-1", "title": "Deploy", "current_node": "a1", "mapping": nodes}
+    ]
+
+
+def test_conversation_follows_current_branch_and_maps_tool_calls():
+    trajectory = conversation_from_chatgpt(_export()[0])
+    assert [m["role"] for m in trajectory] == ["user", "assistant", "tool", "assistant"]
+    call = trajectory[1]["tool_calls"][0]
+    assert call["id"] == "call"
+    assert call["function"]["name"] == "ci_status"
+    assert json.loads(call["function"]["arguments"]) == {"input": "pipeline main"}
+    assert trajectory[2] == {"role": "tool", "tool_call_id": "call", "content": "status: failing"}
+    assert trajectory[3]["content"] == "We should deploy now."
+    assert all("Discarded" not in str(m.get("content")) for m in trajectory)
+
+
+def test_payloads_use_conversation_id():
+    payloads = list(payloads_from_chatgpt_export(_export()))
+    assert [p["id"] for p in payloads] == ["conv-1"]
+
+
+def test_cli_import_chatgpt_emits_payloads_or_judgments(tmp_path, capsys):
+    path = tmp_path / "conversations.json"
+    path.write_text(json.dumps(_export()), encoding="utf-8")
+
+    assert cli_main(["import", "--from", "chatgpt", str(path)]) == 0
+    payload = json.loads(capsys.readouterr().out)
+    assert payload["id"] == "conv-1"
+    assert payload["conversation"][-1]["role"] == "assistant"
+
+    assert cli_main(["import", "--from", "chatgpt", str(path), "--evaluate"]) == 0
+    judgment = json.loads(capsys.readouterr().out)
+    assert judgment["id"] == "conv-1"
+    assert "status" in judgment
@@ -0,0 +1,118 @@
+import io
+import json
+import sys
+
+import pytest
+
+from normcore.cli import main as cli_main
+from normcore.importers import load_batch_requests, payload_from_batch_output
+
+
+def _output_record(custom_id, message, status_code=200, error=None):
+    return {
+        "id": f"batch_req_{custom_id}",
+        "custom_id": custom_id,
+        "response": {
+            "status_code": status_code,
+            "request_id": "req_x",
+            "body": {
+                "object": "chat.completion",
+                "choices": [{"index": 0, "message": message, "finish_reason": "stop"}],
+            },
+        },
+        "error": error,
+    }
+
+
+_TOOL_CONTEXT = [
+    {"role": "user", "content": "Is the deploy blocked?"},
+    {
+        "role": "assistant",
+        "content": None,
+        "tool_calls": [
+            {
+                "id": "callCI",
+                "type": "function",
+                "function": {"name": "ci_status", "arguments": "{}"},
+            }
+        ],
+    },
+    {"role": "tool", "tool_call_id": "callCI", "content": '{"status": "failing"}'},
+]
+
+
+def test_payload_from_batch_output_keeps_assistant_fields_only():
+    record = _output_record(
+        "r1",
+        {"role": "assistant", "content": "We should deploy.", "refusal": None, "annotations": []},
+    )
+    payload = payload_from_batch_output(record)
+    assert payload == {
+        "id": "r1",
+        "conversation": [{"role": "assistant", "content": "We should deploy."}],
+    }
+
+
+def test_payload_from_batch_output_joins_request_context():
+    requests = load_batch_requests(
+        [json.dumps({"custom_id": "r1", "method": "POST", "body": {"messages": _TOOL_CONTEXT}})]
+    )
+    record = _output_record("r1", {"role": "assistant", "content": "Deploy is blocked."})
+    payload = payload_from_batch_output(record, requests)
+    assert payload["conversation"][:-1] == _TOOL_CONTEXT
+    assert payload["conversation"][-1]["content"] == "Deploy is blocked."
+
+
+def test_payload_from_batch_output_rejects_failed_requests():
+    with pytest.raises(ValueError, match="failed"):
+        payload_from_batch_output(_output_record("r1", {}, error={"code": "server_error"}))
+    with pytest.raises(ValueError, match="HTTP 500"):
+        payload_from_batch_output(_output_record("r1", {}, status_code=500))
+
+
+def test_stream_openai_batch_format(monkeypatch, capsys, tmp_path):
+    requests_path = tmp_path / "batch_input.jsonl"
+    requests_path.write_text(
+        json.dumps({"custom_id": "r1", "body": {"messages": _TOOL_CONTEXT}}) + "\n",
+        encoding="utf-8",
+    )
+    lines = [
+        json.dumps(_output_record("r1", {"role": "assistant", "content": "We should deploy."})),
+        json.dumps(_output_record("r2", {}, status_code=429)),
+    ]
+    monkeypatch.setattr(sys, "stdin", io.StringIO("".join(f"{line}\n" for line in lines)))
+
+    argv = ["stream", "--input-format", "openai-batch", "--batch-requests", str(requests_path)]
+    assert cli_main(argv) == 0
+
+    records = [json.loads(line) for line in capsys.readouterr().out.splitlines()]
+    assert records[0]["id"] == "r1"
+    assert "status" in records[0]
+    assert "'r2'" in records[1]["error"]
+    assert "HTTP 429" in records[1]["error"]
+
+
+def test_batch_reads_openai_batch_output_file(capsys, tmp_path):
+    requests_path = tmp_path / "batch_input.jsonl"
+    requests_path.write_text(
+        json.dumps({"custom_id": "r1", "body": {"messages": _TOOL_CONTEXT}}) + "\n",
+        encoding="utf-8",
+    )
+    output_path = tmp_path / "batch_output.jsonl"
+    output_path.write_text(
+        json.dumps(_output_record("r1", {"role": "assistant", "content": "We should deploy."}))
+        + "\n",
+        encoding="utf-8",
+    )
+
+    argv = ["batch", str(output_path), "--input-format", "openai-batch"]
+    assert cli_main([*argv, "--batch-requests", str(requests_path)]) == 0
+    (record,) = [json.loads(line) for line in capsys.readouterr().out.splitlines()]
+    assert record["id"] == "r1"
+    assert "status" in record
+
+
+def test_batch_requests_require_openai_batch_format(capsys, tmp_path):
+    with pytest.raises(SystemExit):
+        cli_main(["batch", str(tmp_path / "payloads.jsonl"), "--batch-requests", "in.jsonl"])
+    assert "--batch-requests requires --input-format openai-batch" in capsys.readouterr().err
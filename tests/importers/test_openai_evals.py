@@ -0,0 +1,70 @@
+import json
+
+from normcore.cli import main as cli_main
+from normcore.importers import EvalsRecorder, payloads_from_evals_jsonl
+from normcore.payloads import evaluate_payload
+
+_LINES = [
+    json.dumps(
+        {
+            "input": [
+                {"role": "system", "content": "Be careful."},
+                {"role": "user", "content": "Should we deploy?"},
+            ],
+            "ideal": "It depends.",
+            "completion": "We should deploy now.",
+        }
+    ),
+    json.dumps({"input": "Plain sample without completion", "ideal": "x"}),
+    json.dumps(
+        {
+            "run_id": "r",
+            "event_id": 3,
+            "sample_id": "deploy.dev.7",
+            "type": "sampling",
+            "data": {"prompt": "Is it safe?", "sampled": ["Hello!"]},
+        }
+    ),
+    json.dumps({"spec": {"eval_name": "other"}}),
+]
+
+
+def test_samples_and_sampling_events_become_payloads():
+    payloads = list(payloads_from_evals_jsonl(_LINES))
+    assert [p["id"] for p in payloads] == ["sample.0", "deploy.dev.7"]
+    assert payloads[0]["conversation"][-1] == {
+        "role": "assistant",
+        "content": "We should deploy now.",
+    }
+    assert payloads[1]["conversation"][0] == {"role": "user", "content": "Is it safe?"}
+
+
+def test_recorder_scores_passing_statuses():
+    recorder = EvalsRecorder(run_id="run1")
+    payloads = list(payloads_from_evals_jsonl(_LINES))
+    events = [recorder.record(p["id"], evaluate_payload(p)) for p in payloads]
+    events.append(recorder.record_error("broken", "bad conversation"))
+
+    assert [e["event_id"] for e in events] == [0, 1, 2]
+    assert [e["data"]["choice"] for e in events] == [
+        "violates_norm",
+        "no_normative_content",
+        "__invalid__",
+    ]
+    assert [e["data"]["score"] for e in events] == [0.0, 1.0, 0.0]
+    report = recorder.final_report()["final_report"]
+    assert report["score"] == 1 / 3
+    assert report["counts/violates_norm"] == 1
+
+
+def test_cli_import_openai_evals_recorder_output(tmp_path, capsys):
+    path = tmp_path / "samples.jsonl"
+    path.write_text("\n".join(_LINES) + "\n", encoding="utf-8")
+
+    argv = ["import", "--from", "openai-evals", str(path), "--evaluate"]
+    assert cli_main([*argv, "--output-format", "openai-evals"]) == 0
+
+    records = [json.loads(line) for line in capsys.readouterr().out.splitlines()]
+    assert records[0]["spec"]["eval_name"] == "graded_by_normcore"
+    assert [r["sample_id"] for r in records[1:-1]] == ["sample.0", "deploy.dev.7"]
+    assert records[-1]["final_report"]["score"] == 0.5
@@ -0,0 +1,92 @@
+import json
+
+import pytest
+
+from normcore.cli import main as cli_main
+from normcore.importers import payload_from_request_log
+
+_QUESTION = [
+    {"role": "system", "content": "You are a CI assistant."},
+    {"role": "user", "content": "Why is CI red?"},
+]
+_TOOL_CALL = {
+    "role": "assistant",
+    "content": None,
+    "tool_calls": [
+        {"id": "call_ci", "type": "function", "function": {"name": "ci_status", "arguments": "{}"}}
+    ],
+}
+_TOOL_RESULT = {"role": "tool", "tool_call_id": "call_ci", "content": '{"runner": "offline"}'}
+
+
+def _completion(completion_id, message):
+    return {
+        "id": completion_id,
+        "object": "chat.completion",
+        "choices": [{"index": 0, "message": message, "finish_reason": "stop"}],
+    }
+
+
+# An agent loop: the first call asks for a tool, the second answers from its result.
+_LOG = [
+    {
+        "request": {"model": "gpt-4o", "messages": _QUESTION},
+        "response": _completion("c1", _TOOL_CALL),
+    },
+    {
+        "request": {"model": "gpt-4o", "messages": [*_QUESTION, _TOOL_CALL, _TOOL_RESULT]},
+        "response": {
+            "status_code": 200,
+            "body": _completion(
+                "c2", {"role": "assistant", "content": "You should restart the runner [@call_ci]."}
+            ),
+        },
+    },
+]
+
+
+def test_request_log_record_replays_request_messages_and_response():
+    payload = payload_from_request_log(_LOG[1])
+
+    assert payload["id"] == "c2"
+    assert payload["conversation"][:-1] == [*_QUESTION, _TOOL_CALL, _TOOL_RESULT]
+    assert payload["conversation"][-1] == {
+        "role": "assistant",
+        "content": "You should restart the runner [@call_ci].",
+    }
+    assert payload_from_request_log({**_LOG[0], "id": "turn-1"})["id"] == "turn-1"
+
+
+@pytest.mark.parametrize(
+    "record,message",
+    [
+        ({"response": _completion("c", _TOOL_CALL)}, "no request.messages list"),
+        ({"request": {"messages": []}}, "no response object"),
+        ({"request": {"messages": []}, "error": "timeout"}, "Logged request failed: timeout"),
+        (
+            {"request": {"messages": []}, "response": {"status_code": 429, "body": {}}},
+            "returned HTTP 429",
+        ),
+        (
+            {"request": {"messages": []}, "response": {"id": "c", "choices": []}},
+            "Logged response 'c' is not a chat completion",
+        ),
+    ],
+)
+def test_invalid_request_log_records(record, message):
+    with pytest.raises(ValueError, match=message):
+        payload_from_request_log(record)
+
+
+def test_replay_evaluates_each_assistant_turn(tmp_path, capsys):
+    path = tmp_path / "requests.jsonl"
+    lines = [json.dumps(record) for record in _LOG] + ['{"request": {}}']
+    path.write_text("\n".join(lines) + "\n", encoding="utf-8")
+
+    assert cli_main(["replay", str(path), "--fail-on", "violates_norm"]) == 0
+
+    records = [json.loads(line) for line in capsys.readouterr().out.splitlines()]
+    assert [record.get("id") for record in records] == ["c1", "c2", None]
+    assert records[1]["status"] == "acceptable"
+    assert records[1]["grounds_cited"] == 1
+    assert records[2] == {"error": "Request log record has no request.messages list", "line": 3}
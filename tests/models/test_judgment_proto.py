@@ -0,0 +1,136 @@
+import re
+import struct
+
+import pytest
+
+from normcore import evaluate
+from normcore.models import (
+    AdmissibilityJudgment,
+    AdmissibilityStatus,
+    GateDecision,
+    RiskTier,
+    StatementAttribution,
+    StatementUncertainty,
+)
+from normcore.proto import (
+    PROTO_SCHEMA_PATH,
+    _JUDGMENT,
+    _Message,
+    judgment_from_protobuf,
+    judgment_to_protobuf,
+)
+
+
+def _rich_judgment():
+    conversation = [
+        {
+            "role": "assistant",
+            "content": "",
+            "tool_calls": [
+                {
+                    "id": "call_ci",
+                    "type": "function",
+                    "function": {"name": "get_ci_status", "arguments": "{}"},
+                }
+            ],
+        },
+        {
+            "role": "tool",
+            "tool_call_id": "call_ci",
+            "content": '{"pipeline_id": "ci-42", "status": "green"}',
+        },
+        {"role": "assistant", "content": "You should deploy now [@call_ci]. We must wait."},
+    ]
+    judgment = evaluate(
+        conversation=conversation,
+        grounds=[{"citation_key": "runbook", "ground_id": "kb-17"}, {"bogus": True}],
+        trace=True,
+    )
+    first = judgment.statement_evaluations[0].model_copy(
+        update={
+            "uncertainty": StatementUncertainty(markers=["probably"], probability=0.0),
+            "attribution": StatementAttribution(source="the team", marker="says"),
+            "weight": -0.0,
+            "subject": "",
+        }
+    )
+    return judgment.model_copy(
+        update={
+            "statement_evaluations": [first, *judgment.statement_evaluations[1:]],
+            "feedback_hint": "",
+            "reasoning_items_skipped": 3,
+        }
+    )
+
+
+def test_protobuf_roundtrip_is_lossless():
+    judgment = _rich_judgment()
+    assert judgment.status_counts and judgment.citation_map and judgment.warnings
+    assert judgment.statement_evaluations[0].license_trace is not None
+
+    decoded = judgment_from_protobuf(judgment_to_protobuf(judgment))
+    assert decoded == judgment
+    assert decoded.model_dump() == judgment.model_dump()
+    assert decoded.feedback_hint == "" and decoded.statement_evaluations[0].subject == ""
+
+
+def test_protobuf_wire_bytes_for_minimal_judgment():
+    judgment = AdmissibilityJudgment(
+        status=AdmissibilityStatus.ACCEPTABLE, licensed=True, can_retry=False
+    )
+    assert judgment_to_protobuf(judgment) == (
+        b"\x08\x01"  # status = ACCEPTABLE
+        b"\x10\x01"  # licensed = true
+        + b"\x59" + struct.pack("<d", 1.0)  # confidence = 1.0
+        + b"\x88\x01\x01"  # risk_tier = LOW
+        + b"\x90\x01\x01"  # gate_decision = ALLOW
+    )
+
+
+def test_protobuf_decoding_skips_unknown_fields_and_rejects_malformed_data():
+    data = judgment_to_protobuf(evaluate(agent_output="We should deploy now."))
+    unknown = b"\xf8\x06\x2a" + b"\xfa\x06\x02hi"  # fields 111 (varint) and 111 (bytes)
+    assert judgment_from_protobuf(data + unknown) == judgment_from_protobuf(data)
+
+    with pytest.raises(ValueError, match="Truncated"):
+        judgment_from_protobuf(data[:-1])
+    with pytest.raises(ValueError, match="Invalid judgment message"):
+        judgment_from_protobuf(b"\x10\x01")  # no status
+    with pytest.raises(ValueError, match="Unknown AdmissibilityStatus value 99"):
+        judgment_from_protobuf(b"\x08\x63")
+
+
+def _schema_messages(message, seen):
+    if message.name not in seen:
+        seen[message.name] = message
+        for field in message.fields:
+            if isinstance(field.kind, _Message):
+                _schema_messages(field.kind, seen)
+    return seen
+
+
+def test_proto_file_matches_codec_schema():
+    text = PROTO_SCHEMA_PATH.read_text(encoding="utf-8")
+    assert "package normcore.v1;" in text
+    for name, message in _schema_messages(_JUDGMENT, {}).items():
+        body = re.search(rf"message {name} \{{(.*?)\}}", text, re.S).group(1)
+        declared = re.findall(r"^\s*(repeated |optional )?(\w+) (\w+) = (\d+);", body, re.M)
+        expected = []
+        for field in message.fields:
+            label = "repeated " if field.repeated else "optional " if field.optional else ""
+            if isinstance(field.kind, _Message):
+                kind = field.kind.name
+            else:
+                kind = field.kind if isinstance(field.kind, str) else field.kind.__name__
+            expected.append((label, kind, field.name, str(field.number)))
+        assert declared == expected, name
+        if name == "AdmissibilityJudgment":
+            assert [f[2] for f in declared] == list(AdmissibilityJudgment.model_fields)
+
+    for enum in (AdmissibilityStatus, RiskTier, GateDecision):
+        body = re.search(rf"enum {enum.__name__} \{{(.*?)\}}", text, re.S).group(1)
+        members = re.findall(r"(\w+) = (\d+);", body)
+        prefix = re.sub(r"(?<!^)([A-Z])", r"_\1", enum.__name__).upper() + "_"
+        assert members == [(prefix + "UNSPECIFIED", "0")] + [
+            (prefix + member.name, str(index)) for index, member in enumerate(enum, 1)
+        ]
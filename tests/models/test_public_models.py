@@ -74,3 +74,35 @@ def test_links_models_defaults():
 def test_models_init_exports():
     # __all__ paths are wired by importing from normcore.models
     assert isinstance(TextSpeechAct(text="x"), AssistantSpeechAct.__args__)
+
+
+def test_judgment_str_is_concise_human_summary():
+    evaluation = StatementEvaluation(
+        statement_id="final_response",
+        statement="We should deploy now.",
+        modality="assertive",
+        license=set(),
+        status=AdmissibilityStatus.VIOLATES_NORM,
+        violated_axiom="A5",
+        explanation="Assertive statement without sufficient grounding",
+        recommended_modality="conditional",
+    )
+    judgment = AdmissibilityJudgment(
+        status=AdmissibilityStatus.VIOLATES_NORM,
+        licensed=False,
+        can_retry=True,
+        statement_evaluations=[evaluation],
+        explanation="Violated axioms: ['A5']",
+        warnings=["Invalid grounds ignored"],
+    )
+    assert str(evaluation) == (
+        "[violates_norm] final_response (assertive): We should deploy now.\n"
+        "  A5: Assertive statement without sufficient grounding\n"
+        "  recommended modality: conditional"
+    )
+    assert str(judgment).splitlines() == [
+        "violates_norm (gate: allow, risk: low, confidence: 1.00)",
+        "Violated axioms: ['A5']",
+        *str(evaluation).splitlines(),
+        "warning: Invalid grounds ignored",
+    ]
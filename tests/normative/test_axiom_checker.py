@@ -1,3 +1,14 @@
+import pytest
+
+from normcore import evaluate
+from normcore.evaluator import AdmissibilityEvaluator
+from normcore.normative import (
+    Axiom,
+    AxiomRegistry,
+    RuleAxiom,
+    register_axiom,
+    unregister_axiom,
+)
 from normcore.normative.axiom_checker import AxiomChecker
 from normcore.normative.models import (
     EvaluationStatus,
@@ -9,6 +20,7 @@ from normcore.normative.models import (
     Source,
     Statement,
     Status,
+    Uncertainty,
 )
 
 
@@ -79,10 +91,22 @@ def test_a4_triggers_when_no_grounding_after_other_checks():
 def test_descriptive_requires_factual_grounding():
     checker = AxiomChecker()
     statement = _statement(Modality.DESCRIPTIVE)
-    grounded = checker.check(statement, License(set()), GroundSet([_node()]), task_goal="goal")
+    license = License(permitted_modalities={Modality.DESCRIPTIVE, Modality.REFUSAL})
+    grounded = checker.check(statement, license, GroundSet([_node()]), task_goal="goal")
     assert grounded.status == EvaluationStatus.ACCEPTABLE
-    ungrounded = checker.check(statement, License(set()), GroundSet([]), task_goal="goal")
+    refusal_only = License(permitted_modalities={Modality.REFUSAL})
+    ungrounded = checker.check(statement, refusal_only, GroundSet([]), task_goal="goal")
     assert ungrounded.status == EvaluationStatus.UNSUPPORTED
+    assert ungrounded.violated_axiom == "A4"
+
+
+def test_descriptive_on_weak_grounding_is_conditionally_acceptable():
+    checker = AxiomChecker()
+    statement = _statement(Modality.DESCRIPTIVE)
+    license = License(permitted_modalities={Modality.CONDITIONAL, Modality.REFUSAL})
+    weak = GroundSet([_node(strength="weak")])
+    result = checker.check(statement, license, weak, task_goal="goal")
+    assert result.status == EvaluationStatus.CONDITIONALLY_ACCEPTABLE
 
 
 def test_default_accepts_when_license_permits():
@@ -91,3 +115,132 @@ def test_default_accepts_when_license_permits():
     license = License(permitted_modalities={Modality.ASSERTIVE})
     result = checker.check(statement, license, GroundSet([_node()]), task_goal="goal")
     assert result.status == EvaluationStatus.ACCEPTABLE
+
+
+def test_hedged_assertive_is_held_to_conditional_license():
+    checker = AxiomChecker()
+    statement = _statement(Modality.ASSERTIVE)
+    statement.uncertainty = Uncertainty(markers=["likely"], probability=0.7)
+    weak = License({Modality.CONDITIONAL, Modality.REFUSAL})
+
+    result = checker.check(statement, weak, GroundSet([_node(strength="weak")]), task_goal="goal")
+    assert result.status == EvaluationStatus.CONDITIONALLY_ACCEPTABLE
+
+    result = checker.check(statement, License({Modality.REFUSAL}), GroundSet([]), task_goal="goal")
+    assert result.status == EvaluationStatus.UNSUPPORTED
+    assert result.violated_axiom == "A4"
+
+
+def test_personalized_conditional_without_user_context_violates_a9():
+    checker = AxiomChecker()
+    statement = _statement(Modality.CONDITIONAL, conditions=["for you"])
+    statement.personalized = True
+    refusal_only = License(permitted_modalities={Modality.REFUSAL})
+    result = checker.check(statement, refusal_only, GroundSet([_node()]), task_goal="goal")
+    assert result.status == EvaluationStatus.UNSUPPORTED
+    assert result.violated_axiom == "A9"
+
+    with_context = License(permitted_modalities={Modality.CONDITIONAL, Modality.REFUSAL})
+    result = checker.check(statement, with_context, GroundSet([_node()]), task_goal="goal")
+    assert result.status == EvaluationStatus.CONDITIONALLY_ACCEPTABLE
+
+
+def _requires_pricing_ground(statement, context):
+    if "$" not in statement.raw_text:
+        return None
+    ground_ids = [node.semantic_id or node.id for node in context.ground_set.nodes]
+    if any(ground_id.startswith("pricing") for ground_id in ground_ids):
+        return None
+    return PRICING.violation("Pricing claim without a pricing ground")
+
+
+PRICING = RuleAxiom(
+    "ORG1",
+    "Pricing claims require a pricing ground",
+    EvaluationStatus.VIOLATES_NORM,
+    _requires_pricing_ground,
+)
+
+
+def test_default_registry_holds_builtin_axioms_in_order():
+    assert AxiomRegistry().codes() == ["I1", "A6", "A4", "A5", "A9", "A7", "A8"]
+    assert isinstance(PRICING, Axiom)
+
+
+def test_registered_axioms_follow_a6_unless_placed():
+    registry = AxiomRegistry()
+    registry.register(PRICING)
+    assert registry.codes() == ["I1", "A6", "ORG1", "A4", "A5", "A9", "A7", "A8"]
+
+    registry.unregister("ORG1")
+    registry.register(PRICING, after="A7")
+    assert registry.codes()[-2:] == ["ORG1", "A8"]
+
+    with pytest.raises(ValueError, match="Unknown axiom 'A42'"):
+        registry.register(PRICING, before="A42")
+    with pytest.raises(TypeError):
+        registry.register(object())
+
+
+def test_unregistered_builtin_no_longer_decides():
+    registry = AxiomRegistry()
+    registry.unregister("A5")
+    statement = _statement(Modality.ASSERTIVE)
+    license = License(permitted_modalities={Modality.REFUSAL})
+
+    result = AxiomChecker(registry).check(statement, license, GroundSet([_node()]), task_goal="g")
+
+    assert "A5" not in registry
+    assert result.status == EvaluationStatus.UNDERDETERMINED
+
+
+def test_formability_and_attribution_are_registry_axioms():
+    checker = AxiomChecker()
+    statement = _statement(Modality.ASSERTIVE)
+    license = License(permitted_modalities={Modality.ASSERTIVE})
+    ground_set = GroundSet([_node()])
+
+    ill_formed = checker.check(
+        statement, license, ground_set, task_goal="g", structure_issues=["fragment"]
+    )
+    assert (ill_formed.status, ill_formed.violated_axiom) == (EvaluationStatus.ILL_FORMED, "I1")
+
+    # A8 refines a passing verdict only
+    result = checker.check(statement, license, ground_set, task_goal="g", required_citations=2)
+    assert (result.status, result.violated_axiom) == (
+        EvaluationStatus.INSUFFICIENT_CITATIONS,
+        "A8",
+    )
+    unlicensed = License(permitted_modalities={Modality.REFUSAL})
+    result = checker.check(statement, unlicensed, ground_set, task_goal="g", required_citations=2)
+    assert result.violated_axiom == "A5"
+
+    checker.registry.unregister("A8")
+    result = checker.check(statement, license, ground_set, task_goal="g", required_citations=2)
+    assert result.status == EvaluationStatus.ACCEPTABLE
+
+
+def test_register_axiom_adds_to_checkers_created_afterwards():
+    register_axiom(PRICING)
+    try:
+        assert "ORG1" in AxiomChecker().registry
+    finally:
+        unregister_axiom("ORG1")
+    assert "ORG1" not in AxiomChecker().registry
+
+
+@pytest.mark.parametrize(
+    "citation_key,ground_id,status",
+    [("pricing", "pricing_sheet_2026", "acceptable"), ("blog", "blog_post", "violates_norm")],
+)
+def test_evaluator_applies_organization_axiom(citation_key, ground_id, status):
+    registry = AxiomRegistry()
+    registry.register(PRICING)
+    evaluator = AdmissibilityEvaluator.builder().axiom_registry(registry).build()
+    text = f"You should upgrade to the Pro plan for $20 per month [@{citation_key}]."
+    grounds = [{"citation_key": citation_key, "ground_id": ground_id}]
+
+    assert evaluate(agent_output=text, grounds=grounds).status == "acceptable"
+    judgment = evaluate(agent_output=text, grounds=grounds, evaluator=evaluator)
+    assert judgment.status == status
+    assert judgment.violated_axioms == ([] if status == "acceptable" else ["ORG1"])
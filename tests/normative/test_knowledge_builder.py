@@ -1,8 +1,11 @@
 import json
 
+from normcore.citations.grounds import Ground
+from normcore.models.links import LinkRole
 from normcore.models.messages import ToolResultSpeechAct
 from normcore.normative.knowledge_builder import KnowledgeStateBuilder
-from normcore.normative.models import Scope, Source, Status
+from normcore.normative.license_deriver import LicenseDeriver
+from normcore.normative.models import GroundSet, Modality, Scope, Source, Status
 
 
 def _tool_result(tool_name: str, result_text: str) -> ToolResultSpeechAct:
@@ -58,3 +61,174 @@ def test_stable_id_fragment_is_deterministic():
     builder = KnowledgeStateBuilder()
     value = "same-input"
     assert builder._stable_id_fragment(value) == builder._stable_id_fragment(value)
+
+
+def test_nodes_carry_parsed_tool_result_payload():
+    builder = KnowledgeStateBuilder()
+    node = builder._tool_result_to_knowledge(
+        _tool_result("get_issue", json.dumps({"issue_id": "123", "points": 5}))
+    )
+    assert node.payload == {"issue_id": "123", "points": 5}
+
+    plain = builder._tool_result_to_knowledge(_tool_result("get_weather", "sunny, 21C"))
+    assert plain.payload is None
+
+
+def test_array_nodes_carry_their_own_item_payload():
+    builder = KnowledgeStateBuilder()
+    payload = json.dumps([{"task_key": "T-1", "n": 1}, {"note": "no id"}, {"task_key": "T-2"}])
+    nodes = builder._tool_result_to_knowledge(_tool_result("search_tasks", payload))
+    assert [(n.semantic_id, n.payload) for n in nodes] == [
+        ("task_T-1", {"task_key": "T-1", "n": 1}),
+        ("task_T-2", {"task_key": "T-2"}),
+    ]
+
+
+def test_ground_set_exposes_payloads():
+    builder = KnowledgeStateBuilder()
+    node = builder._tool_result_to_knowledge(_tool_result("get_issue", '{"issue_id": "9"}'))
+    ground_set = GroundSet(nodes=[node])
+    assert ground_set.payload_for("issue_9") == {"issue_id": "9"}
+    assert ground_set.payload_for("missing") is None
+    assert ground_set.payloads() == {node.id: {"issue_id": "9"}}
+
+
+def _summary_ground(ground_id: str, derived_from: list[str]) -> Ground:
+    return Ground(citation_key=ground_id, ground_id=ground_id, derived_from=derived_from)
+
+
+def test_derived_ground_with_valid_chain_stays_strong():
+    builder = KnowledgeStateBuilder()
+    tool_node = builder._tool_result_to_knowledge(_tool_result("get_issue", '{"issue_id": "9"}'))
+    grounds = [
+        _summary_ground("digest", ["summary"]),
+        _summary_ground("summary", ["issue_9"]),
+    ]
+    nodes = builder.materialize_external_grounds([tool_node], grounds)
+    by_id = {n.semantic_id: n for n in nodes}
+    assert by_id["digest"].strength == "strong"
+    assert by_id["digest"].derived_from == ["summary"]
+    assert by_id["summary"].derived_from == ["issue_9"]
+
+
+def test_derived_ground_with_unknown_parent_is_weak():
+    builder = KnowledgeStateBuilder()
+    nodes = builder.materialize_external_grounds([], [_summary_ground("summary", ["missing"])])
+    assert nodes[0].strength == "weak"
+    assert nodes[0].derived_from == ["missing"]
+
+
+def test_derivation_cycle_is_weak():
+    builder = KnowledgeStateBuilder()
+    grounds = [_summary_ground("a", ["b"]), _summary_ground("b", ["a"])]
+    nodes = builder.materialize_external_grounds([], grounds)
+    assert [n.strength for n in nodes] == ["weak", "weak"]
+
+
+def test_personalization_ground_cannot_be_a_parent():
+    builder = KnowledgeStateBuilder()
+    grounds = [
+        Ground(citation_key="pref", ground_id="pref", role=LinkRole.CONTEXTUALIZES),
+        _summary_ground("summary", ["pref"]),
+    ]
+    nodes = builder.materialize_external_grounds([], grounds)
+    assert [(n.semantic_id, n.strength) for n in nodes] == [("summary", "weak")]
+
+
+def test_tool_call_arguments_become_contextual_node():
+    builder = KnowledgeStateBuilder()
+    result = ToolResultSpeechAct(
+        tool_name="get_weather",
+        tool_call_id="call_w",
+        arguments={"city": "New York"},
+        result_text=json.dumps({"temp_c": 21}),
+    )
+    nodes, refs = builder.build_with_references([result])
+    assert [node.scope for node in nodes] == [Scope.FACTUAL, Scope.CONTEXTUAL]
+    context = nodes[1]
+    assert context.payload == {"city": "New York"}
+    assert context.source == Source.OBSERVED
+    assert context.strength == "strong"
+    # Citing the call cites its result, not its query.
+    assert refs == {"call_w": [nodes[0].semantic_id or nodes[0].id]}
+
+
+def test_arguments_of_filtered_tool_are_dropped():
+    builder = KnowledgeStateBuilder()
+    result = ToolResultSpeechAct(
+        tool_name="save_memory", arguments={"note": "likes jazz"}, result_text="{}"
+    )
+    assert builder.build([result]) == []
+
+
+def test_contextual_arguments_alone_do_not_license():
+    builder = KnowledgeStateBuilder()
+    result = ToolResultSpeechAct(
+        tool_name="get_weather", arguments={"city": "New York"}, result_text="{}"
+    )
+    context = builder._arguments_to_context(result)
+    license = LicenseDeriver().derive(GroundSet(nodes=[context]))
+    assert license.permitted_modalities == {Modality.REFUSAL}
+
+
+def test_user_context_from_stated_preferences_and_user_facts():
+    builder = KnowledgeStateBuilder()
+    nodes = builder.build_user_context(
+        ["Hi! I prefer quiet hotels. My budget is $200 a night. Which one?"],
+        [
+            Ground(citation_key="pref", ground_id="pref", role=LinkRole.CONTEXTUALIZES),
+            Ground(citation_key="doc", ground_id="doc"),
+        ],
+    )
+    assert [node.payload for node in nodes] == [
+        {"text": "I prefer quiet hotels."},
+        {"text": "My budget is $200 a night."},
+        None,
+    ]
+    assert nodes[2].id == "pref"
+    assert {(node.scope, node.source) for node in nodes} == {(Scope.CONTEXTUAL, Source.EXPLICIT)}
+
+
+def test_strong_confidence_threshold_downgrades_low_confidence_nodes():
+    builder = KnowledgeStateBuilder(strong_confidence_threshold=0.8)
+    nodes, _ = builder.from_precomputed(
+        [
+            {"id": "sure", "source": "observed", "status": "confirmed", "confidence": 0.9},
+            {"id": "unsure", "source": "observed", "status": "confirmed", "confidence": 0.5},
+        ]
+    )
+    assert [n.strength for n in nodes] == ["strong", "weak"]
+
+    grounds = [Ground(citation_key="doc", ground_id="doc", confidence=0.5)]
+    (node,) = builder.materialize_external_grounds([], grounds)
+    assert (node.confidence, node.strength) == (0.5, "weak")
+
+
+def test_strength_grading_never_upgrades():
+    builder = KnowledgeStateBuilder(strong_confidence_threshold=0.5)
+    nodes, _ = builder.from_precomputed(
+        [{"id": "n", "source": "observed", "status": "confirmed", "strength": "weak"}]
+    )
+    assert nodes[0].strength == "weak"
+
+
+def test_plaintext_result_yields_resolvable_semantic_ids():
+    builder = KnowledgeStateBuilder()
+    text = (
+        "Top results:\n"
+        "- Login fails on Safari (PROJ-42) https://jira.example.com/browse/PROJ-42.\n"
+        "order_id: 991\n"
+    )
+    nodes = builder._tool_result_to_knowledge(_tool_result("search", text))
+    assert [node.semantic_id for node in nodes] == [
+        "PROJ-42",
+        "https://jira.example.com/browse/PROJ-42",
+        "order_991",
+    ]
+    assert nodes[2].payload == {"text": "order_id: 991"}
+
+
+def test_plaintext_result_without_ids_stays_single_node():
+    builder = KnowledgeStateBuilder()
+    node = builder._tool_result_to_knowledge(_tool_result("tail_log", "all workers healthy"))
+    assert node.semantic_id is None
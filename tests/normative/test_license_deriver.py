@@ -101,3 +101,66 @@ def test_derive_with_trace_includes_mode_and_nodes():
     assert trace["ground_set_size"] == 1
     assert trace["nodes"][0]["id"] == "n1"
     assert Modality.ASSERTIVE in license.permitted_modalities
+
+
+def test_recommend_fallback_picks_first_permitted_modality():
+    deriver = LicenseDeriver()
+    weak = deriver.derive(GroundSet(nodes=[_node("k1", strength="weak")]))
+    empty = deriver.derive(GroundSet(nodes=[]))
+
+    assert deriver.recommend_fallback(Modality.ASSERTIVE, weak) == Modality.CONDITIONAL
+    assert deriver.recommend_fallback(Modality.ASSERTIVE, empty) == Modality.REFUSAL
+    assert deriver.recommend_fallback(Modality.CONDITIONAL, empty) == Modality.REFUSAL
+    assert deriver.recommend_fallback(Modality.REFUSAL, empty) is None
+    assert deriver.recommend_fallback(None, empty) is None
+
+
+def test_recommend_fallback_uses_caller_table():
+    deriver = LicenseDeriver(modality_fallbacks={Modality.ASSERTIVE: [Modality.CONDITIONAL]})
+    empty = deriver.derive(GroundSet(nodes=[]))
+
+    assert deriver.recommend_fallback(Modality.ASSERTIVE, empty) is None
+    assert deriver.recommend_fallback(Modality.CONDITIONAL, empty) == Modality.REFUSAL
+
+
+def test_descriptive_license_follows_factual_strength():
+    deriver = LicenseDeriver()
+    mixed = GroundSet(nodes=[_node("n1"), _node("n2", strength="weak")])
+    strong = deriver.derive_descriptive(mixed)
+    assert strong.permitted_modalities == {
+        Modality.DESCRIPTIVE,
+        Modality.CONDITIONAL,
+        Modality.REFUSAL,
+    }
+    weak = deriver.derive_descriptive(GroundSet(nodes=[_node("n1", strength="weak")]))
+    assert weak.permitted_modalities == {Modality.CONDITIONAL, Modality.REFUSAL}
+    assert deriver.derive_descriptive(GroundSet(nodes=[])).permitted_modalities == {
+        Modality.REFUSAL
+    }
+
+
+def test_personalized_license_requires_user_context():
+    deriver = LicenseDeriver()
+    factual = GroundSet(nodes=[_node("n1")])
+    assert deriver.derive_personalized(factual).permitted_modalities == {Modality.REFUSAL}
+
+    preference = KnowledgeNode(
+        id="user_pref",
+        source=Source.EXPLICIT,
+        status=Status.CONFIRMED,
+        confidence=1.0,
+        scope=Scope.CONTEXTUAL,
+    )
+    with_context = deriver.derive_personalized(GroundSet(nodes=[_node("n1"), preference]))
+    assert with_context.permitted_modalities == {
+        Modality.ASSERTIVE,
+        Modality.CONDITIONAL,
+        Modality.REFUSAL,
+    }
+    context_only = deriver.derive_personalized(GroundSet(nodes=[preference]))
+    assert context_only.permitted_modalities == {Modality.CONDITIONAL, Modality.REFUSAL}
+    # Tool call arguments are context, but not about the user
+    arguments = _node("args", scope=Scope.CONTEXTUAL)
+    assert deriver.derive_personalized(GroundSet(nodes=[arguments])).permitted_modalities == {
+        Modality.REFUSAL
+    }
@@ -1,5 +1,21 @@
-from normcore.normative.modality_detector import ModalityDetector
-from normcore.normative.models import Modality, Statement
+import pytest
+
+from normcore import evaluate
+from normcore.evaluator import AdmissibilityEvaluator
+from normcore.normative.modality_detector import ModalityDetection, ModalityDetector
+from normcore.normative.models import ModalReading, Modality, Statement
+
+
+class HedgeLexiconDetector:
+    """Domain lexicon: "consider" softens a recommendation to a conditional one."""
+
+    def __init__(self):
+        self.fallback = ModalityDetector()
+
+    def detect(self, text):
+        if "consider" in text.lower():
+            return Modality.CONDITIONAL
+        return self.fallback.detect(text)
 
 
 def test_refusal_has_highest_priority():
@@ -72,3 +88,142 @@ def test_detect_with_conditions_does_not_extract_for_assertive_tail_if():
     detector.detect_with_conditions(statement)
     assert statement.modality == Modality.ASSERTIVE
     assert statement.conditions == []
+
+
+def test_first_person_commitment_is_commissive():
+    detector = ModalityDetector()
+    assert detector.detect("I will file the ticket and email you by Friday.") == Modality.COMMISSIVE
+    assert detector.detect("I'll restart the deployment now.") == Modality.COMMISSIVE
+    assert detector.detect("We are going to roll back tonight.") == Modality.COMMISSIVE
+
+
+def test_negated_commitment_stays_refusal():
+    detector = ModalityDetector()
+    assert detector.detect("I will not merge this without review.") == Modality.REFUSAL
+
+
+def test_modal_reading_distinguishes_inference_from_obligation():
+    detector = ModalityDetector()
+    assert detector.detect_reading("The build must have failed.") == ModalReading.EPISTEMIC
+    assert detector.detect_reading("It must be due to the stale cache.") == ModalReading.EPISTEMIC
+    assert detector.detect_reading("You must rotate the key.") == ModalReading.DEONTIC
+    assert detector.detect_reading("You should have enabled 2FA.") == ModalReading.DEONTIC
+    assert detector.detect_reading("Restart the service.") is None
+
+
+def test_detect_with_conditions_sets_reading():
+    detector = ModalityDetector()
+    statement = Statement(
+        id="s1", subject="agent", predicate="participation", raw_text="The job must have crashed."
+    )
+    detector.detect_with_conditions(statement)
+    assert statement.modality == Modality.ASSERTIVE
+    assert statement.reading == ModalReading.EPISTEMIC
+
+
+def test_information_seeking_question_is_clarification():
+    detector = ModalityDetector()
+    assert detector.detect("Which environment are you deploying to?") == Modality.CLARIFICATION
+    assert detector.detect("Could you confirm the cluster name?") == Modality.CLARIFICATION
+
+
+def test_question_shielding_a_claim_is_not_clarification():
+    detector = ModalityDetector()
+    text = "Which env? You should use blue-green."
+    assert detector.detect(text) == Modality.ASSERTIVE
+
+
+def test_uncertainty_extracts_hedges_and_probabilities():
+    detector = ModalityDetector()
+
+    verbal = detector.extract_uncertainty("The outage is almost certainly the cache.")
+    assert verbal.markers == ["almost certainly"]
+    assert verbal.probability == 0.95
+
+    explicit = detector.extract_uncertainty("There is a 30% chance the job fails.")
+    assert explicit.markers == ["30% chance"]
+    assert explicit.probability == 0.3
+
+    approximate = detector.extract_uncertainty("The migration takes roughly 5 minutes.")
+    assert approximate.markers == ["roughly"]
+    assert approximate.probability is None
+
+
+def test_plain_percentages_and_flat_claims_are_not_hedges():
+    detector = ModalityDetector()
+    assert detector.extract_uncertainty("CPU usage is at 30%.") is None
+    assert detector.extract_uncertainty("The cache is the cause.") is None
+
+
+def test_attribution_detects_reported_and_quoted_speech():
+    detector = ModalityDetector()
+    cases = {
+        "The vendor claims you should upgrade immediately.": ("The vendor", "claims"),
+        "According to the AWS docs, you should use gp3 volumes.": ("the AWS docs", "according to"),
+        '"Upgrade now," said the vendor.': ("the vendor", "said"),
+        "Our security team warned: rotate the keys today.": ("Our security team", "warned"),
+    }
+    for text, (source, marker) in cases.items():
+        attribution = detector.extract_attribution(text)
+        assert (attribution.source, attribution.marker) == (source, marker), text
+
+
+def test_own_claims_are_not_attributed():
+    detector = ModalityDetector()
+    for text in (
+        "You should upgrade immediately.",
+        "I think you should upgrade.",
+        "You said we should upgrade.",
+        "It says you should upgrade.",
+        "According to your preferences, the upgrade is better.",
+    ):
+        assert detector.extract_attribution(text) is None, text
+
+
+def test_personalization_framing_is_flagged():
+    detector = ModalityDetector()
+    for text, expected in (
+        ("Hotel H1 is the better choice for you.", True),
+        ("Given your constraints, the night train works best.", True),
+        ("If your goal is speed, Hotel H1 is better for you.", False),
+        ("Hotel H1 is the better choice.", False),
+    ):
+        statement = detector.detect_with_conditions(
+            Statement(id="s", subject="s", predicate="p", raw_text=text)
+        )
+        assert statement.personalized is expected, text
+
+
+def test_default_detector_implements_modality_detection():
+    assert isinstance(ModalityDetector(), ModalityDetection)
+    assert isinstance(HedgeLexiconDetector(), ModalityDetection)
+
+
+def test_plugged_detector_decides_modality_and_indicators_still_apply():
+    statement = Statement(
+        id="s1",
+        subject="agent",
+        predicate="participation",
+        raw_text="Maybe consider a restart if the disk is full.",
+    )
+
+    ModalityDetector().detect_with_conditions(statement, HedgeLexiconDetector())
+
+    assert statement.modality == Modality.CONDITIONAL
+    assert statement.conditions == ["the disk is full."]
+    assert statement.uncertainty is not None
+
+
+def test_evaluator_uses_a_plugged_in_modality_detector():
+    evaluator = AdmissibilityEvaluator.builder().modality_detector(HedgeLexiconDetector()).build()
+    text = "You should consider a rollback."
+
+    assert evaluate(agent_output=text).status == "violates_norm"
+    judgment = evaluate(agent_output=text, evaluator=evaluator)
+    assert judgment.status == "conditionally_acceptable"
+    assert judgment.statement_evaluations[0].modality == "conditional"
+
+
+def test_evaluator_rejects_objects_without_detect():
+    with pytest.raises(TypeError, match="modality_detector must define detect"):
+        AdmissibilityEvaluator(modality_detector=object())
@@ -1,4 +1,19 @@
-from normcore.normative.statement_extractor import StatementExtractor
+import pytest
+
+from normcore import evaluate
+from normcore.evaluator import AdmissibilityEvaluator
+from normcore.normative.models import Modality, Statement
+from normcore.normative.statement_extractor import StatementExtraction, StatementExtractor
+
+
+class OrderSetExtractor:
+    """One statement per ``;``-separated clinical order, keeping the dosing line intact."""
+
+    def extract(self, text):
+        return [
+            Statement(id=f"order:{index}", subject="agent", predicate="orders", raw_text=part)
+            for index, part in enumerate(filter(None, map(str.strip, text.split(";"))), 1)
+        ]
 
 
 def test_extract_empty_text_returns_empty():
@@ -43,3 +58,166 @@ def test_first_person_would_not_is_kept_for_refusal_eval():
     statements = extractor.extract(text)
     assert len(statements) == 1
     assert "would not publish" in statements[0].raw_text.lower()
+
+
+def test_configured_protocol_phrases_are_stripped():
+    extractor = StatementExtractor(
+        protocol_prefix_phrases=["Thanks for contacting Acme Support"],
+        protocol_suffix_phrases=["Best regards", "Hope this helps"],
+    )
+    text = (
+        "Thanks for contacting Acme Support! You should restart the router. "
+        "Hope this helps. Best regards,\nAcme Bot"
+    )
+    statements = extractor.extract(text)
+    assert len(statements) == 1
+    assert statements[0].raw_text == "You should restart the router."
+
+
+def test_configured_protocol_phrases_extend_builtin_patterns():
+    text = "Hello! You should restart the router. Cheers, Acme Bot"
+    assert StatementExtractor().extract(text)[0].raw_text.endswith("Acme Bot")
+
+    extractor = StatementExtractor(protocol_suffix_phrases=["cheers"])
+    statements = extractor.extract(text)
+    assert statements[0].raw_text == "You should restart the router."
+
+
+def test_configured_suffix_phrase_requires_sentence_boundary():
+    extractor = StatementExtractor(protocol_suffix_phrases=["best regards"])
+    text = "You should send it with best regards to the team."
+    statements = extractor.extract(text)
+    assert statements[0].raw_text == text
+
+
+def test_expressive_sentences_are_stripped_at_any_position():
+    extractor = StatementExtractor()
+    text = (
+        "I'm sorry to hear that. If the router is down, you should restart it. "
+        "I apologize for the confusion."
+    )
+    statements = extractor.extract(text)
+    assert statements[0].raw_text == "If the router is down, you should restart it."
+
+    statements = extractor.extract("Great choice! Postgres is better for analytics.")
+    assert statements[0].raw_text == "Postgres is better for analytics."
+
+
+def test_expressive_stripping_preserves_sentence_separators():
+    extractor = StatementExtractor()
+    text = "You should rotate the key.\n\nThanks for your patience.\n\nIt blocks the deploy."
+    assert extractor._strip_expressive_sentences(text) == (
+        "You should rotate the key.\n\nIt blocks the deploy."
+    )
+
+
+def test_expressive_sentence_with_normative_content_is_kept():
+    extractor = StatementExtractor()
+    statements = extractor.extract("Sorry, you should restart it.")
+    assert statements[0].raw_text == "Sorry, you should restart it."
+
+
+def test_clarification_question_is_kept_but_offers_are_protocol():
+    extractor = StatementExtractor()
+    statements = extractor.extract("Which environment are you deploying to?")
+    assert statements[0].raw_text == "Which environment are you deploying to?"
+
+    assert extractor.extract("Would you like me to check the logs?") == []
+    assert extractor.extract("Do you want me to restart it?") == []
+
+
+def test_markdown_table_cells_become_statements():
+    extractor = StatementExtractor()
+    text = (
+        "Here is a comparison.\n\n"
+        "| Database | Verdict | Notes |\n"
+        "| --- | :---: | --- |\n"
+        "| Postgres | Recommended | Best for analytics |\n"
+        "| SQLite | Avoid | Embedded only |\n"
+    )
+    statements = extractor.extract(text)
+
+    by_id = {s.id: s for s in statements}
+    assert set(by_id) == {"table:1:1:2", "table:1:1:3", "table:1:2:2"}
+    assert by_id["table:1:1:2"].raw_text == "Recommended"
+    assert by_id["table:1:1:2"].subject == "Postgres"
+    assert by_id["table:1:1:2"].predicate == "Verdict"
+
+
+def test_table_is_cut_out_of_prose_statement():
+    extractor = StatementExtractor()
+    text = "You should pick Postgres.\n\n| Option | Verdict |\n|---|---|\n| Postgres | best |"
+    statements = extractor.extract(text)
+    assert [s.id for s in statements] == ["final_response", "table:1:1:2"]
+    assert statements[0].raw_text == "You should pick Postgres."
+
+
+def test_code_blocks_are_excluded_by_default():
+    extractor = StatementExtractor()
+    text = "You should clean the volume.\n\n```bash\nrm -rf /data\n```\n"
+    statements = extractor.extract(text)
+    assert [s.id for s in statements] == ["final_response"]
+    assert statements[0].raw_text == "You should clean the volume."
+
+
+def test_executable_code_lines_are_flagged_as_assertive():
+    extractor = StatementExtractor(flag_executable_code=True)
+    text = (
+        "Clean up like this:\n\n"
+        "```console\n$ rm -rf /data\nremoved '/data'\n$ systemctl \\\n  restart db\n```\n\n"
+        "```python\nprint('hi')\n```\n"
+    )
+    statements = extractor.extract(text)
+
+    assert [(s.id, s.raw_text) for s in statements] == [
+        ("code:1:1", "rm -rf /data"),
+        ("code:1:2", "systemctl restart db"),
+    ]
+    assert all(s.modality == Modality.ASSERTIVE for s in statements)
+
+
+def test_sentence_mode_emits_one_statement_per_sentence_and_bullet():
+    extractor = StatementExtractor(split_sentences=True)
+    text = (
+        "Hello! The deploy failed on main [@ci]. You should roll back, e.g. revert it.\n"
+        "Then retry.\n\n"
+        "- Pin the runner version.\n"
+        "1) Check the logs.\n"
+        "---\n"
+        "Let me know if you need anything else."
+    )
+    statements = extractor.extract(text)
+
+    assert [(s.id, s.raw_text) for s in statements] == [
+        ("s1", "The deploy failed on main [@ci]."),
+        ("s2", "You should roll back, e.g. revert it."),
+        ("s3", "Then retry."),
+        ("s4", "Pin the runner version."),
+        ("s5", "Check the logs."),
+    ]
+
+
+def test_sentence_mode_keeps_single_sentence_text_unchanged():
+    statements = StatementExtractor(split_sentences=True).extract("You should pick Postgres.")
+    assert [(s.id, s.raw_text) for s in statements] == [("s1", "You should pick Postgres.")]
+
+
+def test_keyword_extractor_implements_statement_extraction():
+    assert isinstance(StatementExtractor(), StatementExtraction)
+    assert isinstance(OrderSetExtractor(), StatementExtraction)
+
+
+def test_evaluator_uses_a_plugged_in_extractor():
+    evaluator = AdmissibilityEvaluator.builder().extractor(OrderSetExtractor()).build()
+
+    judgment = evaluate(
+        agent_output="Start amoxicillin 500 mg; you should recheck in 48 h", evaluator=evaluator
+    )
+
+    assert [item.statement_id for item in judgment.statement_evaluations] == ["order:1", "order:2"]
+    assert judgment.statement_evaluations[1].modality == "assertive"
+
+
+def test_evaluator_rejects_objects_without_extract():
+    with pytest.raises(TypeError, match="extractor must define extract"):
+        AdmissibilityEvaluator(extractor=object())
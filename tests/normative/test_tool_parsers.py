@@ -0,0 +1,95 @@
+import json
+
+import pytest
+
+from normcore import evaluate
+from normcore.models.messages import ToolResultSpeechAct
+from normcore.normative import (
+    KnowledgeNode,
+    Scope,
+    Source,
+    Status,
+    ToolResultParserRegistry,
+    register_tool_result_parser,
+    unregister_tool_result_parser,
+)
+from normcore.normative.knowledge_builder import KnowledgeStateBuilder
+
+
+class GraphQLOrdersParser:
+    """Maps {"data": {"orders": [{"number": ...}]}} to one node per order."""
+
+    def parse(self, tool_result):
+        orders = json.loads(tool_result.result_text)["data"]["orders"]
+        return [
+            KnowledgeNode(
+                id=f"order_node_{order['number']}",
+                source=Source.OBSERVED,
+                status=Status.CONFIRMED,
+                confidence=1.0,
+                scope=Scope.FACTUAL,
+                semantic_id=f"order_{order['number']}",
+                payload=order,
+            )
+            for order in orders
+        ]
+
+
+RESULT = json.dumps({"data": {"orders": [{"number": "A1"}, {"number": "A2"}]}})
+
+
+def _builder() -> KnowledgeStateBuilder:
+    registry = ToolResultParserRegistry()
+    registry.register("Query_Orders", GraphQLOrdersParser())
+    return KnowledgeStateBuilder(parsers=registry)
+
+
+def test_registered_parser_replaces_conventions():
+    result = ToolResultSpeechAct(tool_name="query_orders", tool_call_id="c1", result_text=RESULT)
+    nodes, refs = _builder().build_with_references([result])
+    assert [node.semantic_id for node in nodes] == ["order_A1", "order_A2"]
+    assert refs == {"c1": ["order_A1", "order_A2"]}
+
+
+def test_untrusted_result_keeps_parsed_nodes_weak():
+    result = ToolResultSpeechAct(tool_name="query_orders", result_text=RESULT, trusted=False)
+    assert {node.strength for node in _builder().build([result])} == {"weak"}
+
+
+def test_non_epistemic_tools_stay_filtered():
+    registry = ToolResultParserRegistry()
+    registry.register("save_memory", GraphQLOrdersParser())
+    builder = KnowledgeStateBuilder(parsers=registry)
+    result = ToolResultSpeechAct(tool_name="save_memory", result_text=RESULT)
+    assert builder.build([result]) == []
+
+
+def test_registry_rejects_objects_without_parse():
+    with pytest.raises(TypeError):
+        ToolResultParserRegistry().register("query_orders", object())
+
+
+def test_default_registry_is_used_by_evaluate():
+    conversation = [
+        {"role": "user", "content": "Which order ships first?"},
+        {
+            "role": "assistant",
+            "content": "",
+            "tool_calls": [
+                {
+                    "id": "call_q",
+                    "type": "function",
+                    "function": {"name": "query_orders", "arguments": "{}"},
+                }
+            ],
+        },
+        {"role": "tool", "tool_call_id": "call_q", "content": RESULT},
+        {"role": "assistant", "content": "You should ship order A1 first [@call_q]."},
+    ]
+    register_tool_result_parser("query_orders", GraphQLOrdersParser())
+    try:
+        judgment = evaluate(conversation=conversation)
+    finally:
+        unregister_tool_result_parser("query_orders")
+    assert judgment.status == "acceptable"
+    assert judgment.cited_ground_ids == ["order_A1", "order_A2"]